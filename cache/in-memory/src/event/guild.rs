@@ -18,6 +18,7 @@ impl InMemoryCache {
         if self.wants(ResourceType::CHANNEL) {
             self.0.guild_channels.insert(guild.id, HashSet::new());
             self.cache_guild_channels(guild.id, guild.channels);
+            self.cache_guild_channels(guild.id, guild.threads);
         }
 
         if self.wants(ResourceType::EMOJI) {
@@ -265,6 +266,7 @@ mod tests {
             stage_instances: Vec::new(),
             system_channel_id: None,
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
+            threads: Vec::new(),
             rules_channel_id: None,
             unavailable: false,
             verification_level: VerificationLevel::VeryHigh,
@@ -336,6 +338,7 @@ mod tests {
             stage_instances: Vec::new(),
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: None,
             verification_level: VerificationLevel::VeryHigh,
@@ -58,9 +58,14 @@ impl UpdateCache for InteractionCreate {
 mod tests {
     use super::*;
     use twilight_model::{
-        application::interaction::{
-            application_command::{CommandData, CommandInteractionDataResolved, InteractionMember},
-            ApplicationCommand, InteractionType,
+        application::{
+            command::CommandType,
+            interaction::{
+                application_command::{
+                    CommandData, CommandInteractionDataResolved, InteractionMember,
+                },
+                ApplicationCommand, InteractionType,
+            },
         },
         guild::{PartialMember, Permissions, Role},
         id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, RoleId, UserId},
@@ -76,9 +81,11 @@ mod tests {
                 channel_id: ChannelId(2),
                 data: CommandData {
                     id: CommandId(5),
+                    kind: CommandType::ChatInput,
                     name: "command name".into(),
                     options: Vec::new(),
                     resolved: Some(CommandInteractionDataResolved {
+                        attachments: Vec::new(),
                         channels: Vec::new(),
                         members: vec![InteractionMember {
                             hoisted_role: None,
@@ -88,6 +95,7 @@ mod tests {
                             premium_since: None,
                             roles: vec![RoleId(8)],
                         }],
+                        messages: Vec::new(),
                         roles: vec![Role {
                             color: 0u32,
                             hoist: false,
@@ -115,6 +123,7 @@ mod tests {
                             verified: None,
                         }],
                     }),
+                    target_id: None,
                 },
                 guild_id: Some(GuildId(3)),
                 id: InteractionId(4),
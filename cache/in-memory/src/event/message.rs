@@ -75,6 +75,10 @@ impl UpdateCache for MessageUpdate {
                 message.attachments = attachments.clone();
             }
 
+            if let Some(components) = &self.components {
+                message.components = components.clone();
+            }
+
             if let Some(content) = &self.content {
                 message.content = content.clone();
             }
@@ -151,6 +155,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
+            components: Vec::new(),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
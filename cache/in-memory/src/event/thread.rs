@@ -0,0 +1,231 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use twilight_model::{
+    channel::{thread::ThreadMember, GuildChannel},
+    gateway::payload::{
+        ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+        ThreadUpdate,
+    },
+    id::{ChannelId, GuildId},
+};
+
+impl InMemoryCache {
+    fn cache_thread(&self, thread: GuildChannel) {
+        if let Some(guild_id) = thread.guild_id() {
+            self.cache_guild_channel(guild_id, thread);
+        }
+    }
+
+    /// Update the thread member of the current user in a cached thread.
+    fn cache_thread_member(&self, thread_id: ChannelId, member: Option<ThreadMember>) {
+        if let Some(mut item) = self.0.channels_guild.get_mut(&thread_id) {
+            if let GuildChannel::Thread(ref mut thread) = item.data {
+                thread.member = member;
+            }
+        }
+    }
+
+    /// Remove the cached threads of a guild, optionally only those created in
+    /// the given parent channels.
+    fn delete_guild_threads(&self, guild_id: GuildId, parent_ids: &[ChannelId]) {
+        let channel_ids = match self.0.guild_channels.get(&guild_id) {
+            Some(channel_ids) => channel_ids.clone(),
+            None => return,
+        };
+
+        for channel_id in channel_ids {
+            let is_synced = self
+                .0
+                .channels_guild
+                .get(&channel_id)
+                .map_or(false, |item| match item.data {
+                    GuildChannel::Thread(ref thread) => {
+                        parent_ids.is_empty()
+                            || thread
+                                .parent_id
+                                .map_or(false, |parent_id| parent_ids.contains(&parent_id))
+                    }
+                    _ => false,
+                });
+
+            if is_synced {
+                self.delete_guild_channel(channel_id);
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        cache.cache_thread(self.0.clone());
+    }
+}
+
+impl UpdateCache for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        cache.delete_guild_channel(self.id);
+    }
+}
+
+impl UpdateCache for ThreadListSync {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        // The list contains all active threads of the synced channels, so
+        // threads that are no longer in it have been archived.
+        cache.delete_guild_threads(self.guild_id, &self.channel_ids);
+        cache.cache_guild_channels(self.guild_id, self.threads.iter().cloned());
+
+        for member in &self.members {
+            if let Some(thread_id) = member.id {
+                cache.cache_thread_member(thread_id, Some(member.clone()));
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Some(thread_id) = self.id {
+            cache.cache_thread_member(thread_id, Some(self.0.clone()));
+        }
+    }
+}
+
+impl UpdateCache for ThreadMembersUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Some(mut item) = cache.0.channels_guild.get_mut(&self.id) {
+            if let GuildChannel::Thread(ref mut thread) = item.data {
+                thread.member_count = self.member_count;
+            }
+        }
+
+        let current_user_id = match cache.current_user() {
+            Some(current_user) => current_user.id,
+            None => return,
+        };
+
+        if self.removed_member_ids.contains(&current_user_id) {
+            cache.cache_thread_member(self.id, None);
+        }
+
+        if let Some(member) = self
+            .added_members
+            .iter()
+            .find(|member| member.user_id == Some(current_user_id))
+        {
+            cache.cache_thread_member(self.id, Some(member.clone()));
+        }
+    }
+}
+
+impl UpdateCache for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        cache.cache_thread(self.0.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, InMemoryCache};
+    use twilight_model::{
+        channel::{thread::ThreadMember, ChannelType, GuildChannel},
+        gateway::payload::{ThreadCreate, ThreadDelete, ThreadListSync, ThreadMembersUpdate},
+        id::{ChannelId, UserId},
+    };
+
+    #[test]
+    fn test_thread_create_delete() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_thread();
+
+        cache.update(&ThreadCreate(channel));
+        assert!(cache.guild_channel(channel_id).is_some());
+        assert!(cache
+            .guild_channels(guild_id)
+            .unwrap()
+            .contains(&channel_id));
+
+        cache.update(&ThreadDelete {
+            guild_id,
+            id: channel_id,
+            kind: ChannelType::GuildPublicThread,
+            parent_id: ChannelId(2),
+        });
+        assert!(cache.guild_channel(channel_id).is_none());
+        assert!(cache.guild_channels(guild_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_thread_list_sync() {
+        let cache = InMemoryCache::new();
+        let (guild_id, text_id, text) = test::guild_channel_text();
+        let (_, channel_id, mut channel) = test::guild_channel_thread();
+        cache.cache_guild_channel(guild_id, text);
+        cache.update(&ThreadCreate(channel.clone()));
+
+        if let GuildChannel::Thread(ref mut thread) = channel {
+            thread.id = ChannelId(6);
+        }
+
+        cache.update(&ThreadListSync {
+            channel_ids: vec![text_id],
+            guild_id,
+            members: vec![ThreadMember {
+                flags: 0,
+                id: Some(ChannelId(6)),
+                join_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                user_id: Some(UserId(1)),
+            }],
+            threads: vec![channel],
+        });
+
+        assert!(cache.guild_channel(text_id).is_some());
+        assert!(cache.guild_channel(channel_id).is_none());
+        assert!(matches!(
+            cache.guild_channel(ChannelId(6)),
+            Some(GuildChannel::Thread(thread)) if thread.member.is_some()
+        ));
+    }
+
+    #[test]
+    fn test_thread_members_update() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_thread();
+        cache.update(&ThreadCreate(channel));
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id,
+            id: channel_id,
+            member_count: 3,
+            removed_member_ids: Vec::new(),
+        });
+
+        assert!(matches!(
+            cache.guild_channel(channel_id),
+            Some(GuildChannel::Thread(thread)) if thread.member_count == 3
+        ));
+    }
+}
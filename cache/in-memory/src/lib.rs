@@ -655,6 +655,12 @@ impl UpdateCache for Event {
             StageInstanceCreate(v) => c.update(v),
             StageInstanceDelete(v) => c.update(v),
             StageInstanceUpdate(v) => c.update(v),
+            ThreadCreate(v) => c.update(v),
+            ThreadDelete(v) => c.update(v),
+            ThreadListSync(v) => c.update(v),
+            ThreadMemberUpdate(v) => c.update(v),
+            ThreadMembersUpdate(v) => c.update(v),
+            ThreadUpdate(v) => c.update(v),
             TypingStart(_) => {}
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
@@ -1,5 +1,6 @@
 use serde::Serialize;
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{
@@ -31,6 +32,8 @@ pub struct CachedMessage {
     pub author: UserId,
     /// ID of the channel the message was sent in.
     pub channel_id: ChannelId,
+    /// Interactive components attached to the message.
+    pub components: Vec<Component>,
     /// Content of the message.
     pub content: String,
     /// ISO 8601 timestamp of the date the message was last edited.
@@ -82,6 +85,7 @@ impl From<Message> for CachedMessage {
             attachments: msg.attachments,
             author: msg.author.id,
             channel_id: msg.channel_id,
+            components: msg.components,
             content: msg.content,
             edited_timestamp: msg.edited_timestamp,
             embeds: msg.embeds,
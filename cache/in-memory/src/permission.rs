@@ -293,7 +293,10 @@ impl<'a> InMemoryCachePermissions<'a> {
     /// # Errors
     ///
     /// Returns a [`ChannelErrorType::ChannelUnavailable`] error type if the
-    /// guild channel is not in the cache.
+    /// guild channel is not in the cache. Permissions of threads are
+    /// calculated with the permission overwrites of their parent channel, so
+    /// this is also returned if the channel is a thread and its parent channel
+    /// is not in the cache.
     ///
     /// Returns a [`ChannelErrorType::MemberUnavailable`] error type if the
     /// member for the user in the guild is not present.
@@ -333,17 +336,43 @@ impl<'a> InMemoryCachePermissions<'a> {
             .member_roles(user_id, guild_id)
             .map_err(ChannelError::from_member_roles)?;
 
-        let overwrites = match &channel.data {
-            GuildChannel::Category(c) => &c.permission_overwrites,
-            GuildChannel::Stage(c) => &c.permission_overwrites,
-            GuildChannel::Text(c) => &c.permission_overwrites,
-            GuildChannel::Voice(c) => &c.permission_overwrites,
+        // Threads don't have their own permission overwrites and instead use
+        // those of the channel they were created in.
+        let parent;
+        let channel = if let GuildChannel::Thread(thread) = &channel.data {
+            let parent_id = thread.parent_id.ok_or(ChannelError {
+                kind: ChannelErrorType::ChannelUnavailable { channel_id },
+                source: None,
+            })?;
+
+            parent = (self.0)
+                .0
+                .channels_guild
+                .get(&parent_id)
+                .ok_or(ChannelError {
+                    kind: ChannelErrorType::ChannelUnavailable {
+                        channel_id: parent_id,
+                    },
+                    source: None,
+                })?;
+
+            &parent.data
+        } else {
+            &channel.data
+        };
+
+        let overwrites = match channel {
+            GuildChannel::Category(c) => c.permission_overwrites.as_slice(),
+            GuildChannel::Stage(c) => c.permission_overwrites.as_slice(),
+            GuildChannel::Text(c) => c.permission_overwrites.as_slice(),
+            GuildChannel::Thread(_) => &[],
+            GuildChannel::Voice(c) => c.permission_overwrites.as_slice(),
         };
 
         let calculator =
             PermissionCalculator::new(guild_id, user_id, everyone, assigned.as_slice());
 
-        Ok(calculator.in_channel(channel.data.kind(), overwrites))
+        Ok(calculator.in_channel(channel.kind(), overwrites))
     }
 
     /// Calculate the guild-level permissions of a member.
@@ -480,7 +509,9 @@ mod tests {
             permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
             Channel, ChannelType, GuildChannel, TextChannel,
         },
-        gateway::payload::{ChannelCreate, GuildCreate, MemberAdd, MemberUpdate, RoleCreate},
+        gateway::payload::{
+            ChannelCreate, GuildCreate, MemberAdd, MemberUpdate, RoleCreate, ThreadCreate,
+        },
         guild::{
             DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel, NSFWLevel,
             Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
@@ -562,6 +593,7 @@ mod tests {
             stage_instances: Vec::new(),
             system_channel_id: None,
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
+            threads: Vec::new(),
             rules_channel_id: None,
             unavailable: false,
             verification_level: VerificationLevel::VeryHigh,
@@ -732,6 +764,38 @@ mod tests {
         Ok(())
     }
 
+    /// Test that [`in_channel`] calculates the permissions of threads with the
+    /// permission overwrites of their parent channel.
+    ///
+    /// [`in_channel`]: super::InMemoryCachePermissions::in_channel
+    #[test]
+    fn test_in_thread() -> Result<(), Box<dyn Error>> {
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+        let (_, thread_id, mut thread) = test::guild_channel_thread();
+
+        if let GuildChannel::Thread(ref mut thread) = thread {
+            thread.parent_id.replace(CHANNEL_ID);
+        }
+
+        cache.update(&GuildCreate(base_guild()));
+        cache.update(&MemberAdd(test::member(USER_ID, GUILD_ID)));
+        cache.update(&ThreadCreate(thread));
+        assert!(matches!(
+            permissions.in_channel(USER_ID, thread_id).unwrap_err().kind(),
+            ChannelErrorType::ChannelUnavailable { channel_id }
+            if *channel_id == CHANNEL_ID
+        ));
+
+        cache.update(&ChannelCreate(channel()));
+        assert_eq!(
+            permissions.in_channel(USER_ID, CHANNEL_ID)?,
+            permissions.in_channel(USER_ID, thread_id)?,
+        );
+
+        Ok(())
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
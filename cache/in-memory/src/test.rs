@@ -2,7 +2,8 @@ use crate::InMemoryCache;
 use twilight_model::{
     channel::{
         message::{Message, MessageFlags, MessageType},
-        ChannelType, GuildChannel, Reaction, ReactionType, TextChannel,
+        thread::{AutoArchiveDuration, ThreadMetadata},
+        ChannelType, GuildChannel, Reaction, ReactionType, TextChannel, ThreadChannel,
     },
     gateway::payload::{MessageCreate, ReactionAdd},
     guild::{Emoji, Member, PartialMember, Permissions, Role},
@@ -35,6 +36,7 @@ pub fn cache_with_message_and_reactions() -> InMemoryCache {
             verified: None,
         },
         channel_id: ChannelId(2),
+        components: Vec::new(),
         content: "ping".to_owned(),
         edited_timestamp: None,
         embeds: Vec::new(),
@@ -197,6 +199,34 @@ pub fn guild_channel_text() -> (GuildId, ChannelId, GuildChannel) {
     (guild_id, channel_id, channel)
 }
 
+/// Public thread created in the channel of [`guild_channel_text`].
+pub fn guild_channel_thread() -> (GuildId, ChannelId, GuildChannel) {
+    let guild_id = GuildId(1);
+    let channel_id = ChannelId(5);
+    let channel = GuildChannel::Thread(ThreadChannel {
+        guild_id: Some(guild_id),
+        id: channel_id,
+        kind: ChannelType::GuildPublicThread,
+        last_message_id: None,
+        member: None,
+        member_count: 1,
+        message_count: 1,
+        name: "thread".to_owned(),
+        owner_id: Some(UserId(3)),
+        parent_id: Some(ChannelId(2)),
+        rate_limit_per_user: None,
+        thread_metadata: ThreadMetadata {
+            archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+            archived: false,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            invitable: None,
+            locked: false,
+        },
+    });
+
+    (guild_id, channel_id, channel)
+}
+
 pub fn member(id: UserId, guild_id: GuildId) -> Member {
     Member {
         deaf: false,
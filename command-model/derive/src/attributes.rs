@@ -0,0 +1,102 @@
+//! Parsing of the `#[command(...)]` and `#[option(...)]` attributes.
+
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Attribute, Error, Lit, Meta, NestedMeta, Result};
+
+/// Key-value pairs of the attributes with a given name.
+pub struct Attributes {
+    values: Vec<(String, Lit)>,
+}
+
+impl Attributes {
+    /// Parse the attributes named `name`, ensuring that only `allowed` keys are
+    /// used.
+    pub fn parse(attrs: &[Attribute], name: &str, allowed: &[&str]) -> Result<Self> {
+        let mut values = Vec::new();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident(name)) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                other => {
+                    return Err(Error::new(
+                        other.span(),
+                        format!("expected `#[{}(key = value, ...)]`", name),
+                    ))
+                }
+            };
+
+            for nested in list.nested {
+                let pair = match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                    other => return Err(Error::new(other.span(), "expected `key = value`")),
+                };
+
+                let key = pair
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .filter(|key| allowed.contains(&key.as_str()))
+                    .ok_or_else(|| {
+                        Error::new(
+                            pair.path.span(),
+                            format!("unknown key, expected one of: {}", allowed.join(", ")),
+                        )
+                    })?;
+
+                if values.iter().any(|(existing, _)| *existing == key) {
+                    return Err(Error::new(pair.path.span(), "duplicate key"));
+                }
+
+                values.push((key, pair.lit));
+            }
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Literal of a key, if present.
+    pub fn get(&self, key: &str) -> Option<&Lit> {
+        self.values
+            .iter()
+            .find(|(existing, _)| existing == key)
+            .map(|(_, lit)| lit)
+    }
+
+    /// Boolean value of a key, if present.
+    pub fn bool(&self, key: &str) -> Result<Option<bool>> {
+        match self.get(key) {
+            Some(Lit::Bool(lit)) => Ok(Some(lit.value)),
+            Some(other) => Err(Error::new(other.span(), "expected a boolean")),
+            None => Ok(None),
+        }
+    }
+
+    /// String value of a key, if present.
+    pub fn string(&self, key: &str) -> Result<Option<String>> {
+        match self.get(key) {
+            Some(Lit::Str(lit)) => Ok(Some(lit.value())),
+            Some(other) => Err(Error::new(other.span(), "expected a string")),
+            None => Ok(None),
+        }
+    }
+
+    /// String value of a key that must be present.
+    pub fn required_string(&self, key: &str, span: Span) -> Result<String> {
+        self.string(key)?
+            .ok_or_else(|| Error::new(span, format!("missing `{}`", key)))
+    }
+}
+
+/// Ensure that the length of a name or description is within a range.
+pub fn validate_length(value: &str, what: &str, min: usize, max: usize, span: Span) -> Result<()> {
+    let len = value.chars().count();
+
+    if len < min || len > max {
+        return Err(Error::new(
+            span,
+            format!("{} must be between {} and {} characters", what, min, max),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+//! Derives of `CommandModel` and `CreateCommand` for structs.
+
+use crate::attributes::{validate_length, Attributes};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument, Ident, PathArguments,
+    Result, Type,
+};
+
+/// Keys of the attribute on the struct.
+const COMMAND_KEYS: &[&str] = &["default_permission", "desc", "name"];
+
+/// Keys of the attribute on fields.
+const FIELD_KEYS: &[&str] = &["desc", "rename"];
+
+/// Field of a command struct, which is an option of the command.
+struct Field {
+    attributes: Attributes,
+    ident: Ident,
+    /// Name of the option.
+    name: String,
+    /// Whether the field is an `Option<T>`.
+    optional: bool,
+    span: Span,
+    /// Type of the option, which is `T` if the field is an `Option<T>`.
+    ty: Type,
+}
+
+/// Parse the fields of a struct, returning `None` if it's a unit struct.
+fn fields(input: &DeriveInput) -> Result<Option<Vec<Field>>> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "commands can only be derived for structs",
+            ))
+        }
+    };
+
+    let named = match &data.fields {
+        Fields::Named(named) => named,
+        Fields::Unit => return Ok(None),
+        Fields::Unnamed(unnamed) => {
+            return Err(Error::new(
+                unnamed.span(),
+                "commands can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    named
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().expect("named fields have idents");
+            let attributes = Attributes::parse(&field.attrs, "command", FIELD_KEYS)?;
+            let name = attributes
+                .string("rename")?
+                .unwrap_or_else(|| ident.to_string());
+            let (optional, ty) = match option_inner(&field.ty) {
+                Some(inner) => (true, inner.clone()),
+                None => (false, field.ty.clone()),
+            };
+
+            validate_length(&name, "option name", 1, 32, field.span())?;
+
+            Ok(Field {
+                attributes,
+                ident,
+                name,
+                optional,
+                span: field.span(),
+                ty,
+            })
+        })
+        .collect::<Result<_>>()
+        .map(Some)
+}
+
+/// Inner type of an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub fn command_model(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = if let Some(fields) = fields(input)? {
+        let names = fields.iter().map(|field| &field.name);
+        let inits = fields.iter().map(|field| {
+            let ident = &field.ident;
+            let name = &field.name;
+
+            if field.optional {
+                quote!(#ident: options.optional(#name)?)
+            } else {
+                quote!(#ident: options.required(#name)?)
+            }
+        });
+
+        quote! {
+            let options = ::twilight_command_model::__private::CommandOptions::new(
+                data,
+                &[#(#names),*],
+            )?;
+
+            ::std::result::Result::Ok(Self { #(#inits),* })
+        }
+    } else {
+        quote! {
+            ::twilight_command_model::__private::CommandOptions::new(data, &[])?;
+
+            ::std::result::Result::Ok(Self)
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_command_model::CommandModel for #ident #ty_generics #where_clause {
+            fn from_interaction(
+                data: &::twilight_command_model::__private::CommandData,
+            ) -> ::std::result::Result<Self, ::twilight_command_model::ParseError> {
+                #body
+            }
+        }
+    })
+}
+
+pub fn create_command(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let attributes = Attributes::parse(&input.attrs, "command", COMMAND_KEYS)?;
+    let name = attributes.required_string("name", ident.span())?;
+    let description = attributes.required_string("desc", ident.span())?;
+    let default_permission = if let Some(value) = attributes.bool("default_permission")? {
+        quote!(::std::option::Option::Some(#value))
+    } else {
+        quote!(::std::option::Option::None)
+    };
+
+    validate_length(&name, "command name", 3, 32, ident.span())?;
+    validate_length(&description, "command description", 1, 100, ident.span())?;
+
+    let options = fields(input)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|field| {
+            let description = field.attributes.required_string("desc", field.span)?;
+            validate_length(&description, "option description", 1, 100, field.span)?;
+
+            let name = &field.name;
+            let required = !field.optional;
+            let ty = &field.ty;
+
+            Ok(quote! {
+                <#ty as ::twilight_command_model::CreateOption>::create_option(
+                    ::twilight_command_model::CreateOptionData {
+                        description: ::std::string::String::from(#description),
+                        name: ::std::string::String::from(#name),
+                        required: #required,
+                    },
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_command_model::CreateCommand for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #name;
+
+            fn create_command() -> ::twilight_command_model::__private::Command {
+                ::twilight_command_model::__private::command(
+                    #name,
+                    #description,
+                    #default_permission,
+                    ::std::vec![#(#options),*],
+                )
+            }
+        }
+    })
+}
@@ -0,0 +1,66 @@
+//! # twilight-command-model-derive
+//!
+//! Derive macros for [`twilight-command-model`]. Use the re-exports of the
+//! macros from that crate instead of depending on this crate directly.
+//!
+//! [`twilight-command-model`]: https://docs.rs/twilight-command-model
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+
+mod attributes;
+mod command;
+mod option;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `CommandModel` for a struct whose fields are options of a command.
+#[proc_macro_derive(CommandModel, attributes(command))]
+pub fn command_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    command::command_model(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derive `CreateCommand` for a struct whose fields are options of a command.
+#[proc_macro_derive(CreateCommand, attributes(command))]
+pub fn create_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    command::create_command(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derive `CommandOption` for an enum whose variants are choices of an option.
+#[proc_macro_derive(CommandOption, attributes(option))]
+pub fn command_option(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    option::command_option(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derive `CreateOption` for an enum whose variants are choices of an option.
+#[proc_macro_derive(CreateOption, attributes(option))]
+pub fn create_option(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    option::create_option(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
@@ -0,0 +1,191 @@
+//! Derives of `CommandOption` and `CreateOption` for choice enums.
+
+use crate::attributes::{validate_length, Attributes};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Ident, Lit, LitInt, LitStr, Result};
+
+/// Keys of the attribute on variants.
+const VARIANT_KEYS: &[&str] = &["name", "value"];
+
+/// Variant of a choice enum, which is a choice of the option.
+struct Choice<T> {
+    ident: Ident,
+    /// Name of the choice displayed to users.
+    name: String,
+    value: T,
+}
+
+/// Choices of an enum, which are either all strings or all integers.
+enum Choices {
+    Integer(Vec<Choice<LitInt>>),
+    String(Vec<Choice<LitStr>>),
+}
+
+fn choices(input: &DeriveInput) -> Result<Choices> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "options can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut integers = Vec::new();
+    let mut strings = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.ident.span(),
+                "choices must be unit variants",
+            ));
+        }
+
+        let attributes = Attributes::parse(&variant.attrs, "option", VARIANT_KEYS)?;
+        let name = attributes.required_string("name", variant.ident.span())?;
+        validate_length(&name, "choice name", 1, 100, variant.ident.span())?;
+
+        let ident = variant.ident.clone();
+
+        match attributes.get("value") {
+            Some(Lit::Int(value)) => integers.push(Choice {
+                ident,
+                name,
+                value: value.clone(),
+            }),
+            Some(Lit::Str(value)) => strings.push(Choice {
+                ident,
+                name,
+                value: value.clone(),
+            }),
+            Some(other) => {
+                return Err(Error::new(
+                    other.span(),
+                    "expected a string or integer value",
+                ))
+            }
+            None => return Err(Error::new(variant.ident.span(), "missing `value`")),
+        }
+    }
+
+    match (integers.is_empty(), strings.is_empty()) {
+        (true, true) => Err(Error::new(
+            input.ident.span(),
+            "choice enums must have at least one variant",
+        )),
+        (false, true) => Ok(Choices::Integer(integers)),
+        (true, false) => Ok(Choices::String(strings)),
+        (false, false) => Err(Error::new(
+            input.ident.span(),
+            "choices must either all be strings or all be integers",
+        )),
+    }
+}
+
+pub fn command_option(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match choices(input)? {
+        Choices::Integer(choices) => {
+            let arms = choices.iter().map(|choice| {
+                let variant = &choice.ident;
+                let value = &choice.value;
+
+                quote!(#value => ::std::result::Result::Ok(Self::#variant))
+            });
+
+            quote! {
+                let value = <i64 as ::twilight_command_model::CommandOption>::from_option(
+                    option,
+                    resolved,
+                )?;
+
+                match value {
+                    #(#arms,)*
+                    other => ::std::result::Result::Err(
+                        ::twilight_command_model::ParseErrorType::InvalidChoice {
+                            value: ::std::string::ToString::to_string(&other),
+                        },
+                    ),
+                }
+            }
+        }
+        Choices::String(choices) => {
+            let arms = choices.iter().map(|choice| {
+                let variant = &choice.ident;
+                let value = &choice.value;
+
+                quote!(#value => ::std::result::Result::Ok(Self::#variant))
+            });
+
+            quote! {
+                let value = <::std::string::String as ::twilight_command_model::CommandOption>::from_option(
+                    option,
+                    resolved,
+                )?;
+
+                match value.as_str() {
+                    #(#arms,)*
+                    _ => ::std::result::Result::Err(
+                        ::twilight_command_model::ParseErrorType::InvalidChoice { value },
+                    ),
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_command_model::CommandOption for #ident #ty_generics #where_clause {
+            fn from_option(
+                option: &::twilight_command_model::__private::CommandDataOption,
+                resolved: ::std::option::Option<
+                    &::twilight_command_model::__private::CommandInteractionDataResolved,
+                >,
+            ) -> ::std::result::Result<Self, ::twilight_command_model::ParseErrorType> {
+                #body
+            }
+        }
+    })
+}
+
+pub fn create_option(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match choices(input)? {
+        Choices::Integer(choices) => {
+            let pairs = choices.iter().map(|choice| {
+                let name = &choice.name;
+                let value = &choice.value;
+
+                quote!((#name, #value))
+            });
+
+            quote!(::twilight_command_model::__private::integer_choices(data, &[#(#pairs),*]))
+        }
+        Choices::String(choices) => {
+            let pairs = choices.iter().map(|choice| {
+                let name = &choice.name;
+                let value = &choice.value;
+
+                quote!((#name, #value))
+            });
+
+            quote!(::twilight_command_model::__private::string_choices(data, &[#(#pairs),*]))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_command_model::CreateOption for #ident #ty_generics #where_clause {
+            fn create_option(
+                data: ::twilight_command_model::CreateOptionData,
+            ) -> ::twilight_command_model::__private::ModelOption {
+                #body
+            }
+        }
+    })
+}
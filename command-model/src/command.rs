@@ -0,0 +1,42 @@
+//! Parse and create whole commands.
+
+use crate::error::ParseError;
+use twilight_model::application::{
+    command::Command, interaction::application_command::CommandData,
+};
+
+/// Parse a type from the data of a command interaction.
+///
+/// Each field of the type is an option of the command. Fields of an
+/// [`Option`] type are optional options, while all other fields are required.
+///
+/// This is usually derived, refer to the [crate documentation] for more
+/// information.
+///
+/// [crate documentation]: crate
+pub trait CommandModel: Sized {
+    /// Parse the options of the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] naming the option that failed to parse if an
+    /// option is missing, unknown, or has a value that is not valid for its
+    /// field.
+    fn from_interaction(data: &CommandData) -> Result<Self, ParseError>;
+}
+
+/// Create the registration of a command from a type.
+///
+/// This is usually derived alongside [`CommandModel`], refer to the
+/// [crate documentation] for more information.
+///
+/// [crate documentation]: crate
+pub trait CreateCommand {
+    /// Name of the command.
+    const NAME: &'static str;
+
+    /// Create the command, which can be registered via the HTTP client.
+    ///
+    /// Required options are listed before optional ones.
+    fn create_command() -> Command;
+}
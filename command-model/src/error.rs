@@ -0,0 +1,147 @@
+//! Errors returned when parsing commands.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Parsing a command or one of its options failed.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorType,
+    name: String,
+}
+
+impl ParseError {
+    /// Create a new error for the option with the given name.
+    pub(crate) fn new(name: impl Into<String>, kind: ParseErrorType) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+        }
+    }
+
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ParseErrorType {
+        &self.kind
+    }
+
+    /// Name of the option that failed to parse.
+    #[must_use = "retrieving the name has no effect if left unused"]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("failed to parse option `")?;
+        f.write_str(&self.name)?;
+        f.write_str("`: ")?;
+
+        match &self.kind {
+            ParseErrorType::InvalidChoice { value } => {
+                f.write_str("`")?;
+                f.write_str(value)?;
+
+                f.write_str("` is not one of the choices")
+            }
+            ParseErrorType::InvalidId { value } => {
+                f.write_str("`")?;
+                f.write_str(value)?;
+
+                f.write_str("` is not a valid id")
+            }
+            ParseErrorType::InvalidType { expected, found } => {
+                f.write_str("expected a value of type ")?;
+                f.write_str(expected)?;
+                f.write_str(" but got ")?;
+
+                f.write_str(found)
+            }
+            ParseErrorType::MissingOption => f.write_str("required option is missing"),
+            ParseErrorType::ResolvedDataMissing { id } => {
+                f.write_str("resolved data for id ")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(" is missing")
+            }
+            ParseErrorType::UnknownOption => f.write_str("option is not part of the command"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Type of [`ParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseErrorType {
+    /// Value of the option is not one of the choices of the type.
+    InvalidChoice {
+        /// Provided value.
+        value: String,
+    },
+    /// Value of the option is not a valid ID.
+    InvalidId {
+        /// Provided value.
+        value: String,
+    },
+    /// Value of the option is of a different type than the field.
+    InvalidType {
+        /// Name of the type that was expected.
+        expected: &'static str,
+        /// Name of the type that was received.
+        found: &'static str,
+    },
+    /// Option is required but was not received.
+    MissingOption,
+    /// Resolved data of an ID was not received.
+    ResolvedDataMissing {
+        /// ID that has no resolved data.
+        id: u64,
+    },
+    /// Option was received but the command has no option with its name.
+    UnknownOption,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, ParseErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(ParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(ParseError: Error, Send, Sync);
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(
+            "count",
+            ParseErrorType::InvalidType {
+                expected: "Integer",
+                found: "String",
+            },
+        );
+
+        assert_eq!("count", error.name());
+        assert_eq!(
+            "failed to parse option `count`: expected a value of type Integer but got String",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! # twilight-command-model
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-command-model` parses the data of slash command interactions
+//! into Rust types, and creates the registrations of commands from the same
+//! types, for the [`twilight-rs`] ecosystem.
+//!
+//! Instead of matching on the names and variants of
+//! [`CommandData::options`] and looking up users in the resolved data by
+//! hand, a struct with a field for each option can derive [`CommandModel`]
+//! to parse the options and [`CreateCommand`] to create the command that is
+//! registered with Discord.
+//!
+//! Fields can be of the following types, which implement [`CommandOption`]
+//! and [`CreateOption`]:
+//!
+//! | Type                     | Option type |
+//! |--------------------------|-------------|
+//! | [`String`]               | String      |
+//! | [`i64`]                  | Integer     |
+//! | [`f64`]                  | Number      |
+//! | [`bool`]                 | Boolean     |
+//! | [`UserId`], [`ResolvedUser`] | User    |
+//! | [`ChannelId`], [`InteractionChannel`] | Channel |
+//! | [`RoleId`], [`Role`]     | Role        |
+//!
+//! Fields of an [`Option`] type are optional options. Enums with unit
+//! variants can derive [`CommandOption`] and [`CreateOption`] to be used as
+//! the choices of a string or integer option.
+//!
+//! ## Attributes
+//!
+//! The struct has a `#[command(name = "...", desc = "...")]` attribute with
+//! the name and description of the command, and may have a
+//! `default_permission = false` to disable the command by default.
+//!
+//! Each field has a `#[command(desc = "...")]` attribute with the description
+//! of its option. Options are named after their field unless renamed with
+//! `rename = "..."`.
+//!
+//! Each variant of a choice enum has an `#[option(name = "...", value = ...)]`
+//! attribute with the name displayed to users and either a string or an
+//! integer value.
+//!
+//! ## Features
+//!
+//! ### `derive`
+//!
+//! Enables the derive macros. This is enabled by default.
+//!
+//! ## Examples
+//!
+//! Parse a command greeting a user:
+//!
+//! ```rust
+//! use twilight_command_model::{
+//!     CommandModel, CommandOption, CreateCommand, CreateOption, ResolvedUser,
+//! };
+//! use twilight_model::application::interaction::application_command::CommandData;
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello to a user")]
+//! struct HelloCommand {
+//!     #[command(desc = "User to say hello to")]
+//!     user: ResolvedUser,
+//!     #[command(desc = "How to greet the user")]
+//!     greeting: Option<Greeting>,
+//! }
+//!
+//! #[derive(CommandOption, CreateOption)]
+//! enum Greeting {
+//!     #[option(name = "Hello", value = "hello")]
+//!     Hello,
+//!     #[option(name = "Good morning", value = "morning")]
+//!     Morning,
+//! }
+//!
+//! fn handle(data: &CommandData) -> Result<String, Box<dyn std::error::Error>> {
+//!     let command = HelloCommand::from_interaction(data)?;
+//!     let greeting = match command.greeting {
+//!         Some(Greeting::Morning) => "Good morning",
+//!         Some(Greeting::Hello) | None => "Hello",
+//!     };
+//!
+//!     Ok(format!("{}, {}!", greeting, command.user.resolved.name))
+//! }
+//!
+//! let command = HelloCommand::create_command();
+//! assert_eq!("hello", command.name);
+//! assert_eq!(2, command.options.len());
+//! ```
+//!
+//! [`ChannelId`]: twilight_model::id::ChannelId
+//! [`CommandData::options`]: twilight_model::application::interaction::application_command::CommandData::options
+//! [`InteractionChannel`]: twilight_model::application::interaction::application_command::InteractionChannel
+//! [`Role`]: twilight_model::guild::Role
+//! [`RoleId`]: twilight_model::id::RoleId
+//! [`UserId`]: twilight_model::id::UserId
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.49+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub mod command;
+pub mod error;
+pub mod option;
+
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+
+pub use self::{
+    command::{CommandModel, CreateCommand},
+    error::{ParseError, ParseErrorType},
+    option::{CommandOption, CreateOption, CreateOptionData, ResolvedUser},
+};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use twilight_command_model_derive::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -0,0 +1,423 @@
+//! Parse and create individual command options.
+
+use crate::error::ParseErrorType;
+use std::str::FromStr;
+use twilight_model::{
+    application::{
+        command::{
+            BaseCommandOptionData, ChoiceCommandOptionData, CommandOption as ModelOption,
+            NumberCommandOptionData,
+        },
+        interaction::application_command::{
+            CommandDataOption, CommandInteractionDataResolved, InteractionChannel,
+            InteractionMember,
+        },
+    },
+    guild::Role,
+    id::{ChannelId, RoleId, UserId},
+    user::User,
+};
+
+/// Parse a type from the value of a command option.
+///
+/// This is implemented for the types that fields of a [`CommandModel`] can
+/// have, and can be derived for enums whose variants are the choices of a
+/// string or integer option.
+///
+/// [`CommandModel`]: crate::CommandModel
+pub trait CommandOption: Sized {
+    /// Parse the option received in an interaction.
+    ///
+    /// `resolved` is the resolved data of the interaction, which contains the
+    /// users, channels, and roles that options refer to.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseErrorType::InvalidType`] if the value of the option is
+    /// not of the expected type.
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType>;
+}
+
+/// Create the registration of a command option for a type.
+///
+/// This is implemented for the same types as [`CommandOption`], and determines
+/// the type and choices of the option that is registered for a field of a
+/// [`CreateCommand`].
+///
+/// [`CreateCommand`]: crate::CreateCommand
+pub trait CreateOption {
+    /// Create the option from its name, description, and whether it's
+    /// required.
+    fn create_option(data: CreateOptionData) -> ModelOption;
+}
+
+/// Data of an option that is independent of its type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateOptionData {
+    /// Description of the option.
+    pub description: String,
+    /// Name of the option.
+    pub name: String,
+    /// Whether the option is required.
+    pub required: bool,
+}
+
+impl CreateOptionData {
+    /// Convert the data into the base data of an option without choices.
+    pub fn into_base(self) -> BaseCommandOptionData {
+        BaseCommandOptionData {
+            description: self.description,
+            name: self.name,
+            required: self.required,
+        }
+    }
+
+    /// Convert the data into the data of an option without any choices yet.
+    pub fn into_choice(self) -> ChoiceCommandOptionData {
+        ChoiceCommandOptionData {
+            autocomplete: false,
+            choices: Vec::new(),
+            description: self.description,
+            name: self.name,
+            required: self.required,
+        }
+    }
+
+    /// Convert the data into the data of a number option without any choices
+    /// or bounds.
+    pub fn into_number(self) -> NumberCommandOptionData {
+        NumberCommandOptionData {
+            autocomplete: false,
+            choices: Vec::new(),
+            description: self.description,
+            max_value: None,
+            min_value: None,
+            name: self.name,
+            required: self.required,
+        }
+    }
+}
+
+/// User received in an option along with its guild member, if any.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedUser {
+    /// Member of the user, if the command was used in a guild.
+    pub member: Option<InteractionMember>,
+    /// Resolved user.
+    pub resolved: User,
+}
+
+impl CommandOption for String {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        match option {
+            CommandDataOption::String { value, .. } => Ok(value.clone()),
+            other => Err(invalid_type("String", other)),
+        }
+    }
+}
+
+impl CreateOption for String {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::String(data.into_choice())
+    }
+}
+
+impl CommandOption for i64 {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        match option {
+            CommandDataOption::Integer { value, .. } => Ok(*value),
+            other => Err(invalid_type("Integer", other)),
+        }
+    }
+}
+
+impl CreateOption for i64 {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Integer(data.into_choice())
+    }
+}
+
+impl CommandOption for f64 {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        match option {
+            CommandDataOption::Number { value, .. } => Ok(value.0),
+            other => Err(invalid_type("Number", other)),
+        }
+    }
+}
+
+impl CreateOption for f64 {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Number(data.into_number())
+    }
+}
+
+impl CommandOption for bool {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        match option {
+            CommandDataOption::Boolean { value, .. } => Ok(*value),
+            other => Err(invalid_type("Boolean", other)),
+        }
+    }
+}
+
+impl CreateOption for bool {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Boolean(data.into_base())
+    }
+}
+
+impl CommandOption for UserId {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        parse_id(option).map(UserId)
+    }
+}
+
+impl CreateOption for UserId {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::User(data.into_base())
+    }
+}
+
+impl CommandOption for ResolvedUser {
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        let id = UserId::from_option(option, resolved)?;
+        let resolved = resolved.ok_or(ParseErrorType::ResolvedDataMissing { id: id.0 })?;
+
+        let user = resolved
+            .users
+            .iter()
+            .find(|user| user.id == id)
+            .ok_or(ParseErrorType::ResolvedDataMissing { id: id.0 })?;
+        let member = resolved.members.iter().find(|member| member.id == id);
+
+        Ok(Self {
+            member: member.cloned(),
+            resolved: user.clone(),
+        })
+    }
+}
+
+impl CreateOption for ResolvedUser {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::User(data.into_base())
+    }
+}
+
+impl CommandOption for ChannelId {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        parse_id(option).map(ChannelId)
+    }
+}
+
+impl CreateOption for ChannelId {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Channel(data.into_base())
+    }
+}
+
+impl CommandOption for InteractionChannel {
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        let id = ChannelId::from_option(option, resolved)?;
+
+        resolved
+            .and_then(|resolved| resolved.channels.iter().find(|channel| channel.id == id))
+            .cloned()
+            .ok_or(ParseErrorType::ResolvedDataMissing { id: id.0 })
+    }
+}
+
+impl CreateOption for InteractionChannel {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Channel(data.into_base())
+    }
+}
+
+impl CommandOption for RoleId {
+    fn from_option(
+        option: &CommandDataOption,
+        _: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        parse_id(option).map(RoleId)
+    }
+}
+
+impl CreateOption for RoleId {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Role(data.into_base())
+    }
+}
+
+impl CommandOption for Role {
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseErrorType> {
+        let id = RoleId::from_option(option, resolved)?;
+
+        resolved
+            .and_then(|resolved| resolved.roles.iter().find(|role| role.id == id))
+            .cloned()
+            .ok_or(ParseErrorType::ResolvedDataMissing { id: id.0 })
+    }
+}
+
+impl CreateOption for Role {
+    fn create_option(data: CreateOptionData) -> ModelOption {
+        ModelOption::Role(data.into_base())
+    }
+}
+
+const fn invalid_type(expected: &'static str, option: &CommandDataOption) -> ParseErrorType {
+    ParseErrorType::InvalidType {
+        expected,
+        found: option.kind(),
+    }
+}
+
+/// Parse the ID of a user, channel, or role option, which are received as
+/// strings.
+fn parse_id(option: &CommandDataOption) -> Result<u64, ParseErrorType> {
+    match option {
+        CommandDataOption::String { value, .. } => {
+            u64::from_str(value).map_err(|_| ParseErrorType::InvalidId {
+                value: value.clone(),
+            })
+        }
+        other => Err(invalid_type("String", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandOption, CreateOption, CreateOptionData, ResolvedUser};
+    use crate::error::ParseErrorType;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::{
+            command::{CommandOption as ModelOption, CommandOptionType, Number},
+            interaction::application_command::{CommandDataOption, CommandInteractionDataResolved},
+        },
+        id::UserId,
+        user::User,
+    };
+
+    assert_impl_all!(CreateOptionData: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ResolvedUser: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn string(value: &str) -> CommandDataOption {
+        CommandDataOption::String {
+            name: "option".to_owned(),
+            value: value.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_number() {
+        let option = CommandDataOption::Number {
+            name: "option".to_owned(),
+            value: Number(2.0),
+        };
+
+        assert!((f64::from_option(&option, None).unwrap() - 2.0).abs() < f64::EPSILON);
+        assert!(matches!(
+            f64::from_option(
+                &CommandDataOption::Integer {
+                    name: "option".to_owned(),
+                    value: 2,
+                },
+                None
+            ),
+            Err(ParseErrorType::InvalidType {
+                expected: "Number",
+                found: "Integer",
+            })
+        ));
+    }
+
+    #[test]
+    fn test_ids() {
+        assert_eq!(UserId(3), UserId::from_option(&string("3"), None).unwrap());
+        assert!(matches!(
+            UserId::from_option(&string("three"), None),
+            Err(ParseErrorType::InvalidId { value }) if value == "three"
+        ));
+    }
+
+    #[test]
+    fn test_resolved_user() {
+        let user = User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+        let resolved = CommandInteractionDataResolved {
+            attachments: Vec::new(),
+            channels: Vec::new(),
+            members: Vec::new(),
+            messages: Vec::new(),
+            roles: Vec::new(),
+            users: vec![user.clone()],
+        };
+
+        let parsed = ResolvedUser::from_option(&string("3"), Some(&resolved)).unwrap();
+        assert_eq!(user, parsed.resolved);
+        assert!(parsed.member.is_none());
+
+        assert!(matches!(
+            ResolvedUser::from_option(&string("4"), Some(&resolved)),
+            Err(ParseErrorType::ResolvedDataMissing { id: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_create_option() {
+        let option = ResolvedUser::create_option(CreateOptionData {
+            description: "user to greet".to_owned(),
+            name: "user".to_owned(),
+            required: true,
+        });
+
+        assert!(matches!(option, ModelOption::User(_)));
+        assert_eq!(CommandOptionType::User, option.kind());
+        assert!(option.is_required());
+    }
+}
@@ -0,0 +1,108 @@
+//! Items used by the derive macros, which aren't part of the public API.
+
+pub use twilight_model::application::{
+    command::{Command, CommandOption as ModelOption},
+    interaction::application_command::{
+        CommandData, CommandDataOption, CommandInteractionDataResolved,
+    },
+};
+
+use crate::{
+    error::{ParseError, ParseErrorType},
+    option::{CommandOption, CreateOptionData},
+};
+use twilight_model::application::command::{CommandOptionChoice, CommandType};
+
+/// Options of a command interaction.
+pub struct CommandOptions<'a> {
+    options: &'a [CommandDataOption],
+    resolved: Option<&'a CommandInteractionDataResolved>,
+}
+
+impl<'a> CommandOptions<'a> {
+    /// Create the options of the data, ensuring that all of the options are
+    /// among the names of the command's options.
+    pub fn new(data: &'a CommandData, names: &[&str]) -> Result<Self, ParseError> {
+        if let Some(option) = data
+            .options
+            .iter()
+            .find(|option| !names.contains(&option.name()))
+        {
+            return Err(ParseError::new(
+                option.name(),
+                ParseErrorType::UnknownOption,
+            ));
+        }
+
+        Ok(Self {
+            options: &data.options,
+            resolved: data.resolved.as_ref(),
+        })
+    }
+
+    /// Parse an option that may not be present.
+    pub fn optional<T: CommandOption>(&self, name: &str) -> Result<Option<T>, ParseError> {
+        self.options
+            .iter()
+            .find(|option| option.name() == name)
+            .map(|option| {
+                T::from_option(option, self.resolved).map_err(|kind| ParseError::new(name, kind))
+            })
+            .transpose()
+    }
+
+    /// Parse an option that must be present.
+    pub fn required<T: CommandOption>(&self, name: &str) -> Result<T, ParseError> {
+        self.optional(name)?
+            .ok_or_else(|| ParseError::new(name, ParseErrorType::MissingOption))
+    }
+}
+
+/// Create a chat input command, placing required options first.
+pub fn command(
+    name: &str,
+    description: &str,
+    default_permission: Option<bool>,
+    mut options: Vec<ModelOption>,
+) -> Command {
+    options.sort_by_key(|option| !option.is_required());
+
+    Command {
+        application_id: None,
+        guild_id: None,
+        name: name.to_owned(),
+        default_permission,
+        description: description.to_owned(),
+        id: None,
+        kind: CommandType::ChatInput,
+        options,
+    }
+}
+
+/// Create an integer option with the given choices of names and values.
+pub fn integer_choices(data: CreateOptionData, choices: &[(&str, i64)]) -> ModelOption {
+    let mut data = data.into_choice();
+    data.choices = choices
+        .iter()
+        .map(|(name, value)| CommandOptionChoice::Int {
+            name: (*name).to_owned(),
+            value: *value,
+        })
+        .collect();
+
+    ModelOption::Integer(data)
+}
+
+/// Create a string option with the given choices of names and values.
+pub fn string_choices(data: CreateOptionData, choices: &[(&str, &str)]) -> ModelOption {
+    let mut data = data.into_choice();
+    data.choices = choices
+        .iter()
+        .map(|(name, value)| CommandOptionChoice::String {
+            name: (*name).to_owned(),
+            value: (*value).to_owned(),
+        })
+        .collect();
+
+    ModelOption::String(data)
+}
@@ -0,0 +1,226 @@
+use twilight_command_model::{
+    CommandModel, CommandOption, CreateCommand, CreateOption, ParseErrorType, ResolvedUser,
+};
+use twilight_model::{
+    application::{
+        command::{CommandOption as ModelOption, CommandOptionChoice, CommandType, Number},
+        interaction::application_command::{
+            CommandData, CommandDataOption, CommandInteractionDataResolved,
+        },
+    },
+    id::{ChannelId, CommandId, UserId},
+    user::User,
+};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "ban", desc = "Ban a user", default_permission = false)]
+struct BanCommand {
+    #[command(desc = "Reason for the ban")]
+    reason: Option<String>,
+    #[command(desc = "User to ban")]
+    user: ResolvedUser,
+    #[command(desc = "Days of messages to delete", rename = "days")]
+    delete_message_days: Option<DeleteDays>,
+    #[command(desc = "Channel to log the ban in")]
+    log: Option<ChannelId>,
+}
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq)]
+enum DeleteDays {
+    #[option(name = "None", value = 0)]
+    Zero,
+    #[option(name = "Last week", value = 7)]
+    Week,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "ping", desc = "Ping the bot")]
+struct PingCommand;
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq)]
+enum Unit {
+    #[option(name = "Celsius", value = "c")]
+    Celsius,
+    #[option(name = "Fahrenheit", value = "f")]
+    Fahrenheit,
+}
+
+#[derive(CommandModel, Debug, PartialEq)]
+struct ConvertCommand {
+    degrees: f64,
+    unit: Unit,
+}
+
+fn user() -> User {
+    User {
+        avatar: None,
+        bot: false,
+        discriminator: "0001".to_owned(),
+        email: None,
+        flags: None,
+        id: UserId(3),
+        locale: None,
+        mfa_enabled: None,
+        name: "user".to_owned(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+    }
+}
+
+fn data(options: Vec<CommandDataOption>) -> CommandData {
+    CommandData {
+        id: CommandId(1),
+        kind: CommandType::ChatInput,
+        name: "ban".to_owned(),
+        options,
+        resolved: Some(CommandInteractionDataResolved {
+            attachments: Vec::new(),
+            channels: Vec::new(),
+            members: Vec::new(),
+            messages: Vec::new(),
+            roles: Vec::new(),
+            users: vec![user()],
+        }),
+        target_id: None,
+    }
+}
+
+fn string(name: &str, value: &str) -> CommandDataOption {
+    CommandDataOption::String {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+#[test]
+fn test_command_model() {
+    let command = BanCommand::from_interaction(&data(vec![
+        string("user", "3"),
+        CommandDataOption::Integer {
+            name: "days".to_owned(),
+            value: 7,
+        },
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        BanCommand {
+            reason: None,
+            user: ResolvedUser {
+                member: None,
+                resolved: user(),
+            },
+            delete_message_days: Some(DeleteDays::Week),
+            log: None,
+        },
+        command
+    );
+
+    let command = ConvertCommand::from_interaction(&data(vec![
+        CommandDataOption::Number {
+            name: "degrees".to_owned(),
+            value: Number(20.0),
+        },
+        string("unit", "f"),
+    ]))
+    .unwrap();
+
+    assert!((command.degrees - 20.0).abs() < f64::EPSILON);
+    assert_eq!(Unit::Fahrenheit, command.unit);
+    assert_eq!(
+        PingCommand,
+        PingCommand::from_interaction(&data(Vec::new())).unwrap()
+    );
+}
+
+#[test]
+fn test_command_model_errors() {
+    let error = BanCommand::from_interaction(&data(Vec::new())).unwrap_err();
+    assert_eq!("user", error.name());
+    assert!(matches!(error.kind(), ParseErrorType::MissingOption));
+
+    let error = BanCommand::from_interaction(&data(vec![
+        string("user", "3"),
+        CommandDataOption::Integer {
+            name: "days".to_owned(),
+            value: 3,
+        },
+    ]))
+    .unwrap_err();
+    assert_eq!("days", error.name());
+    assert!(matches!(
+        error.kind(),
+        ParseErrorType::InvalidChoice { value } if value == "3"
+    ));
+
+    let error = BanCommand::from_interaction(&data(vec![
+        string("user", "3"),
+        CommandDataOption::Boolean {
+            name: "log".to_owned(),
+            value: true,
+        },
+    ]))
+    .unwrap_err();
+    assert_eq!("log", error.name());
+    assert!(matches!(
+        error.kind(),
+        ParseErrorType::InvalidType {
+            expected: "String",
+            found: "Boolean",
+        }
+    ));
+
+    let error = PingCommand::from_interaction(&data(vec![string("user", "3")])).unwrap_err();
+    assert_eq!("user", error.name());
+    assert!(matches!(error.kind(), ParseErrorType::UnknownOption));
+}
+
+#[test]
+fn test_create_command() {
+    assert_eq!("ban", BanCommand::NAME);
+
+    let command = BanCommand::create_command();
+    assert_eq!("ban", command.name);
+    assert_eq!("Ban a user", command.description);
+    assert_eq!(Some(false), command.default_permission);
+    assert_eq!(CommandType::ChatInput, command.kind);
+
+    let names = command
+        .options
+        .iter()
+        .map(|option| match option {
+            ModelOption::Channel(data) | ModelOption::User(data) => data.name.as_str(),
+            ModelOption::Integer(data) | ModelOption::String(data) => data.name.as_str(),
+            other => panic!("unexpected option {:?}", other),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(["user", "reason", "days", "log"], names.as_slice());
+    assert!(command.options[0].is_required());
+
+    match &command.options[2] {
+        ModelOption::Integer(data) => {
+            assert_eq!("Days of messages to delete", data.description);
+            assert_eq!(
+                vec![
+                    CommandOptionChoice::Int {
+                        name: "None".to_owned(),
+                        value: 0,
+                    },
+                    CommandOptionChoice::Int {
+                        name: "Last week".to_owned(),
+                        value: 7,
+                    },
+                ],
+                data.choices
+            );
+        }
+        other => panic!("expected integer option, got {:?}", other),
+    }
+
+    let command = PingCommand::create_command();
+    assert_eq!("ping", command.name);
+    assert!(command.options.is_empty());
+    assert!(command.default_permission.is_none());
+}
@@ -5,10 +5,10 @@ use super::{
     scheme::ShardScheme,
 };
 use crate::{
-    shard::{LargeThresholdError, ResumeSession, ShardBuilder},
+    shard::{store::SessionStore, Backpressure, LargeThresholdError, ResumeSession, ShardBuilder},
     EventTypeFlags,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client;
 use twilight_model::gateway::{payload::update_presence::UpdatePresencePayload, Intents};
@@ -62,6 +62,7 @@ impl ClusterBuilder {
                 shard_config: shard_config.0,
                 shard_scheme: ShardScheme::Auto,
                 queue: Arc::new(Box::new(LocalQueue::new())),
+                reshard_timeout: Duration::from_secs(120),
                 resume_sessions: HashMap::new(),
             },
             ShardBuilder::new(token, intents),
@@ -95,6 +96,19 @@ impl ClusterBuilder {
         Cluster::new_with_config(self.0).await
     }
 
+    /// Bound the event stream of each shard to a capacity, with the behavior
+    /// when it's full.
+    ///
+    /// Refer to [`ShardBuilder::event_buffer`] for more information.
+    ///
+    /// [`ShardBuilder::event_buffer`]: crate::shard::ShardBuilder::event_buffer
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn event_buffer(mut self, capacity: usize, backpressure: Backpressure) -> Self {
+        self.1 = self.1.event_buffer(capacity, backpressure);
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -213,6 +227,25 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the maximum time to wait for the guilds of each new shard to be
+    /// ready when [resharding].
+    ///
+    /// The time starts once the shard has been started. If the shard received
+    /// its `Ready` event by then, its remaining unavailable guilds are
+    /// considered ready. Otherwise the reshard fails with a
+    /// [`ClusterReshardErrorType::ReadyTimedOut`] error type.
+    ///
+    /// Defaults to two minutes.
+    ///
+    /// [`ClusterReshardErrorType::ReadyTimedOut`]: super::ClusterReshardErrorType::ReadyTimedOut
+    /// [resharding]: Cluster::reshard
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn reshard_timeout(mut self, reshard_timeout: Duration) -> Self {
+        self.0.reshard_timeout = reshard_timeout;
+
+        self
+    }
+
     /// Set the session information to resume shards with.
     ///
     /// This requires having recovered the resume data when shutting down the
@@ -226,6 +259,22 @@ impl ClusterBuilder {
         self.0.resume_sessions = resume_sessions;
         self
     }
+
+    /// Set the store to persist the sessions of the shards in.
+    ///
+    /// Shards resume the sessions loaded from the store when the cluster is
+    /// brought up, so restarting the process doesn't require the shards to
+    /// identify again.
+    ///
+    /// Refer to the shard's [`ShardBuilder::session_store`] for more
+    /// information.
+    ///
+    /// [`ShardBuilder::session_store`]: crate::shard::ShardBuilder::session_store
+    pub fn session_store(mut self, session_store: Arc<dyn SessionStore>) -> Self {
+        self.1 = self.1.session_store(session_store);
+
+        self
+    }
 }
 
 impl<T: Into<String>> From<(T, Intents)> for ClusterBuilder {
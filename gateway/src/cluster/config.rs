@@ -3,7 +3,7 @@ use crate::{
     shard::{Config as ShardConfig, ResumeSession},
     EventTypeFlags,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
 
@@ -16,6 +16,7 @@ pub struct Config {
     pub(super) shard_config: ShardConfig,
     pub(super) shard_scheme: ShardScheme,
     pub(super) queue: Arc<Box<dyn Queue>>,
+    pub(super) reshard_timeout: Duration,
     pub(super) resume_sessions: HashMap<u64, ResumeSession>,
 }
 
@@ -59,6 +60,16 @@ impl Config {
     pub fn queue(&self) -> &Arc<Box<dyn Queue>> {
         &self.queue
     }
+
+    /// Maximum time to wait for the guilds of a new shard to be ready when
+    /// resharding.
+    ///
+    /// Refer to [`ClusterBuilder::reshard_timeout`] for the default value.
+    ///
+    /// [`ClusterBuilder::reshard_timeout`]: super::ClusterBuilder::reshard_timeout
+    pub const fn reshard_timeout(&self) -> Duration {
+        self.reshard_timeout
+    }
 }
 
 #[cfg(test)]
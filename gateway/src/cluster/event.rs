@@ -12,12 +12,14 @@
 //! [`EventType`]: twilight_model::gateway::event::EventType
 //! [`ClusterBuilder::event_types`]: crate::cluster::ClusterBuilder::event_types
 
-use crate::shard::Events as ShardEvents;
+use crate::{shard::Events as ShardEvents, EventTypeFlags};
 use futures_util::stream::{SelectAll, Stream};
 use std::{
+    collections::VecDeque,
     pin::Pin,
     task::{Context, Poll},
 };
+use tokio::sync::mpsc::UnboundedReceiver;
 use twilight_model::gateway::event::Event;
 
 /// Stream of events from a [`Cluster`].
@@ -28,21 +30,30 @@ use twilight_model::gateway::event::Event;
 ///
 /// This implements [`futures_util::stream::Stream`].
 ///
+/// When the cluster is [resharded] the stream switches to the events of the
+/// new shards.
+///
 /// # Examples
 ///
 /// Refer to [`Cluster`] for an example of how to use this.
 ///
 /// [`Cluster`]: super::Cluster
 /// [`Events`]: crate::shard::Events
+/// [resharded]: super::Cluster::reshard
 #[derive(Debug)]
 pub struct Events {
     stream: SelectAll<ShardEventsWithId>,
+    switch: UnboundedReceiver<SelectAll<ShardEventsWithId>>,
 }
 
 impl Events {
-    /// Create a new stream of shards' events.
-    pub(super) const fn new(stream: SelectAll<ShardEventsWithId>) -> Self {
-        Self { stream }
+    /// Create a new stream of shards' events, switching to the streams
+    /// received from the switch.
+    pub(super) const fn new(
+        stream: SelectAll<ShardEventsWithId>,
+        switch: UnboundedReceiver<SelectAll<ShardEventsWithId>>,
+    ) -> Self {
+        Self { stream, switch }
     }
 }
 
@@ -50,23 +61,76 @@ impl Stream for Events {
     type Item = (u64, Event);
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        while let Poll::Ready(Some(stream)) = self.switch.poll_recv(cx) {
+            self.stream = stream;
+        }
+
         Pin::new(&mut self.stream).poll_next(cx)
     }
 }
 
 /// Poll a shard's [`Events`] stream, mapping the result to the shard's ID.
 ///
+/// Events buffered while the shard was started are returned first, and events
+/// of types the cluster doesn't want are skipped.
+///
 /// [`Events`]: crate::shard::Events
 #[derive(Debug)]
 pub struct ShardEventsWithId {
+    buffer: VecDeque<Event>,
+    event_types: EventTypeFlags,
     id: u64,
     stream: ShardEvents,
 }
 
 impl ShardEventsWithId {
     /// Create a new stream with shard's ID and event stream.
-    pub(super) const fn new(id: u64, stream: ShardEvents) -> Self {
-        Self { id, stream }
+    pub(super) fn new(id: u64, stream: ShardEvents) -> Self {
+        Self::buffered(id, stream, VecDeque::new(), EventTypeFlags::all())
+    }
+
+    /// Create a new stream with shard's ID and event stream, returning the
+    /// buffered events first and only events of the given types.
+    pub(super) const fn buffered(
+        id: u64,
+        stream: ShardEvents,
+        buffer: VecDeque<Event>,
+        event_types: EventTypeFlags,
+    ) -> Self {
+        Self {
+            buffer,
+            event_types,
+            id,
+            stream,
+        }
+    }
+
+    /// Read the events available from the shard's stream into the buffer,
+    /// keeping only the events of the types the cluster wants.
+    ///
+    /// Every event is inspected before it's buffered. Returns
+    /// `Poll::Ready` if the stream ended.
+    pub(super) fn poll_buffer(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut inspect: impl FnMut(&Event),
+    ) -> Poll<()> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    inspect(&event);
+
+                    if self
+                        .event_types
+                        .contains(EventTypeFlags::from(event.kind()))
+                    {
+                        self.buffer.push_back(event);
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(()),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
     }
 }
 
@@ -74,20 +138,96 @@ impl Stream for ShardEventsWithId {
     type Item = (u64, Event);
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        match Pin::new(&mut self.stream).poll_next(cx) {
-            Poll::Ready(Some(event)) => Poll::Ready(Some((self.id, event))),
-            Poll::Ready(None) => Poll::Ready(None),
-            Poll::Pending => Poll::Pending,
+        if let Some(event) = self.buffer.pop_front() {
+            return Poll::Ready(Some((self.id, event)));
+        }
+
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if self
+                        .event_types
+                        .contains(EventTypeFlags::from(event.kind()))
+                    {
+                        return Poll::Ready(Some((self.id, event)));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Events;
-    use futures_util::stream::Stream;
+    use super::{Events, ShardEventsWithId};
+    use crate::{shard::Shard, EventTypeFlags};
+    use futures_util::{
+        future::FutureExt,
+        stream::{SelectAll, Stream, StreamExt},
+    };
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{collections::VecDeque, fmt::Debug};
+    use tokio::sync::mpsc;
+    use twilight_model::gateway::{event::Event, Intents};
 
     assert_impl_all!(Events: Debug, Send, Stream, Sync);
+
+    fn stream(shards: &mut Vec<Shard>, id: u64, buffer: Vec<Event>) -> ShardEventsWithId {
+        let (shard, events) = Shard::new("token", Intents::empty());
+        shards.push(shard);
+
+        ShardEventsWithId::buffered(id, events, VecDeque::from(buffer), EventTypeFlags::all())
+    }
+
+    #[tokio::test]
+    async fn test_switch() {
+        // The shards are kept so that their event streams stay open.
+        let mut shards = Vec::new();
+        let (switch_tx, switch_rx) = mpsc::unbounded_channel();
+
+        let old = vec![
+            stream(
+                &mut shards,
+                0,
+                vec![Event::GatewayHeartbeatAck, Event::GatewayHeartbeatAck],
+            ),
+            stream(&mut shards, 1, vec![Event::GatewayHeartbeatAck]),
+        ];
+        let mut events = Events::new(old.into_iter().collect(), switch_rx);
+
+        let (id, _) = events.next().now_or_never().flatten().unwrap();
+        assert!(id < 2);
+
+        let new = vec![
+            stream(
+                &mut shards,
+                0,
+                vec![Event::GatewayReconnect, Event::GatewayHeartbeat(1)],
+            ),
+            stream(&mut shards, 1, vec![Event::GatewayHeartbeat(2)]),
+            stream(&mut shards, 2, vec![Event::GatewayHeartbeat(3)]),
+        ];
+        switch_tx
+            .send(new.into_iter().collect::<SelectAll<_>>())
+            .unwrap();
+
+        let mut received = Vec::new();
+
+        while let Some(Some(item)) = events.next().now_or_never() {
+            received.push(item);
+        }
+
+        received.sort_by_key(|(id, _)| *id);
+        assert!(matches!(
+            received.as_slice(),
+            [
+                (0, Event::GatewayReconnect),
+                (0, Event::GatewayHeartbeat(1)),
+                (1, Event::GatewayHeartbeat(2)),
+                (2, Event::GatewayHeartbeat(3)),
+            ]
+        ));
+    }
 }
@@ -1,18 +1,34 @@
 use super::{builder::ClusterBuilder, config::Config, event::Events, scheme::ShardScheme};
 use crate::{
     cluster::event::ShardEventsWithId,
-    shard::{raw_message::Message, Information, ResumeSession, Shard},
-    Intents,
+    shard::{raw_message::Message, Information, ResumeSession, Shard, ShardStartError},
+    EventTypeFlags, Intents,
+};
+use futures_util::{
+    future,
+    stream::{FuturesUnordered, SelectAll, StreamExt},
 };
-use futures_util::{future, stream::SelectAll};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet, VecDeque},
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
     iter::FromIterator,
-    sync::{Arc, Mutex},
+    mem,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc::{self, UnboundedSender},
+    time::{self, Sleep},
 };
 use twilight_http::Client as HttpClient;
+use twilight_model::{gateway::event::Event, id::GuildId};
 
 /// Sending a command to a shard failed.
 #[derive(Debug)]
@@ -161,6 +177,109 @@ pub enum ClusterSendErrorType {
     },
 }
 
+/// Resharding a cluster failed.
+#[derive(Debug)]
+pub struct ClusterReshardError {
+    kind: ClusterReshardErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClusterReshardError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClusterReshardErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ClusterReshardErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ClusterReshardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ClusterReshardErrorType::AlreadyResharding => {
+                f.write_str("the cluster is already being resharded")
+            }
+            ClusterReshardErrorType::EventStreamEnded { id } => {
+                f.write_str("the event stream of new shard ")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(" ended before its guilds were ready")
+            }
+            ClusterReshardErrorType::ReadyTimedOut { id } => {
+                f.write_str("new shard ")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(" didn't receive its ready event in time")
+            }
+            ClusterReshardErrorType::RetrievingGatewayInfo => {
+                f.write_str("getting the bot's gateway info failed")
+            }
+            ClusterReshardErrorType::StartingShard { id } => {
+                f.write_str("starting new shard ")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(" failed")
+            }
+        }
+    }
+}
+
+impl Error for ClusterReshardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ClusterReshardError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClusterReshardErrorType {
+    /// The cluster is already being resharded.
+    AlreadyResharding,
+    /// The event stream of a new shard ended before its guilds were ready.
+    EventStreamEnded {
+        /// ID of the shard.
+        id: u64,
+    },
+    /// A new shard didn't receive its `Ready` event within the
+    /// [reshard timeout].
+    ///
+    /// [reshard timeout]: super::ClusterBuilder::reshard_timeout
+    ReadyTimedOut {
+        /// ID of the shard.
+        id: u64,
+    },
+    /// Retrieving the bot's gateway information via the HTTP API failed.
+    ///
+    /// This can occur when resharding with [automatic sharding].
+    ///
+    /// [automatic sharding]: ShardScheme::Auto
+    RetrievingGatewayInfo,
+    /// Starting a new shard failed.
+    StartingShard {
+        /// ID of the shard.
+        id: u64,
+    },
+}
+
 /// Starting a cluster failed.
 #[derive(Debug)]
 pub struct ClusterStartError {
@@ -223,9 +342,11 @@ pub enum ClusterStartErrorType {
 #[derive(Debug)]
 struct ClusterRef {
     config: Config,
-    shard_from: u64,
-    shard_to: u64,
+    resharding: AtomicBool,
     shards: Mutex<HashMap<u64, Shard>>,
+    /// Sender of the event streams of new shards to switch the cluster's
+    /// event stream to.
+    switch: UnboundedSender<SelectAll<ShardEventsWithId>>,
 }
 
 /// A manager for multiple shards.
@@ -337,15 +458,16 @@ impl Cluster {
 
         #[allow(clippy::from_iter_instead_of_collect)]
         let select_all = SelectAll::from_iter(streams);
+        let (switch, switch_rx) = mpsc::unbounded_channel();
 
         Ok((
             Self(Arc::new(ClusterRef {
                 config,
-                shard_from: scheme.from().expect("shard scheme is not auto"),
-                shard_to: scheme.to().expect("shard scheme is not auto"),
+                resharding: AtomicBool::new(false),
                 shards: Mutex::new(shards),
+                switch,
             })),
-            Events::new(select_all),
+            Events::new(select_all, switch_rx),
         ))
     }
 
@@ -442,8 +564,19 @@ impl Cluster {
     /// # Ok(()) }
     /// ```
     pub async fn up(&self) {
+        let mut ids = self
+            .0
+            .shards
+            .lock()
+            .expect("shards poisoned")
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+
         future::join_all(
-            (self.0.shard_from..=self.0.shard_to).map(|id| Self::start(Arc::clone(&self.0), id)),
+            ids.into_iter()
+                .map(|id| Self::start(Arc::clone(&self.0), id)),
         )
         .await;
     }
@@ -475,6 +608,176 @@ impl Cluster {
             .collect()
     }
 
+    /// Reshard the cluster, replacing its shards with shards started with a new
+    /// shard scheme without downtime.
+    ///
+    /// The new shards are started in the background through the [`Queue`]
+    /// while the current shards keep running. The events of the new shards are
+    /// buffered until every new shard has received its `Ready` event and, with
+    /// the [`GUILDS`] intent, the guilds it contains. Guilds that aren't ready
+    /// within the [reshard timeout] of a shard being started are considered
+    /// ready. The cluster's event stream then switches to the new shards,
+    /// starting with the buffered events, and the current shards are shut
+    /// down. Events received by both sets of shards in the meantime may be
+    /// returned twice.
+    ///
+    /// Resharding with [`ShardScheme::Auto`] retrieves the recommended number
+    /// of shards from the HTTP API again. If the shard IDs and the total
+    /// number of shards are unchanged then the cluster isn't resharded.
+    ///
+    /// The new shards are shut down if resharding fails or the returned future
+    /// is dropped. Note that [`Config::shard_scheme`] remains the shard scheme
+    /// the cluster was built with.
+    ///
+    /// # Examples
+    ///
+    /// Reshard to the recommended number of shards every day:
+    ///
+    /// ```no_run
+    /// use std::{env, time::Duration};
+    /// use twilight_gateway::{cluster::ShardScheme, Cluster, Intents};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (cluster, _) = Cluster::new(env::var("DISCORD_TOKEN")?, Intents::GUILDS).await?;
+    /// cluster.up().await;
+    ///
+    /// loop {
+    ///     tokio::time::sleep(Duration::from_secs(60 * 60 * 24)).await;
+    ///     cluster.reshard(ShardScheme::Auto).await?;
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClusterReshardErrorType::AlreadyResharding`] error type if
+    /// the cluster is already being resharded.
+    ///
+    /// Returns a [`ClusterReshardErrorType::EventStreamEnded`] error type if
+    /// the event stream of a new shard ended before its guilds were ready.
+    ///
+    /// Returns a [`ClusterReshardErrorType::ReadyTimedOut`] error type if a
+    /// new shard didn't receive its `Ready` event within the reshard timeout.
+    ///
+    /// Returns a [`ClusterReshardErrorType::RetrievingGatewayInfo`] error type
+    /// if there was an HTTP error retrieving the gateway information.
+    ///
+    /// Returns a [`ClusterReshardErrorType::StartingShard`] error type if
+    /// starting a new shard failed.
+    ///
+    /// [`Config::shard_scheme`]: super::Config::shard_scheme
+    /// [`GUILDS`]: Intents::GUILDS
+    /// [`Queue`]: crate::queue::Queue
+    /// [reshard timeout]: super::ClusterBuilder::reshard_timeout
+    pub async fn reshard(&self, scheme: ShardScheme) -> Result<(), ClusterReshardError> {
+        if self.0.resharding.swap(true, Ordering::AcqRel) {
+            return Err(ClusterReshardError {
+                kind: ClusterReshardErrorType::AlreadyResharding,
+                source: None,
+            });
+        }
+
+        let mut reshard = Reshard {
+            cluster: &self.0,
+            shards: HashMap::new(),
+        };
+
+        let scheme = match scheme {
+            ShardScheme::Auto => Self::retrieve_shard_count(&self.0.config.http_client)
+                .await
+                .map_err(|source| ClusterReshardError {
+                    kind: ClusterReshardErrorType::RetrievingGatewayInfo,
+                    source: source.into_source(),
+                })?,
+            other => other,
+        };
+
+        let ids = scheme
+            .iter()
+            .expect("shard scheme is not auto")
+            .collect::<Vec<_>>();
+        let total = scheme.total().expect("shard scheme is not auto");
+
+        let unchanged = {
+            let shards = self.0.shards.lock().expect("shards poisoned");
+
+            shards.len() == ids.len()
+                && ids.iter().all(|id| {
+                    shards
+                        .get(id)
+                        .map_or(false, |shard| shard.config().shard() == [*id, total])
+                })
+        };
+
+        if unchanged {
+            tracing::debug!(total, "shards unchanged, not resharding");
+
+            return Ok(());
+        }
+
+        let shard_config = self.0.config.shard_config();
+        let event_types = shard_config.event_types();
+        let guilds = shard_config.intents().contains(Intents::GUILDS);
+
+        // The new shards need to emit the events used to know when their
+        // guilds are ready, which are filtered out if the cluster doesn't
+        // want them.
+        let mut ready_event_types = EventTypeFlags::READY;
+
+        if guilds {
+            ready_event_types |= EventTypeFlags::GUILD_CREATE | EventTypeFlags::GUILD_DELETE;
+        }
+
+        let new_keys = ids.iter().map(|id| [*id, total]).collect::<HashSet<_>>();
+
+        // Old shards with the same ID and total as a new shard save their
+        // session under the same key, so they stop saving until the reshard
+        // either completes or fails.
+        for shard in self.0.shards.lock().expect("shards poisoned").values() {
+            if new_keys.contains(&shard.config().shard()) {
+                shard.set_replaced(true);
+            }
+        }
+
+        let mut starting = FuturesUnordered::new();
+        let mut waiting = HashMap::new();
+
+        for id in ids {
+            let mut config = shard_config.clone();
+            config.event_types |= ready_event_types;
+            config.resume_from_store = false;
+            config.shard = [id, total];
+
+            let (shard, events) = Shard::new_with_config(config);
+            let stream = ShardEventsWithId::buffered(id, events, VecDeque::new(), event_types);
+
+            reshard.shards.insert(id, shard.clone());
+            waiting.insert(id, ReshardShard::new(id, stream, guilds));
+            starting.push(async move { (id, shard.start().await) });
+        }
+
+        let timeout = self.0.config.reshard_timeout();
+
+        future::poll_fn(|cx| Self::poll_reshard(cx, &mut starting, &mut waiting, timeout)).await?;
+
+        let old_shards = {
+            let mut shards = self.0.shards.lock().expect("shards poisoned");
+            let old_shards = mem::replace(&mut *shards, mem::take(&mut reshard.shards));
+            let streams = waiting.into_iter().map(|(_, shard)| shard.stream);
+
+            // The event stream may have been dropped, in which case there's
+            // nothing to switch.
+            let _res = self.0.switch.send(streams.collect());
+
+            old_shards
+        };
+
+        shutdown_replaced(&old_shards, &new_keys);
+
+        Ok(())
+    }
+
     /// Return a Shard by its ID.
     pub fn shard(&self, id: u64) -> Option<Shard> {
         self.0
@@ -645,24 +948,474 @@ impl Cluster {
 
         Some(shard)
     }
+
+    /// Poll the new shards of a reshard, resolving once all of them are
+    /// started and their guilds are ready.
+    ///
+    /// The streams of all shards are drained into their buffers on every
+    /// poll, so that shards that are ready keep reading from the gateway
+    /// while waiting for the others.
+    fn poll_reshard<F: Future<Output = (u64, Result<(), ShardStartError>)>>(
+        cx: &mut Context<'_>,
+        starting: &mut FuturesUnordered<F>,
+        shards: &mut HashMap<u64, ReshardShard>,
+        timeout: Duration,
+    ) -> Poll<Result<(), ClusterReshardError>> {
+        while let Poll::Ready(Some((id, result))) = starting.poll_next_unpin(cx) {
+            result.map_err(|source| ClusterReshardError {
+                kind: ClusterReshardErrorType::StartingShard { id },
+                source: Some(Box::new(source)),
+            })?;
+
+            if let Some(shard) = shards.get_mut(&id) {
+                shard.started(timeout);
+            }
+        }
+
+        let mut ready = starting.is_empty();
+
+        for shard in shards.values_mut() {
+            match shard.poll_ready(cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(source)) => return Poll::Ready(Err(source)),
+                Poll::Pending => ready = false,
+            }
+        }
+
+        if ready {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Shut down shards replaced by other shards.
+///
+/// Their sessions are removed from the session store, unless a replacing shard
+/// has the same ID and total and so uses the same stored session.
+fn shutdown_replaced(shards: &HashMap<u64, Shard>, replacing: &HashSet<[u64; 2]>) {
+    for shard in shards.values() {
+        shard.close();
+
+        if !replacing.contains(&shard.config().shard()) {
+            shard.remove_session();
+        }
+    }
+}
+
+/// New shard of a reshard waiting for its guilds to be ready.
+struct ReshardShard {
+    /// Time at which the shard's remaining guilds are considered ready, once
+    /// it has been started.
+    deadline: Option<Pin<Box<Sleep>>>,
+    id: u64,
+    /// Whether the shard's guilds are ready.
+    is_ready: bool,
+    ready: GuildsReady,
+    stream: ShardEventsWithId,
+}
+
+impl ReshardShard {
+    const fn new(id: u64, stream: ShardEventsWithId, guilds: bool) -> Self {
+        Self {
+            deadline: None,
+            id,
+            is_ready: false,
+            ready: GuildsReady::new(guilds),
+            stream,
+        }
+    }
+
+    /// Start the timeout of the shard, now that it has been started.
+    fn started(&mut self, timeout: Duration) {
+        self.deadline = Some(Box::pin(time::sleep(timeout)));
+    }
+
+    /// Buffer the shard's available events, resolving once its guilds are
+    /// ready.
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), ClusterReshardError>> {
+        let was_ready = self.is_ready;
+        let ready = &mut self.ready;
+        let is_ready = &mut self.is_ready;
+
+        let ended = self
+            .stream
+            .poll_buffer(cx, |event| {
+                if !*is_ready {
+                    *is_ready = ready.update(event);
+                }
+            })
+            .is_ready();
+
+        if !self.is_ready {
+            if ended {
+                return Poll::Ready(Err(ClusterReshardError {
+                    kind: ClusterReshardErrorType::EventStreamEnded { id: self.id },
+                    source: None,
+                }));
+            }
+
+            let timed_out = self
+                .deadline
+                .as_mut()
+                .map_or(false, |deadline| deadline.as_mut().poll(cx).is_ready());
+
+            if !timed_out {
+                return Poll::Pending;
+            }
+
+            let unavailable = self.ready.expire().ok_or(ClusterReshardError {
+                kind: ClusterReshardErrorType::ReadyTimedOut { id: self.id },
+                source: None,
+            })?;
+
+            tracing::warn!(
+                shard_id = self.id,
+                unavailable,
+                "timed out waiting for guilds of new shard, considering them ready",
+            );
+
+            self.is_ready = true;
+        }
+
+        if !was_ready {
+            tracing::debug!(shard_id = self.id, "new shard is ready");
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Shards started by a reshard.
+///
+/// Shuts down the shards if the reshard didn't complete, and allows the
+/// cluster to be resharded again.
+struct Reshard<'a> {
+    cluster: &'a ClusterRef,
+    shards: HashMap<u64, Shard>,
+}
+
+impl Drop for Reshard<'_> {
+    fn drop(&mut self) {
+        let mut current = HashSet::new();
+
+        for shard in self
+            .cluster
+            .shards
+            .lock()
+            .expect("shards poisoned")
+            .values()
+        {
+            // If the reshard didn't complete these are the old shards, which
+            // are still in use and have to save their sessions again.
+            shard.set_replaced(false);
+            current.insert(shard.config().shard());
+        }
+
+        shutdown_replaced(&self.shards, &current);
+
+        self.cluster.resharding.store(false, Ordering::Release);
+    }
+}
+
+/// Whether the guilds of a shard's session are ready.
+///
+/// A session's guilds are ready once a guild create or delete event has been
+/// received for each unavailable guild of its `Ready` event.
+#[derive(Debug)]
+struct GuildsReady {
+    /// Whether the shard receives guild events.
+    guilds: bool,
+    /// Guilds of the session that aren't ready, if the session is ready.
+    unavailable: Option<HashSet<GuildId>>,
+}
+
+impl GuildsReady {
+    const fn new(guilds: bool) -> Self {
+        Self {
+            guilds,
+            unavailable: None,
+        }
+    }
+
+    /// Update the state with an event, returning whether the guilds are ready.
+    fn update(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Ready(ready) => {
+                let unavailable = if self.guilds {
+                    ready.guilds.iter().map(|guild| guild.id).collect()
+                } else {
+                    HashSet::new()
+                };
+
+                self.unavailable = Some(unavailable);
+            }
+            Event::GuildCreate(guild) => self.available(guild.id),
+            Event::GuildDelete(guild) => self.available(guild.id),
+            _ => {}
+        }
+
+        self.unavailable.as_ref().map_or(false, HashSet::is_empty)
+    }
+
+    /// Consider the remaining unavailable guilds ready, returning how many
+    /// there were if the session is ready.
+    fn expire(&mut self) -> Option<usize> {
+        let unavailable = self.unavailable.as_mut()?;
+        let count = unavailable.len();
+        unavailable.clear();
+
+        Some(count)
+    }
+
+    fn available(&mut self, guild_id: GuildId) {
+        if let Some(unavailable) = self.unavailable.as_mut() {
+            unavailable.remove(&guild_id);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterSendError,
-        ClusterSendErrorType, ClusterStartError, ClusterStartErrorType,
+        shutdown_replaced, Cluster, ClusterCommandError, ClusterCommandErrorType,
+        ClusterReshardError, ClusterReshardErrorType, ClusterSendError, ClusterSendErrorType,
+        ClusterStartError, ClusterStartErrorType, GuildsReady, Reshard, ShardScheme,
+    };
+    use crate::shard::{
+        store::{SessionStore, SessionStoreFuture},
+        ResumeSession, Shard,
     };
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{
+        collections::HashMap,
+        error::Error,
+        fmt::Debug,
+        sync::{atomic::Ordering, Arc, Mutex},
+        time::Duration,
+    };
+    use tokio::time;
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::{GuildDelete, Ready},
+            Intents,
+        },
+        guild::UnavailableGuild,
+        id::{ApplicationId, GuildId, UserId},
+        oauth::PartialApplication,
+        user::{CurrentUser, UserFlags},
+    };
 
     assert_impl_all!(ClusterCommandErrorType: Debug, Send, Sync);
     assert_fields!(ClusterCommandErrorType::ShardNonexistent: id);
     assert_impl_all!(ClusterCommandError: Error, Send, Sync);
+    assert_impl_all!(ClusterReshardErrorType: Debug, Send, Sync);
+    assert_fields!(ClusterReshardErrorType::EventStreamEnded: id);
+    assert_fields!(ClusterReshardErrorType::ReadyTimedOut: id);
+    assert_fields!(ClusterReshardErrorType::StartingShard: id);
+    assert_impl_all!(ClusterReshardError: Error, Send, Sync);
     assert_impl_all!(ClusterSendErrorType: Debug, Send, Sync);
     assert_fields!(ClusterSendErrorType::ShardNonexistent: id);
     assert_impl_all!(ClusterSendError: Error, Send, Sync);
     assert_impl_all!(ClusterStartErrorType: Debug, Send, Sync);
     assert_impl_all!(ClusterStartError: Error, Send, Sync);
     assert_impl_all!(Cluster: Clone, Debug, Send, Sync);
+
+    #[derive(Debug, Default)]
+    struct MemoryStore(Mutex<HashMap<[u64; 2], ResumeSession>>);
+
+    impl MemoryStore {
+        fn contains(&self, shard: [u64; 2]) -> bool {
+            self.0.lock().unwrap().contains_key(&shard)
+        }
+
+        fn insert(&self, shard: [u64; 2]) {
+            self.0.lock().unwrap().insert(
+                shard,
+                ResumeSession {
+                    session_id: "session".to_owned(),
+                    sequence: 1,
+                },
+            );
+        }
+
+        /// Wait for the spawned removal of a shard's session.
+        async fn removed(&self, shard: [u64; 2]) {
+            time::timeout(Duration::from_secs(10), async {
+                while self.contains(shard) {
+                    time::sleep(Duration::from_millis(1)).await;
+                }
+            })
+            .await
+            .expect("session wasn't removed");
+        }
+    }
+
+    impl SessionStore for MemoryStore {
+        fn load(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, Option<ResumeSession>> {
+            let session = self.0.lock().unwrap().get(&shard).cloned();
+
+            Box::pin(async move { Ok(session) })
+        }
+
+        fn save(&self, shard: [u64; 2], session: ResumeSession) -> SessionStoreFuture<'_, ()> {
+            self.0.lock().unwrap().insert(shard, session);
+
+            Box::pin(async { Ok(()) })
+        }
+
+        fn remove(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, ()> {
+            self.0.lock().unwrap().remove(&shard);
+
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn shard(store: &Arc<MemoryStore>, id: u64, total: u64) -> Shard {
+        Shard::builder("token", Intents::empty())
+            .session_store(Arc::clone(store) as Arc<dyn SessionStore>)
+            .shard(id, total)
+            .unwrap()
+            .build()
+            .0
+    }
+
+    fn ready_event(guild_ids: &[u64]) -> Event {
+        Event::Ready(Box::new(Ready {
+            application: PartialApplication {
+                flags: UserFlags::empty(),
+                id: ApplicationId(1),
+            },
+            guilds: guild_ids
+                .iter()
+                .map(|id| UnavailableGuild {
+                    id: GuildId(*id),
+                    unavailable: true,
+                })
+                .collect(),
+            session_id: "session".to_owned(),
+            shard: Some([0, 2]),
+            user: CurrentUser {
+                avatar: None,
+                bot: true,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(2),
+                locale: None,
+                mfa_enabled: false,
+                name: "bot".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                verified: None,
+            },
+            version: 8,
+        }))
+    }
+
+    fn guild_delete(id: u64) -> Event {
+        Event::GuildDelete(Box::new(GuildDelete {
+            id: GuildId(id),
+            unavailable: true,
+        }))
+    }
+
+    #[test]
+    fn test_guilds_ready() {
+        let mut ready = GuildsReady::new(true);
+        assert!(!ready.update(&guild_delete(1)));
+        assert!(!ready.update(&ready_event(&[1, 2])));
+        assert!(!ready.update(&guild_delete(1)));
+        assert!(!ready.update(&guild_delete(3)));
+        assert!(ready.update(&guild_delete(2)));
+
+        // Guilds can only be considered ready once the session is.
+        let mut ready = GuildsReady::new(true);
+        assert!(ready.expire().is_none());
+        assert!(!ready.update(&ready_event(&[1, 2])));
+        assert!(!ready.update(&guild_delete(1)));
+        assert_eq!(Some(1), ready.expire());
+        assert!(ready.update(&guild_delete(3)));
+
+        // Without guild events the guilds are ready once the session is.
+        let mut ready = GuildsReady::new(false);
+        assert!(!ready.update(&guild_delete(1)));
+        assert!(ready.update(&ready_event(&[1, 2])));
+    }
+
+    /// Sessions of replaced shards are removed, unless a new shard has the
+    /// same ID and total and so uses the same session.
+    #[tokio::test]
+    async fn test_shutdown_replaced() {
+        let store = Arc::new(MemoryStore::default());
+        store.insert([0, 2]);
+        store.insert([1, 2]);
+
+        let shards = vec![(0, shard(&store, 0, 2)), (1, shard(&store, 1, 2))]
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        shutdown_replaced(&shards, &vec![[0, 2], [2, 3]].into_iter().collect());
+
+        store.removed([1, 2]).await;
+        assert!(store.contains([0, 2]));
+    }
+
+    #[tokio::test]
+    async fn test_reshard_guard() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let scheme = ShardScheme::Range {
+            from: 0,
+            to: 1,
+            total: 2,
+        };
+        let store = Arc::new(MemoryStore::default());
+        let (cluster, _events) = Cluster::builder("token", Intents::empty())
+            .shard_scheme(scheme.clone())
+            .session_store(Arc::clone(&store) as Arc<dyn SessionStore>)
+            .build()
+            .await?;
+
+        store.insert([0, 2]);
+        store.insert([1, 2]);
+        store.insert([0, 3]);
+
+        cluster.0.resharding.store(true, Ordering::Release);
+
+        let error = cluster.reshard(scheme.clone()).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ClusterReshardErrorType::AlreadyResharding
+        ));
+
+        // Dropping the guard of a reshard that didn't complete shuts down its
+        // shards, keeping the sessions still used by the cluster's shards.
+        let reshard = Reshard {
+            cluster: &cluster.0,
+            shards: vec![(0, shard(&store, 0, 3)), (1, shard(&store, 1, 2))]
+                .into_iter()
+                .collect(),
+        };
+        drop(reshard);
+
+        store.removed([0, 3]).await;
+        assert!(store.contains([0, 2]));
+        assert!(store.contains([1, 2]));
+        assert!(!cluster.0.resharding.load(Ordering::Acquire));
+
+        // Resharding to the current scheme does nothing.
+        cluster.reshard(scheme).await?;
+        assert!(!cluster.0.resharding.load(Ordering::Acquire));
+
+        let mut shards = cluster
+            .shards()
+            .iter()
+            .map(|shard| shard.config().shard())
+            .collect::<Vec<_>>();
+        shards.sort_unstable();
+        assert_eq!(vec![[0, 2], [1, 2]], shards);
+
+        Ok(())
+    }
 }
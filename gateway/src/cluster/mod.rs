@@ -95,8 +95,8 @@ pub use self::{
     config::Config,
     event::Events,
     r#impl::{
-        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterStartError,
-        ClusterStartErrorType,
+        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterReshardError,
+        ClusterReshardErrorType, ClusterStartError, ClusterStartErrorType,
     },
     scheme::{ShardScheme, ShardSchemeRangeError, ShardSchemeRangeErrorType},
 };
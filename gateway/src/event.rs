@@ -119,6 +119,19 @@ bitflags! {
         const STAGE_INSTANCE_DELETE = 1 << 58;
         /// Stage instance was updated in a stage channel.
         const STAGE_INSTANCE_UPDATE = 1 << 59;
+        /// Thread was created or the current user was added to a private
+        /// thread.
+        const THREAD_CREATE = 1 << 50;
+        /// Thread was deleted.
+        const THREAD_DELETE = 1 << 51;
+        /// Active threads of a guild's channels were synced.
+        const THREAD_LIST_SYNC = 1 << 52;
+        /// Thread member of the current user was updated.
+        const THREAD_MEMBER_UPDATE = 1 << 53;
+        /// Members were added to or removed from a thread.
+        const THREAD_MEMBERS_UPDATE = 1 << 54;
+        /// Thread was updated.
+        const THREAD_UPDATE = 1 << 55;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
@@ -189,6 +202,12 @@ impl From<EventType> for EventTypeFlags {
             EventType::StageInstanceCreate => EventTypeFlags::STAGE_INSTANCE_CREATE,
             EventType::StageInstanceDelete => EventTypeFlags::STAGE_INSTANCE_DELETE,
             EventType::StageInstanceUpdate => EventTypeFlags::STAGE_INSTANCE_UPDATE,
+            EventType::ThreadCreate => EventTypeFlags::THREAD_CREATE,
+            EventType::ThreadDelete => EventTypeFlags::THREAD_DELETE,
+            EventType::ThreadListSync => EventTypeFlags::THREAD_LIST_SYNC,
+            EventType::ThreadMemberUpdate => EventTypeFlags::THREAD_MEMBER_UPDATE,
+            EventType::ThreadMembersUpdate => EventTypeFlags::THREAD_MEMBERS_UPDATE,
+            EventType::ThreadUpdate => EventTypeFlags::THREAD_UPDATE,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
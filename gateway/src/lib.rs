@@ -44,6 +44,16 @@
 //! twilight-gateway = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! #### `etf`
+//!
+//! The `etf` feature connects to the gateway with Erlang's External Term
+//! Format (ETF) instead of JSON. Payloads are smaller and cheaper to parse,
+//! while events are received and commands are sent as the same types. It is
+//! not enabled by default.
+//!
+//! When enabled, [`Event::ShardPayload`] events contain the ETF bytes of
+//! payloads instead of JSON.
+//!
 //! ### TLS
 //!
 //! `twilight-gateway` has features to enable [`async-tungstenite`] and
@@ -84,6 +94,13 @@
 //! Enabling **only** `zlib-simd` will make the library use [`zlib-ng`] which is a modern
 //! fork of zlib that is faster and more effective, but it needs `cmake` to compile.
 //!
+//! ### zstd
+//!
+//! The `zstd` feature uses zstd for transport compression instead of zlib,
+//! which compresses payloads better and is faster to decompress. It takes
+//! precedence over `compression` if both are enabled, and it is not enabled by
+//! default.
+//!
 //! ### Metrics
 //!
 //! The `metrics` feature provides metrics information via the `metrics` crate.
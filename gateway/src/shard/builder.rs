@@ -1,4 +1,8 @@
-use super::{config::Config, Events, Shard};
+use super::{
+    config::{Backpressure, Config},
+    store::SessionStore,
+    Events, Shard,
+};
 use crate::EventTypeFlags;
 use std::{
     error::Error,
@@ -174,6 +178,8 @@ impl ShardBuilder {
         }
 
         Self(Config {
+            backpressure: Backpressure::default(),
+            event_buffer: None,
             event_types: EventTypeFlags::default(),
             gateway_url: None,
             http_client: HttpClient::new(token.clone()),
@@ -181,6 +187,8 @@ impl ShardBuilder {
             large_threshold: 250,
             presence: None,
             queue: Arc::new(Box::new(LocalQueue::new())),
+            resume_from_store: true,
+            session_store: None,
             shard: [0, 1],
             token: token.into_boxed_str(),
             session_id: None,
@@ -193,6 +201,39 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Bound the event stream to a capacity, with the behavior when it's
+    /// full.
+    ///
+    /// By default the event stream is unbounded, so if events aren't received
+    /// as fast as they come in, such as while requesting the members of a
+    /// large guild, they use an unbounded amount of memory. The number of
+    /// events in the stream is available via [`Information::events_queued`].
+    ///
+    /// A capacity of zero is treated as a capacity of one.
+    ///
+    /// # Examples
+    ///
+    /// Drop presence updates and typing events if more than 1000 events are
+    /// waiting to be received:
+    ///
+    /// ```no_run
+    /// use twilight_gateway::{shard::Backpressure, EventTypeFlags, Intents, Shard};
+    ///
+    /// let low_priority = EventTypeFlags::PRESENCE_UPDATE | EventTypeFlags::TYPING_START;
+    ///
+    /// let (shard, events) = Shard::builder("token", Intents::all())
+    ///     .event_buffer(1000, Backpressure::DropLowPriority(low_priority))
+    ///     .build();
+    /// ```
+    ///
+    /// [`Information::events_queued`]: super::Information::events_queued
+    pub const fn event_buffer(mut self, capacity: usize, backpressure: Backpressure) -> Self {
+        self.0.backpressure = backpressure;
+        self.0.event_buffer = Some(capacity);
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -324,6 +365,47 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the store to persist the shard's session in.
+    ///
+    /// The shard saves its session in the store when it receives a `Ready` or
+    /// `Resumed` event, and checkpoints its sequence each time the gateway
+    /// acknowledges a heartbeat. When started, the shard resumes the session
+    /// loaded from the store instead of identifying, so restarting the process
+    /// doesn't require identifying again. Sessions the gateway invalidates are
+    /// removed from the store.
+    ///
+    /// Session details provided via [`ClusterBuilder::resume_sessions`] take
+    /// precedence over the store.
+    ///
+    /// Refer to the [`store`] module for more information.
+    ///
+    /// The default value is no store.
+    ///
+    /// # Examples
+    ///
+    /// Persist the shard's session in the `sessions` directory:
+    ///
+    /// ```no_run
+    /// use std::{env, sync::Arc};
+    /// use twilight_gateway::{shard::store::FileSessionStore, Intents, Shard};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    ///
+    /// let shard = Shard::builder(token, Intents::GUILD_MESSAGES)
+    ///     .session_store(Arc::new(FileSessionStore::new("sessions")))
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`ClusterBuilder::resume_sessions`]: crate::cluster::ClusterBuilder::resume_sessions
+    /// [`store`]: super::store
+    pub fn session_store(mut self, session_store: Arc<dyn SessionStore>) -> Self {
+        self.0.session_store = Some(session_store);
+
+        self
+    }
+
     /// Set the shard ID to connect as, and the total number of shards used by
     /// the bot.
     ///
@@ -1,9 +1,42 @@
+use super::store::SessionStore;
 use crate::EventTypeFlags;
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
 use twilight_model::gateway::{payload::update_presence::UpdatePresencePayload, Intents};
 
+/// Behavior of a bounded event stream when it's full.
+///
+/// Refer to [`ShardBuilder::event_buffer`] for more information.
+///
+/// [`ShardBuilder::event_buffer`]: super::ShardBuilder::event_buffer
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Backpressure {
+    /// Stop reading from the websocket until the stream has room for the
+    /// event.
+    ///
+    /// The gateway may close the connection if the shard stops reading for a
+    /// long time, such as when heartbeat acknowledgements aren't processed.
+    Block,
+    /// Drop the event.
+    ///
+    /// The number of dropped events is available via [`Information::events_dropped`].
+    ///
+    /// [`Information::events_dropped`]: super::Information::events_dropped
+    Drop,
+    /// Drop the event if its type is included in the low priority event
+    /// types, otherwise stop reading from the websocket until the stream has
+    /// room for it.
+    DropLowPriority(EventTypeFlags),
+}
+
+impl Default for Backpressure {
+    fn default() -> Self {
+        Self::Block
+    }
+}
+
 /// The configuration used by the shard to identify with the gateway and
 /// operate.
 ///
@@ -12,6 +45,8 @@ use twilight_model::gateway::{payload::update_presence::UpdatePresencePayload, I
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(super) backpressure: Backpressure,
+    pub(super) event_buffer: Option<usize>,
     pub(crate) event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Client,
@@ -19,6 +54,12 @@ pub struct Config {
     pub(super) large_threshold: u64,
     pub(super) presence: Option<UpdatePresencePayload>,
     pub(super) queue: Arc<Box<dyn Queue>>,
+    /// Whether to load the session to resume from the session store.
+    ///
+    /// Shards started by a reshard identify, because a shard with the same ID
+    /// and total may still be running.
+    pub(crate) resume_from_store: bool,
+    pub(crate) session_store: Option<Arc<dyn SessionStore>>,
     pub(crate) shard: [u64; 2],
     pub(super) token: Box<str>,
     pub(crate) session_id: Option<Box<str>>,
@@ -26,6 +67,16 @@ pub struct Config {
 }
 
 impl Config {
+    /// Behavior of the event stream when it's full.
+    pub const fn backpressure(&self) -> Backpressure {
+        self.backpressure
+    }
+
+    /// Capacity of the event stream, if it's bounded.
+    pub const fn event_buffer(&self) -> Option<usize> {
+        self.event_buffer
+    }
+
     /// Copy of the event type flags.
     pub const fn event_types(&self) -> EventTypeFlags {
         self.event_types
@@ -62,6 +113,12 @@ impl Config {
         self.presence.as_ref()
     }
 
+    /// Return an immutable reference to the store used to persist the
+    /// shard's session.
+    pub fn session_store(&self) -> Option<&dyn SessionStore> {
+        self.session_store.as_deref()
+    }
+
     /// The shard's ID and the total number of shards used by the bot.
     pub const fn shard(&self) -> [u64; 2] {
         self.shard
@@ -1,11 +1,24 @@
+#[cfg(feature = "etf")]
+use super::etf;
+#[cfg(not(feature = "etf"))]
 use super::json;
+use super::Backpressure;
 use crate::{Event, EventTypeFlags};
 use std::{
+    collections::VecDeque,
     convert::TryFrom,
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc::{
+    self, error::TrySendError, Receiver as BoundedReceiver, Sender as BoundedSender,
+    UnboundedReceiver, UnboundedSender,
 };
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use twilight_model::gateway::event::shard::Payload;
 
 #[derive(Debug)]
@@ -60,20 +73,108 @@ pub enum EmitJsonErrorType {
     Parsing,
 }
 
+/// Sending half of the channel of events.
+#[derive(Clone, Debug)]
+enum Sender {
+    Bounded(BoundedSender<Event>),
+    Unbounded(UnboundedSender<Event>),
+}
+
+/// Receiving half of the channel of events.
+#[derive(Debug)]
+pub enum Receiver {
+    Bounded(BoundedReceiver<Event>),
+    Unbounded(UnboundedReceiver<Event>),
+}
+
+impl Receiver {
+    /// Poll to receive the next event, decrementing the number of queued
+    /// events.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>, counters: &Counters) -> Poll<Option<Event>> {
+        let poll = match self {
+            Self::Bounded(rx) => rx.poll_recv(cx),
+            Self::Unbounded(rx) => rx.poll_recv(cx),
+        };
+
+        if let Poll::Ready(Some(_)) = &poll {
+            counters.queued.fetch_sub(1, Ordering::Relaxed);
+        }
+
+        poll
+    }
+}
+
+/// Counters of the events of the channel, shared between the emitter and the
+/// event stream.
+#[derive(Debug, Default)]
+pub struct Counters {
+    dropped: AtomicU64,
+    queued: AtomicUsize,
+}
+
+impl Counters {
+    /// Number of events that were dropped because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of events in the channel that haven't been received yet.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+}
+
 /// Emitter over a listener with some useful things on top to abstract common
 /// operations.
 #[derive(Clone, Debug)]
 pub struct Emitter {
+    backpressure: Backpressure,
+    counters: Arc<Counters>,
     event_types: EventTypeFlags,
-    tx: UnboundedSender<Event>,
+    /// Events waiting for room in a full bounded channel, which are sent
+    /// before the next payload is read.
+    overflow: Arc<Mutex<VecDeque<Event>>>,
+    tx: Sender,
 }
 
 impl Emitter {
     /// Create a new emitter for events and bytes.
-    pub fn new(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<Event>) {
-        let (tx, rx) = mpsc::unbounded_channel();
+    ///
+    /// The channel is bounded if a capacity is provided, in which case the
+    /// backpressure determines what happens to events when it's full.
+    pub fn new(
+        event_types: EventTypeFlags,
+        capacity: Option<usize>,
+        backpressure: Backpressure,
+    ) -> (Self, Receiver, Arc<Counters>) {
+        let (tx, rx) = if let Some(capacity) = capacity {
+            let (tx, rx) = mpsc::channel(capacity.max(1));
+
+            (Sender::Bounded(tx), Receiver::Bounded(rx))
+        } else {
+            let (tx, rx) = mpsc::unbounded_channel();
+
+            (Sender::Unbounded(tx), Receiver::Unbounded(rx))
+        };
+
+        let counters = Arc::new(Counters::default());
+
+        (
+            Self {
+                backpressure,
+                counters: Arc::clone(&counters),
+                event_types,
+                overflow: Arc::new(Mutex::new(VecDeque::new())),
+                tx,
+            },
+            rx,
+            counters,
+        )
+    }
 
-        (Self { event_types, tx }, rx)
+    /// Counters of the events of the channel.
+    pub fn counters(&self) -> &Counters {
+        &self.counters
     }
 
     /// Whether the configured event types include an individual event type.
@@ -120,6 +221,7 @@ impl Emitter {
     /// Returns a [`EmitJsonError::Parsing`] error type if the combination of
     /// the provided opcode, sequence, event type, and JSON could not be parsed
     /// into an event.
+    #[cfg(not(feature = "etf"))]
     pub fn json(
         &self,
         op: u8,
@@ -127,17 +229,7 @@ impl Emitter {
         event_type: Option<&str>,
         json: &mut str,
     ) -> Result<(), EmitJsonError> {
-        let flag = EventTypeFlags::try_from((op, event_type)).map_err(|(op, event_type)| {
-            EmitJsonError {
-                kind: EmitJsonErrorType::EventTypeUnknown {
-                    event_type: event_type.map(ToOwned::to_owned),
-                    op,
-                },
-                source: None,
-            }
-        })?;
-
-        if self.wants(flag) {
+        if self.wants(Self::flag(op, event_type)?) {
             let gateway_event =
                 json::parse_gateway_event(op, seq, event_type, json).map_err(|source| {
                     EmitJsonError {
@@ -151,38 +243,285 @@ impl Emitter {
         Ok(())
     }
 
+    /// Emit an ETF payload that hasn't been deserialized yet, but only if the
+    /// listener wants the event type.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`EmitJsonError::EventTypeUnknown`] error type if the
+    /// event type is unknown.
+    ///
+    /// Returns a [`EmitJsonError::Parsing`] error type if the combination of
+    /// the provided opcode, sequence, event type, and term could not be parsed
+    /// into an event.
+    #[cfg(feature = "etf")]
+    pub fn etf(
+        &self,
+        op: u8,
+        seq: Option<u64>,
+        event_type: Option<&str>,
+        bytes: &[u8],
+    ) -> Result<(), EmitJsonError> {
+        if self.wants(Self::flag(op, event_type)?) {
+            let gateway_event =
+                etf::parse_gateway_event(op, seq, event_type, bytes).map_err(|source| {
+                    EmitJsonError {
+                        kind: EmitJsonErrorType::Parsing,
+                        source: Some(Box::new(source)),
+                    }
+                })?;
+            self.event(Event::from(gateway_event));
+        }
+
+        Ok(())
+    }
+
+    /// Event type flag of an opcode and dispatch event type.
+    fn flag(op: u8, event_type: Option<&str>) -> Result<EventTypeFlags, EmitJsonError> {
+        EventTypeFlags::try_from((op, event_type)).map_err(|(op, event_type)| EmitJsonError {
+            kind: EmitJsonErrorType::EventTypeUnknown {
+                event_type: event_type.map(ToOwned::to_owned),
+                op,
+            },
+            source: None,
+        })
+    }
+
+    /// Wait for the events that didn't fit in the bounded channel to be sent.
+    ///
+    /// This is called before reading the next payload, so that the shard
+    /// stops reading from the websocket while the channel is full.
+    pub async fn flush(&self) {
+        let tx = match &self.tx {
+            Sender::Bounded(tx) => tx,
+            Sender::Unbounded(_) => return,
+        };
+
+        while !self.overflow().is_empty() {
+            // Reserve room before taking the event so that it isn't lost if
+            // the future is dropped while waiting.
+            let permit = if let Ok(permit) = tx.reserve().await {
+                permit
+            } else {
+                self.overflow().clear();
+
+                return;
+            };
+
+            if let Some(event) = self.overflow().pop_front() {
+                self.counters.queued.fetch_add(1, Ordering::Relaxed);
+                permit.send(event);
+            }
+        }
+    }
+
+    fn overflow(&self) -> MutexGuard<'_, VecDeque<Event>> {
+        self.overflow.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     fn send(&self, event: Event) {
-        let _res = self.tx.send(event);
+        let tx = match &self.tx {
+            Sender::Bounded(tx) => tx,
+            Sender::Unbounded(tx) => {
+                self.counters.queued.fetch_add(1, Ordering::Relaxed);
+
+                if tx.send(event).is_err() {
+                    self.counters.queued.fetch_sub(1, Ordering::Relaxed);
+                }
+
+                return;
+            }
+        };
+
+        let mut overflow = self.overflow();
+
+        // Keep the order of events if some are already waiting for room,
+        // treating the channel as full.
+        let event = if overflow.is_empty() {
+            self.counters.queued.fetch_add(1, Ordering::Relaxed);
+
+            match tx.try_send(event) {
+                Ok(()) => return,
+                Err(TrySendError::Closed(_)) => {
+                    self.counters.queued.fetch_sub(1, Ordering::Relaxed);
+
+                    return;
+                }
+                Err(TrySendError::Full(event)) => {
+                    self.counters.queued.fetch_sub(1, Ordering::Relaxed);
+
+                    event
+                }
+            }
+        } else {
+            event
+        };
+
+        let drop = match self.backpressure {
+            Backpressure::Block => false,
+            Backpressure::Drop => true,
+            Backpressure::DropLowPriority(low_priority) => {
+                low_priority.contains(EventTypeFlags::from(event.kind()))
+            }
+        };
+
+        if drop {
+            tracing::debug!(kind = ?event.kind(), "event channel full, dropping event");
+            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+        } else {
+            overflow.push_back(event);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Emitter;
-    use crate::{Event, EventTypeFlags};
+    use super::{Counters, Emitter, Receiver};
+    use crate::{shard::Backpressure, Event, EventTypeFlags};
+    use futures_util::future;
+    use std::sync::Arc;
     use tokio::time::{self, Duration};
+    use twilight_model::{
+        gateway::payload::TypingStart,
+        id::{ChannelId, UserId},
+    };
+
+    async fn recv(rx: &mut Receiver, counters: &Arc<Counters>) -> Option<Event> {
+        future::poll_fn(|cx| rx.poll_recv(cx, counters)).await
+    }
+
+    fn typing_start() -> Event {
+        Event::TypingStart(Box::new(TypingStart {
+            channel_id: ChannelId(1),
+            guild_id: None,
+            member: None,
+            timestamp: 1,
+            user_id: UserId(2),
+        }))
+    }
 
     #[tokio::test]
     async fn test_bytes_send() {
-        let (emitter, mut rx) = Emitter::new(EventTypeFlags::SHARD_PAYLOAD);
+        let (emitter, mut rx, counters) =
+            Emitter::new(EventTypeFlags::SHARD_PAYLOAD, None, Backpressure::Block);
         emitter.bytes(&[1]);
 
-        assert!(rx.recv().await.is_some());
-        assert!(time::timeout(Duration::from_millis(10), rx.recv())
-            .await
-            .is_err());
+        assert!(recv(&mut rx, &counters).await.is_some());
+        assert!(
+            time::timeout(Duration::from_millis(10), recv(&mut rx, &counters))
+                .await
+                .is_err()
+        );
     }
 
     #[tokio::test]
     async fn test_event_sends_to_rx() {
-        let (emitter, mut rx) = Emitter::new(EventTypeFlags::default());
+        let (emitter, mut rx, counters) =
+            Emitter::new(EventTypeFlags::default(), None, Backpressure::Block);
         emitter.event(Event::GatewayReconnect);
+        assert_eq!(1, counters.queued());
 
-        assert!(rx.recv().await.is_some());
+        assert!(recv(&mut rx, &counters).await.is_some());
+        assert_eq!(0, counters.queued());
 
         // now check that the event didn't send the event twice
-        assert!(time::timeout(Duration::from_millis(10), rx.recv())
+        assert!(
+            time::timeout(Duration::from_millis(10), recv(&mut rx, &counters))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backpressure_drop() {
+        let (emitter, mut rx, counters) =
+            Emitter::new(EventTypeFlags::default(), Some(1), Backpressure::Drop);
+        emitter.event(Event::GatewayReconnect);
+        emitter.event(Event::GatewayHeartbeatAck);
+        emitter.flush().await;
+
+        assert_eq!(1, counters.dropped());
+        assert_eq!(1, counters.queued());
+        assert!(matches!(
+            recv(&mut rx, &counters).await,
+            Some(Event::GatewayReconnect)
+        ));
+        assert!(
+            time::timeout(Duration::from_millis(10), recv(&mut rx, &counters))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backpressure_drop_low_priority() {
+        let (emitter, mut rx, counters) = Emitter::new(
+            EventTypeFlags::default(),
+            Some(1),
+            Backpressure::DropLowPriority(EventTypeFlags::TYPING_START),
+        );
+        emitter.event(Event::GatewayReconnect);
+        emitter.event(typing_start());
+        emitter.event(Event::GatewayHeartbeatAck);
+        // Events waiting for room are kept in order, so low priority events
+        // after them are dropped too.
+        emitter.event(typing_start());
+
+        assert_eq!(2, counters.dropped());
+        assert_eq!(1, counters.queued());
+
+        let flush = tokio::spawn({
+            let emitter = emitter.clone();
+
+            async move { emitter.flush().await }
+        });
+
+        assert!(matches!(
+            recv(&mut rx, &counters).await,
+            Some(Event::GatewayReconnect)
+        ));
+        assert!(matches!(
+            recv(&mut rx, &counters).await,
+            Some(Event::GatewayHeartbeatAck)
+        ));
+        flush.await.unwrap();
+        assert_eq!(0, counters.queued());
+    }
+
+    #[tokio::test]
+    async fn test_backpressure_block() {
+        let (emitter, mut rx, counters) =
+            Emitter::new(EventTypeFlags::default(), Some(1), Backpressure::Block);
+        emitter.event(Event::GatewayReconnect);
+        emitter.event(Event::GatewayHeartbeatAck);
+        emitter.event(Event::Resumed);
+
+        // Flushing waits until there's room for every event.
+        assert!(time::timeout(Duration::from_millis(10), emitter.flush())
             .await
             .is_err());
+        assert_eq!(0, counters.dropped());
+
+        // Cancelling a flush doesn't lose events.
+        let flush = tokio::spawn({
+            let emitter = emitter.clone();
+
+            async move { emitter.flush().await }
+        });
+
+        assert!(matches!(
+            recv(&mut rx, &counters).await,
+            Some(Event::GatewayReconnect)
+        ));
+        assert!(matches!(
+            recv(&mut rx, &counters).await,
+            Some(Event::GatewayHeartbeatAck)
+        ));
+        assert!(matches!(
+            recv(&mut rx, &counters).await,
+            Some(Event::Resumed)
+        ));
+        flush.await.unwrap();
+        assert_eq!(0, counters.queued());
     }
 }
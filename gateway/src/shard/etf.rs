@@ -0,0 +1,1303 @@
+//! Encoding and decoding of gateway payloads in Erlang's [External Term Format].
+//!
+//! Only the terms sent by the gateway are supported: atoms, binaries,
+//! integers, floats, lists, maps, and tuples. Atoms are decoded as strings,
+//! except for `true` and `false`, which are booleans, and `nil`, which is
+//! null. Integers, such as IDs, may be decoded as strings.
+//!
+//! Strings are encoded as binaries and null values are encoded as the `nil`
+//! atom.
+//!
+//! [External Term Format]: https://www.erlang.org/doc/apps/erts/erl_ext_dist.html
+
+use super::json::{GatewayEventParsingError, GatewayEventParsingErrorType};
+use serde::{
+    de::{
+        self, value::BorrowedStrDeserializer, DeserializeSeed, EnumAccess, IgnoredAny,
+        IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{
+        self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Deserialize,
+};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::{self, Utf8Error},
+};
+use twilight_model::gateway::event::{GatewayEvent, GatewayEventDeserializer};
+
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+/// Encoding or decoding a term failed.
+#[derive(Debug)]
+pub struct EtfError {
+    kind: EtfErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EtfError {
+    const fn new(kind: EtfErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EtfErrorType::Eof => f.write_str("payload ended before the term was complete"),
+            EtfErrorType::IntegerTooLarge => f.write_str("integer doesn't fit in 64 bits"),
+            EtfErrorType::LengthInvalid => {
+                f.write_str("list or map has a different number of elements than used")
+            }
+            EtfErrorType::Message { message } => f.write_str(message),
+            EtfErrorType::TagUnsupported { tag } => {
+                f.write_str("term tag ")?;
+                Display::fmt(tag, f)?;
+
+                f.write_str(" is unsupported")
+            }
+            EtfErrorType::TrailingBytes => f.write_str("payload has bytes after the term"),
+            EtfErrorType::Utf8 => f.write_str("atom isn't valid utf-8"),
+            EtfErrorType::VersionUnsupported { version } => {
+                f.write_str("format version ")?;
+                Display::fmt(version, f)?;
+
+                f.write_str(" is unsupported")
+            }
+        }
+    }
+}
+
+impl Error for EtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+impl de::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(EtfErrorType::Message {
+            message: msg.to_string(),
+        })
+    }
+}
+
+impl ser::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(EtfErrorType::Message {
+            message: msg.to_string(),
+        })
+    }
+}
+
+impl From<Utf8Error> for EtfError {
+    fn from(source: Utf8Error) -> Self {
+        Self {
+            kind: EtfErrorType::Utf8,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+/// Type of [`EtfError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EtfErrorType {
+    /// Payload ended before the term was complete.
+    Eof,
+    /// Integer doesn't fit in 64 bits.
+    IntegerTooLarge,
+    /// List or map has a different number of elements than were used.
+    LengthInvalid,
+    /// Serde failed to map the term to or from a type.
+    Message {
+        /// Message describing the failure.
+        message: String,
+    },
+    /// Term has a tag that isn't supported.
+    TagUnsupported {
+        /// Tag of the term.
+        tag: u8,
+    },
+    /// Payload has bytes after the term.
+    TrailingBytes,
+    /// Atom isn't valid UTF-8.
+    Utf8,
+    /// Payload isn't in a supported version of the format.
+    VersionUnsupported {
+        /// Version of the payload.
+        version: u8,
+    },
+}
+
+/// Encode a value as a term.
+///
+/// # Errors
+///
+/// Returns an [`EtfErrorType::Message`] error type if the value failed to
+/// serialize.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let mut serializer = Serializer {
+        output: vec![VERSION],
+    };
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.output)
+}
+
+/// Decode a value from a term.
+///
+/// # Errors
+///
+/// Returns an error if the payload isn't a valid term or if it failed to
+/// deserialize into the type.
+pub fn from_slice<'de, T: Deserialize<'de>>(input: &'de [u8]) -> Result<T, EtfError> {
+    let mut deserializer = Deserializer::from_slice(input)?;
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+/// Read the opcode, sequence, and event type of a gateway payload without
+/// decoding its data.
+///
+/// Returns `None` if the payload isn't a map or doesn't have an opcode.
+pub fn header(input: &[u8]) -> Option<(u8, Option<u64>, Option<&str>)> {
+    let mut deserializer = Deserializer::from_slice(input).ok()?;
+
+    if deserializer.read_u8().ok()? != MAP_EXT {
+        return None;
+    }
+
+    let mut op = None;
+    let mut seq = None;
+    let mut event_type = None;
+
+    for _ in 0..deserializer.read_u32().ok()? {
+        match <&str>::deserialize(&mut deserializer).ok()? {
+            "op" => op = Some(u8::deserialize(&mut deserializer).ok()?),
+            "s" => seq = Option::deserialize(&mut deserializer).ok()?,
+            "t" => event_type = Option::deserialize(&mut deserializer).ok()?,
+            _ => {
+                IgnoredAny::deserialize(&mut deserializer).ok()?;
+            }
+        }
+    }
+
+    Some((op?, seq, event_type))
+}
+
+/// Parse a gateway event from a term with headers.
+///
+/// # Errors
+///
+/// Returns a [`GatewayEventParsingErrorType::Deserializing`] error type if the
+/// payload failed to deserialize.
+///
+/// Returns a [`GatewayEventParsingErrorType::PayloadInvalid`] error type if the
+/// payload isn't in a supported version of the format.
+pub fn parse_gateway_event(
+    op: u8,
+    sequence: Option<u64>,
+    event_type: Option<&str>,
+    input: &[u8],
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    let gateway_deserializer = GatewayEventDeserializer::new(op, sequence, event_type);
+    let mut deserializer =
+        Deserializer::from_slice(input).map_err(|source| GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::PayloadInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+    gateway_deserializer
+        .deserialize(&mut deserializer)
+        .map_err(|source| {
+            tracing::debug!(payload = ?input, "invalid ETF");
+
+            GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            }
+        })
+}
+
+/// Integer decoded from a term.
+#[derive(Clone, Copy)]
+enum Integer {
+    Negative(i64),
+    Positive(u64),
+}
+
+impl Integer {
+    fn visit<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, EtfError> {
+        match self {
+            Self::Negative(value) => visitor.visit_i64(value),
+            Self::Positive(value) => visitor.visit_u64(value),
+        }
+    }
+}
+
+impl Display for Integer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Negative(value) => Display::fmt(value, f),
+            Self::Positive(value) => Display::fmt(value, f),
+        }
+    }
+}
+
+struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    fn from_slice(input: &'de [u8]) -> Result<Self, EtfError> {
+        match input.split_first() {
+            Some((&VERSION, input)) => Ok(Self { input }),
+            Some((&version, _)) => Err(EtfError::new(EtfErrorType::VersionUnsupported { version })),
+            None => Err(EtfError::new(EtfErrorType::Eof)),
+        }
+    }
+
+    fn end(&self) -> Result<(), EtfError> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(EtfError::new(EtfErrorType::TrailingBytes))
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'de [u8], EtfError> {
+        if self.input.len() < len {
+            return Err(EtfError::new(EtfErrorType::Eof));
+        }
+
+        let (bytes, input) = self.input.split_at(len);
+        self.input = input;
+
+        Ok(bytes)
+    }
+
+    fn peek_u8(&self) -> Result<u8, EtfError> {
+        self.input
+            .first()
+            .copied()
+            .ok_or_else(|| EtfError::new(EtfErrorType::Eof))
+    }
+
+    fn read_u8(&mut self) -> Result<u8, EtfError> {
+        let value = self.peek_u8()?;
+        self.input = &self.input[1..];
+
+        Ok(value)
+    }
+
+    fn read_u16(&mut self) -> Result<u16, EtfError> {
+        let bytes = self.take(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, EtfError> {
+        let bytes = self.take(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_len(&mut self) -> Result<usize, EtfError> {
+        self.read_u32().map(|len| len as usize)
+    }
+
+    /// Read the name of an atom after its tag.
+    fn read_atom(&mut self, tag: u8) -> Result<&'de str, EtfError> {
+        let len = match tag {
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => usize::from(self.read_u8()?),
+            _ => usize::from(self.read_u16()?),
+        };
+
+        Ok(str::from_utf8(self.take(len)?)?)
+    }
+
+    /// Read a big integer after its tag.
+    #[allow(clippy::cast_possible_wrap)]
+    fn read_big(&mut self, tag: u8) -> Result<Integer, EtfError> {
+        let len = if tag == SMALL_BIG_EXT {
+            usize::from(self.read_u8()?)
+        } else {
+            self.read_len()?
+        };
+        let negative = self.read_u8()? != 0;
+        let digits = self.take(len)?;
+
+        let mut magnitude = 0_u64;
+
+        for (index, digit) in digits.iter().enumerate() {
+            if *digit == 0 {
+                continue;
+            }
+
+            if index >= 8 {
+                return Err(EtfError::new(EtfErrorType::IntegerTooLarge));
+            }
+
+            magnitude |= u64::from(*digit) << (index * 8);
+        }
+
+        if !negative {
+            return Ok(Integer::Positive(magnitude));
+        }
+
+        if magnitude > i64::MAX as u64 + 1 {
+            return Err(EtfError::new(EtfErrorType::IntegerTooLarge));
+        }
+
+        Ok(Integer::Negative((magnitude as i64).wrapping_neg()))
+    }
+
+    /// Read an integer if the next term is one.
+    #[allow(clippy::cast_possible_wrap)]
+    fn parse_integer(&mut self) -> Result<Option<Integer>, EtfError> {
+        let integer = match self.peek_u8()? {
+            SMALL_INTEGER_EXT => {
+                self.read_u8()?;
+
+                Integer::Positive(u64::from(self.read_u8()?))
+            }
+            INTEGER_EXT => {
+                self.read_u8()?;
+                let value = self.read_u32()? as i32;
+
+                match u64::try_from(value) {
+                    Ok(value) => Integer::Positive(value),
+                    Err(_) => Integer::Negative(i64::from(value)),
+                }
+            }
+            SMALL_BIG_EXT | LARGE_BIG_EXT => {
+                let tag = self.read_u8()?;
+
+                self.read_big(tag)?
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(integer))
+    }
+
+    /// Read the `nil` atom if the next term is one.
+    fn parse_nil(&mut self) -> bool {
+        let len = match self.input {
+            [SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT, 3, ..] => 2,
+            [ATOM_EXT | ATOM_UTF8_EXT, 0, 3, ..] => 3,
+            _ => return false,
+        };
+
+        if self.input.get(len..len + 3) != Some(b"nil") {
+            return false;
+        }
+
+        self.input = &self.input[len + 3..];
+
+        true
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if let Some(integer) = self.parse_integer()? {
+            return integer.visit(visitor);
+        }
+
+        let tag = self.read_u8()?;
+
+        match tag {
+            NEW_FLOAT_EXT => {
+                let bytes = self.take(8)?;
+                let mut bits = [0; 8];
+                bits.copy_from_slice(bytes);
+
+                visitor.visit_f64(f64::from_be_bytes(bits))
+            }
+            FLOAT_EXT => {
+                let bytes = self.take(31)?;
+                let text = str::from_utf8(bytes)?.trim_end_matches('\0');
+                let value = text
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(<EtfError as de::Error>::custom)?;
+
+                visitor.visit_f64(value)
+            }
+            ATOM_EXT | SMALL_ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_UTF8_EXT => {
+                match self.read_atom(tag)? {
+                    "false" => visitor.visit_bool(false),
+                    "nil" => visitor.visit_unit(),
+                    "true" => visitor.visit_bool(true),
+                    name => visitor.visit_borrowed_str(name),
+                }
+            }
+            BINARY_EXT => {
+                let len = self.read_len()?;
+                let bytes = self.take(len)?;
+
+                match str::from_utf8(bytes) {
+                    Ok(value) => visitor.visit_borrowed_str(value),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            }
+            // Lists of small integers, which are sent as bytes.
+            STRING_EXT => {
+                let len = usize::from(self.read_u16()?);
+                let bytes = self.take(len)?;
+
+                visitor.visit_seq(bytes.to_vec().into_deserializer())
+            }
+            NIL_EXT => visitor.visit_seq(Elements {
+                deserializer: self,
+                remaining: 0,
+            }),
+            LIST_EXT => {
+                let remaining = self.read_len()?;
+                let value = visit_elements(self, remaining, visitor)?;
+
+                // Lists from the gateway are proper lists, which end with an
+                // empty list.
+                match self.read_u8()? {
+                    NIL_EXT => Ok(value),
+                    tag => Err(EtfError::new(EtfErrorType::TagUnsupported { tag })),
+                }
+            }
+            SMALL_TUPLE_EXT => {
+                let remaining = usize::from(self.read_u8()?);
+
+                visit_elements(self, remaining, visitor)
+            }
+            LARGE_TUPLE_EXT => {
+                let remaining = self.read_len()?;
+
+                visit_elements(self, remaining, visitor)
+            }
+            MAP_EXT => {
+                let remaining = self.read_len()?;
+                let mut entries = Entries {
+                    deserializer: self,
+                    remaining,
+                };
+                let value = visitor.visit_map(&mut entries)?;
+
+                if entries.remaining == 0 {
+                    Ok(value)
+                } else {
+                    Err(EtfError::new(EtfErrorType::LengthInvalid))
+                }
+            }
+            _ => Err(EtfError::new(EtfErrorType::TagUnsupported { tag })),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.parse_nil() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        // IDs are sent as integers.
+        match self.parse_integer()? {
+            Some(integer) => visitor.visit_string(integer.to_string()),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.peek_u8()? == MAP_EXT {
+            self.read_u8()?;
+
+            if self.read_u32()? != 1 {
+                return Err(EtfError::new(EtfErrorType::LengthInvalid));
+            }
+
+            visitor.visit_enum(Variant { deserializer: self })
+        } else {
+            let name = <&str>::deserialize(&mut *self)?;
+
+            visitor.visit_enum(BorrowedStrDeserializer::new(name))
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+/// Visit the elements of a list or tuple, ensuring that all of them were used.
+fn visit_elements<'de, V: Visitor<'de>>(
+    deserializer: &mut Deserializer<'de>,
+    remaining: usize,
+    visitor: V,
+) -> Result<V::Value, EtfError> {
+    let mut elements = Elements {
+        deserializer,
+        remaining,
+    };
+    let value = visitor.visit_seq(&mut elements)?;
+
+    if elements.remaining == 0 {
+        Ok(value)
+    } else {
+        Err(EtfError::new(EtfErrorType::LengthInvalid))
+    }
+}
+
+struct Elements<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct Entries<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> MapAccess<'de> for Entries<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Enum variant encoded as a map with a single entry.
+struct Variant<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+}
+
+impl<'de> EnumAccess<'de> for Variant<'_, 'de> {
+    type Error = EtfError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let value = seed.deserialize(&mut *self.deserializer)?;
+
+        Ok((value, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant<'_, 'de> {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        <()>::deserialize(self.deserializer)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self.deserializer)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_seq(self.deserializer, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_map(self.deserializer, visitor)
+    }
+}
+
+struct Serializer {
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_atom(&mut self, name: &str) {
+        self.output.push(SMALL_ATOM_UTF8_EXT);
+        // Atoms written by the serializer are short.
+        self.output.push(name.len() as u8);
+        self.output.extend_from_slice(name.as_bytes());
+    }
+
+    fn write_binary(&mut self, bytes: &[u8]) -> Result<(), EtfError> {
+        let len = u32::try_from(bytes.len()).map_err(<EtfError as ser::Error>::custom)?;
+
+        self.output.push(BINARY_EXT);
+        self.output.extend_from_slice(&len.to_be_bytes());
+        self.output.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_big(&mut self, negative: bool, magnitude: u64) {
+        let bytes = magnitude.to_le_bytes();
+        let len = 8 - magnitude.leading_zeros() as usize / 8;
+
+        self.output.push(SMALL_BIG_EXT);
+        self.output.push(len as u8);
+        self.output.push(u8::from(negative));
+        self.output.extend_from_slice(&bytes[..len]);
+    }
+
+    /// Start a list or map, with a placeholder length to be set when it ends.
+    fn start(&mut self, tag: u8) -> Compound<'_> {
+        let start = self.output.len();
+        self.output.push(tag);
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            len: 0,
+            serializer: self,
+            start,
+        }
+    }
+
+    /// Start a map with a single entry keyed by the name of an enum variant.
+    fn start_variant(&mut self, variant: &str) -> Result<(), EtfError> {
+        self.output.push(MAP_EXT);
+        self.output.extend_from_slice(&1_u32.to_be_bytes());
+
+        self.write_binary(variant.as_bytes())
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = EtfError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Self::Error> {
+        self.write_atom(if v { "true" } else { "false" });
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Self::Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Self::Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Self::Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Self::Error> {
+        if let Ok(value) = u8::try_from(v) {
+            self.output.push(SMALL_INTEGER_EXT);
+            self.output.push(value);
+        } else if let Ok(value) = i32::try_from(v) {
+            self.output.push(INTEGER_EXT);
+            self.output.extend_from_slice(&value.to_be_bytes());
+        } else {
+            self.write_big(v < 0, v.unsigned_abs());
+        }
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Self::Error> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Self::Error> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Self::Error> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Self::Error> {
+        if let Ok(value) = i32::try_from(v) {
+            self.serialize_i64(i64::from(value))
+        } else {
+            self.write_big(false, v);
+
+            Ok(())
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Self::Error> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Self::Error> {
+        self.output.push(NEW_FLOAT_EXT);
+        self.output.extend_from_slice(&v.to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Self::Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Self::Error> {
+        self.write_binary(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Self::Error> {
+        self.write_binary(v)
+    }
+
+    fn serialize_none(self) -> Result<(), Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Self::Error> {
+        self.write_atom("nil");
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.start_variant(variant)?;
+
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.start_variant(variant)?;
+
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self.start(MAP_EXT))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self.start(MAP_EXT))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.start_variant(variant)?;
+
+        Ok(self.start(MAP_EXT))
+    }
+}
+
+/// List or map being serialized.
+///
+/// The number of elements or entries is written when it ends, since fields
+/// may be skipped.
+struct Compound<'a> {
+    len: u32,
+    serializer: &'a mut Serializer,
+    start: usize,
+}
+
+impl Compound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.len += 1;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn finish(self) {
+        let output = &mut self.serializer.output;
+
+        if output[self.start] == LIST_EXT {
+            // Empty lists are written as the empty list itself, and other
+            // lists end with it.
+            if self.len == 0 {
+                output.truncate(self.start);
+            }
+
+            output.push(NIL_EXT);
+
+            if self.len == 0 {
+                return;
+            }
+        }
+
+        output[self.start + 1..self.start + 5].copy_from_slice(&self.len.to_be_bytes());
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), EtfError> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_possible_truncation)]
+mod tests {
+    use super::{from_slice, header, parse_gateway_event, to_vec, EtfError, EtfErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::{
+            event::{DispatchEvent, GatewayEvent},
+            payload::{Heartbeat, RequestGuildMembers},
+        },
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(EtfErrorType: Debug, Send, Sync);
+    assert_impl_all!(EtfError: Error, Send, Sync);
+
+    /// Small atom with a UTF-8 name.
+    fn atom(name: &str) -> Vec<u8> {
+        let mut bytes = vec![119, name.len() as u8];
+        bytes.extend(name.as_bytes());
+
+        bytes
+    }
+
+    fn binary(value: &str) -> Vec<u8> {
+        let mut bytes = vec![109];
+        bytes.extend(&(value.len() as u32).to_be_bytes());
+        bytes.extend(value.as_bytes());
+
+        bytes
+    }
+
+    fn map(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = vec![116];
+        bytes.extend(&(entries.len() as u32).to_be_bytes());
+
+        for (key, value) in entries {
+            bytes.extend(atom(key));
+            bytes.extend(value);
+        }
+
+        bytes
+    }
+
+    fn term(value: Vec<u8>) -> Vec<u8> {
+        let mut bytes = vec![131];
+        bytes.extend(value);
+
+        bytes
+    }
+
+    #[test]
+    fn test_hello() {
+        let payload = term(map(&[
+            ("t", atom("nil")),
+            ("s", atom("nil")),
+            ("op", vec![97, 10]),
+            (
+                "d",
+                map(&[
+                    ("heartbeat_interval", vec![98, 0, 0, 0xa1, 0x22]),
+                    ("_trace", vec![106]),
+                ]),
+            ),
+        ]));
+
+        assert_eq!(Some((10, None, None)), header(&payload));
+        assert!(matches!(
+            parse_gateway_event(10, None, None, &payload).unwrap(),
+            GatewayEvent::Hello(41250)
+        ));
+    }
+
+    #[test]
+    fn test_dispatch() {
+        // Snowflakes are sent as big integers.
+        let guild_id = vec![110, 8, 0, 0x00, 0x00, 0x9e, 0x18, 0x69, 0xd0, 0x29, 0x04];
+        let role = map(&[
+            ("id", vec![110, 1, 0, 3]),
+            ("name", binary("role")),
+            ("color", vec![97, 0]),
+            ("hoist", atom("false")),
+            ("managed", atom("false")),
+            ("mentionable", atom("true")),
+            ("permissions", binary("0")),
+            ("position", vec![97, 1]),
+            ("icon", atom("nil")),
+        ]);
+        let payload = term(map(&[
+            ("op", vec![97, 0]),
+            ("s", vec![97, 2]),
+            ("t", atom("GUILD_ROLE_CREATE")),
+            ("d", map(&[("guild_id", guild_id), ("role", role)])),
+        ]));
+
+        let (op, seq, event_type) = header(&payload).unwrap();
+        assert_eq!(
+            (0, Some(2), Some("GUILD_ROLE_CREATE")),
+            (op, seq, event_type)
+        );
+
+        let event = parse_gateway_event(op, seq, event_type, &payload).unwrap();
+
+        match event {
+            GatewayEvent::Dispatch(2, event) => match *event {
+                DispatchEvent::RoleCreate(event) => {
+                    assert_eq!(GuildId(300_000_000_000_000_000), event.guild_id);
+                    assert_eq!(RoleId(3), event.role.id);
+                    assert!(event.role.mentionable);
+                }
+                other => panic!("unexpected event {:?}", other),
+            },
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_heartbeat() {
+        let heartbeat = to_vec(&Heartbeat::new(5)).unwrap();
+        let mut expected = vec![131, 116, 0, 0, 0, 2];
+        expected.extend(binary("d"));
+        expected.extend(&[97, 5]);
+        expected.extend(binary("op"));
+        expected.extend(&[97, 1]);
+        assert_eq!(expected, heartbeat);
+    }
+
+    #[test]
+    fn test_request_guild_members() {
+        let request = RequestGuildMembers::builder(GuildId(300_000_000_000_000_000))
+            .nonce("a")
+            .query("", None);
+        let bytes = to_vec(&request).unwrap();
+
+        assert_eq!(Some((8, None, None)), header(&bytes));
+        assert_eq!(request, from_slice::<RequestGuildMembers>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_integers() {
+        for value in &[0, 255, 256, -1, i64::from(i32::MIN), i64::MIN, i64::MAX] {
+            assert_eq!(*value, from_slice::<i64>(&to_vec(value).unwrap()).unwrap());
+        }
+
+        assert_eq!(
+            u64::MAX,
+            from_slice::<u64>(&to_vec(&u64::MAX).unwrap()).unwrap()
+        );
+        assert_eq!(
+            "300000000000000000",
+            from_slice::<String>(&to_vec(&300_000_000_000_000_000_u64).unwrap()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let empty: Vec<u8> = Vec::new();
+        assert_eq!(vec![131, 106], to_vec(&empty).unwrap());
+        assert_eq!(empty, from_slice::<Vec<u8>>(&[131, 106]).unwrap());
+
+        let values = vec![Some(1), None];
+        assert_eq!(
+            values,
+            from_slice::<Vec<Option<u8>>>(&to_vec(&values).unwrap()).unwrap()
+        );
+
+        // Lists of small integers.
+        assert_eq!(
+            vec![1, 2],
+            from_slice::<Vec<u8>>(&[131, 107, 0, 2, 1, 2]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            from_slice::<u8>(&[130, 97, 1]).unwrap_err().kind,
+            EtfErrorType::VersionUnsupported { version: 130 }
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 97]).unwrap_err().kind,
+            EtfErrorType::Eof
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 97, 1, 97]).unwrap_err().kind,
+            EtfErrorType::TrailingBytes
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 80]).unwrap_err().kind,
+            EtfErrorType::TagUnsupported { tag: 80 }
+        ));
+        assert!(header(&[131, 97, 1]).is_none());
+    }
+}
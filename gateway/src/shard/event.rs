@@ -12,13 +12,14 @@
 //! [`EventType`]: ::twilight_model::gateway::event::EventType
 //! [`ShardBuilder::event_types`]: crate::shard::ShardBuilder::event_types
 
+use super::emitter::{Counters, Receiver};
 use crate::EventTypeFlags;
 use futures_util::stream::Stream;
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
-use tokio::sync::mpsc::UnboundedReceiver;
 use twilight_model::gateway::event::Event;
 
 /// A stream of events from a [`Shard`].
@@ -29,22 +30,37 @@ use twilight_model::gateway::event::Event;
 ///
 /// This implements [`futures::stream::Stream`].
 ///
+/// The stream is unbounded by default. If it's bounded via
+/// [`ShardBuilder::event_buffer`] then the shard's [`Backpressure`] applies
+/// while it's full.
+///
 /// # Examples
 ///
 /// Refer to [`Shard`] for an example of how to use this.
 ///
+/// [`Backpressure`]: super::Backpressure
 /// [`Events::event_types`]: Self::event_types
+/// [`ShardBuilder::event_buffer`]: super::ShardBuilder::event_buffer
 /// [`Shard`]: super::Shard
 /// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
 #[derive(Debug)]
 pub struct Events {
+    counters: Arc<Counters>,
     event_types: EventTypeFlags,
-    rx: UnboundedReceiver<Event>,
+    rx: Receiver,
 }
 
 impl Events {
-    pub(super) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
-        Self { event_types, rx }
+    pub(super) const fn new(
+        event_types: EventTypeFlags,
+        rx: Receiver,
+        counters: Arc<Counters>,
+    ) -> Self {
+        Self {
+            counters,
+            event_types,
+            rx,
+        }
     }
 
     /// Returns the event types that can be passed to this stream.
@@ -56,8 +72,10 @@ impl Events {
 impl Stream for Events {
     type Item = Event;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.rx.poll_recv(cx)
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        this.rx.poll_recv(cx, &this.counters)
     }
 }
 
@@ -1,9 +1,12 @@
+#[cfg(feature = "etf")]
+use super::etf::to_vec;
+#[cfg(not(feature = "etf"))]
+use super::json::to_vec;
 use super::{
     builder::ShardBuilder,
     config::Config,
     emitter::Emitter,
     event::Events,
-    json,
     processor::{ConnectingErrorType, Latency, Session, ShardProcessor},
     raw_message::Message,
     stage::Stage,
@@ -16,9 +19,12 @@ use std::{
     borrow::Cow,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    sync::{atomic::Ordering, Arc},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
-use tokio::{sync::watch::Receiver as WatchReceiver, task::JoinHandle};
+use tokio::{runtime::Handle, sync::watch::Receiver as WatchReceiver, task::JoinHandle};
 use tokio_tungstenite::tungstenite::protocol::{
     frame::coding::CloseCode, CloseFrame as TungsteniteCloseFrame,
 };
@@ -243,6 +249,10 @@ pub enum ShardStartErrorType {
 /// and connection stage.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Information {
+    #[serde(default)]
+    events_dropped: u64,
+    #[serde(default)]
+    events_queued: usize,
     id: u64,
     latency: Latency,
     session_id: Option<Box<str>>,
@@ -251,6 +261,18 @@ pub struct Information {
 }
 
 impl Information {
+    /// Number of events dropped because the event stream was full.
+    ///
+    /// Refer to [`ShardBuilder::event_buffer`] for more information.
+    pub const fn events_dropped(&self) -> u64 {
+        self.events_dropped
+    }
+
+    /// Number of events waiting to be received from the event stream.
+    pub const fn events_queued(&self) -> usize {
+        self.events_queued
+    }
+
     /// Return the ID of the shard.
     pub const fn id(&self) -> u64 {
         self.id
@@ -304,6 +326,9 @@ struct ShardRef {
     config: Arc<Config>,
     emitter: Emitter,
     processor_handle: OnceCell<JoinHandle<()>>,
+    /// Whether the shard is being replaced by a shard with the same ID and
+    /// total, in which case it stops saving its session.
+    replaced: Arc<AtomicBool>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
 }
 
@@ -405,16 +430,18 @@ impl Shard {
         let config = Arc::new(config);
         let event_types = config.event_types();
 
-        let (emitter, rx) = Emitter::new(event_types);
+        let (emitter, rx, counters) =
+            Emitter::new(event_types, config.event_buffer(), config.backpressure());
 
         let this = Self(Arc::new(ShardRef {
             config,
             emitter,
             processor_handle: OnceCell::new(),
+            replaced: Arc::new(AtomicBool::new(false)),
             session: OnceCell::new(),
         }));
 
-        (this, Events::new(event_types, rx))
+        (this, Events::new(event_types, rx, counters))
     }
 
     /// Create a builder to configure and construct a shard.
@@ -461,24 +488,24 @@ impl Shard {
 
         let config = Arc::clone(&self.0.config);
         let emitter = self.0.emitter.clone();
-        let (processor, wrx) =
-            ShardProcessor::new(config, url, emitter)
-                .await
-                .map_err(|source| {
-                    let (kind, source) = source.into_parts();
-
-                    let new_kind = match kind {
-                        ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
-                        ConnectingErrorType::ParsingUrl { url } => {
-                            ShardStartErrorType::ParsingGatewayUrl { url }
-                        }
-                    };
-
-                    ShardStartError {
-                        source,
-                        kind: new_kind,
+        let replaced = Arc::clone(&self.0.replaced);
+        let (processor, wrx) = ShardProcessor::new(config, url, emitter, replaced)
+            .await
+            .map_err(|source| {
+                let (kind, source) = source.into_parts();
+
+                let new_kind = match kind {
+                    ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
+                    ConnectingErrorType::ParsingUrl { url } => {
+                        ShardStartErrorType::ParsingGatewayUrl { url }
                     }
-                })?;
+                };
+
+                ShardStartError {
+                    source,
+                    kind: new_kind,
+                }
+            })?;
 
         let handle = tokio::spawn(async move {
             processor.run().await;
@@ -502,7 +529,11 @@ impl Shard {
     pub fn info(&self) -> Result<Information, SessionInactiveError> {
         let session = self.session()?;
 
+        let counters = self.0.emitter.counters();
+
         Ok(Information {
+            events_dropped: counters.dropped(),
+            events_queued: counters.queued(),
             id: self.config().shard()[0],
             latency: session.heartbeats.latency(),
             session_id: session.id(),
@@ -520,17 +551,18 @@ impl Shard {
     /// restarting.
     ///
     /// Returns a [`CommandErrorType::Serializing`] error type if the provided
-    /// value failed to serialize into JSON.
+    /// value failed to serialize into JSON, or ETF if the `etf` feature is
+    /// enabled.
     ///
     /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
     /// has not been started.
     pub async fn command(&self, value: &impl serde::Serialize) -> Result<(), CommandError> {
-        let json = json::to_vec(value).map_err(|source| CommandError {
+        let bytes = to_vec(value).map_err(|source| CommandError {
             source: Some(Box::new(source)),
             kind: CommandErrorType::Serializing,
         })?;
 
-        self.send(Message::Binary(json))
+        self.send(Message::Binary(bytes))
             .await
             .map_err(CommandError::from_send)
     }
@@ -639,8 +671,18 @@ impl Shard {
     ///
     /// The shard will cleanly close the connection by sending a normal close
     /// code, causing Discord to show the bot as being offline. The session will
-    /// not be resumable.
+    /// not be resumable, so it's removed from the configured [session store]
+    /// in the background.
+    ///
+    /// [session store]: ShardBuilder::session_store
     pub fn shutdown(&self) {
+        self.close();
+        self.remove_session();
+    }
+
+    /// Stop the shard and cleanly close its connection, without removing its
+    /// session from the session store.
+    pub(crate) fn close(&self) {
         if let Some(processor_handle) = self.0.processor_handle.get() {
             processor_handle.abort();
         }
@@ -655,6 +697,44 @@ impl Shard {
         }
     }
 
+    /// Set whether the shard is being replaced by a shard with the same ID and
+    /// total.
+    ///
+    /// Replaced shards don't save their session, so they can't overwrite the
+    /// session saved by the new shard.
+    pub(crate) fn set_replaced(&self, replaced: bool) {
+        self.0.replaced.store(replaced, Ordering::Release);
+    }
+
+    /// Remove the shard's session from the session store, if there is one.
+    ///
+    /// The removal is spawned because shutting down isn't asynchronous. This
+    /// does nothing if it isn't called within a Tokio runtime.
+    pub(crate) fn remove_session(&self) {
+        let store = match self.config().session_store.as_ref() {
+            Some(store) => Arc::clone(store),
+            None => return,
+        };
+
+        let handle = match Handle::try_current() {
+            Ok(handle) => handle,
+            Err(_) => return,
+        };
+
+        let shard = self.config().shard();
+
+        handle.spawn(async move {
+            if let Err(source) = store.remove(shard).await {
+                tracing::warn!(
+                    shard_id = shard[0],
+                    shard_total = shard[1],
+                    "removing session from the store failed: {}",
+                    source,
+                );
+            }
+        });
+    }
+
     /// Shut down the shard in a resumable fashion.
     ///
     /// The shard will cleanly close the connection by sending a restart close
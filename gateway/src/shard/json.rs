@@ -1,6 +1,8 @@
 #[cfg(not(feature = "simd-json"))]
+#[cfg_attr(feature = "etf", allow(unused_imports))]
 pub use serde_json::{from_slice, from_str, to_string, to_vec, Error as JsonError};
 #[cfg(feature = "simd-json")]
+#[cfg_attr(feature = "etf", allow(unused_imports))]
 pub use simd_json::{from_slice, from_str, to_string, to_vec, Error as JsonError};
 
 use std::{
@@ -21,10 +21,13 @@
 
 pub mod raw_message;
 pub mod stage;
+pub mod store;
 
 mod builder;
 mod config;
 mod emitter;
+#[cfg(feature = "etf")]
+mod etf;
 mod event;
 mod r#impl;
 mod json;
@@ -34,7 +37,7 @@ pub use self::{
     builder::{
         LargeThresholdError, LargeThresholdErrorType, ShardBuilder, ShardIdError, ShardIdErrorType,
     },
-    config::Config,
+    config::{Backpressure, Config},
     event::Events,
     processor::heartbeat::Latency,
     r#impl::{
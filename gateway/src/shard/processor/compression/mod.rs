@@ -1,20 +1,25 @@
-#[cfg(feature = "compression")]
+#[cfg(all(feature = "compression", not(feature = "zstd")))]
 mod inflater;
+#[cfg(feature = "zstd")]
+mod zstd;
 
 use super::r#impl::ReceivingEventError;
 use std::str;
 
-#[cfg(feature = "compression")]
+#[cfg(feature = "zstd")]
+use self::zstd::ZstdInflater as Inflater;
+#[cfg(all(feature = "compression", not(feature = "zstd")))]
 use inflater::Inflater;
 
-/// Interface for working with buffers variable on the `compression` feature flag.
+/// Interface for working with buffers variable on the `compression` and `zstd`
+/// feature flags.
 #[derive(Debug)]
 pub struct Compression {
     /// Inflater for use with compression.
-    #[cfg(feature = "compression")]
+    #[cfg(any(feature = "compression", feature = "zstd"))]
     inner: Inflater,
     /// Buffer for use without compression.
-    #[cfg(not(feature = "compression"))]
+    #[cfg(not(any(feature = "compression", feature = "zstd")))]
     inner: Vec<u8>,
 }
 
@@ -22,14 +27,14 @@ impl Compression {
     /// Create a new buffer, abstracting over an inflater if the `compression`
     /// feature is enabled or a simple `Vec` if the feature is disabled.
     #[cfg_attr(
-        not(feature = "compression"),
+        not(any(feature = "compression", feature = "zstd")),
         allow(clippy::missing_const_for_fn, unused_variables)
     )]
     pub fn new(shard_id: [u64; 2]) -> Self {
         Self {
-            #[cfg(feature = "compression")]
+            #[cfg(any(feature = "compression", feature = "zstd"))]
             inner: Inflater::new(shard_id),
-            #[cfg(not(feature = "compression"))]
+            #[cfg(not(any(feature = "compression", feature = "zstd")))]
             inner: Vec::new(),
         }
     }
@@ -41,13 +46,14 @@ impl Compression {
     ///
     /// When compression is disabled this will mutably reference the standard
     /// buffer.
+    #[cfg_attr(feature = "etf", allow(dead_code))]
     pub fn buffer_slice_mut(&mut self) -> &mut [u8] {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             self.inner.buffer_mut()
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         self.inner.as_mut_slice()
     }
 
@@ -59,12 +65,12 @@ impl Compression {
     /// When compression is disabled this will immutably reference the standard
     /// buffer.
     pub fn buffer_slice_ref(&self) -> &[u8] {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             self.inner.buffer_ref()
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         self.inner.as_slice()
     }
 
@@ -74,6 +80,7 @@ impl Compression {
     ///
     /// Ensuring that the internal buffer slice is UTF-8 valid is left to the
     /// caller to determine.
+    #[cfg_attr(feature = "etf", allow(dead_code))]
     pub unsafe fn buffer_str_mut(&mut self) -> &mut str {
         // SAFETY: ensuring safety is left to the caller.
         str::from_utf8_unchecked_mut(self.buffer_slice_mut())
@@ -86,22 +93,37 @@ impl Compression {
 
     /// Extend the buffer with bytes from a Binary websocket message.
     ///
-    /// If compression is disabled then this will do nothing.
+    /// If compression and the `etf` feature are disabled then this will do
+    /// nothing.
     ///
     /// Returns whether the inner buffer was extended.
     #[cfg_attr(
-        not(feature = "compression"),
+        all(
+            not(any(feature = "compression", feature = "zstd")),
+            not(feature = "etf")
+        ),
         allow(clippy::unused_self, unused_variables)
     )]
     pub fn extend_binary(&mut self, bytes: &[u8]) -> bool {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             self.inner.extend(bytes);
 
             true
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(all(not(any(feature = "compression", feature = "zstd")), feature = "etf"))]
+        {
+            // Uncompressed ETF payloads are received as Binary messages.
+            self.inner.extend_from_slice(bytes);
+
+            true
+        }
+
+        #[cfg(all(
+            not(any(feature = "compression", feature = "zstd")),
+            not(feature = "etf")
+        ))]
         // Binary payloads are not received when compression is disabled.
         false
     }
@@ -111,16 +133,19 @@ impl Compression {
     /// If compression is enabled then this will do nothing.
     ///
     /// Returns whether the inner buffer was extended.
-    #[cfg_attr(feature = "compression", allow(clippy::unused_self, unused_variables))]
+    #[cfg_attr(
+        any(feature = "compression", feature = "zstd"),
+        allow(clippy::unused_self, unused_variables)
+    )]
     pub fn extend_text(&mut self, bytes: &[u8]) -> bool {
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         {
             self.inner.extend_from_slice(bytes);
 
             true
         }
 
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         // Text payloads are not received when compression is enabled.
         false
     }
@@ -134,16 +159,23 @@ impl Compression {
     /// If compression is enabled and a message has *not* completed then a
     /// successful `None` is returned.
     ///
-    /// If compression is disabled then a successful `None` is returned.
+    /// If compression is disabled then a successful `None` is returned, unless
+    /// the `etf` feature is enabled, in which case the buffer is returned.
     ///
     /// # Errors
     ///
     /// If compression is enabled then this returns a
     /// `ReceivingEventErrorType::Decompressing` error type if decompressing the
     /// message failed.
-    #[cfg_attr(not(feature = "compression"), allow(clippy::unused_self))]
+    #[cfg_attr(
+        all(
+            not(any(feature = "compression", feature = "zstd")),
+            not(feature = "etf")
+        ),
+        allow(clippy::unused_self)
+    )]
     pub fn message_mut(&mut self) -> Result<Option<&mut [u8]>, ReceivingEventError> {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             use super::r#impl::ReceivingEventErrorType;
 
@@ -153,16 +185,22 @@ impl Compression {
             })
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(all(not(any(feature = "compression", feature = "zstd")), feature = "etf"))]
+        return Ok(Some(self.inner.as_mut_slice()));
+
+        #[cfg(all(
+            not(any(feature = "compression", feature = "zstd")),
+            not(feature = "etf")
+        ))]
         Ok(None)
     }
 
     /// Reset the buffer for a new gateway session.
     pub fn reset(&mut self) {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         self.inner.reset();
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         self.clear();
     }
 }
@@ -171,10 +209,17 @@ impl Compression {
 /// enabled.
 ///
 /// If compression is enabled then the `compress` query parameter is appended
-/// with a value of `zlib-stream`.
-#[cfg_attr(not(feature = "compression"), allow(unused_variables))]
+/// with a value of `zlib-stream`, or `zstd-stream` if the `zstd` feature is
+/// enabled.
+#[cfg_attr(
+    not(any(feature = "compression", feature = "zstd")),
+    allow(unused_variables)
+)]
 pub fn add_url_feature(buf: &mut String) {
-    #[cfg(feature = "compression")]
+    #[cfg(feature = "zstd")]
+    buf.push_str("&compress=zstd-stream");
+
+    #[cfg(all(feature = "compression", not(feature = "zstd")))]
     buf.push_str("&compress=zlib-stream");
 }
 
@@ -185,12 +230,17 @@ mod tests {
         let mut buf = String::new();
         super::add_url_feature(&mut buf);
 
-        #[cfg(feature = "compression")]
+        #[cfg(feature = "zstd")]
+        {
+            assert_eq!("&compress=zstd-stream", buf);
+        }
+
+        #[cfg(all(feature = "compression", not(feature = "zstd")))]
         {
             assert_eq!("&compress=zlib-stream", buf);
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         assert!(buf.is_empty());
     }
 }
@@ -0,0 +1,264 @@
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::Result as IoResult,
+    mem,
+    time::Instant,
+};
+use zstd::stream::raw::{Decoder, InBuffer, Operation, OutBuffer};
+
+const INTERNAL_BUFFER_SIZE: usize = 32 * 1024;
+
+/// Decompressor for the `zstd-stream` transport compression.
+///
+/// The whole connection is a single zstd stream, and each websocket message
+/// is flushed, so every message can be decompressed as soon as it's received
+/// with the context of the previous messages.
+pub struct ZstdInflater {
+    decoder: Decoder<'static>,
+    compressed: Vec<u8>,
+    internal_buffer: Vec<u8>,
+    buffer: Vec<u8>,
+    last_resize: Instant,
+    shard: [u64; 2],
+    total_in: u64,
+    total_out: u64,
+}
+
+impl ZstdInflater {
+    /// Create a new inflater for a shard.
+    pub fn new(shard: [u64; 2]) -> Self {
+        Self {
+            buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            compressed: Vec::new(),
+            decoder: Decoder::new().expect("zstd decompression context can be created"),
+            internal_buffer: vec![0; INTERNAL_BUFFER_SIZE],
+            last_resize: Instant::now(),
+            shard,
+            total_in: 0,
+            total_out: 0,
+        }
+    }
+
+    /// Return an immutable reference to the buffer.
+    pub fn buffer_ref(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Return a mutable reference to the buffer.
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        self.buffer.as_mut_slice()
+    }
+
+    /// Extend the internal compressed buffer with bytes.
+    pub fn extend(&mut self, slice: &[u8]) {
+        self.compressed.extend_from_slice(slice);
+    }
+
+    /// Decompress the next message.
+    ///
+    /// Returns `None` if no payload was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload isn't valid zstd data.
+    #[tracing::instrument(level = "trace")]
+    pub fn msg(&mut self) -> IoResult<Option<&mut [u8]>> {
+        if self.compressed.is_empty() {
+            return Ok(None);
+        }
+
+        let mut input = InBuffer::around(&self.compressed);
+
+        loop {
+            let mut output = OutBuffer::around(&mut self.internal_buffer[..]);
+            self.decoder.run(&mut input, &mut output)?;
+            let written = output.pos();
+
+            self.buffer
+                .extend_from_slice(&self.internal_buffer[..written]);
+
+            // The decoder may still hold decompressed data if the output
+            // buffer was filled, even if all input was consumed.
+            if input.pos() == self.compressed.len() && written < self.internal_buffer.len() {
+                break;
+            }
+        }
+
+        self.total_in += self.compressed.len() as u64;
+        self.total_out += self.buffer.len() as u64;
+
+        tracing::trace!(
+            bytes_in = self.compressed.len(),
+            bytes_out = self.buffer.len(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            "payload lengths",
+        );
+        self.compressed.clear();
+
+        // It doesn't matter if we lose precision for logging.
+        #[allow(clippy::cast_precision_loss)]
+        let saved_percentage = self.total_in as f64 / self.total_out as f64;
+        let saved_percentage_readable = saved_percentage * 100.0;
+
+        let saved_kib = self.total_out.saturating_sub(self.total_in) / 1_024;
+
+        tracing::trace!(
+            saved_kib = saved_kib,
+            saved_percentage = %saved_percentage_readable,
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            total_in = self.total_in,
+            total_out = self.total_out,
+            "data saved",
+        );
+
+        #[cfg(feature = "metrics")]
+        self.inflater_metrics();
+
+        tracing::trace!("capacity: {}", self.buffer.capacity());
+        Ok(Some(&mut self.buffer))
+    }
+
+    /// Clear the buffer and shrink it if the capacity is too large.
+    #[tracing::instrument(level = "trace")]
+    pub fn clear(&mut self) {
+        self.shrink();
+
+        self.compressed.clear();
+        self.buffer.clear();
+    }
+
+    /// Reset the state of the inflater back to its default state.
+    pub fn reset(&mut self) {
+        let _old_inflater = mem::replace(self, Self::new(self.shard));
+    }
+
+    /// Log metrics about the inflater.
+    #[cfg(feature = "metrics")]
+    #[allow(clippy::cast_precision_loss)]
+    fn inflater_metrics(&self) {
+        metrics::gauge!(
+            format!("Inflater-Capacity-{}", self.shard[0]),
+            self.buffer.capacity() as f64
+        );
+        metrics::gauge!(
+            format!("Inflater-In-{}", self.shard[0]),
+            self.total_in as f64
+        );
+        metrics::gauge!(
+            format!("Inflater-Out-{}", self.shard[0]),
+            self.total_out as f64
+        );
+    }
+
+    /// Shrink the capacity of the compressed buffer and payload buffer if at
+    /// least 60 seconds have passed since the last shrink.
+    fn shrink(&mut self) {
+        if self.last_resize.elapsed().as_secs() < 60 {
+            return;
+        }
+
+        self.compressed.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+
+        tracing::trace!(
+            capacity = self.compressed.capacity(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            "compressed capacity",
+        );
+        tracing::trace!(
+            capacity = self.buffer.capacity(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            "buffer capacity",
+        );
+
+        self.last_resize = Instant::now();
+    }
+}
+
+impl Debug for ZstdInflater {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ZstdInflater")
+            .field("compressed", &self.compressed)
+            .field("buffer", &self.buffer)
+            .field("last_resize", &self.last_resize)
+            .field("shard", &self.shard)
+            .field("total_in", &self.total_in)
+            .field("total_out", &self.total_out)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZstdInflater;
+    use std::error::Error;
+
+    /// Hello payload, starting the stream.
+    const HELLO: &[u8] = &[
+        40, 181, 47, 253, 0, 88, 68, 3, 0, 226, 6, 24, 28, 112, 137, 115, 20, 65, 84, 89, 208, 70,
+        129, 24, 83, 182, 201, 222, 231, 255, 151, 242, 24, 196, 228, 104, 95, 245, 127, 9, 1, 49,
+        38, 79, 162, 2, 168, 252, 122, 26, 94, 26, 243, 5, 209, 23, 59, 140, 35, 189, 166, 76, 98,
+        236, 164, 132, 34, 182, 149, 240, 133, 28, 201, 239, 162, 220, 202, 145, 50, 2, 133, 32,
+        239, 244, 192, 219, 175, 41, 173, 59, 104, 214, 45, 28, 230, 217, 145, 42, 242, 78, 243,
+        28, 57, 103, 120, 249, 62, 12, 1, 0, 36, 74, 18,
+    ];
+    /// Heartbeat acknowledgement payload.
+    const HEARTBEAT_ACK: &[u8] = &[
+        116, 0, 0, 48, 49, 110, 117, 108, 108, 125, 2, 0, 32, 72, 190, 4, 32,
+    ];
+    /// Hello payload again, which references the previous payloads.
+    const HELLO_REPEATED: &[u8] = &[100, 0, 0, 8, 48, 3, 0, 212, 49, 3, 160, 189, 179, 168, 32];
+    const HELLO_OUTPUT: &[u8] = br#"{"t":null,"s":null,"op":10,"d":{"heartbeat_interval":41250,"_trace":["[\"gateway-prd-main-858d\",{\"micros\":0.0}]"]}}"#;
+    const HEARTBEAT_ACK_OUTPUT: &[u8] = br#"{"t":null,"s":null,"op":11,"d":null}"#;
+    const SHARD: [u64; 2] = [2, 5];
+
+    #[test]
+    fn test_inflater() -> Result<(), Box<dyn Error>> {
+        let mut inflater = ZstdInflater::new(SHARD);
+        assert_eq!(None, inflater.msg()?);
+
+        inflater.extend(HELLO);
+        assert!(!inflater.compressed.is_empty());
+        assert!(inflater.buffer.is_empty());
+        assert_eq!(Some(HELLO_OUTPUT), inflater.msg()?.as_deref());
+
+        // Calling `msg` clears `compressed` and fills `buffer`.
+        assert!(inflater.compressed.is_empty());
+        assert_eq!(HELLO_OUTPUT, inflater.buffer_ref());
+        assert_eq!(HELLO_OUTPUT, inflater.buffer_mut());
+
+        inflater.clear();
+        assert!(inflater.buffer.is_empty());
+
+        // The context of the stream is kept between messages.
+        inflater.extend(HEARTBEAT_ACK);
+        assert_eq!(Some(HEARTBEAT_ACK_OUTPUT), inflater.msg()?.as_deref());
+        inflater.clear();
+
+        inflater.extend(HELLO_REPEATED);
+        assert_eq!(Some(HELLO_OUTPUT), inflater.msg()?.as_deref());
+        inflater.clear();
+
+        assert_eq!(
+            (HELLO.len() + HEARTBEAT_ACK.len() + HELLO_REPEATED.len()) as u64,
+            inflater.total_in
+        );
+        assert_eq!(
+            (HELLO_OUTPUT.len() * 2 + HEARTBEAT_ACK_OUTPUT.len()) as u64,
+            inflater.total_out
+        );
+
+        // Resetting the inflater starts a new stream, so messages referencing
+        // the previous stream can't be decompressed.
+        inflater.reset();
+        assert_eq!(0, inflater.total_in);
+        inflater.extend(HELLO_REPEATED);
+        assert!(inflater.msg().is_err());
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
-use super::{
-    super::json,
-    session::{SessionSendError, SessionSendErrorType},
-};
+#[cfg(feature = "etf")]
+use super::super::etf::to_vec;
+#[cfg(not(feature = "etf"))]
+use super::super::json::to_vec;
+use super::session::{SessionSendError, SessionSendErrorType};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::VecDeque,
@@ -231,7 +232,7 @@ impl Heartbeater {
 
             let seq = self.seq.load(Ordering::Acquire);
             let heartbeat = Heartbeat::new(seq);
-            let bytes = json::to_vec(&heartbeat).map_err(|source| SessionSendError {
+            let bytes = to_vec(&heartbeat).map_err(|source| SessionSendError {
                 kind: SessionSendErrorType::Serializing,
                 source: Some(Box::new(source)),
             })?;
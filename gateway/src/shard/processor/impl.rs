@@ -1,10 +1,14 @@
+#[cfg(feature = "etf")]
+use super::super::etf;
+#[cfg(not(feature = "etf"))]
+use super::super::json;
 use super::{
     super::{
         config::Config,
-        emitter::{EmitJsonErrorType, Emitter},
-        json::{self, GatewayEventParsingError, GatewayEventParsingErrorType},
+        emitter::{EmitJsonError, EmitJsonErrorType, Emitter},
+        json::{GatewayEventParsingError, GatewayEventParsingErrorType},
         stage::Stage,
-        ShardStream,
+        ResumeSession, ShardStream,
     },
     compression::{self, Compression},
     session::{Session, SessionSendError, SessionSendErrorType},
@@ -18,7 +22,10 @@ use std::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     str,
-    sync::{atomic::Ordering, Arc},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 use tokio::sync::{
@@ -29,10 +36,12 @@ use tokio_tungstenite::tungstenite::{
     protocol::{frame::coding::CloseCode, CloseFrame, WebSocketConfig},
     Message,
 };
+#[cfg(not(feature = "etf"))]
+use twilight_model::gateway::event::GatewayEventDeserializer;
 use twilight_model::gateway::{
     event::{
         shard::{Connected, Connecting, Disconnected, Identifying, Reconnecting, Resuming},
-        DispatchEvent, Event, GatewayEvent, GatewayEventDeserializer,
+        DispatchEvent, Event, GatewayEvent,
     },
     payload::{
         identify::{Identify, IdentifyInfo, IdentifyProperties},
@@ -148,6 +157,7 @@ enum ProcessErrorType {
     ParsingPayload,
     /// The binary payload received from Discord wasn't validly encoded as
     /// UTF-8.
+    #[cfg_attr(feature = "etf", allow(dead_code))]
     PayloadNotUtf8,
     /// A close message tried to be sent but the receiving half was dropped.
     /// This typically means that the shard is shutdown.
@@ -271,6 +281,9 @@ pub struct ShardProcessor {
     pub rx: UnboundedReceiver<Message>,
     pub session: Arc<Session>,
     compression: Compression,
+    /// Whether the shard is being replaced by a shard with the same ID and
+    /// total, in which case its session isn't saved.
+    replaced: Arc<AtomicBool>,
     url: Box<str>,
     resume: Option<(u64, Box<str>)>,
     wtx: WatchSender<Arc<Session>>,
@@ -281,11 +294,13 @@ impl ShardProcessor {
         config: Arc<Config>,
         mut url: String,
         emitter: Emitter,
+        replaced: Arc<AtomicBool>,
     ) -> Result<(Self, WatchReceiver<Arc<Session>>), ConnectingError> {
-        //if we got resume info we don't need to wait
         let shard_id = config.shard();
-        let resumable = config.sequence.is_some() && config.session_id.is_some();
-        if !resumable {
+        let resume = Self::resume_session(&config).await;
+
+        //if we got resume info we don't need to wait
+        if resume.is_none() {
             tracing::debug!("shard {:?} is not resumable", shard_id);
             tracing::debug!("shard {:?} queued", shard_id);
             config.queue.request(shard_id).await;
@@ -295,6 +310,8 @@ impl ShardProcessor {
         let properties = IdentifyProperties::new("twilight.rs", "twilight.rs", OS, "", "");
 
         url.push_str("?v=8");
+        #[cfg(feature = "etf")]
+        url.push_str("&encoding=etf");
         compression::add_url_feature(&mut url);
 
         emitter.event(Event::ShardConnecting(Connecting {
@@ -308,11 +325,11 @@ impl ShardProcessor {
         });
 
         let session = Arc::new(Session::new(tx));
-        if resumable {
-            session.set_id(config.session_id.clone().unwrap());
-            session
-                .seq
-                .store(config.sequence.unwrap(), Ordering::Relaxed)
+        let resumable = resume.is_some();
+
+        if let Some(resume) = resume {
+            session.set_id(resume.session_id.into_boxed_str());
+            session.seq.store(resume.sequence, Ordering::Relaxed);
         }
 
         let (wtx, wrx) = watch_channel(Arc::clone(&session));
@@ -322,6 +339,7 @@ impl ShardProcessor {
             config,
             emitter,
             properties,
+            replaced,
             rx,
             session,
             url: url.into_boxed_str(),
@@ -337,8 +355,42 @@ impl ShardProcessor {
         Ok((processor, wrx))
     }
 
+    /// Details to resume the session with, either provided in the
+    /// configuration or loaded from the session store.
+    async fn resume_session(config: &Config) -> Option<ResumeSession> {
+        if let (Some(session_id), Some(sequence)) = (&config.session_id, config.sequence) {
+            return Some(ResumeSession {
+                session_id: session_id.clone().into_string(),
+                sequence,
+            });
+        }
+
+        if !config.resume_from_store {
+            return None;
+        }
+
+        let store = config.session_store.as_ref()?;
+
+        match store.load(config.shard()).await {
+            Ok(session) => session,
+            Err(source) => {
+                tracing::warn!(
+                    shard_id = config.shard()[0],
+                    shard_total = config.shard()[1],
+                    "loading session from the store failed: {}",
+                    source,
+                );
+
+                None
+            }
+        }
+    }
+
     pub async fn run(mut self) {
         loop {
+            // Stop reading from the websocket while the event stream is full.
+            self.emitter.flush().await;
+
             match self.next_payload().await {
                 Ok(v) => v,
                 Err(source) => {
@@ -380,126 +432,74 @@ impl ShardProcessor {
         }
     }
 
-    #[allow(clippy::too_many_lines)]
     async fn process(&mut self) -> Result<(), ProcessError> {
-        let (op, seq, event_type) = {
-            let buffer = self.compression.buffer_slice_mut();
-            let json = str::from_utf8_mut(buffer).map_err(|source| ProcessError {
-                kind: ProcessErrorType::PayloadNotUtf8,
-                source: Some(Box::new(source)),
-            })?;
+        let (op, seq, event_type) = self.payload_header()?;
 
-            tracing::trace!(%json, "Received JSON");
-            let emitter = self.emitter.clone();
-
-            let (op, seq, event_type) =
-                if let Some(deserializer) = GatewayEventDeserializer::from_json(json) {
-                    let (op, seq, event_type) = deserializer.into_parts();
-
-                    // Unfortunately lifetimes and mutability requirements
-                    // conflict here if we return an immutable reference to the
-                    // event type, so we're going to have to take ownership of
-                    // this if we don't want to do anything too dangerous. It
-                    // should be a good trade-off either way.
-                    (op, seq, event_type.map(ToOwned::to_owned))
-                } else {
-                    tracing::warn!(
-                        json = ?self.compression.buffer_slice_ref(),
-                        shard_id = self.config.shard()[0],
-                        shard_total = self.config.shard()[1],
-                        seq = self.session.seq(),
-                        stage = ?self.session.stage(),
-                        "received payload without opcode",
-                    );
-
-                    return Err(ProcessError {
+        // We can do a few little optimisation tricks here. For the
+        // "heartbeat ack" and "reconnect" opcodes we can construct
+        // the gateway events without needing to go through a serde
+        // context.
+        //
+        // Additionally, the processor cares about the "resumed"
+        // dispatch event type, which has no payload and can be constructed.
+        //
+        // This might not be shaving off entire milliseconds for these few
+        // events each time, but it certainly adds up.
+        if matches!(op, 1 | 7 | 9 | 10 | 11) {
+            // Have to use an if statement here if we want to use the OpCode
+            // enum, since matching with repr values isn't allowed.
+            let gateway_event = if op == OpCode::HeartbeatAck as u8 {
+                GatewayEvent::HeartbeatAck
+            } else if op == OpCode::Reconnect as u8 {
+                GatewayEvent::Reconnect
+            } else {
+                self.parse_gateway_event(op, seq, event_type.as_deref())
+                    .map_err(|source| ProcessError {
                         kind: ProcessErrorType::ParsingPayload,
-                        source: Some(Box::new(GatewayEventParsingError {
-                            kind: GatewayEventParsingErrorType::PayloadInvalid,
-                            source: None,
-                        })),
-                    });
-                };
-
-            // We can do a few little optimisation tricks here. For the
-            // "heartbeat ack" and "reconnect" opcodes we can construct
-            // the gateway events without needing to go through a serde
-            // context.
-            //
-            // Additionally, the processor cares about the "resumed"
-            // dispatch event type, which has no payload and can be constructed.
-            //
-            // This might not be shaving off entire milliseconds for these few
-            // events each time, but it certainly adds up.
-            if matches!(op, 1 | 7 | 9 | 10 | 11) {
-                // Have to use an if statement here if we want to use the OpCode
-                // enum, since matching with repr values isn't allowed.
-                let gateway_event = if op == OpCode::HeartbeatAck as u8 {
-                    GatewayEvent::HeartbeatAck
-                } else if op == OpCode::Reconnect as u8 {
-                    GatewayEvent::Reconnect
-                } else {
-                    json::parse_gateway_event(op, seq, event_type.as_deref(), json).map_err(
-                        |source| ProcessError {
-                            kind: ProcessErrorType::ParsingPayload,
-                            source: Some(Box::new(source)),
-                        },
-                    )?
-                };
+                        source: Some(Box::new(source)),
+                    })?
+            };
 
-                self.process_gateway_event(&gateway_event).await?;
-                emitter.event(Event::from(gateway_event));
+            self.process_gateway_event(&gateway_event).await?;
+            self.emitter.event(Event::from(gateway_event));
 
-                if let Some(seq) = seq {
-                    self.session.set_seq(seq);
-                }
-
-                return Ok(());
+            if let Some(seq) = seq {
+                self.session.set_seq(seq);
             }
 
-            let seq = seq.ok_or(ProcessError {
-                kind: ProcessErrorType::SequenceMissing,
-                source: None,
-            })?;
-
-            if event_type.as_deref() == Some("RESUMED") {
-                self.process_resumed(seq);
-
-                if emitter.wants(EventTypeFlags::RESUMED) {
-                    let gateway_event =
-                        GatewayEvent::Dispatch(seq, Box::new(DispatchEvent::Resumed));
+            return Ok(());
+        }
 
-                    emitter.event(Event::from(gateway_event));
-                }
+        let seq = seq.ok_or(ProcessError {
+            kind: ProcessErrorType::SequenceMissing,
+            source: None,
+        })?;
 
-                return Ok(());
-            } else if event_type.as_deref() == Some("READY") {
-                let ready = json::from_slice::<ReadyMinimal>(self.compression.buffer_slice_mut())
-                    .map_err(|source| ProcessError {
-                    kind: ProcessErrorType::ParsingPayload,
-                    source: Some(Box::new(GatewayEventParsingError {
-                        kind: GatewayEventParsingErrorType::Deserializing,
-                        source: Some(Box::new(source)),
-                    })),
-                })?;
+        if event_type.as_deref() == Some("RESUMED") {
+            self.process_resumed(seq).await;
 
-                self.process_ready(&ready.d);
-                emitter.event(Event::Ready(Box::new(ready.d)));
+            if self.emitter.wants(EventTypeFlags::RESUMED) {
+                let gateway_event = GatewayEvent::Dispatch(seq, Box::new(DispatchEvent::Resumed));
 
-                return Ok(());
+                self.emitter.event(Event::from(gateway_event));
             }
 
-            self.session.set_seq(seq);
+            return Ok(());
+        } else if event_type.as_deref() == Some("READY") {
+            let ready = self.parse_ready().map_err(|source| ProcessError {
+                kind: ProcessErrorType::ParsingPayload,
+                source: Some(Box::new(source)),
+            })?;
 
-            (op, seq, event_type)
-        };
+            self.process_ready(seq, &ready).await;
+            self.emitter.event(Event::Ready(Box::new(ready)));
 
-        // We already know from earlier that the payload is valid UTF-8, so we
-        // can skip having to re-validate here since it hasn't been mutated.
-        let json = unsafe { self.compression.buffer_str_mut() };
+            return Ok(());
+        }
 
-        self.emitter
-            .json(op, Some(seq), event_type.as_deref(), json)
+        self.session.set_seq(seq);
+
+        self.emit_payload(op, seq, event_type.as_deref())
             .map_err(|source| {
                 let (kind, source) = source.into_parts();
 
@@ -517,13 +517,154 @@ impl ShardProcessor {
             })
     }
 
-    fn process_ready(&mut self, ready: &Ready) {
+    /// Read the opcode, sequence, and event type of the received JSON payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProcessErrorType::PayloadNotUtf8`] error type if the
+    /// payload isn't valid UTF-8.
+    ///
+    /// Returns a [`ProcessErrorType::ParsingPayload`] error type if the
+    /// payload doesn't have an opcode.
+    #[cfg(not(feature = "etf"))]
+    fn payload_header(&mut self) -> Result<(u8, Option<u64>, Option<String>), ProcessError> {
+        let buffer = self.compression.buffer_slice_mut();
+        let json = str::from_utf8_mut(buffer).map_err(|source| ProcessError {
+            kind: ProcessErrorType::PayloadNotUtf8,
+            source: Some(Box::new(source)),
+        })?;
+
+        tracing::trace!(%json, "Received JSON");
+
+        if let Some(deserializer) = GatewayEventDeserializer::from_json(json) {
+            let (op, seq, event_type) = deserializer.into_parts();
+
+            // Unfortunately lifetimes and mutability requirements
+            // conflict here if we return an immutable reference to the
+            // event type, so we're going to have to take ownership of
+            // this if we don't want to do anything too dangerous. It
+            // should be a good trade-off either way.
+            Ok((op, seq, event_type.map(ToOwned::to_owned)))
+        } else {
+            Err(self.payload_header_missing())
+        }
+    }
+
+    /// Read the opcode, sequence, and event type of the received ETF payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProcessErrorType::ParsingPayload`] error type if the
+    /// payload doesn't have an opcode.
+    #[cfg(feature = "etf")]
+    fn payload_header(&mut self) -> Result<(u8, Option<u64>, Option<String>), ProcessError> {
+        let buffer = self.compression.buffer_slice_ref();
+
+        tracing::trace!(payload = ?buffer, "Received ETF");
+
+        if let Some((op, seq, event_type)) = etf::header(buffer) {
+            Ok((op, seq, event_type.map(ToOwned::to_owned)))
+        } else {
+            Err(self.payload_header_missing())
+        }
+    }
+
+    fn payload_header_missing(&self) -> ProcessError {
+        tracing::warn!(
+            payload = ?self.compression.buffer_slice_ref(),
+            shard_id = self.config.shard()[0],
+            shard_total = self.config.shard()[1],
+            seq = self.session.seq(),
+            stage = ?self.session.stage(),
+            "received payload without opcode",
+        );
+
+        ProcessError {
+            kind: ProcessErrorType::ParsingPayload,
+            source: Some(Box::new(GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::PayloadInvalid,
+                source: None,
+            })),
+        }
+    }
+
+    /// Parse the received payload into a gateway event.
+    #[cfg(not(feature = "etf"))]
+    fn parse_gateway_event(
+        &mut self,
+        op: u8,
+        seq: Option<u64>,
+        event_type: Option<&str>,
+    ) -> Result<GatewayEvent, GatewayEventParsingError> {
+        // We already know from reading the header that the payload is valid
+        // UTF-8, so we can skip having to re-validate here since it hasn't
+        // been mutated.
+        let json = unsafe { self.compression.buffer_str_mut() };
+
+        json::parse_gateway_event(op, seq, event_type, json)
+    }
+
+    /// Parse the received payload into a gateway event.
+    #[cfg(feature = "etf")]
+    fn parse_gateway_event(
+        &mut self,
+        op: u8,
+        seq: Option<u64>,
+        event_type: Option<&str>,
+    ) -> Result<GatewayEvent, GatewayEventParsingError> {
+        etf::parse_gateway_event(op, seq, event_type, self.compression.buffer_slice_ref())
+    }
+
+    /// Parse the received payload as a Ready event.
+    fn parse_ready(&mut self) -> Result<Ready, GatewayEventParsingError> {
+        #[cfg(not(feature = "etf"))]
+        let ready = json::from_slice::<ReadyMinimal>(self.compression.buffer_slice_mut());
+        #[cfg(feature = "etf")]
+        let ready = etf::from_slice::<ReadyMinimal>(self.compression.buffer_slice_ref());
+
+        ready
+            .map(|ready| ready.d)
+            .map_err(|source| GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            })
+    }
+
+    /// Emit the received payload if the listener wants its event type.
+    fn emit_payload(
+        &mut self,
+        op: u8,
+        seq: u64,
+        event_type: Option<&str>,
+    ) -> Result<(), EmitJsonError> {
+        #[cfg(not(feature = "etf"))]
+        {
+            // We already know from reading the header that the payload is
+            // valid UTF-8, so we can skip having to re-validate here since it
+            // hasn't been mutated.
+            let json = unsafe { self.compression.buffer_str_mut() };
+
+            self.emitter.json(op, Some(seq), event_type, json)
+        }
+
+        #[cfg(feature = "etf")]
+        self.emitter.etf(
+            op,
+            Some(seq),
+            event_type,
+            self.compression.buffer_slice_ref(),
+        )
+    }
+
+    async fn process_ready(&mut self, seq: u64, ready: &Ready) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
+        self.session.set_seq(seq);
         self.session.set_stage(Stage::Connected);
         self.session
             .set_id(ready.session_id.clone().into_boxed_str());
+        self.save_session().await;
 
         self.emitter.event(Event::ShardConnected(Connected {
             heartbeat_interval: self.session.heartbeat_interval(),
@@ -531,12 +672,13 @@ impl ShardProcessor {
         }));
     }
 
-    fn process_resumed(&self, seq: u64) {
+    async fn process_resumed(&self, seq: u64) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
         self.session.set_seq(seq);
         self.session.set_stage(Stage::Connected);
+        self.save_session().await;
         self.emitter.event(Event::ShardConnected(Connected {
             heartbeat_interval: self.session.heartbeat_interval(),
             shard_id: self.config.shard()[0],
@@ -549,7 +691,7 @@ impl ShardProcessor {
             GatewayEvent::Dispatch(_, _) => unreachable!("dispatch events separately handled"),
             GatewayEvent::Heartbeat(seq) => self.process_heartbeat(*seq).await,
             GatewayEvent::Hello(interval) => self.process_hello(*interval).await?,
-            GatewayEvent::HeartbeatAck => self.process_heartbeat_ack(),
+            GatewayEvent::HeartbeatAck => self.process_heartbeat_ack().await,
             GatewayEvent::InvalidateSession(resumable) => {
                 self.process_invalidate_session(*resumable).await
             }
@@ -559,11 +701,15 @@ impl ShardProcessor {
         Ok(())
     }
 
-    fn process_heartbeat_ack(&self) {
+    async fn process_heartbeat_ack(&self) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "HeartbeatAck");
 
         self.session.heartbeats.receive();
+
+        // Checkpoint the sequence so that few events are replayed when
+        // resuming from the store.
+        self.save_session().await;
     }
 
     async fn process_heartbeat(&mut self, seq: u64) {
@@ -638,6 +784,7 @@ impl ShardProcessor {
             metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "InvalidateSessionFalse");
 
             tracing::debug!("got request to invalidate the session and reconnect");
+            self.remove_session().await;
             self.reconnect().await;
         }
     }
@@ -664,6 +811,58 @@ impl ShardProcessor {
         Ok(())
     }
 
+    /// Save the current session in the session store, if there is one.
+    ///
+    /// Nothing is saved while the shard is being replaced, since the new shard
+    /// saves its session under the same key.
+    async fn save_session(&self) {
+        let store = match self.config.session_store.as_ref() {
+            Some(store) => store,
+            None => return,
+        };
+
+        if self.replaced.load(Ordering::Acquire) {
+            return;
+        }
+
+        let session_id = match self.session.id() {
+            Some(session_id) => session_id,
+            None => return,
+        };
+
+        let session = ResumeSession {
+            session_id: session_id.into_string(),
+            sequence: self.session.seq(),
+        };
+
+        if let Err(source) = store.save(self.config.shard(), session).await {
+            tracing::warn!(
+                shard_id = self.config.shard()[0],
+                shard_total = self.config.shard()[1],
+                "saving session to the store failed: {}",
+                source,
+            );
+        }
+    }
+
+    /// Remove the invalidated session from the session store, if there is
+    /// one.
+    async fn remove_session(&self) {
+        let store = match self.config.session_store.as_ref() {
+            Some(store) => store,
+            None => return,
+        };
+
+        if let Err(source) = store.remove(self.config.shard()).await {
+            tracing::warn!(
+                shard_id = self.config.shard()[0],
+                shard_total = self.config.shard()[1],
+                "removing session from the store failed: {}",
+                source,
+            );
+        }
+    }
+
     pub async fn send(&mut self, payload: impl Serialize) -> Result<(), SessionSendError> {
         if let Err(source) = self.session.send(payload) {
             tracing::warn!("sending message failed: {:?}", source);
@@ -987,3 +1186,134 @@ impl ShardProcessor {
         }));
     }
 }
+
+#[cfg(all(
+    test,
+    feature = "compression",
+    not(any(feature = "etf", feature = "zstd"))
+))]
+mod tests {
+    use crate::shard::{
+        store::{SessionStore, SessionStoreFuture},
+        ResumeSession, Shard,
+    };
+    use flate2::{Compress, Compression, FlushCompress};
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::Value;
+    use std::{
+        collections::HashMap,
+        error::Error,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use tokio::{net::TcpListener, sync::mpsc, time};
+    use tokio_tungstenite::tungstenite::Message;
+    use twilight_model::gateway::Intents;
+
+    #[derive(Debug, Default)]
+    struct MemoryStore(Mutex<HashMap<[u64; 2], ResumeSession>>);
+
+    impl SessionStore for MemoryStore {
+        fn load(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, Option<ResumeSession>> {
+            let session = self.0.lock().unwrap().get(&shard).cloned();
+
+            Box::pin(async move { Ok(session) })
+        }
+
+        fn save(&self, shard: [u64; 2], session: ResumeSession) -> SessionStoreFuture<'_, ()> {
+            self.0.lock().unwrap().insert(shard, session);
+
+            Box::pin(async { Ok(()) })
+        }
+
+        fn remove(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, ()> {
+            self.0.lock().unwrap().remove(&shard);
+
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    /// Compress a payload like Discord's `zlib-stream` transport compression.
+    ///
+    /// The inflater expects payloads to be larger than their compressed
+    /// length, so payloads shouldn't be tiny.
+    fn compress(payload: &str) -> Vec<u8> {
+        let mut compress = Compress::new(Compression::default(), true);
+        let mut output = Vec::with_capacity(payload.len() + 64);
+        compress
+            .compress_vec(payload.as_bytes(), &mut output, FlushCompress::Sync)
+            .unwrap();
+
+        output
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_store() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        // Send a hello to every connection and forward the first payload
+        // received in response. Resuming opens a new connection, so the first
+        // one is closed without a response.
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let tx = tx.clone();
+
+                tokio::spawn(async move {
+                    let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    let hello = compress(&format!(
+                        r#"{{"op":10,"d":{{"heartbeat_interval":41250,"_trace":["{}"]}}}}"#,
+                        "gateway".repeat(32),
+                    ));
+                    socket.send(Message::Binary(hello)).await.unwrap();
+
+                    while let Some(Ok(message)) = socket.next().await {
+                        if message.is_binary() || message.is_text() {
+                            let payload = serde_json::from_slice::<Value>(&message.into_data());
+                            let _res = tx.send(payload);
+
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        let store = Arc::new(MemoryStore::default());
+        store
+            .save(
+                [0, 1],
+                ResumeSession {
+                    session_id: "session".to_owned(),
+                    sequence: 5,
+                },
+            )
+            .await?;
+
+        let (shard, _events) = Shard::builder("token", Intents::empty())
+            .gateway_url(Some(url))
+            .session_store(Arc::clone(&store) as Arc<dyn SessionStore>)
+            .build();
+        shard.start().await?;
+
+        let payload = time::timeout(Duration::from_secs(10), rx.recv())
+            .await?
+            .expect("server is running")?;
+        assert_eq!(6, payload["op"]);
+        assert_eq!("session", payload["d"]["session_id"]);
+        assert_eq!(5, payload["d"]["seq"]);
+
+        // Cleanly shutting down makes the session unresumable.
+        shard.shutdown();
+
+        time::timeout(Duration::from_secs(10), async {
+            while store.load([0, 1]).await.unwrap().is_some() {
+                time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+
+        Ok(())
+    }
+}
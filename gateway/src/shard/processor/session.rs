@@ -1,5 +1,9 @@
+#[cfg(feature = "etf")]
+use super::super::etf::to_vec;
+#[cfg(not(feature = "etf"))]
+use super::super::json::to_vec;
 use super::{
-    super::{json, stage::Stage},
+    super::stage::Stage,
     heartbeat::{Heartbeater, Heartbeats},
     throttle::Throttle,
 };
@@ -101,7 +105,7 @@ impl Session {
     /// receiving channel has hung up. This will only happen when the shard has
     /// either not started or has already shutdown.
     pub fn send(&self, payload: impl Serialize) -> Result<(), SessionSendError> {
-        let bytes = json::to_vec(&payload).map_err(|source| SessionSendError {
+        let bytes = to_vec(&payload).map_err(|source| SessionSendError {
             kind: SessionSendErrorType::Serializing,
             source: Some(Box::new(source)),
         })?;
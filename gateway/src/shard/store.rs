@@ -0,0 +1,167 @@
+//! Persistent storage of the details needed to resume gateway sessions.
+//!
+//! Refer to [`ShardBuilder::session_store`] for more information.
+//!
+//! [`ShardBuilder::session_store`]: super::ShardBuilder::session_store
+
+use super::ResumeSession;
+use std::{
+    error::Error,
+    fmt::Debug,
+    future::Future,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    pin::Pin,
+};
+use tokio::fs;
+
+/// Future returned by the methods of a [`SessionStore`].
+pub type SessionStoreFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, Box<dyn Error + Send + Sync>>> + Send + 'a>>;
+
+/// Storage of the details needed to resume the sessions of shards.
+///
+/// Sessions are keyed by the shard's ID and the total number of shards, so
+/// that shards started with a different total don't resume the sessions of
+/// other shards.
+///
+/// Shards save their session when they receive a `Ready` or `Resumed` event,
+/// and checkpoint its sequence each time the gateway acknowledges a heartbeat.
+/// When a shard starts it loads its session, allowing it to resume instead of
+/// identifying after the process restarts.
+///
+/// Failing to load or save a session is logged and doesn't stop the shard.
+///
+/// Refer to [`FileSessionStore`] for an implementation storing sessions in
+/// files.
+pub trait SessionStore: Debug + Send + Sync {
+    /// Load the session of a shard, if one was saved.
+    fn load(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, Option<ResumeSession>>;
+
+    /// Save the session of a shard, replacing the previous session.
+    fn save(&self, shard: [u64; 2], session: ResumeSession) -> SessionStoreFuture<'_, ()>;
+
+    /// Remove the session of a shard because it can no longer be resumed.
+    fn remove(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, ()>;
+}
+
+/// [`SessionStore`] saving the session of each shard as a JSON file in a
+/// directory.
+///
+/// The session of a shard is stored in a file named after its ID and the total
+/// number of shards, such as `3-16.json`. The directory is created when a
+/// session is first saved.
+#[derive(Clone, Debug)]
+pub struct FileSessionStore {
+    directory: PathBuf,
+}
+
+impl FileSessionStore {
+    /// Create a new store saving sessions in a directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Immutable reference to the directory the sessions are saved in.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, [id, total]: [u64; 2]) -> PathBuf {
+        self.directory.join(format!("{}-{}.json", id, total))
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn load(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, Option<ResumeSession>> {
+        Box::pin(async move {
+            let bytes = match fs::read(self.path(shard)).await {
+                Ok(bytes) => bytes,
+                Err(source) if source.kind() == ErrorKind::NotFound => return Ok(None),
+                Err(source) => return Err(Box::new(source) as Box<dyn Error + Send + Sync>),
+            };
+
+            Ok(Some(serde_json::from_slice(&bytes)?))
+        })
+    }
+
+    fn save(&self, shard: [u64; 2], session: ResumeSession) -> SessionStoreFuture<'_, ()> {
+        Box::pin(async move {
+            let bytes = serde_json::to_vec(&session)?;
+            let path = self.path(shard);
+            let temporary = path.with_extension("json.tmp");
+
+            fs::create_dir_all(&self.directory).await?;
+
+            // Write to a temporary file first so that a crash while writing
+            // doesn't leave a partially written session behind.
+            fs::write(&temporary, bytes).await?;
+            fs::rename(&temporary, &path).await?;
+
+            Ok(())
+        })
+    }
+
+    fn remove(&self, shard: [u64; 2]) -> SessionStoreFuture<'_, ()> {
+        Box::pin(async move {
+            match fs::remove_file(self.path(shard)).await {
+                Ok(()) => Ok(()),
+                Err(source) if source.kind() == ErrorKind::NotFound => Ok(()),
+                Err(source) => Err(Box::new(source) as Box<dyn Error + Send + Sync>),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FileSessionStore, SessionStore};
+    use crate::shard::ResumeSession;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{env, error::Error, fmt::Debug, fs, process};
+
+    assert_impl_all!(FileSessionStore: Clone, Debug, SessionStore, Send, Sync);
+    assert_obj_safe!(SessionStore);
+
+    #[tokio::test]
+    async fn test_file_session_store() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let directory = env::temp_dir().join(format!("twilight-gateway-{}", process::id()));
+        let store = FileSessionStore::new(&directory);
+
+        assert!(store.load([3, 16]).await?.is_none());
+        store.remove([3, 16]).await?;
+
+        store
+            .save(
+                [3, 16],
+                ResumeSession {
+                    session_id: "a".to_owned(),
+                    sequence: 10,
+                },
+            )
+            .await?;
+        store
+            .save(
+                [3, 16],
+                ResumeSession {
+                    session_id: "b".to_owned(),
+                    sequence: 20,
+                },
+            )
+            .await?;
+
+        let session = store.load([3, 16]).await?.expect("session was saved");
+        assert_eq!("b", session.session_id);
+        assert_eq!(20, session.sequence);
+        assert!(store.load([3, 32]).await?.is_none());
+
+        store.remove([3, 16]).await?;
+        assert!(store.load([3, 16]).await?.is_none());
+
+        fs::remove_dir_all(directory)?;
+
+        Ok(())
+    }
+}
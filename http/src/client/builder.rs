@@ -1,5 +1,9 @@
-use super::{Client, State};
-use crate::ratelimiting::Ratelimiter;
+use super::{Client, InvalidRequests, RetryPolicy, State};
+use crate::{
+    observer::Observer,
+    ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    transport::{HyperTransport, Transport},
+};
 use hyper::header::HeaderMap;
 use std::{
     sync::{
@@ -14,12 +18,18 @@ use twilight_model::{channel::message::allowed_mentions::AllowedMentions, id::Ap
 /// A builder for [`Client`].
 pub struct ClientBuilder {
     pub(crate) application_id: AtomicU64,
+    pub(crate) attachment_size_limit: u64,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    pub(crate) invalid_request_threshold: Option<usize>,
+    pub(crate) observer: Option<Box<dyn Observer>>,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
+    pub(crate) timeout_includes_ratelimit: bool,
     pub(crate) token: Option<Box<str>>,
+    pub(crate) transport: Option<Box<dyn Transport>>,
     pub(crate) use_http: bool,
 }
 
@@ -31,26 +41,22 @@ impl ClientBuilder {
 
     /// Build the [`Client`].
     pub fn build(self) -> Client {
-        #[cfg(feature = "rustls-native-roots")]
-        let connector = hyper_rustls::HttpsConnector::with_native_roots();
-        #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
-        let connector = hyper_rustls::HttpsConnector::with_webpki_roots();
-        #[cfg(all(
-            feature = "hyper-tls",
-            not(feature = "rustls-native-roots"),
-            not(feature = "rustls-webpki-roots")
-        ))]
-        let connector = hyper_tls::HttpsConnector::new();
-
-        let http = hyper::client::Builder::default().build(connector);
+        let http = self
+            .transport
+            .unwrap_or_else(|| Box::new(HyperTransport::new()));
 
         Client {
             state: Arc::new(State {
                 http,
+                attachment_size_limit: self.attachment_size_limit,
                 default_headers: self.default_headers,
+                invalid_requests: InvalidRequests::new(self.invalid_request_threshold),
+                observer: self.observer,
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
+                retry_policy: self.retry_policy,
                 timeout: self.timeout,
+                timeout_includes_ratelimit: self.timeout_includes_ratelimit,
                 token_invalid: AtomicBool::new(false),
                 token: self.token,
                 application_id: self.application_id,
@@ -68,6 +74,22 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the maximum total length in bytes of the attachments of a request.
+    ///
+    /// Requests uploading larger attachments fail with an
+    /// [`ErrorType::AttachmentsTooLarge`] error type before they are sent.
+    /// Guilds with boosts allow larger uploads.
+    ///
+    /// Defaults to [`ATTACHMENT_SIZE_LIMIT`].
+    ///
+    /// [`ATTACHMENT_SIZE_LIMIT`]: super::ATTACHMENT_SIZE_LIMIT
+    /// [`ErrorType::AttachmentsTooLarge`]: crate::error::ErrorType::AttachmentsTooLarge
+    pub const fn attachment_size_limit(mut self, limit: u64) -> Self {
+        self.attachment_size_limit = limit;
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
@@ -76,6 +98,40 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the number of invalid requests within the window at which the
+    /// client refuses to send requests.
+    ///
+    /// Discord bans IP addresses that send [`INVALID_REQUEST_LIMIT`] invalid
+    /// requests within [`INVALID_REQUEST_WINDOW`]. Once the threshold is
+    /// reached requests fail with an [`ErrorType::InvalidRequestLimit`] error
+    /// type until enough invalid requests have left the window. A threshold
+    /// below the limit should be used if other clients share the IP address.
+    ///
+    /// If the argument is `None` then requests are sent regardless of the
+    /// number of invalid requests.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`ErrorType::InvalidRequestLimit`]: crate::error::ErrorType::InvalidRequestLimit
+    /// [`INVALID_REQUEST_LIMIT`]: super::INVALID_REQUEST_LIMIT
+    /// [`INVALID_REQUEST_WINDOW`]: super::INVALID_REQUEST_WINDOW
+    pub fn invalid_request_threshold(mut self, threshold: impl Into<Option<usize>>) -> Self {
+        self.invalid_request_threshold = threshold.into();
+
+        self
+    }
+
+    /// Set an observer to notify of every request sent.
+    ///
+    /// Refer to the [`observer`] module for more information.
+    ///
+    /// [`observer`]: crate::observer
+    pub fn observer(mut self, observer: Box<dyn Observer>) -> Self {
+        self.observer.replace(observer);
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -108,16 +164,45 @@ impl ClientBuilder {
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by [`ClientBuilder::build`].
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    /// If this method is not called at all then a default
+    /// [`InMemoryRatelimiter`] will be created by [`ClientBuilder::build`].
+    pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
+        self.ratelimiter = ratelimiter;
+
+        self
+    }
+
+    /// Set the policy for automatically retrying failed requests.
+    ///
+    /// If the argument is `None` then failed requests will not be retried.
+    ///
+    /// Requests are not retried by default.
+    pub fn retry_policy(mut self, retry_policy: impl Into<Option<RetryPolicy>>) -> Self {
+        self.retry_policy = retry_policy.into();
+
+        self
+    }
+
+    /// Set the transport used to send requests.
+    ///
+    /// If this method is not called at all then a [`HyperTransport`] will be
+    /// created by [`ClientBuilder::build`], sending requests over the network.
+    ///
+    /// Use a [`MockTransport`] to test code making requests without network
+    /// access.
+    ///
+    /// [`MockTransport`]: crate::transport::MockTransport
+    pub fn transport(mut self, transport: Box<dyn Transport>) -> Self {
+        self.transport.replace(transport);
 
         self
     }
 
     /// Set the timeout for HTTP requests.
     ///
+    /// This can be overridden for individual requests by the `timeout` method
+    /// of request builders.
+    ///
     /// The default is 10 seconds.
     pub const fn timeout(mut self, duration: Duration) -> Self {
         self.timeout = duration;
@@ -125,6 +210,20 @@ impl ClientBuilder {
         self
     }
 
+    /// Set whether time spent waiting on the ratelimiter counts against the
+    /// timeout of requests.
+    ///
+    /// When disabled the timeout only starts once the ratelimiter allows the
+    /// request to be sent, so requests may wait on ratelimits for longer than
+    /// the timeout.
+    ///
+    /// The default is true.
+    pub const fn timeout_includes_ratelimit(mut self, include: bool) -> Self {
+        self.timeout_includes_ratelimit = include;
+
+        self
+    }
+
     /// Set a group headers which are sent in every request.
     pub fn default_headers(mut self, headers: HeaderMap) -> Self {
         self.default_headers.replace(headers);
@@ -155,12 +254,18 @@ impl Default for ClientBuilder {
     fn default() -> Self {
         Self {
             application_id: AtomicU64::default(),
+            attachment_size_limit: super::ATTACHMENT_SIZE_LIMIT,
             default_allowed_mentions: None,
             default_headers: None,
+            invalid_request_threshold: None,
+            observer: None,
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
+            retry_policy: None,
             timeout: Duration::from_secs(10),
+            timeout_includes_ratelimit: true,
             token: None,
+            transport: None,
             use_http: false,
         }
     }
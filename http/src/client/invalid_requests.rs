@@ -0,0 +1,292 @@
+use crate::routing::Path;
+use hyper::{header::HeaderMap, StatusCode};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// Number of invalid requests within the [`INVALID_REQUEST_WINDOW`] after
+/// which Discord temporarily bans the IP address sending them.
+pub const INVALID_REQUEST_LIMIT: usize = 10_000;
+
+/// Duration of the sliding window in which Discord counts invalid requests.
+pub const INVALID_REQUEST_WINDOW: Duration = Duration::from_mins(10);
+
+/// Tracker of invalid requests sent by the client.
+///
+/// Discord considers responses with a status code of 401, 403, or 429 to be
+/// invalid requests, except for 429s of shared ratelimits. If an IP address
+/// sends [`INVALID_REQUEST_LIMIT`] invalid requests within
+/// [`INVALID_REQUEST_WINDOW`] then it is banned from the API for a period of
+/// time.
+///
+/// When a threshold is configured via
+/// [`ClientBuilder::invalid_request_threshold`] the client refuses to send
+/// requests once the number of invalid requests within the window reaches it,
+/// returning an [`ErrorType::InvalidRequestLimit`] error type instead.
+///
+/// The tracker of a client can be retrieved via [`Client::invalid_requests`].
+///
+/// [`Client::invalid_requests`]: super::Client::invalid_requests
+/// [`ClientBuilder::invalid_request_threshold`]: super::ClientBuilder::invalid_request_threshold
+/// [`ErrorType::InvalidRequestLimit`]: crate::error::ErrorType::InvalidRequestLimit
+#[derive(Debug)]
+pub struct InvalidRequests {
+    forbidden: Mutex<HashMap<Path, VecDeque<Instant>>>,
+    invalid: Mutex<VecDeque<Instant>>,
+    threshold: Option<usize>,
+}
+
+impl InvalidRequests {
+    pub(crate) fn new(threshold: Option<usize>) -> Self {
+        Self {
+            forbidden: Mutex::new(HashMap::new()),
+            invalid: Mutex::new(VecDeque::new()),
+            threshold,
+        }
+    }
+
+    /// Number of invalid requests within the window.
+    pub fn count(&self) -> usize {
+        self.count_at(Instant::now())
+    }
+
+    /// Number of responses with a status code of 403 received for a path
+    /// within the window.
+    ///
+    /// A rising count for a path usually means that the current user is
+    /// missing permissions that it is expected to have.
+    pub fn forbidden(&self, path: &Path) -> usize {
+        let mut forbidden = self.forbidden_paths();
+
+        forbidden.get_mut(path).map_or(0, |instants| {
+            prune(instants, Instant::now());
+
+            instants.len()
+        })
+    }
+
+    /// Number of invalid requests within the window at which the client
+    /// refuses to send requests, if any.
+    pub const fn threshold(&self) -> Option<usize> {
+        self.threshold
+    }
+
+    /// Number of invalid requests within the window if the threshold has been
+    /// reached.
+    pub(crate) fn exceeded(&self) -> Option<usize> {
+        let threshold = self.threshold?;
+        let count = self.count();
+
+        if count >= threshold {
+            Some(count)
+        } else {
+            None
+        }
+    }
+
+    /// Record a response, returning the number of 403s for the path within
+    /// the window if the response was a 403.
+    pub(crate) fn record(
+        &self,
+        path: &Path,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<usize> {
+        self.record_at(path, status, headers, Instant::now())
+    }
+
+    fn count_at(&self, now: Instant) -> usize {
+        let mut invalid = self.invalid();
+        prune(&mut invalid, now);
+
+        invalid.len()
+    }
+
+    fn record_at(
+        &self,
+        path: &Path,
+        status: StatusCode,
+        headers: &HeaderMap,
+        now: Instant,
+    ) -> Option<usize> {
+        let invalid = match status {
+            StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED => true,
+            StatusCode::TOO_MANY_REQUESTS => headers
+                .get("x-ratelimit-scope")
+                .map_or(true, |scope| scope != "shared"),
+            _ => false,
+        };
+
+        if !invalid {
+            return None;
+        }
+
+        let mut requests = self.invalid();
+        prune(&mut requests, now);
+        requests.push_back(now);
+        drop(requests);
+
+        if status != StatusCode::FORBIDDEN {
+            return None;
+        }
+
+        let mut forbidden = self.forbidden_paths();
+
+        forbidden.retain(|_, instants| {
+            prune(instants, now);
+
+            !instants.is_empty()
+        });
+
+        let instants = forbidden.entry(path.clone()).or_default();
+        instants.push_back(now);
+
+        Some(instants.len())
+    }
+
+    fn forbidden_paths(&self) -> MutexGuard<'_, HashMap<Path, VecDeque<Instant>>> {
+        self.forbidden.lock().expect("forbidden paths poisoned")
+    }
+
+    fn invalid(&self) -> MutexGuard<'_, VecDeque<Instant>> {
+        self.invalid.lock().expect("invalid requests poisoned")
+    }
+}
+
+/// Remove instants that are no longer within the window.
+fn prune(instants: &mut VecDeque<Instant>, now: Instant) {
+    while let Some(instant) = instants.front() {
+        if now.duration_since(*instant) < INVALID_REQUEST_WINDOW {
+            break;
+        }
+
+        instants.pop_front();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidRequests, INVALID_REQUEST_WINDOW};
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        routing::{Path, Route},
+        transport::{MockResponse, MockTransport},
+    };
+    use hyper::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(InvalidRequests: Debug, Send, Sync);
+
+    #[test]
+    fn test_record() {
+        let invalid = InvalidRequests::new(None);
+        let path = Path::ChannelsIdMessages(1);
+        let headers = HeaderMap::new();
+
+        assert!(invalid.record(&path, StatusCode::OK, &headers).is_none());
+        assert!(invalid
+            .record(&path, StatusCode::NOT_FOUND, &headers)
+            .is_none());
+        assert_eq!(0, invalid.count());
+
+        assert!(invalid
+            .record(&path, StatusCode::UNAUTHORIZED, &headers)
+            .is_none());
+        assert!(invalid
+            .record(&path, StatusCode::TOO_MANY_REQUESTS, &headers)
+            .is_none());
+        assert_eq!(
+            Some(1),
+            invalid.record(&path, StatusCode::FORBIDDEN, &headers)
+        );
+        assert_eq!(
+            Some(2),
+            invalid.record(&path, StatusCode::FORBIDDEN, &headers)
+        );
+        assert_eq!(4, invalid.count());
+        assert_eq!(2, invalid.forbidden(&path));
+        assert_eq!(0, invalid.forbidden(&Path::ChannelsIdMessages(2)));
+    }
+
+    #[test]
+    fn test_shared_ratelimit() {
+        let invalid = InvalidRequests::new(None);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-scope", HeaderValue::from_static("shared"));
+
+        invalid.record(&Path::Guilds, StatusCode::TOO_MANY_REQUESTS, &headers);
+        assert_eq!(0, invalid.count());
+    }
+
+    #[test]
+    fn test_window() {
+        let invalid = InvalidRequests::new(None);
+        let path = Path::Guilds;
+        let headers = HeaderMap::new();
+        let now = Instant::now();
+        let later = now + Duration::from_mins(5);
+
+        invalid.record_at(&path, StatusCode::FORBIDDEN, &headers, now);
+        invalid.record_at(&path, StatusCode::UNAUTHORIZED, &headers, later);
+        assert_eq!(2, invalid.count_at(later));
+        assert_eq!(1, invalid.count_at(now + INVALID_REQUEST_WINDOW));
+        assert_eq!(
+            Some(1),
+            invalid.record_at(
+                &path,
+                StatusCode::FORBIDDEN,
+                &headers,
+                now + INVALID_REQUEST_WINDOW
+            )
+        );
+    }
+
+    #[test]
+    fn test_threshold() {
+        let invalid = InvalidRequests::new(Some(2));
+        let path = Path::Guilds;
+        let headers = HeaderMap::new();
+
+        invalid.record(&path, StatusCode::UNAUTHORIZED, &headers);
+        assert!(invalid.exceeded().is_none());
+
+        invalid.record(&path, StatusCode::UNAUTHORIZED, &headers);
+        assert_eq!(Some(2), invalid.exceeded());
+    }
+
+    #[tokio::test]
+    async fn test_client_threshold() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::GetChannel { channel_id: 1 },
+            MockResponse::error(StatusCode::FORBIDDEN, 50001, "Missing Access"),
+        );
+        let client = Client::builder()
+            .invalid_request_threshold(1)
+            .token("token")
+            .transport(Box::new(transport))
+            .build();
+
+        let error = client.channel(ChannelId(1)).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Response { .. }));
+        assert_eq!(1, client.invalid_requests().count());
+        assert_eq!(1, client.invalid_requests().forbidden(&Path::ChannelsId(1)));
+
+        let error = client.channel(ChannelId(1)).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::InvalidRequestLimit { count: 1 }
+        ));
+    }
+}
@@ -1,10 +1,19 @@
 mod builder;
-
-pub use self::builder::ClientBuilder;
+mod invalid_requests;
+mod retry;
+mod webhook;
+
+pub use self::{
+    builder::ClientBuilder,
+    invalid_requests::{InvalidRequests, INVALID_REQUEST_LIMIT, INVALID_REQUEST_WINDOW},
+    retry::RetryPolicy,
+    webhook::{WebhookClient, WebhookClientError, WebhookClientErrorType},
+};
 
 use crate::{
     api_error::ApiError,
     error::{Error, ErrorType},
+    observer::{Observer, RequestInfo},
     ratelimiting::{RatelimitHeaders, Ratelimiter},
     request::{
         application::{
@@ -12,23 +21,29 @@ use crate::{
             DeleteGlobalCommand, DeleteGuildCommand, DeleteOriginalResponse, GetCommandPermissions,
             GetGlobalCommands, GetGuildCommandPermissions, GetGuildCommands, GetOriginalResponse,
             InteractionCallback, InteractionError, InteractionErrorType, SetCommandPermissions,
-            SetGlobalCommands, SetGuildCommands, UpdateCommandPermissions, UpdateFollowupMessage,
-            UpdateGlobalCommand, UpdateGuildCommand, UpdateOriginalResponse,
+            SetGlobalCommands, SetGuildCommands, SyncCommands, UpdateCommandPermissions,
+            UpdateFollowupMessage, UpdateGlobalCommand, UpdateGuildCommand, UpdateOriginalResponse,
+        },
+        channel::{
+            stage::create_stage_instance::CreateStageInstanceError,
+            thread::{
+                create_thread::CreateThreadError,
+                create_thread_from_message::CreateThreadFromMessageError,
+            },
         },
-        channel::stage::create_stage_instance::CreateStageInstanceError,
         guild::{
             create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError,
             update_guild_channel_positions::Position,
         },
         prelude::*,
-        GetUserApplicationInfo, Method, Request,
+        Form, GetUserApplicationInfo, Method, Request,
     },
+    transport::{cassette, Transport},
     API_VERSION,
 };
 use hyper::body::Bytes;
 use hyper::{
     body,
-    client::{Client as HyperClient, HttpConnector},
     header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Body, Response, StatusCode,
 };
@@ -42,13 +57,13 @@ use std::{
     },
     time::Duration,
 };
-use tokio::time;
+use tokio::time::{self, Instant};
 use twilight_model::{
     application::{
         callback::InteractionResponse,
-        command::{permissions::CommandPermissions, Command},
+        command::{permissions::CommandPermissions, Command, CommandType},
     },
-    channel::message::allowed_mentions::AllowedMentions,
+    channel::{message::allowed_mentions::AllowedMentions, ChannelType},
     guild::Permissions,
     id::{
         ApplicationId, ChannelId, CommandId, EmojiId, GuildId, IntegrationId, InteractionId,
@@ -56,17 +71,23 @@ use twilight_model::{
     },
 };
 
-#[cfg(feature = "hyper-rustls")]
-type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
-#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
-type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
+/// Default maximum total length in bytes of the attachments of a request,
+/// which is Discord's upload limit for guilds without boosts.
+///
+/// Refer to [`ClientBuilder::attachment_size_limit`] for more information.
+pub const ATTACHMENT_SIZE_LIMIT: u64 = 8 * 1024 * 1024;
 
 struct State {
-    http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    http: Box<dyn Transport>,
+    attachment_size_limit: u64,
     default_headers: Option<HeaderMap>,
+    invalid_requests: InvalidRequests,
+    observer: Option<Box<dyn Observer>>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    retry_policy: Option<RetryPolicy>,
     timeout: Duration,
+    timeout_includes_ratelimit: bool,
     token_invalid: AtomicBool,
     token: Option<Box<str>>,
     use_http: bool,
@@ -78,11 +99,23 @@ impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("State")
             .field("http", &self.http)
+            .field("attachment_size_limit", &self.attachment_size_limit)
             .field("default_headers", &self.default_headers)
+            .field("invalid_requests", &self.invalid_requests)
+            .field("observer", &self.observer)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("timeout", &self.timeout)
+            .field(
+                "timeout_includes_ratelimit",
+                &self.timeout_includes_ratelimit,
+            )
+            .field("token_invalid", &self.token_invalid)
             .field("token", &self.token)
             .field("use_http", &self.use_http)
+            .field("application_id", &self.application_id)
+            .field("default_allowed_mentions", &self.default_allowed_mentions)
             .finish()
     }
 }
@@ -109,6 +142,10 @@ impl Debug for State {
 /// # Ok(()) }
 /// ```
 ///
+/// Access tokens can be retrieved and refreshed via the [`oauth2`] module.
+///
+/// [`oauth2`]: crate::oauth2
+///
 /// # Cloning
 ///
 /// The client internally wraps its data within an Arc. This means that the
@@ -211,12 +248,19 @@ impl Client {
         self.state.default_allowed_mentions.clone()
     }
 
+    /// Get the tracker of invalid requests sent by the client.
+    ///
+    /// Refer to its documentation for more information.
+    pub fn invalid_requests(&self) -> &InvalidRequests {
+        &self.state.invalid_requests
+    }
+
     /// Get the Ratelimiter used by the client internally.
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.state.ratelimiter.as_deref()
     }
 
     /// Get the audit log for a guild.
@@ -1406,6 +1450,122 @@ impl Client {
         DeleteStageInstance::new(self, channel_id)
     }
 
+    /// Create a thread that is not connected to a message.
+    ///
+    /// The `kind` must be one of the thread [`ChannelType`]s.
+    ///
+    /// # Examples
+    ///
+    /// Create a public thread in channel `1`:
+    ///
+    /// ```rust,no_run
+    /// use twilight_http::Client;
+    /// use twilight_model::{
+    ///     channel::{thread::AutoArchiveDuration, ChannelType},
+    ///     id::ChannelId,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let thread = client
+    ///     .create_thread(ChannelId(1), "planning", ChannelType::GuildPublicThread)?
+    ///     .auto_archive_duration(AutoArchiveDuration::Day)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadErrorType::NameInvalid`] error type if the name
+    /// is not between 1 and 100 characters in length.
+    ///
+    /// Returns a [`CreateThreadErrorType::TypeInvalid`] error type if the kind
+    /// is not a thread type.
+    ///
+    /// [`ChannelType`]: twilight_model::channel::ChannelType
+    /// [`CreateThreadErrorType::NameInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::NameInvalid
+    /// [`CreateThreadErrorType::TypeInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::TypeInvalid
+    pub fn create_thread(
+        &self,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        kind: ChannelType,
+    ) -> Result<CreateThread<'_>, CreateThreadError> {
+        CreateThread::new(self, channel_id, name, kind)
+    }
+
+    /// Create a thread from an existing message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadFromMessageErrorType::NameInvalid`] error type
+    /// if the name is not between 1 and 100 characters in length.
+    ///
+    /// [`CreateThreadFromMessageErrorType::NameInvalid`]: crate::request::channel::thread::create_thread_from_message::CreateThreadFromMessageErrorType::NameInvalid
+    pub fn create_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+    ) -> Result<CreateThreadFromMessage<'_>, CreateThreadFromMessageError> {
+        CreateThreadFromMessage::new(self, channel_id, message_id, name)
+    }
+
+    /// Get the active threads of a guild.
+    pub fn active_threads(&self, guild_id: GuildId) -> GetActiveThreads<'_> {
+        GetActiveThreads::new(self, guild_id)
+    }
+
+    /// Get the archived public threads of a channel.
+    pub fn public_archived_threads(&self, channel_id: ChannelId) -> GetPublicArchivedThreads<'_> {
+        GetPublicArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel.
+    pub fn private_archived_threads(&self, channel_id: ChannelId) -> GetPrivateArchivedThreads<'_> {
+        GetPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel that the current user has
+    /// joined.
+    pub fn joined_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+    ) -> GetJoinedPrivateArchivedThreads<'_> {
+        GetJoinedPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Add the current user to a thread.
+    pub fn join_thread(&self, channel_id: ChannelId) -> JoinThread<'_> {
+        JoinThread::new(self, channel_id)
+    }
+
+    /// Remove the current user from a thread.
+    pub fn leave_thread(&self, channel_id: ChannelId) -> LeaveThread<'_> {
+        LeaveThread::new(self, channel_id)
+    }
+
+    /// Add a member to a thread.
+    pub fn add_thread_member(&self, channel_id: ChannelId, user_id: UserId) -> AddThreadMember<'_> {
+        AddThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Remove a member from a thread.
+    pub fn remove_thread_member(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> RemoveThreadMember<'_> {
+        RemoveThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Get the members of a thread.
+    pub fn thread_members(&self, channel_id: ChannelId) -> GetThreadMembers<'_> {
+        GetThreadMembers::new(self, channel_id)
+    }
+
     /// Create a new guild based on a template.
     ///
     /// This endpoint can only be used by bots in less than 10 guilds.
@@ -1829,7 +1989,89 @@ impl Client {
             kind: InteractionErrorType::ApplicationIdNotPresent,
         })?;
 
-        CreateGuildCommand::new(&self, application_id, guild_id, name, description)
+        CreateGuildCommand::new(
+            &self,
+            application_id,
+            guild_id,
+            name,
+            description,
+            CommandType::ChatInput,
+        )
+    }
+
+    /// Create a new user command in a guild.
+    ///
+    /// User commands are shown in the context menu of users. The name must be
+    /// between 1 and 32 characters in length and may contain spaces and upper
+    /// case characters. Creating a guild command with the same name as an
+    /// already-existing guild command of the same type in the same guild will
+    /// overwrite the old command. See [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`]
+    /// error type if the command name is not between 1 and 32 characters.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#user-commands
+    pub fn create_guild_user_command(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+    ) -> Result<CreateGuildCommand<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        CreateGuildCommand::new(
+            self,
+            application_id,
+            guild_id,
+            name,
+            String::new(),
+            CommandType::User,
+        )
+    }
+
+    /// Create a new message command in a guild.
+    ///
+    /// Message commands are shown in the context menu of messages. The name
+    /// must be between 1 and 32 characters in length and may contain spaces
+    /// and upper case characters. Creating a guild command with the same name
+    /// as an already-existing guild command of the same type in the same guild
+    /// will overwrite the old command. See [the discord docs] for more
+    /// information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`]
+    /// error type if the command name is not between 1 and 32 characters.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#message-commands
+    pub fn create_guild_message_command(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+    ) -> Result<CreateGuildCommand<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        CreateGuildCommand::new(
+            self,
+            application_id,
+            guild_id,
+            name,
+            String::new(),
+            CommandType::Message,
+        )
     }
 
     /// Fetch all commands for a guild, by ID.
@@ -1930,6 +2172,33 @@ impl Client {
         ))
     }
 
+    /// Synchronize a guild's commands with local definitions, only creating,
+    /// updating, or deleting the commands that differ.
+    ///
+    /// Refer to [`SyncCommands`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    pub fn sync_guild_commands(
+        &self,
+        guild_id: GuildId,
+        commands: Vec<Command>,
+    ) -> Result<SyncCommands<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        Ok(SyncCommands::new(
+            self,
+            application_id,
+            Some(guild_id),
+            commands,
+        ))
+    }
+
     /// Create a new global command.
     ///
     /// The name must be between 3 and 32 characters in length, and the
@@ -1960,7 +2229,77 @@ impl Client {
             kind: InteractionErrorType::ApplicationIdNotPresent,
         })?;
 
-        CreateGlobalCommand::new(self, application_id, name, description)
+        CreateGlobalCommand::new(
+            self,
+            application_id,
+            name,
+            description,
+            CommandType::ChatInput,
+        )
+    }
+
+    /// Create a new user command globally.
+    ///
+    /// User commands are shown in the context menu of users. The name must be
+    /// between 1 and 32 characters in length and may contain spaces and upper
+    /// case characters. Creating a global command with the same name as
+    /// an already-existing global command of the same type will overwrite the
+    /// old command. See [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`]
+    /// error type if the command name is not between 1 and 32 characters.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#user-commands
+    pub fn create_global_user_command(
+        &self,
+        name: impl Into<String>,
+    ) -> Result<CreateGlobalCommand<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        CreateGlobalCommand::new(self, application_id, name, String::new(), CommandType::User)
+    }
+
+    /// Create a new message command globally.
+    ///
+    /// Message commands are shown in the context menu of messages. The name
+    /// must be between 1 and 32 characters in length and may contain spaces
+    /// and upper case characters. Creating a global command with the same name
+    /// as an already-existing global command of the same type will overwrite
+    /// the old command. See [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`]
+    /// error type if the command name is not between 1 and 32 characters.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#message-commands
+    pub fn create_global_message_command(
+        &self,
+        name: impl Into<String>,
+    ) -> Result<CreateGlobalCommand<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        CreateGlobalCommand::new(
+            self,
+            application_id,
+            name,
+            String::new(),
+            CommandType::Message,
+        )
     }
 
     /// Fetch all global commands for your application.
@@ -2040,6 +2379,27 @@ impl Client {
         Ok(SetGlobalCommands::new(self, application_id, commands))
     }
 
+    /// Synchronize global commands with local definitions, only creating,
+    /// updating, or deleting the commands that differ.
+    ///
+    /// Refer to [`SyncCommands`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    pub fn sync_global_commands(
+        &self,
+        commands: Vec<Command>,
+    ) -> Result<SyncCommands<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        Ok(SyncCommands::new(self, application_id, None, commands))
+    }
+
     /// Fetch command permissions for a command from the current application
     /// in a guild.
     ///
@@ -2148,6 +2508,13 @@ impl Client {
             });
         }
 
+        if let Some(count) = self.state.invalid_requests.exceeded() {
+            return Err(Error {
+                kind: ErrorType::InvalidRequestLimit { count },
+                source: None,
+            });
+        }
+
         let Request {
             body,
             form,
@@ -2155,9 +2522,16 @@ impl Client {
             method,
             path: bucket,
             path_str: path,
+            timeout,
+            timeout_includes_ratelimit,
             use_authorization_token,
         } = request;
 
+        let timeout = timeout.unwrap_or(self.state.timeout);
+        let timeout_includes_ratelimit =
+            timeout_includes_ratelimit.unwrap_or(self.state.timeout_includes_ratelimit);
+        let deadline = Instant::now() + timeout;
+
         let protocol = if self.state.use_http { "http" } else { "https" };
         let host = self.state.proxy.as_deref().unwrap_or("discord.com");
 
@@ -2226,16 +2600,31 @@ impl Client {
         }
 
         let req = if let Some(form) = form {
-            let form_bytes = form.build();
+            let len = form.attachments_len();
+
+            if len > self.state.attachment_size_limit {
+                return Err(Error {
+                    kind: ErrorType::AttachmentsTooLarge {
+                        len,
+                        limit: self.state.attachment_size_limit,
+                    },
+                    source: None,
+                });
+            }
+
             if let Some(headers) = builder.headers_mut() {
-                headers.insert(CONTENT_LENGTH, form_bytes.len().into());
+                headers.insert(CONTENT_LENGTH, form.len().into());
             };
-            builder
-                .body(Body::from(form_bytes))
-                .map_err(|source| Error {
-                    kind: ErrorType::BuildingRequest,
-                    source: Some(Box::new(source)),
-                })?
+
+            let body = form.into_body().ok_or(Error {
+                kind: ErrorType::AttachmentStreamConsumed,
+                source: None,
+            })?;
+
+            builder.body(body).map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?
         } else if let Some(bytes) = body {
             builder.body(Body::from(bytes)).map_err(|source| Error {
                 kind: ErrorType::BuildingRequest,
@@ -2257,59 +2646,135 @@ impl Client {
             })?
         };
 
-        let inner = self.state.http.request(req);
-        let fut = time::timeout(self.state.timeout, inner);
-
-        let ratelimiter = match self.state.ratelimiter.as_ref() {
-            Some(ratelimiter) => ratelimiter,
-            None => {
-                return fut
-                    .await
-                    .map_err(|source| Error {
-                        kind: ErrorType::RequestTimedOut,
-                        source: Some(Box::new(source)),
-                    })?
-                    .map_err(|source| Error {
-                        kind: ErrorType::RequestError,
+        // Only keep a copy of the route for observing the request if it will
+        // be observed, without the webhook or interaction token it may
+        // contain.
+        let route = if self.state.observer.is_some() || cfg!(feature = "metrics") {
+            Some(cassette::redact(&path))
+        } else {
+            None
+        };
+        let inner = self.state.http.send(req);
+
+        let (tx, queued, deadline) = match self.state.ratelimiter.as_ref() {
+            Some(ratelimiter) => {
+                let started = Instant::now();
+
+                let ticket = async {
+                    let rx = ratelimiter
+                        .ticket(bucket.clone())
+                        .await
+                        .map_err(|source| Error {
+                            kind: ErrorType::RatelimiterTicket,
+                            source: Some(source),
+                        })?;
+
+                    rx.await.map_err(|source| Error {
+                        kind: ErrorType::RequestCanceled,
                         source: Some(Box::new(source)),
-                    });
+                    })
+                };
+
+                let (tx, deadline) = if timeout_includes_ratelimit {
+                    let tx = time::timeout_at(deadline, ticket)
+                        .await
+                        .map_err(|source| Error {
+                            kind: ErrorType::RequestTimedOut,
+                            source: Some(Box::new(source)),
+                        })??;
+
+                    (tx, deadline)
+                } else {
+                    (ticket.await?, Instant::now() + timeout)
+                };
+
+                (Some(tx), started.elapsed(), deadline)
             }
+            None => (None, Duration::default(), deadline),
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx.await.map_err(|source| Error {
-            kind: ErrorType::RequestCanceled,
-            source: Some(Box::new(source)),
-        })?;
-
-        let resp = fut
+        let sent = Instant::now();
+        let resp = time::timeout_at(deadline, inner)
             .await
             .map_err(|source| Error {
                 kind: ErrorType::RequestTimedOut,
                 source: Some(Box::new(source)),
-            })?
-            .map_err(|source| Error {
-                kind: ErrorType::RequestError,
-                source: Some(Box::new(source)),
-            })?;
+            })
+            .and_then(|resp| {
+                resp.map_err(|source| Error {
+                    kind: if cassette::is_unmatched(&*source) {
+                        ErrorType::ReplayUnmatched
+                    } else {
+                        ErrorType::RequestError
+                    },
+                    source: Some(source),
+                })
+            });
+        let latency = sent.elapsed();
+
+        let headers = resp
+            .as_ref()
+            .ok()
+            .map(|resp| RatelimitHeaders::try_from(resp.headers()));
+
+        if let Ok(resp) = resp.as_ref() {
+            let forbidden =
+                self.state
+                    .invalid_requests
+                    .record(&bucket, resp.status(), resp.headers());
+
+            if let (Some(count), Some(observer)) = (forbidden, self.state.observer.as_ref()) {
+                observer.on_forbidden(&bucket, count);
+            }
+        }
+
+        if let Some(route) = route {
+            let info = RequestInfo {
+                latency,
+                method,
+                path: bucket,
+                queued,
+                ratelimit_headers: headers
+                    .as_ref()
+                    .and_then(|headers| headers.as_ref().ok())
+                    .cloned(),
+                route,
+                status: resp.as_ref().ok().map(Response::status),
+            };
+
+            #[cfg(feature = "metrics")]
+            crate::observer::record(&info);
+
+            if let Some(observer) = self.state.observer.as_ref() {
+                observer.on_request(&info);
+            }
+        }
+
+        let resp = resp?;
 
         // If the API sent back an Unauthorized response, then the client's
         // configured token is permanently invalid and future requests must be
-        // ignored to avoid API bans.
-        if resp.status() == StatusCode::UNAUTHORIZED {
+        // ignored to avoid API bans. Requests sent without the token, such as
+        // webhook executions and OAuth2 token exchanges, say nothing about it.
+        if resp.status() == StatusCode::UNAUTHORIZED
+            && use_authorization_token
+            && self.state.token.is_some()
+        {
             self.state.token_invalid.store(true, Ordering::Relaxed);
         }
 
-        match RatelimitHeaders::try_from(resp.headers()) {
-            Ok(v) => {
-                let _res = tx.send(Some(v));
-            }
-            #[allow(unused_variables)]
-            Err(why) => {
-                #[cfg(feature = "tracing")]
-                tracing::warn!("header parsing failed: {:?}; {:?}", why, resp);
+        if let (Some(tx), Some(headers)) = (tx, headers) {
+            match headers {
+                Ok(v) => {
+                    let _res = tx.send(Some(v));
+                }
+                #[allow(unused_variables)]
+                Err(why) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("header parsing failed: {:?}; {:?}", why, resp);
 
-                let _res = tx.send(None);
+                    let _res = tx.send(None);
+                }
             }
         }
 
@@ -2361,6 +2826,56 @@ impl Client {
     }
 
     async fn make_request(&self, request: Request) -> Result<Response<Body>, Error> {
+        let policy = match self.state.retry_policy.as_ref() {
+            Some(policy) => policy,
+            None => return self.make_request_once(request).await,
+        };
+
+        // Streamed files are consumed by the first attempt, so retrying would
+        // only replace the original error with one about the consumed stream.
+        if request.form.as_ref().is_some_and(Form::is_streamed) {
+            return self.make_request_once(request).await;
+        }
+
+        let method = request.method;
+        let mut request = Some(request);
+        let mut attempt = 1;
+
+        loop {
+            // Keep a copy of the request around in case the attempt fails and
+            // it has to be sent again.
+            let current = match request.as_ref() {
+                Some(request) if attempt < policy.max_attempts() => request.clone(),
+                _ => request.take().ok_or(Error {
+                    kind: ErrorType::BuildingRequest,
+                    source: None,
+                })?,
+            };
+
+            let error = match self.make_request_once(current).await {
+                Ok(resp) => return Ok(resp),
+                Err(source) => source,
+            };
+
+            let delay = match policy.retry_after(attempt, method, &error) {
+                Some(delay) => delay,
+                None => return Err(error),
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                attempt,
+                milliseconds = %delay.as_millis(),
+                "retrying failed request: {}",
+                error,
+            );
+
+            time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn make_request_once(&self, request: Request) -> Result<Response<Body>, Error> {
         let resp = self.raw(request).await?;
         let status = resp.status();
 
@@ -2396,7 +2911,18 @@ impl Client {
                 source: Some(Box::new(source)),
             })?;
 
-        let error = crate::json::parse_bytes::<ApiError>(&bytes)?;
+        let error = match crate::json::from_bytes::<ApiError>(&bytes) {
+            Ok(error) => error,
+            Err(source) => {
+                return Err(Error {
+                    kind: ErrorType::UnexpectedResponse {
+                        body: bytes.to_vec(),
+                        status,
+                    },
+                    source: Some(Box::new(source)),
+                })
+            }
+        };
 
         #[cfg(feature = "tracing")]
         if let ApiError::General(ref general) = error {
@@ -0,0 +1,400 @@
+use crate::{
+    api_error::ApiError,
+    error::{Error, ErrorType},
+    request::Method,
+};
+use hyper::{header::HeaderMap, StatusCode};
+use rand::Rng;
+use std::time::Duration;
+
+/// Policy for automatically retrying failed requests.
+///
+/// Requests are retried when they fail with a status code marked as
+/// retryable, when they time out, or when sending them fails, up to the
+/// maximum number of attempts. Between attempts the client waits using an
+/// exponential backoff with jitter. If Discord sent a `Retry-After` header or
+/// ratelimit headers then the client will wait at least that long.
+///
+/// Retried requests go through the client's ratelimiter like any other
+/// request, so the ratelimit headers of failed attempts update the buckets
+/// and queued requests wait for the bucket to reset.
+///
+/// Requests made with a method that isn't idempotent, such as creating a
+/// message, are never retried unless [`non_idempotent`] is enabled.
+///
+/// # Examples
+///
+/// Retry requests up to 5 times, waiting at most 10 seconds between
+/// attempts:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use twilight_http::{client::RetryPolicy, Client};
+///
+/// let policy = RetryPolicy::new()
+///     .attempts(5)
+///     .backoff(Duration::from_millis(250), Duration::from_secs(10));
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .retry_policy(policy)
+///     .build();
+/// ```
+///
+/// [`non_idempotent`]: Self::non_idempotent
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    attempts: u32,
+    base_delay: Duration,
+    jitter: bool,
+    max_delay: Duration,
+    non_idempotent: bool,
+    request_error: bool,
+    statuses: Vec<StatusCode>,
+    timed_out: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy with the default configuration.
+    ///
+    /// By default requests are attempted up to 3 times with a base delay of
+    /// 500 milliseconds and a maximum delay of 30 seconds, with jitter.
+    /// Responses with a status code of 429, 502, 503, or 504 are retried, as
+    /// are requests that time out or fail to send.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of attempts, including the first.
+    ///
+    /// A value of 1 or less disables retrying.
+    pub const fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+
+        self
+    }
+
+    /// Set the base and maximum delay of the exponential backoff.
+    ///
+    /// The delay before the `n`th retry is `base * 2^(n - 1)`, capped at
+    /// `max`.
+    pub const fn backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.base_delay = base;
+        self.max_delay = max;
+
+        self
+    }
+
+    /// Set whether to randomize the backoff delay.
+    ///
+    /// When enabled the delay is randomly picked between half of and the full
+    /// backoff delay. This avoids many clients retrying at the same time.
+    ///
+    /// Defaults to true.
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Set whether to retry requests with a method that isn't idempotent.
+    ///
+    /// `POST` and `PATCH` requests may have been processed by Discord even
+    /// if they failed, so retrying them can cause an action to happen more
+    /// than once, such as a message being sent twice.
+    ///
+    /// Defaults to false.
+    pub const fn non_idempotent(mut self, non_idempotent: bool) -> Self {
+        self.non_idempotent = non_idempotent;
+
+        self
+    }
+
+    /// Set whether to retry requests that failed to send, such as due to a
+    /// connection error.
+    ///
+    /// This corresponds to [`ErrorType::RequestError`].
+    ///
+    /// Defaults to true.
+    pub const fn request_error(mut self, request_error: bool) -> Self {
+        self.request_error = request_error;
+
+        self
+    }
+
+    /// Set the response status codes to retry.
+    ///
+    /// This replaces the default status codes.
+    pub fn statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+
+        self
+    }
+
+    /// Set whether to retry requests that timed out.
+    ///
+    /// This corresponds to [`ErrorType::RequestTimedOut`].
+    ///
+    /// Defaults to true.
+    pub const fn timed_out(mut self, timed_out: bool) -> Self {
+        self.timed_out = timed_out;
+
+        self
+    }
+
+    /// Maximum number of attempts, including the first.
+    pub(super) const fn max_attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Determine how long to wait before the next attempt of a request, if it
+    /// should be retried at all.
+    ///
+    /// `attempt` is the number of the attempt that failed, starting at 1.
+    pub(super) fn retry_after(
+        &self,
+        attempt: u32,
+        method: Method,
+        error: &Error,
+    ) -> Option<Duration> {
+        if attempt >= self.attempts || !self.retries(method, error.kind()) {
+            return None;
+        }
+
+        let backoff = self.backoff_delay(attempt);
+
+        Some(match requested_delay(error.kind()) {
+            Some(requested) if requested > backoff => requested,
+            _ => backoff,
+        })
+    }
+
+    fn retries(&self, method: Method, kind: &ErrorType) -> bool {
+        if !self.non_idempotent && !method.is_idempotent() {
+            return false;
+        }
+
+        match kind {
+            ErrorType::RequestError => self.request_error,
+            ErrorType::RequestTimedOut => self.timed_out,
+            ErrorType::Response { status, .. } | ErrorType::UnexpectedResponse { status, .. } => {
+                self.statuses.contains(status)
+            }
+            ErrorType::ServiceUnavailable { .. } => {
+                self.statuses.contains(&StatusCode::SERVICE_UNAVAILABLE)
+            }
+            _ => false,
+        }
+    }
+
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        if !self.jitter || delay == Duration::from_secs(0) {
+            return delay;
+        }
+
+        let half = delay / 2;
+
+        rand::thread_rng().gen_range(half..=delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            base_delay: Duration::from_millis(500),
+            jitter: true,
+            max_delay: Duration::from_secs(30),
+            non_idempotent: false,
+            request_error: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            timed_out: true,
+        }
+    }
+}
+
+/// Delay that Discord asked us to wait for before retrying, if any.
+fn requested_delay(kind: &ErrorType) -> Option<Duration> {
+    match kind {
+        ErrorType::Response {
+            error: ApiError::Ratelimited(ratelimited),
+            ..
+        } if ratelimited.retry_after.is_finite() && ratelimited.retry_after > 0. => {
+            Some(Duration::from_secs_f64(ratelimited.retry_after))
+        }
+        ErrorType::ServiceUnavailable { response } => header_delay(response.headers()),
+        _ => None,
+    }
+}
+
+/// Parse the `Retry-After` header as a number of seconds.
+fn header_delay(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get("retry-after")?
+        .to_str()
+        .ok()?
+        .parse::<f64>()
+        .ok()?;
+
+    if seconds.is_finite() && seconds > 0. {
+        Some(Duration::from_secs_f64(seconds))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::{
+        api_error::{ApiError, RatelimitedApiError},
+        error::{Error, ErrorType},
+        request::Method,
+    };
+    use hyper::StatusCode;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(RetryPolicy: Clone, Debug, Default, Send, Sync);
+
+    fn error(kind: ErrorType) -> Error {
+        Error { kind, source: None }
+    }
+
+    fn ratelimited(retry_after: f64) -> Error {
+        error(ErrorType::Response {
+            body: Vec::new(),
+            error: ApiError::Ratelimited(RatelimitedApiError {
+                global: false,
+                message: "You are being rate limited.".to_owned(),
+                retry_after,
+            }),
+            status: StatusCode::TOO_MANY_REQUESTS,
+        })
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::new()
+            .attempts(10)
+            .backoff(Duration::from_millis(100), Duration::from_millis(500))
+            .jitter(false);
+        let timed_out = error(ErrorType::RequestTimedOut);
+
+        assert_eq!(
+            Some(Duration::from_millis(100)),
+            policy.retry_after(1, Method::Get, &timed_out)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(200)),
+            policy.retry_after(2, Method::Get, &timed_out)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(400)),
+            policy.retry_after(3, Method::Get, &timed_out)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(500)),
+            policy.retry_after(4, Method::Get, &timed_out)
+        );
+    }
+
+    #[test]
+    fn test_jitter_within_bounds() {
+        let policy = RetryPolicy::new().backoff(Duration::from_secs(1), Duration::from_secs(1));
+        let timed_out = error(ErrorType::RequestTimedOut);
+
+        for _ in 0..100 {
+            let delay = policy.retry_after(1, Method::Get, &timed_out).unwrap();
+            assert!(delay >= Duration::from_millis(500));
+            assert!(delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_attempts_exhausted() {
+        let policy = RetryPolicy::new().attempts(2);
+        let timed_out = error(ErrorType::RequestTimedOut);
+
+        assert!(policy.retry_after(1, Method::Get, &timed_out).is_some());
+        assert!(policy.retry_after(2, Method::Get, &timed_out).is_none());
+    }
+
+    #[test]
+    fn test_non_idempotent() {
+        let timed_out = error(ErrorType::RequestTimedOut);
+
+        let policy = RetryPolicy::new();
+        assert!(policy.retry_after(1, Method::Post, &timed_out).is_none());
+        assert!(policy.retry_after(1, Method::Patch, &timed_out).is_none());
+        assert!(policy.retry_after(1, Method::Put, &timed_out).is_some());
+        assert!(policy.retry_after(1, Method::Delete, &timed_out).is_some());
+
+        let policy = policy.non_idempotent(true);
+        assert!(policy.retry_after(1, Method::Post, &timed_out).is_some());
+    }
+
+    #[test]
+    fn test_retryable_kinds() {
+        let policy = RetryPolicy::new().timed_out(false);
+
+        assert!(policy
+            .retry_after(1, Method::Get, &error(ErrorType::RequestTimedOut))
+            .is_none());
+        assert!(policy
+            .retry_after(1, Method::Get, &error(ErrorType::RequestError))
+            .is_some());
+        assert!(policy
+            .retry_after(1, Method::Get, &error(ErrorType::Unauthorized))
+            .is_none());
+        assert!(policy
+            .retry_after(1, Method::Get, &error(ErrorType::ReplayUnmatched))
+            .is_none());
+
+        let policy = policy.statuses(vec![StatusCode::BAD_GATEWAY]);
+        assert!(policy
+            .retry_after(1, Method::Get, &ratelimited(1.))
+            .is_none());
+
+        let unexpected = |status| {
+            error(ErrorType::UnexpectedResponse {
+                body: b"<html></html>".to_vec(),
+                status,
+            })
+        };
+        assert!(policy
+            .retry_after(1, Method::Get, &unexpected(StatusCode::BAD_GATEWAY))
+            .is_some());
+        assert!(policy
+            .retry_after(1, Method::Get, &unexpected(StatusCode::NOT_FOUND))
+            .is_none());
+    }
+
+    #[test]
+    fn test_honors_retry_after() {
+        let policy = RetryPolicy::new()
+            .backoff(Duration::from_millis(100), Duration::from_secs(1))
+            .jitter(false);
+
+        assert_eq!(
+            Some(Duration::from_millis(2500)),
+            policy.retry_after(1, Method::Get, &ratelimited(2.5))
+        );
+        assert_eq!(
+            Some(Duration::from_millis(100)),
+            policy.retry_after(1, Method::Get, &ratelimited(0.05))
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use super::Client;
+use crate::request::channel::webhook::{
+    DeleteWebhookMessage, ExecuteWebhook, GetWebhookMessage, UpdateWebhookMessage,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::{MessageId, WebhookId};
+use twilight_util::link::webhook;
+
+/// Creating a [`WebhookClient`] from a URL failed.
+#[derive(Debug)]
+pub struct WebhookClientError {
+    kind: WebhookClientErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl WebhookClientError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &WebhookClientErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (WebhookClientErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for WebhookClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            WebhookClientErrorType::TokenMissing => f.write_str("webhook url has no token"),
+            WebhookClientErrorType::UrlInvalid => f.write_str("webhook url is invalid"),
+        }
+    }
+}
+
+impl Error for WebhookClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`WebhookClientError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WebhookClientErrorType {
+    /// URL has no token, which is required to execute the webhook.
+    TokenMissing,
+    /// URL is not a webhook URL.
+    ///
+    /// The source error contains details about why the URL is invalid.
+    UrlInvalid,
+}
+
+/// Client executing a single webhook and managing its messages.
+///
+/// Unlike the [`Client`], a webhook client doesn't need a bot token: the ID
+/// and token of the webhook, such as from a webhook URL, are enough. This
+/// makes it suitable for services that only post to webhooks, like logging
+/// and alerting.
+///
+/// By default the webhook client has its own HTTP client, with its own
+/// ratelimiter. An existing [`Client`] can be used instead via
+/// [`with_client`], sharing its configuration and ratelimiter.
+///
+/// Cloning a webhook client is cheap.
+///
+/// # Examples
+///
+/// Send a message to a webhook and then edit it:
+///
+/// ```no_run
+/// use twilight_http::client::WebhookClient;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let webhook = WebhookClient::from_url(
+///     "https://discord.com/api/webhooks/794590023369752587/tjxHaPHLKp9aEdSwJuLeHhHHGEqIxt1aay4I67FOP9uzsYEWmj0eJmDn-2ZvCYLyOb_K",
+/// )?;
+///
+/// let message = webhook
+///     .execute()
+///     .content("Deployment started")
+///     .wait(true)
+///     .await?
+///     .expect("wait returns the message");
+///
+/// webhook
+///     .update_message(message.id)
+///     .content(Some("Deployment finished".to_owned()))?
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`with_client`]: Self::with_client
+#[derive(Clone, Debug)]
+pub struct WebhookClient {
+    http: Client,
+    id: WebhookId,
+    token: String,
+}
+
+impl WebhookClient {
+    /// Create a new webhook client from the ID and token of a webhook.
+    pub fn new(id: WebhookId, token: impl Into<String>) -> Self {
+        Self::with_client(Client::builder().build(), id, token)
+    }
+
+    /// Create a new webhook client from the URL of a webhook.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WebhookClientErrorType::UrlInvalid`] error type if the URL
+    /// is not a webhook URL.
+    ///
+    /// Returns a [`WebhookClientErrorType::TokenMissing`] error type if the
+    /// URL doesn't contain the token of the webhook.
+    pub fn from_url(url: &str) -> Result<Self, WebhookClientError> {
+        let (id, token) = webhook::parse(url).map_err(|source| WebhookClientError {
+            kind: WebhookClientErrorType::UrlInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+        let token = token.ok_or(WebhookClientError {
+            kind: WebhookClientErrorType::TokenMissing,
+            source: None,
+        })?;
+
+        Ok(Self::new(id, token))
+    }
+
+    /// Create a new webhook client sending requests through an existing HTTP
+    /// client.
+    ///
+    /// The HTTP client's token isn't sent with webhook requests.
+    pub fn with_client(http: Client, id: WebhookId, token: impl Into<String>) -> Self {
+        Self {
+            http,
+            id,
+            token: token.into(),
+        }
+    }
+
+    /// HTTP client used to send requests.
+    pub const fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// ID of the webhook.
+    pub const fn id(&self) -> WebhookId {
+        self.id
+    }
+
+    /// Token of the webhook.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Execute the webhook, sending a message to its channel.
+    ///
+    /// Refer to [`Client::execute_webhook`] for more information.
+    pub fn execute(&self) -> ExecuteWebhook<'_> {
+        ExecuteWebhook::new(&self.http, self.id, self.token.clone())
+    }
+
+    /// Get a message sent by the webhook.
+    pub fn message(&self, message_id: MessageId) -> GetWebhookMessage<'_> {
+        GetWebhookMessage::new(&self.http, self.id, self.token.clone(), message_id)
+    }
+
+    /// Update a message sent by the webhook.
+    ///
+    /// Refer to [`Client::update_webhook_message`] for more information.
+    pub fn update_message(&self, message_id: MessageId) -> UpdateWebhookMessage<'_> {
+        UpdateWebhookMessage::new(&self.http, self.id, self.token.clone(), message_id)
+    }
+
+    /// Delete a message sent by the webhook.
+    pub fn delete_message(&self, message_id: MessageId) -> DeleteWebhookMessage<'_> {
+        DeleteWebhookMessage::new(&self.http, self.id, self.token.clone(), message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebhookClient, WebhookClientError, WebhookClientErrorType};
+    use crate::{
+        client::Client,
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+    };
+    use hyper::{header::AUTHORIZATION, Method, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::id::{ChannelId, MessageId, WebhookId};
+
+    assert_impl_all!(WebhookClient: Clone, Debug, Send, Sync);
+    assert_impl_all!(WebhookClientErrorType: Debug, Send, Sync);
+    assert_impl_all!(WebhookClientError: Error, Send, Sync);
+
+    #[test]
+    fn test_from_url() {
+        let webhook = WebhookClient::from_url("https://discord.com/api/webhooks/1/token").unwrap();
+        assert_eq!(WebhookId(1), webhook.id());
+        assert_eq!("token", webhook.token());
+        assert!(webhook.http().token().is_none());
+
+        assert!(matches!(
+            WebhookClient::from_url("https://discord.com/api/webhooks/1")
+                .unwrap_err()
+                .kind(),
+            WebhookClientErrorType::TokenMissing
+        ));
+        assert!(matches!(
+            WebhookClient::from_url("https://example.com/1/token")
+                .unwrap_err()
+                .kind(),
+            WebhookClientErrorType::UrlInvalid
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_requests() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::ExecuteWebhook {
+                thread_id: Some(3),
+                token: "token".to_owned(),
+                wait: Some(false),
+                webhook_id: 1,
+            },
+            MockResponse::new(StatusCode::NO_CONTENT),
+        );
+        transport.respond(
+            &Route::DeleteWebhookMessage {
+                message_id: 2,
+                thread_id: None,
+                token: "token".to_owned(),
+                webhook_id: 1,
+            },
+            MockResponse::new(StatusCode::NO_CONTENT),
+        );
+
+        let http = Client::builder()
+            .token("bot token")
+            .transport(Box::new(transport.clone()))
+            .build();
+        let webhook = WebhookClient::with_client(http, WebhookId(1), "token");
+
+        let message = webhook
+            .execute()
+            .content("alert")
+            .thread_id(ChannelId(3))
+            .wait(false)
+            .await
+            .unwrap();
+        assert!(message.is_none());
+        webhook.delete_message(MessageId(2)).await.unwrap();
+        assert_eq!(0, transport.remaining());
+
+        let requests = transport.requests();
+        assert_eq!(Method::POST, requests[0].method);
+        assert_eq!("webhooks/1/token?thread_id=3&wait=false", requests[0].path);
+        assert_eq!(Method::DELETE, requests[1].method);
+        assert_eq!("webhooks/1/token/messages/2", requests[1].path);
+        assert!(requests
+            .iter()
+            .all(|request| !request.headers.contains_key(AUTHORIZATION)));
+    }
+}
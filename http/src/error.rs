@@ -46,6 +46,17 @@ impl Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ErrorType::AttachmentsTooLarge { len, limit } => {
+                f.write_str("attachments are ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" bytes, which is more than the limit of ")?;
+                Display::fmt(limit, f)?;
+
+                f.write_str(" bytes")
+            }
+            ErrorType::AttachmentStreamConsumed => {
+                f.write_str("streamed attachment was already sent")
+            }
             ErrorType::BuildingRequest => f.write_str("failed to build the request"),
             ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
             ErrorType::CreatingHeader { name, .. } => {
@@ -54,12 +65,24 @@ impl Display for Error {
 
                 f.write_str(" failed")
             }
+            ErrorType::InvalidRequestLimit { count } => {
+                f.write_str("refusing to send request after ")?;
+                Display::fmt(count, f)?;
+
+                f.write_str(" invalid requests within the window")
+            }
             ErrorType::Json => f.write_str("Given value couldn't be serialized"),
             ErrorType::Parsing { body, .. } => {
                 f.write_str("Response body couldn't be deserialized: ")?;
 
                 Debug::fmt(body, f)
             }
+            ErrorType::RatelimiterTicket => {
+                f.write_str("failed to get a ticket from the ratelimiter")
+            }
+            ErrorType::ReplayUnmatched => {
+                f.write_str("no recorded interaction matches the request")
+            }
             ErrorType::RequestCanceled => {
                 f.write_str("Request was canceled either before or while being sent")
             }
@@ -78,6 +101,12 @@ impl Display for Error {
             ErrorType::Unauthorized => {
                 f.write_str("token in use is invalid, expired, or is revoked")
             }
+            ErrorType::UnexpectedResponse { status, .. } => {
+                f.write_str("Response error: status code ")?;
+                Display::fmt(status, f)?;
+
+                f.write_str(", body isn't an api error")
+            }
         }
     }
 }
@@ -94,15 +123,54 @@ impl StdError for Error {
 #[non_exhaustive]
 /// Type of [`Error`] that occurred.
 pub enum ErrorType {
+    /// Total length of the attachments of a request is more than the
+    /// client's limit.
+    ///
+    /// Refer to [`ClientBuilder::attachment_size_limit`] for more
+    /// information.
+    ///
+    /// [`ClientBuilder::attachment_size_limit`]: crate::client::ClientBuilder::attachment_size_limit
+    AttachmentsTooLarge {
+        /// Total length of the attachments in bytes.
+        len: u64,
+        /// Configured limit in bytes.
+        limit: u64,
+    },
+    /// Contents of a streamed attachment were already sent, such as by a
+    /// previous attempt of a retried request.
+    AttachmentStreamConsumed,
     BuildingRequest,
     ChunkingResponse,
     CreatingHeader {
         name: String,
     },
+    /// Client refused to send the request because the number of invalid
+    /// requests within the window reached the configured threshold.
+    ///
+    /// Refer to [`ClientBuilder::invalid_request_threshold`] for more
+    /// information.
+    ///
+    /// [`ClientBuilder::invalid_request_threshold`]: crate::client::ClientBuilder::invalid_request_threshold
+    InvalidRequestLimit {
+        /// Number of invalid requests within the window.
+        count: usize,
+    },
     Json,
     Parsing {
         body: Vec<u8>,
     },
+    /// Ratelimiter failed to provide a ticket for the request.
+    ///
+    /// This may occur if a remote ratelimiter is unreachable.
+    RatelimiterTicket,
+    /// Request being replayed by a [`ReplayTransport`] doesn't match any
+    /// remaining recorded interaction.
+    ///
+    /// The source error is a [`CassetteError`]. This is never retried.
+    ///
+    /// [`CassetteError`]: crate::transport::cassette::CassetteError
+    /// [`ReplayTransport`]: crate::transport::ReplayTransport
+    ReplayUnmatched,
     RequestCanceled,
     RequestError,
     RequestTimedOut,
@@ -123,4 +191,14 @@ pub enum ErrorType {
     /// This can occur if a bot token is invalidated or an access token expires
     /// or is revoked. Recreate the client to configure a new token.
     Unauthorized,
+    /// Response has an unsuccessful status code and a body that isn't an API
+    /// error, such as an HTML page returned by Discord's edge with a 502.
+    ///
+    /// The source error is the error deserializing the body.
+    UnexpectedResponse {
+        /// Body of the response.
+        body: Vec<u8>,
+        /// Status code of the response.
+        status: StatusCode,
+    },
 }
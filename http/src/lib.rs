@@ -60,6 +60,13 @@
 //!
 //! This is enabled by default.
 //!
+//! ### Metrics
+//!
+//! The `metrics` feature records metrics about requests via the [`metrics`]
+//! crate. Refer to the [`observer`] module for the metrics that are recorded.
+//!
+//! This is disabled by default.
+//!
 //! ### Tracing
 //!
 //! The `tracing` feature enables logging via the [`tracing`] crate.
@@ -68,6 +75,7 @@
 //!
 //! [`native-tls`]: https://crates.io/crates/native-tls
 //! [`hyper`]: https://crates.io/crates/hyper
+//! [`metrics`]: https://crates.io/crates/metrics
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [`serde_json`]: https://crates.io/crates/serde_json
 //! [`simd-json`]: https://crates.io/crates/simd-json
@@ -103,9 +111,12 @@
 pub mod api_error;
 pub mod client;
 pub mod error;
+pub mod oauth2;
+pub mod observer;
 pub mod ratelimiting;
 pub mod request;
 pub mod routing;
+pub mod transport;
 
 mod json;
 
@@ -0,0 +1,139 @@
+use super::{encode, Scope};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use twilight_model::id::ApplicationId;
+
+/// Whether users are prompted to authorize an application again.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Prompt {
+    /// Always prompt the user, even if they have authorized the application
+    /// before.
+    Consent,
+    /// Skip the prompt if the user has already authorized the application
+    /// with the same scopes.
+    None,
+}
+
+impl Prompt {
+    /// Name of the prompt, as used in URLs.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Consent => "consent",
+            Self::None => "none",
+        }
+    }
+}
+
+/// URL that users are sent to in order to authorize an application.
+///
+/// After the user authorizes the application they are redirected to the
+/// redirect URI with a `code` query parameter, which can be exchanged for an
+/// access token via [`OAuth2Client::exchange_code`]. If a [`state`] was set
+/// it is passed back as the `state` query parameter and should be compared
+/// to the one that was sent.
+///
+/// The URL is built by displaying it.
+///
+/// [`OAuth2Client::exchange_code`]: super::OAuth2Client::exchange_code
+/// [`state`]: Self::state
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorizeUrl {
+    client_id: ApplicationId,
+    prompt: Option<Prompt>,
+    redirect_uri: String,
+    scopes: Vec<Scope>,
+    state: Option<String>,
+}
+
+impl AuthorizeUrl {
+    pub(crate) fn new(client_id: ApplicationId, redirect_uri: String, scopes: &[Scope]) -> Self {
+        Self {
+            client_id,
+            prompt: None,
+            redirect_uri,
+            scopes: scopes.to_vec(),
+            state: None,
+        }
+    }
+
+    /// Set whether the user is prompted to authorize the application again.
+    pub fn prompt(mut self, prompt: Prompt) -> Self {
+        self.prompt.replace(prompt);
+
+        self
+    }
+
+    /// Set the state passed back to the redirect URI, used to prevent
+    /// cross-site request forgery.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state.replace(state.into());
+
+        self
+    }
+}
+
+impl Display for AuthorizeUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("https://discord.com/api/oauth2/authorize?client_id=")?;
+        Display::fmt(&self.client_id, f)?;
+
+        if let Some(prompt) = self.prompt {
+            f.write_str("&prompt=")?;
+            f.write_str(prompt.name())?;
+        }
+
+        f.write_str("&redirect_uri=")?;
+        Display::fmt(&encode(&self.redirect_uri), f)?;
+        f.write_str("&response_type=code&scope=")?;
+
+        for (index, scope) in self.scopes.iter().enumerate() {
+            if index > 0 {
+                f.write_str("%20")?;
+            }
+
+            f.write_str(scope.name())?;
+        }
+
+        if let Some(state) = &self.state {
+            f.write_str("&state=")?;
+            Display::fmt(&encode(state), f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizeUrl, Prompt, Scope};
+    use static_assertions::assert_impl_all;
+    use std::fmt::{Debug, Display};
+    use twilight_model::id::ApplicationId;
+
+    assert_impl_all!(AuthorizeUrl: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Prompt: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_display() {
+        let url = AuthorizeUrl::new(
+            ApplicationId(1),
+            "https://example.com/callback".to_owned(),
+            &[Scope::Identify, Scope::Guilds],
+        );
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?client_id=1\
+            &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback\
+            &response_type=code&scope=identify%20guilds",
+            url.to_string()
+        );
+
+        let url = url.prompt(Prompt::Consent).state("a b");
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?client_id=1&prompt=consent\
+            &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback\
+            &response_type=code&scope=identify%20guilds&state=a%20b",
+            url.to_string()
+        );
+    }
+}
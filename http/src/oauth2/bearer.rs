@@ -0,0 +1,150 @@
+use super::{AccessToken, OAuth2Client};
+use crate::{client::Client, error::Error};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// Duration before an access token expires within which a [`BearerClient`]
+/// refreshes it.
+pub const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Function building the HTTP client for an access token.
+type BuildClient = Box<dyn Fn(&AccessToken) -> Client + Send + Sync>;
+
+/// Current access token and the HTTP client using it.
+struct Session {
+    client: Client,
+    expires_at: Instant,
+    token: AccessToken,
+}
+
+impl Session {
+    fn new(build: &BuildClient, token: AccessToken) -> Self {
+        Self {
+            client: build(&token),
+            expires_at: Instant::now() + Duration::from_secs(token.expires_in),
+            token,
+        }
+    }
+}
+
+struct BearerClientRef {
+    build: BuildClient,
+    oauth: OAuth2Client,
+    session: Mutex<Session>,
+}
+
+/// Client making requests on behalf of a user with an OAuth2 access token.
+///
+/// Before the access token expires it is refreshed with its refresh token,
+/// and a new HTTP client is built for the new access token. The HTTP client
+/// is retrieved via [`client`], which refreshes the token first if it expires
+/// within the [`REFRESH_MARGIN`]. Tokens without a refresh token are never
+/// refreshed.
+///
+/// Created via [`OAuth2Client::bearer`] or [`OAuth2Client::bearer_with`].
+/// Cloning a bearer client is cheap, and clones share the same token.
+///
+/// [`client`]: Self::client
+#[derive(Clone)]
+pub struct BearerClient(Arc<BearerClientRef>);
+
+impl BearerClient {
+    pub(crate) fn new(oauth: OAuth2Client, token: AccessToken, build: BuildClient) -> Self {
+        let session = Session::new(&build, token);
+
+        Self(Arc::new(BearerClientRef {
+            build,
+            oauth,
+            session: Mutex::new(session),
+        }))
+    }
+
+    /// HTTP client using the current access token, refreshing it first if it
+    /// is about to expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if refreshing the access token failed.
+    pub async fn client(&self) -> Result<Client, Error> {
+        let mut session = self.0.session.lock().await;
+
+        let remaining = session.expires_at.saturating_duration_since(Instant::now());
+
+        if remaining <= REFRESH_MARGIN && session.token.refresh_token.is_some() {
+            self.refresh_session(&mut session).await?;
+        }
+
+        Ok(session.client.clone())
+    }
+
+    /// Refresh the access token, even if it isn't about to expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if refreshing the access token failed. Tokens without
+    /// a refresh token aren't refreshed.
+    pub async fn refresh(&self) -> Result<(), Error> {
+        let mut session = self.0.session.lock().await;
+
+        if session.token.refresh_token.is_some() {
+            self.refresh_session(&mut session).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Revoke the access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if revoking the access token failed.
+    pub async fn revoke(&self) -> Result<(), Error> {
+        let session = self.0.session.lock().await;
+
+        self.0.oauth.revoke(&session.token.access_token).await
+    }
+
+    /// Current access token.
+    pub async fn token(&self) -> AccessToken {
+        self.0.session.lock().await.token.clone()
+    }
+
+    async fn refresh_session(&self, session: &mut Session) -> Result<(), Error> {
+        let refresh_token = match session.token.refresh_token.as_deref() {
+            Some(refresh_token) => refresh_token,
+            None => return Ok(()),
+        };
+
+        let mut token = self.0.oauth.refresh(refresh_token).await?;
+
+        // Keep using the previous refresh token if a new one wasn't provided.
+        if token.refresh_token.is_none() {
+            token.refresh_token = session.token.refresh_token.take();
+        }
+
+        *session = Session::new(&self.0.build, token);
+
+        Ok(())
+    }
+}
+
+impl Debug for BearerClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("BearerClient")
+            .field("oauth", &self.0.oauth)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BearerClient;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(BearerClient: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,328 @@
+use super::{form, AccessToken, AuthorizeUrl, BearerClient, Scope};
+use crate::{client::Client, error::Error, request::Request, routing::Route};
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter,
+};
+use twilight_model::id::ApplicationId;
+
+/// Client performing the OAuth2 authorization code flow of an application.
+///
+/// Users are sent to the [`authorize_url`], after which they are redirected
+/// to the redirect URI with a code that is [exchanged] for an
+/// [`AccessToken`]. The access token can then be used to make requests on
+/// behalf of the user with a [`BearerClient`], which refreshes the token
+/// before it expires.
+///
+/// Token requests are sent through an HTTP client without a token, which can
+/// be configured via [`with_client`], such as to send them to a proxy.
+///
+/// # Examples
+///
+/// Exchange a code and get the guilds of the user:
+///
+/// ```no_run
+/// use twilight_http::oauth2::{OAuth2Client, Prompt, Scope};
+/// use twilight_model::id::ApplicationId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let oauth = OAuth2Client::new(
+///     ApplicationId(1),
+///     "client secret",
+///     "https://example.com/callback",
+/// );
+///
+/// let url = oauth
+///     .authorize_url(&[Scope::Identify, Scope::Guilds])
+///     .prompt(Prompt::None)
+///     .state("random state");
+/// println!("authorize at {}", url);
+///
+/// // After the user is redirected with a code:
+/// let token = oauth.exchange_code("code").await?;
+/// let bearer = oauth.bearer(token);
+///
+/// let guilds = bearer.client().await?.current_user_guilds().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`authorize_url`]: Self::authorize_url
+/// [`with_client`]: Self::with_client
+/// [exchanged]: Self::exchange_code
+#[derive(Clone)]
+pub struct OAuth2Client {
+    client_id: ApplicationId,
+    client_secret: String,
+    http: Client,
+    redirect_uri: String,
+}
+
+impl Debug for OAuth2Client {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("OAuth2Client")
+            .field("client_id", &self.client_id)
+            .field("http", &self.http)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuth2Client {
+    /// Create a new OAuth2 client from the credentials of an application and
+    /// the URI that users are redirected to after authorizing it.
+    pub fn new(
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self::with_client(
+            Client::builder().build(),
+            client_id,
+            client_secret,
+            redirect_uri,
+        )
+    }
+
+    /// Create a new OAuth2 client sending token requests through an existing
+    /// HTTP client.
+    ///
+    /// The HTTP client's token isn't sent with token requests.
+    pub fn with_client(
+        http: Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            http,
+            redirect_uri: redirect_uri.into(),
+        }
+    }
+
+    /// ID of the application.
+    pub const fn client_id(&self) -> ApplicationId {
+        self.client_id
+    }
+
+    /// HTTP client used to send token requests.
+    pub const fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// URI that users are redirected to after authorizing the application.
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Create the URL that users are sent to in order to authorize the
+    /// application with some scopes.
+    pub fn authorize_url(&self, scopes: &[Scope]) -> AuthorizeUrl {
+        AuthorizeUrl::new(self.client_id, self.redirect_uri.clone(), scopes)
+    }
+
+    /// Exchange the code that a user was redirected with for an access token.
+    pub async fn exchange_code(&self, code: &str) -> Result<AccessToken, Error> {
+        self.token(&[
+            ("code", code),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", &self.redirect_uri),
+        ])
+        .await
+    }
+
+    /// Exchange a refresh token for a new access token.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<AccessToken, Error> {
+        self.token(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+
+    /// Revoke an access or refresh token.
+    pub async fn revoke(&self, token: &str) -> Result<(), Error> {
+        let request = self.request(Route::RevokeOauthToken, &[("token", token)]);
+
+        self.http.verify(request).await
+    }
+
+    /// Create a client making requests on behalf of a user, which refreshes
+    /// its access token before it expires.
+    pub fn bearer(&self, token: AccessToken) -> BearerClient {
+        self.bearer_with(token, |token| Client::new(token.authorization()))
+    }
+
+    /// Create a client making requests on behalf of a user, building the HTTP
+    /// client for each access token with a function.
+    ///
+    /// This can be used to configure the HTTP clients, such as to set a proxy
+    /// or timeout. The clients must be built with the token's
+    /// [`authorization`].
+    ///
+    /// [`authorization`]: AccessToken::authorization
+    pub fn bearer_with(
+        &self,
+        token: AccessToken,
+        build: impl Fn(&AccessToken) -> Client + Send + Sync + 'static,
+    ) -> BearerClient {
+        BearerClient::new(self.clone(), token, Box::new(build))
+    }
+
+    async fn token(&self, params: &[(&str, &str)]) -> Result<AccessToken, Error> {
+        let request = self.request(Route::CreateOauthToken, params);
+
+        self.http.request(request).await
+    }
+
+    /// Build a request to a token route with a form body containing the
+    /// parameters and the application's credentials.
+    fn request(&self, route: Route, params: &[(&str, &str)]) -> Request {
+        let client_id = self.client_id.0.to_string();
+        let credentials = [
+            ("client_id", client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        let body = form(credentials.iter().chain(params));
+
+        Request::builder(route)
+            .body(body)
+            .headers(iter::once((
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/x-www-form-urlencoded"),
+            )))
+            .use_authorization_token(false)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OAuth2Client;
+    use crate::{
+        client::Client,
+        oauth2::AccessToken,
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+    };
+    use hyper::{
+        header::{AUTHORIZATION, CONTENT_TYPE},
+        StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::ApplicationId;
+
+    assert_impl_all!(OAuth2Client: Clone, Debug, Send, Sync);
+
+    fn token(access: &str, expires_in: u64) -> AccessToken {
+        AccessToken {
+            access_token: access.to_owned(),
+            expires_in,
+            refresh_token: Some(format!("{}-refresh", access)),
+            scope: "identify".to_owned(),
+            token_type: "Bearer".to_owned(),
+        }
+    }
+
+    fn oauth(transport: &MockTransport) -> OAuth2Client {
+        let http = Client::builder()
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        OAuth2Client::with_client(
+            http,
+            ApplicationId(1),
+            "secret",
+            "https://example.com/callback",
+        )
+    }
+
+    #[test]
+    fn test_debug_omits_secret() {
+        let oauth = oauth(&MockTransport::new());
+
+        assert!(!format!("{:?}", oauth).contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::CreateOauthToken,
+            MockResponse::json(&token("access", 604_800)).unwrap(),
+        );
+        transport.respond(&Route::RevokeOauthToken, MockResponse::new(StatusCode::OK));
+
+        let oauth = oauth(&transport);
+        assert_eq!(
+            token("access", 604_800),
+            oauth.exchange_code("a/b").await.unwrap()
+        );
+        oauth.revoke("access").await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!("oauth2/token", requests[0].path);
+        assert_eq!(
+            "client_id=1&client_secret=secret&code=a%2Fb&grant_type=authorization_code\
+            &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback",
+            String::from_utf8_lossy(&requests[0].body)
+        );
+        assert_eq!(
+            "application/x-www-form-urlencoded",
+            requests[0].headers[CONTENT_TYPE]
+        );
+        assert!(!requests[0].headers.contains_key(AUTHORIZATION));
+        assert_eq!("oauth2/token/revoke", requests[1].path);
+        assert_eq!(
+            "client_id=1&client_secret=secret&token=access",
+            String::from_utf8_lossy(&requests[1].body)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bearer_refresh() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::CreateOauthToken,
+            MockResponse::json(&token("new", 604_800)).unwrap(),
+        );
+        transport.respond(
+            &Route::GetGuilds {
+                after: None,
+                before: None,
+                limit: None,
+            },
+            MockResponse::json(&Vec::<()>::new()).unwrap(),
+        );
+
+        let oauth = oauth(&transport);
+        let bearer_transport = transport.clone();
+        let bearer = oauth.bearer_with(token("old", 0), move |token| {
+            Client::builder()
+                .token(token.authorization())
+                .transport(Box::new(bearer_transport.clone()))
+                .build()
+        });
+
+        let client = bearer.client().await.unwrap();
+        assert_eq!(Some("Bearer new"), client.token());
+        assert_eq!(token("new", 604_800), bearer.token().await);
+        client.current_user_guilds().await.unwrap();
+        assert_eq!(0, transport.remaining());
+
+        let requests = transport.requests();
+        assert_eq!(
+            "client_id=1&client_secret=secret&grant_type=refresh_token\
+            &refresh_token=old-refresh",
+            String::from_utf8_lossy(&requests[0].body)
+        );
+        assert_eq!("Bearer new", requests[1].headers[AUTHORIZATION]);
+
+        // The new token doesn't expire soon, so it isn't refreshed again.
+        assert_eq!(Some("Bearer new"), bearer.client().await.unwrap().token());
+    }
+}
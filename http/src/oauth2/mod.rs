@@ -0,0 +1,60 @@
+//! OAuth2 authorization code flow, to make requests on behalf of users.
+//!
+//! Users authorize an application by visiting an [`AuthorizeUrl`], after
+//! which they are redirected back with a code. The [`OAuth2Client`]
+//! exchanges the code for an [`AccessToken`], and refreshes and revokes
+//! tokens. A [`BearerClient`] makes requests with an access token, such as
+//! getting the [guilds] or [connections] of the user, refreshing the token
+//! before it expires.
+//!
+//! Refer to [the discord docs] for more information.
+//!
+//! [connections]: crate::Client::current_user_connections
+//! [guilds]: crate::Client::current_user_guilds
+//! [the discord docs]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant
+
+mod authorize_url;
+mod bearer;
+mod client;
+mod scope;
+mod token;
+
+pub use self::{
+    authorize_url::{AuthorizeUrl, Prompt},
+    bearer::{BearerClient, REFRESH_MARGIN},
+    client::OAuth2Client,
+    scope::Scope,
+    token::AccessToken,
+};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, NON_ALPHANUMERIC};
+
+/// Characters that are percent encoded, which are all but the unreserved
+/// characters of RFC 3986.
+const ENCODED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent encode a value of a query or form.
+fn encode(value: &str) -> PercentEncode<'_> {
+    utf8_percent_encode(value, ENCODED)
+}
+
+/// Encode parameters as an `application/x-www-form-urlencoded` body.
+fn form<'a>(params: impl IntoIterator<Item = &'a (&'a str, &'a str)>) -> Vec<u8> {
+    let mut body = String::new();
+
+    for (name, value) in params {
+        if !body.is_empty() {
+            body.push('&');
+        }
+
+        body.push_str(name);
+        body.push('=');
+        body.extend(encode(value));
+    }
+
+    body.into_bytes()
+}
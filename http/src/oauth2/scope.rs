@@ -0,0 +1,77 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Scope of access that a user grants to an application.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#shared-resources-oauth2-scopes
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Scope {
+    /// Create commands in the guild the application is added to.
+    ApplicationsCommands,
+    /// Update the application's commands with a client credentials token.
+    ApplicationsCommandsUpdate,
+    /// Add the application's bot to a guild.
+    Bot,
+    /// Get the user's third-party connections.
+    Connections,
+    /// Get the user's email address.
+    Email,
+    /// Join the user to a group DM.
+    GdmJoin,
+    /// Get the guilds the user is in.
+    Guilds,
+    /// Join the user to a guild.
+    GuildsJoin,
+    /// Get the user, without their email address.
+    Identify,
+    /// Read the messages of all the user's channels over a local RPC server.
+    MessagesRead,
+    /// Create a webhook in a channel chosen by the user.
+    WebhookIncoming,
+}
+
+impl Scope {
+    /// Name of the scope, as used in URLs and responses.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ApplicationsCommands => "applications.commands",
+            Self::ApplicationsCommandsUpdate => "applications.commands.update",
+            Self::Bot => "bot",
+            Self::Connections => "connections",
+            Self::Email => "email",
+            Self::GdmJoin => "gdm.join",
+            Self::Guilds => "guilds",
+            Self::GuildsJoin => "guilds.join",
+            Self::Identify => "identify",
+            Self::MessagesRead => "messages.read",
+            Self::WebhookIncoming => "webhook.incoming",
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::{Debug, Display},
+        hash::Hash,
+    };
+
+    assert_impl_all!(Scope: Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_names() {
+        assert_eq!("applications.commands", Scope::ApplicationsCommands.name());
+        assert_eq!("guilds.join", Scope::GuildsJoin.name());
+        assert_eq!("identify", Scope::Identify.to_string());
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Access token received from exchanging an authorization code or refreshing
+/// a previous access token.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessToken {
+    /// Token used to make requests on behalf of the user.
+    pub access_token: String,
+    /// Number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// Token used to get a new access token once it expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Space separated scopes that the user granted.
+    pub scope: String,
+    /// Type of the token, which is always `Bearer`.
+    pub token_type: String,
+}
+
+impl AccessToken {
+    /// Value of the `Authorization` header for requests made with the token,
+    /// which can be passed to [`Client::new`].
+    ///
+    /// [`Client::new`]: crate::Client::new
+    pub fn authorization(&self) -> String {
+        format!("Bearer {}", self.access_token)
+    }
+
+    /// Iterator over the scopes that the user granted.
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.split_whitespace()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessToken;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        AccessToken: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_access_token() {
+        let token = AccessToken {
+            access_token: "access".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("refresh".to_owned()),
+            scope: "identify guilds".to_owned(),
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &token,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("access"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("refresh"),
+                Token::Str("scope"),
+                Token::Str("identify guilds"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!("Bearer access", token.authorization());
+        assert_eq!(
+            ["identify", "guilds"],
+            token.scopes().collect::<Vec<_>>().as_slice()
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Observing requests made by the client.
+//!
+//! An [`Observer`] can be configured via [`ClientBuilder::observer`] to be
+//! notified of every request the client sends, such as to find which routes
+//! are being ratelimited the most or are the slowest to respond.
+//!
+//! With the `metrics` feature enabled the client also records the following
+//! metrics via the [`metrics`] crate, labelled by the request's method and
+//! the [name] of its [`Path`]:
+//!
+//! - `HttpRequest`: counter of requests, additionally labelled by the
+//!   response status or `error` if no response was received;
+//! - `HttpRequestLatency`: histogram of the time waited on a response;
+//! - `HttpRequestQueued`: histogram of the time waited on the ratelimiter.
+//!
+//! [`ClientBuilder::observer`]: crate::client::ClientBuilder::observer
+//! [`metrics`]: https://crates.io/crates/metrics
+//! [name]: crate::routing::Path::name
+
+use crate::{ratelimiting::RatelimitHeaders, request::Method, routing::Path};
+use hyper::StatusCode;
+use std::{fmt::Debug, time::Duration};
+
+/// Information about a request that was sent.
+#[derive(Clone, Debug)]
+pub struct RequestInfo {
+    pub(crate) latency: Duration,
+    pub(crate) method: Method,
+    pub(crate) path: Path,
+    pub(crate) queued: Duration,
+    pub(crate) ratelimit_headers: Option<RatelimitHeaders>,
+    pub(crate) route: String,
+    pub(crate) status: Option<StatusCode>,
+}
+
+impl RequestInfo {
+    /// Time spent waiting on the response after the request was sent.
+    ///
+    /// If the request timed out then this is the time until it timed out.
+    pub const fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Path of the request, which is used as the ratelimit bucket.
+    pub const fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Time spent waiting on the ratelimiter before the request was sent.
+    ///
+    /// This is zero if the client has no ratelimiter.
+    pub const fn queued(&self) -> Duration {
+        self.queued
+    }
+
+    /// Ratelimit headers of the response, if there was a response and its
+    /// headers could be parsed.
+    pub const fn ratelimit_headers(&self) -> Option<&RatelimitHeaders> {
+        self.ratelimit_headers.as_ref()
+    }
+
+    /// Route of the request, which is the path and query of its URL relative
+    /// to the API version.
+    ///
+    /// Webhook and interaction tokens in the route are replaced with
+    /// `REDACTED`.
+    pub fn route(&self) -> &str {
+        &self.route
+    }
+
+    /// Status of the response.
+    ///
+    /// This is `None` if no response was received, such as when the request
+    /// timed out or the transport returned an error.
+    pub const fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+}
+
+/// Observer notified of requests sent by the client.
+///
+/// Observers are called synchronously while a request is being processed, so
+/// implementations should avoid blocking.
+pub trait Observer: Debug + Send + Sync {
+    /// Called when a request has been sent and either a response was received
+    /// or the request failed.
+    ///
+    /// Requests are only observed once the ratelimiter has allowed them to be
+    /// sent. Each attempt of a retried request is observed separately.
+    fn on_request(&self, info: &RequestInfo);
+
+    /// Called when a response with a status code of 403 was received.
+    ///
+    /// `count` is the number of 403s received for the path within the
+    /// [invalid request window]. Discord bans IP addresses that send too many
+    /// invalid requests, so a spike of 403s for a path should be investigated.
+    ///
+    /// This is called before [`on_request`] and does nothing by default.
+    ///
+    /// [`on_request`]: Self::on_request
+    /// [invalid request window]: crate::client::INVALID_REQUEST_WINDOW
+    fn on_forbidden(&self, path: &Path, count: usize) {
+        let _ = (path, count);
+    }
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn record(info: &RequestInfo) {
+    let method = info.method.name();
+    let route = info.path.name();
+    let status = info
+        .status
+        .map_or_else(|| "error".to_owned(), |status| status.as_u16().to_string());
+
+    metrics::counter!("HttpRequest", 1, "method" => method, "route" => route, "status" => status);
+    metrics::histogram!("HttpRequestLatency", info.latency, "method" => method, "route" => route);
+    metrics::histogram!("HttpRequestQueued", info.queued, "method" => method, "route" => route);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Observer, RequestInfo};
+    use crate::{
+        client::Client,
+        request::Method,
+        routing::{Path, Route},
+        transport::{MockResponse, MockTransport},
+    };
+    use hyper::{
+        header::{HeaderName, HeaderValue},
+        StatusCode,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::{ChannelId, MessageId, WebhookId};
+
+    assert_impl_all!(RequestInfo: Clone, Debug, Send, Sync);
+    assert_impl_all!(dyn Observer: Debug, Send, Sync);
+    assert_obj_safe!(Observer);
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        forbidden: Arc<Mutex<Vec<(Path, usize)>>>,
+        infos: Arc<Mutex<Vec<RequestInfo>>>,
+    }
+
+    impl Observer for Recorder {
+        fn on_request(&self, info: &RequestInfo) {
+            self.infos.lock().expect("not poisoned").push(info.clone());
+        }
+
+        fn on_forbidden(&self, path: &Path, count: usize) {
+            self.forbidden
+                .lock()
+                .expect("not poisoned")
+                .push((path.clone(), count));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observe_redacts_token() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::GetWebhook {
+                token: Some("secret".to_owned()),
+                webhook_id: 1,
+            },
+            MockResponse::error(StatusCode::NOT_FOUND, 10015, "Unknown Webhook"),
+        );
+
+        let recorder = Recorder::default();
+        let infos = Arc::clone(&recorder.infos);
+        let client = Client::builder()
+            .observer(Box::new(recorder))
+            .token("token")
+            .transport(Box::new(transport))
+            .build();
+
+        let webhook = client.webhook(WebhookId(1)).token("secret").await.unwrap();
+        assert!(webhook.is_none());
+
+        let infos = infos.lock().unwrap();
+        assert_eq!("webhooks/1/REDACTED", infos[0].route());
+    }
+
+    #[tokio::test]
+    async fn test_observe() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::DeleteMessage {
+                channel_id: 1,
+                message_id: 2,
+            },
+            MockResponse::new(StatusCode::NO_CONTENT)
+                .header(
+                    HeaderName::from_static("x-ratelimit-bucket"),
+                    HeaderValue::from_static("abcd"),
+                )
+                .header(
+                    HeaderName::from_static("x-ratelimit-limit"),
+                    HeaderValue::from_static("5"),
+                )
+                .header(
+                    HeaderName::from_static("x-ratelimit-remaining"),
+                    HeaderValue::from_static("4"),
+                )
+                .header(
+                    HeaderName::from_static("x-ratelimit-reset"),
+                    HeaderValue::from_static("1470173023.123"),
+                )
+                .header(
+                    HeaderName::from_static("x-ratelimit-reset-after"),
+                    HeaderValue::from_static("1"),
+                ),
+        );
+        transport.respond(
+            &Route::DeleteMessage {
+                channel_id: 1,
+                message_id: 3,
+            },
+            MockResponse::error(StatusCode::NOT_FOUND, 10008, "Unknown Message"),
+        );
+
+        let recorder = Recorder::default();
+        let infos = Arc::clone(&recorder.infos);
+        let client = Client::builder()
+            .observer(Box::new(recorder))
+            .token("token")
+            .transport(Box::new(transport))
+            .build();
+
+        client
+            .delete_message(ChannelId(1), MessageId(2))
+            .await
+            .unwrap();
+        client
+            .delete_message(ChannelId(1), MessageId(3))
+            .await
+            .unwrap_err();
+
+        let infos = infos.lock().unwrap();
+        assert_eq!(2, infos.len());
+        assert_eq!(Method::Delete, infos[0].method());
+        assert_eq!("ChannelsIdMessagesId", infos[0].path().name());
+        assert_eq!("channels/1/messages/2", infos[0].route());
+        assert_eq!(Some(StatusCode::NO_CONTENT), infos[0].status());
+        assert_eq!(
+            Some("abcd"),
+            infos[0]
+                .ratelimit_headers()
+                .and_then(|headers| headers.bucket())
+        );
+        assert_eq!(Some(StatusCode::NOT_FOUND), infos[1].status());
+        assert!(infos[1]
+            .ratelimit_headers()
+            .and_then(|headers| headers.bucket())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_observe_forbidden() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::DeleteMessage {
+                channel_id: 1,
+                message_id: 3,
+            },
+            MockResponse::error(StatusCode::FORBIDDEN, 50013, "Missing Permissions"),
+        );
+
+        let recorder = Recorder::default();
+        let forbidden = Arc::clone(&recorder.forbidden);
+        let infos = Arc::clone(&recorder.infos);
+        let client = Client::builder()
+            .observer(Box::new(recorder))
+            .token("token")
+            .transport(Box::new(transport))
+            .build();
+
+        client
+            .delete_message(ChannelId(1), MessageId(3))
+            .await
+            .unwrap_err();
+
+        let infos = infos.lock().unwrap();
+        assert_eq!(1, infos.len());
+        assert_eq!(Some(StatusCode::FORBIDDEN), infos[0].status());
+
+        let forbidden = forbidden.lock().unwrap();
+        assert_eq!(
+            [(Path::ChannelsIdMessagesId(Method::Delete, 3), 1)],
+            forbidden.as_slice()
+        );
+    }
+}
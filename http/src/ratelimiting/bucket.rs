@@ -1,7 +1,8 @@
-use super::{headers::RatelimitHeaders, GlobalLockPair};
-use crate::routing::Path;
+use super::{headers::RatelimitHeaders, in_memory::GlobalLockPair, TicketSender};
+use crate::{request::Method, routing::Path};
 use std::{
-    collections::HashMap,
+    collections::hash_map::{Entry, HashMap},
+    mem::{self, Discriminant},
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
@@ -17,6 +18,112 @@ use tokio::{
     time::{sleep, timeout},
 };
 
+/// Route of a path regardless of its major parameter.
+///
+/// Discord assigns the same bucket hash to a route no matter its major
+/// parameter, so hashes are learned per template rather than per path.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PathTemplate(Discriminant<Path>, Option<Method>);
+
+impl PathTemplate {
+    pub fn new(path: &Path) -> Self {
+        // Message paths have different ratelimits depending on the method.
+        let method = match path {
+            Path::ChannelsIdMessagesId(method, _) => Some(*method),
+            _ => None,
+        };
+
+        Self(mem::discriminant(path), method)
+    }
+}
+
+/// Key of a bucket within the ratelimiter.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BucketKey {
+    /// Bucket of a path whose bucket hash isn't known yet.
+    Path(Path),
+    /// Bucket shared by all paths with the same bucket hash and major
+    /// parameter.
+    Hash {
+        hash: String,
+        major_parameter: Option<u64>,
+    },
+}
+
+/// Buckets in use and the bucket hashes learned from responses.
+#[derive(Debug, Default)]
+pub struct Buckets {
+    buckets: HashMap<BucketKey, Arc<Bucket>>,
+    hashes: HashMap<PathTemplate, String>,
+}
+
+impl Buckets {
+    /// Key of the bucket that requests to a path must go through.
+    pub fn key(&self, path: &Path) -> BucketKey {
+        match self.hashes.get(&PathTemplate::new(path)) {
+            Some(hash) => BucketKey::Hash {
+                hash: hash.clone(),
+                major_parameter: path.major_parameter(),
+            },
+            None => BucketKey::Path(path.clone()),
+        }
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&Arc<Bucket>> {
+        self.buckets.get(&self.key(path))
+    }
+
+    pub fn entry(&mut self, path: &Path) -> Entry<'_, BucketKey, Arc<Bucket>> {
+        let key = self.key(path);
+
+        self.buckets.entry(key)
+    }
+
+    /// Record the bucket hash of a path's bucket, moving the bucket to be
+    /// keyed by its hash.
+    ///
+    /// If another bucket already has the hash then it is returned, and
+    /// requests queued in the path's bucket must be moved into it.
+    pub fn learn(&mut self, path: &Path, hash: &str, bucket: &Arc<Bucket>) -> Option<Arc<Bucket>> {
+        let template = PathTemplate::new(path);
+
+        if self.hashes.get(&template).map(String::as_str) != Some(hash) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?path, hash, "learned bucket hash");
+
+            self.hashes.insert(template, hash.to_owned());
+        }
+
+        let path_key = BucketKey::Path(path.clone());
+
+        if matches!(self.buckets.get(&path_key), Some(other) if Arc::ptr_eq(other, bucket)) {
+            self.buckets.remove(&path_key);
+        }
+
+        match self.buckets.entry(self.key(path)) {
+            Entry::Occupied(entry) if Arc::ptr_eq(entry.get(), bucket) => None,
+            Entry::Occupied(entry) => Some(Arc::clone(entry.get())),
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::clone(bucket));
+
+                None
+            }
+        }
+    }
+
+    /// Remove a bucket that has finished processing its queue.
+    pub fn remove(&mut self, path: &Path, bucket: &Arc<Bucket>) {
+        let key = self.key(path);
+
+        if matches!(self.buckets.get(&key), Some(other) if Arc::ptr_eq(other, bucket)) {
+            self.buckets.remove(&key);
+        } else {
+            // The bucket may have been moved by a changed hash.
+            self.buckets.retain(|_, other| !Arc::ptr_eq(other, bucket));
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum TimeRemaining {
     Finished,
@@ -114,19 +221,25 @@ impl Bucket {
 
 #[derive(Debug)]
 pub struct BucketQueue {
-    rx: Mutex<UnboundedReceiver<Sender<Sender<Option<RatelimitHeaders>>>>>,
-    tx: UnboundedSender<Sender<Sender<Option<RatelimitHeaders>>>>,
+    rx: Mutex<UnboundedReceiver<Sender<TicketSender>>>,
+    tx: UnboundedSender<Sender<TicketSender>>,
 }
 
 impl BucketQueue {
-    pub fn push(&self, tx: Sender<Sender<Option<RatelimitHeaders>>>) {
+    pub fn push(&self, tx: Sender<TicketSender>) {
         let _sent = self.tx.send(tx);
     }
 
-    pub async fn pop(
-        &self,
-        timeout_duration: Duration,
-    ) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    /// Move all queued requests into another queue, keeping their order.
+    pub async fn drain_into(&self, other: &Self) {
+        let mut rx = self.rx.lock().await;
+
+        while let Ok(tx) = rx.try_recv() {
+            other.push(tx);
+        }
+    }
+
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<Sender<TicketSender>> {
         let mut rx = self.rx.lock().await;
 
         match timeout(timeout_duration, rx.recv()).await.ok() {
@@ -149,7 +262,7 @@ impl Default for BucketQueue {
 
 pub(super) struct BucketQueueTask {
     bucket: Arc<Bucket>,
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    buckets: Arc<Mutex<Buckets>>,
     global: Arc<GlobalLockPair>,
     path: Path,
 }
@@ -159,7 +272,7 @@ impl BucketQueueTask {
 
     pub fn new(
         bucket: Arc<Bucket>,
-        buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+        buckets: Arc<Mutex<Buckets>>,
         global: Arc<GlobalLockPair>,
         path: Path,
     ) -> Self {
@@ -204,7 +317,7 @@ impl BucketQueueTask {
         #[cfg(feature = "tracing")]
         tracing::debug!(parent: &span, "bucket appears finished, removing");
 
-        self.buckets.lock().await.remove(&self.path);
+        self.buckets.lock().await.remove(&self.path, &self.bucket);
     }
 
     async fn handle_headers(&self, headers: &RatelimitHeaders) {
@@ -230,11 +343,33 @@ impl BucketQueueTask {
             }
         };
 
+        if let Some(hash) = headers.bucket() {
+            self.learn_hash(hash, ratelimits).await;
+        }
+
         #[cfg(feature = "tracing")]
         tracing::debug!(path=?self.path, "updating bucket");
         self.bucket.update(ratelimits).await;
     }
 
+    /// Key the bucket by its hash, merging it into an existing bucket with
+    /// the same hash if there is one.
+    async fn learn_hash(&self, hash: &str, ratelimits: Option<(u64, u64, u64)>) {
+        let other = self
+            .buckets
+            .lock()
+            .await
+            .learn(&self.path, hash, &self.bucket);
+
+        if let Some(other) = other {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(path=?self.path, hash, "merging bucket into existing bucket");
+
+            other.update(ratelimits).await;
+            self.bucket.queue.drain_into(&other.queue).await;
+        }
+    }
+
     async fn lock_global(&self, wait: Duration) {
         #[cfg(feature = "tracing")]
         tracing::debug!(path=?self.path, "request got global ratelimited");
@@ -246,7 +381,7 @@ impl BucketQueueTask {
         drop(lock);
     }
 
-    async fn next(&self) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    async fn next(&self) -> Option<Sender<TicketSender>> {
         #[cfg(feature = "tracing")]
         tracing::debug!(path=?self.path, "starting to get next in queue");
 
@@ -293,3 +428,77 @@ impl BucketQueueTask {
         self.bucket.try_reset().await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Bucket, BucketKey, Buckets, PathTemplate};
+    use crate::{request::Method, routing::Path};
+    use std::sync::Arc;
+
+    fn insert(buckets: &mut Buckets, path: &Path) -> Arc<Bucket> {
+        let bucket = Arc::new(Bucket::new(path.clone()));
+        buckets.entry(path).or_insert_with(|| Arc::clone(&bucket));
+
+        bucket
+    }
+
+    #[test]
+    fn test_template_ignores_major_parameter() {
+        assert_eq!(
+            PathTemplate::new(&Path::ChannelsIdMessages(1)),
+            PathTemplate::new(&Path::ChannelsIdMessages(2))
+        );
+        assert_ne!(
+            PathTemplate::new(&Path::ChannelsIdMessages(1)),
+            PathTemplate::new(&Path::ChannelsIdPins(1))
+        );
+        assert_ne!(
+            PathTemplate::new(&Path::ChannelsIdMessagesId(Method::Get, 1)),
+            PathTemplate::new(&Path::ChannelsIdMessagesId(Method::Delete, 1))
+        );
+    }
+
+    #[test]
+    fn test_learn_rekeys_bucket() {
+        let mut buckets = Buckets::default();
+        let path = Path::ChannelsIdMessages(1);
+        let bucket = insert(&mut buckets, &path);
+
+        assert_eq!(BucketKey::Path(path.clone()), buckets.key(&path));
+        assert!(buckets.learn(&path, "abcd", &bucket).is_none());
+
+        let key = BucketKey::Hash {
+            hash: "abcd".to_owned(),
+            major_parameter: Some(1),
+        };
+        assert_eq!(key, buckets.key(&path));
+        assert!(Arc::ptr_eq(&bucket, buckets.get(&path).unwrap()));
+        assert!(!buckets.buckets.contains_key(&BucketKey::Path(path)));
+
+        // The hash is learned for the route, but other major parameters get
+        // their own bucket.
+        assert!(buckets.get(&Path::ChannelsIdMessages(2)).is_none());
+    }
+
+    #[test]
+    fn test_learn_merges_shared_hash() {
+        let mut buckets = Buckets::default();
+        let messages = Path::ChannelsIdMessages(1);
+        let pins = Path::ChannelsIdPins(1);
+        let messages_bucket = insert(&mut buckets, &messages);
+        let pins_bucket = insert(&mut buckets, &pins);
+
+        assert!(buckets.learn(&messages, "abcd", &messages_bucket).is_none());
+        let other = buckets.learn(&pins, "abcd", &pins_bucket).unwrap();
+        assert!(Arc::ptr_eq(&messages_bucket, &other));
+        assert!(Arc::ptr_eq(&messages_bucket, buckets.get(&pins).unwrap()));
+
+        // Removing the merged bucket leaves the shared bucket alone.
+        buckets.remove(&pins, &pins_bucket);
+        assert!(buckets.get(&messages).is_some());
+
+        buckets.remove(&messages, &messages_bucket);
+        assert!(buckets.get(&messages).is_none());
+        assert!(buckets.buckets.is_empty());
+    }
+}
@@ -23,6 +23,17 @@ pub enum RatelimitHeaders {
 }
 
 impl RatelimitHeaders {
+    /// Hash of the bucket the route belongs to, if present.
+    ///
+    /// Discord groups multiple routes into the same bucket. Routes with the
+    /// same bucket hash and major parameter share a ratelimit.
+    pub fn bucket(&self) -> Option<&str> {
+        match self {
+            Self::GlobalLimited { .. } | Self::None => None,
+            Self::Present { bucket, .. } => bucket.as_deref(),
+        }
+    }
+
     pub const fn global(&self) -> bool {
         match self {
             Self::GlobalLimited { .. } => true,
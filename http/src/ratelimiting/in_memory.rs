@@ -0,0 +1,155 @@
+use super::{
+    bucket::{Bucket, BucketQueueTask, Buckets, TimeRemaining},
+    GetTicketFuture, Ratelimiter, TicketReceiver, TicketSender,
+};
+use crate::routing::Path;
+use std::{
+    collections::hash_map::Entry,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::{
+    oneshot::{self, Sender},
+    Mutex,
+};
+
+/// Global lock. We use a pair to avoid actually locking the mutex every check.
+/// This allows futures to only wait on the global lock when a global ratelimit
+/// is in place by, in turn, waiting for a guard, and then each immediately
+/// dropping it.
+#[derive(Debug, Default)]
+pub(super) struct GlobalLockPair(pub Mutex<()>, AtomicBool);
+
+impl GlobalLockPair {
+    pub fn lock(&self) {
+        self.1.store(true, Ordering::Release);
+    }
+
+    pub fn unlock(&self) {
+        self.1.store(false, Ordering::Release);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.1.load(Ordering::Relaxed)
+    }
+}
+
+/// Default in-process [`Ratelimiter`] implementation.
+///
+/// Buckets are tracked in memory, so the ratelimits are only known to this
+/// process. Requests are first ratelimited by their [`Path`]; once Discord
+/// reports the bucket hash of a path's route, all paths with the same bucket
+/// hash and major parameter share a bucket. If multiple processes share the
+/// same token then consider using a [`TcpRatelimiter`] backed by a shared
+/// server.
+///
+/// [`TcpRatelimiter`]: super::TcpRatelimiter
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRatelimiter {
+    buckets: Arc<Mutex<Buckets>>,
+    global: Arc<GlobalLockPair>,
+}
+
+impl InMemoryRatelimiter {
+    /// Create a new in-memory ratelimiter.
+    ///
+    /// Most users won't need to use this directly. If you're creating your own
+    /// HTTP proxy then this is good to use for your own ratelimiting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Provide an estimate for the time left until a path can be used
+    /// without being ratelimited.
+    ///
+    /// This method is not guaranteed to be accurate and may return
+    /// None if either no ratelimit is known or buckets are remaining.
+    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
+        let buckets = self.buckets.lock().await;
+        match buckets.get(path)?.time_remaining().await {
+            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+            TimeRemaining::Some(duration) => Some(duration),
+        }
+    }
+
+    async fn get(&self, path: Path) -> TicketReceiver {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("getting bucket for path: {:?}", path);
+
+        let (tx, rx) = oneshot::channel();
+        let (bucket, fresh) = self.entry(path.clone(), tx).await;
+
+        if fresh {
+            tokio::spawn(
+                BucketQueueTask::new(
+                    bucket,
+                    Arc::clone(&self.buckets),
+                    Arc::clone(&self.global),
+                    path,
+                )
+                .run(),
+            );
+        }
+
+        rx
+    }
+
+    async fn entry(&self, path: Path, tx: Sender<TicketSender>) -> (Arc<Bucket>, bool) {
+        // nb: not realisically point of contention
+        let mut buckets = self.buckets.lock().await;
+
+        match buckets.entry(&path) {
+            Entry::Occupied(bucket) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("got existing bucket: {:?}", path);
+
+                let bucket = bucket.into_mut();
+                bucket.queue.push(tx);
+                #[cfg(feature = "tracing")]
+                tracing::debug!("added request into bucket queue: {:?}", path);
+
+                (Arc::clone(&bucket), false)
+            }
+            Entry::Vacant(entry) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("making new bucket for path: {:?}", path);
+                let bucket = Bucket::new(path.clone());
+                bucket.queue.push(tx);
+
+                let bucket = Arc::new(bucket);
+                entry.insert(Arc::clone(&bucket));
+
+                (bucket, true)
+            }
+        }
+    }
+}
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn ticket(&'_ self, path: Path) -> GetTicketFuture<'_> {
+        Box::pin(async move { Ok(self.get(path).await) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InMemoryRatelimiter, Ratelimiter};
+    use crate::routing::Path;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+
+    #[tokio::test]
+    async fn test_ticket_granted() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let rx = ratelimiter.ticket(Path::ChannelsId(1)).await?;
+        let tx = rx.await?;
+        assert!(tx.send(None).is_ok());
+
+        Ok(())
+    }
+}
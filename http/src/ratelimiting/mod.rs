@@ -1,130 +1,75 @@
+//! Ratelimiting functionality for HTTP requests.
+//!
+//! The [`Client`] asks a [`Ratelimiter`] for a ticket before sending each
+//! request. Once the ticket is granted the request is sent, and the
+//! ratelimit headers of the response are sent back to the ratelimiter through
+//! the ticket.
+//!
+//! By default the client uses the [`InMemoryRatelimiter`], which tracks
+//! ratelimits within the process. When multiple processes make requests with
+//! the same token they can share ratelimits via a [`TcpRatelimiter`], or a
+//! custom implementation of the [`Ratelimiter`] trait.
+//!
+//! [`Client`]: crate::Client
+
 pub mod error;
 
 mod bucket;
 mod headers;
+mod in_memory;
+mod tcp;
 
 pub use self::{
     error::{RatelimitError, RatelimitResult},
     headers::RatelimitHeaders,
+    in_memory::InMemoryRatelimiter,
+    tcp::{TcpRatelimiter, TicketRequest, TicketRequestParseError, TicketRequestParseErrorType},
 };
 
 use crate::routing::Path;
-use bucket::{Bucket, BucketQueueTask, TimeRemaining};
-use std::{
-    collections::hash_map::{Entry, HashMap},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
-    },
-    time::Duration,
-};
-use tokio::sync::{
-    oneshot::{self, Receiver, Sender},
-    Mutex,
-};
-
-/// Global lock. We use a pair to avoid actually locking the mutex every check.
-/// This allows futures to only wait on the global lock when a global ratelimit
-/// is in place by, in turn, waiting for a guard, and then each immediately
-/// dropping it.
-#[derive(Debug, Default)]
-struct GlobalLockPair(Mutex<()>, AtomicBool);
-
-impl GlobalLockPair {
-    pub fn lock(&self) {
-        self.1.store(true, Ordering::Release);
-    }
-
-    pub fn unlock(&self) {
-        self.1.store(false, Ordering::Release);
-    }
-
-    pub fn is_locked(&self) -> bool {
-        self.1.load(Ordering::Relaxed)
-    }
-}
-
-#[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
-    global: Arc<GlobalLockPair>,
-}
-
-impl Ratelimiter {
-    /// Create a new ratelimiter.
+use std::{error::Error, fmt::Debug, future::Future, pin::Pin};
+use tokio::sync::oneshot::{Receiver, Sender};
+
+/// Sender used to report the ratelimit headers of a response back to the
+/// ratelimiter once a ticket has been granted.
+///
+/// `None` is sent if the request failed or no headers could be parsed.
+pub type TicketSender = Sender<Option<RatelimitHeaders>>;
+
+/// Receiver of a ticket, which resolves once a request can be sent.
+pub type TicketReceiver = Receiver<TicketSender>;
+
+/// Future returned by [`Ratelimiter::ticket`].
+pub type GetTicketFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<TicketReceiver, Box<dyn Error + Send + Sync>>> + Send + 'a>>;
+
+/// Ratelimiter for requests made by the HTTP client.
+///
+/// This will usually only need to be implemented when multiple processes
+/// make requests with the same token and need to share ratelimits. Refer to
+/// the [module-level] documentation for more information.
+///
+/// [module-level]: crate::ratelimiting
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Request a ticket to make a request to a path.
     ///
-    /// Most users won't need to use this directly. If you're creating your own
-    /// HTTP proxy then this is good to use for your own ratelimiting.
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
-        #[cfg(feature = "tracing")]
-        tracing::debug!("getting bucket for path: {:?}", path);
-
-        let (tx, rx) = oneshot::channel();
-        let (bucket, fresh) = self.entry(path.clone(), tx).await;
-
-        if fresh {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
-        }
-
-        rx
-    }
-
-    /// Provide an estimate for the time left until a path can be used
-    /// without being ratelimited.
+    /// The returned receiver must resolve only when the request can be made
+    /// without exceeding the path's ratelimit. The resolved sender is then
+    /// used to report the ratelimit headers of the response.
     ///
-    /// This method is not guaranteed to be accurate and may return
-    /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
-        let buckets = self.buckets.lock().await;
-        match buckets.get(path)?.time_remaining().await {
-            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
-            TimeRemaining::Some(duration) => Some(duration),
-        }
-    }
-
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
-        // nb: not realisically point of contention
-        let mut buckets = self.buckets.lock().await;
-
-        match buckets.entry(path.clone()) {
-            Entry::Occupied(bucket) => {
-                #[cfg(feature = "tracing")]
-                tracing::debug!("got existing bucket: {:?}", path);
-
-                let bucket = bucket.into_mut();
-                bucket.queue.push(tx);
-                #[cfg(feature = "tracing")]
-                tracing::debug!("added request into bucket queue: {:?}", path);
-
-                (Arc::clone(&bucket), false)
-            }
-            Entry::Vacant(entry) => {
-                #[cfg(feature = "tracing")]
-                tracing::debug!("making new bucket for path: {:?}", path);
-                let bucket = Bucket::new(path.clone());
-                bucket.queue.push(tx);
+    /// # Errors
+    ///
+    /// Returns an error if a ticket could not be requested, such as when a
+    /// remote ratelimiter is unreachable.
+    fn ticket(&'_ self, path: Path) -> GetTicketFuture<'_>;
+}
 
-                let bucket = Arc::new(bucket);
-                entry.insert(Arc::clone(&bucket));
+#[cfg(test)]
+mod tests {
+    use super::Ratelimiter;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
 
-                (bucket, true)
-            }
-        }
-    }
+    assert_impl_all!(dyn Ratelimiter: Debug, Send, Sync);
+    assert_obj_safe!(Ratelimiter);
 }
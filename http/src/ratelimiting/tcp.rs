@@ -0,0 +1,666 @@
+use super::{GetTicketFuture, RatelimitHeaders, Ratelimiter, TicketSender};
+use crate::{request::Method, routing::Path};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex as StdMutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream,
+    },
+    sync::{
+        oneshot::{self, Receiver, Sender},
+        Mutex,
+    },
+    time::timeout,
+};
+
+/// Grants that have been requested but not yet received, by ticket ID.
+type PendingGrants = Arc<StdMutex<HashMap<u64, Sender<()>>>>;
+
+/// Parsing a [`TicketRequest`] failed.
+#[derive(Debug)]
+pub struct TicketRequestParseError {
+    kind: TicketRequestParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl TicketRequestParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TicketRequestParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        TicketRequestParseErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for TicketRequestParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TicketRequestParseErrorType::Format => {
+                f.write_str("line isn't an ACQUIRE command with four fields")
+            }
+            TicketRequestParseErrorType::IdInvalid => f.write_str("ticket id is invalid"),
+            TicketRequestParseErrorType::MajorParameterInvalid => {
+                f.write_str("major parameter is invalid")
+            }
+            TicketRequestParseErrorType::MethodInvalid => f.write_str("method is invalid"),
+            TicketRequestParseErrorType::PathInvalid => f.write_str("path name is invalid"),
+        }
+    }
+}
+
+impl Error for TicketRequestParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`TicketRequestParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TicketRequestParseErrorType {
+    /// Line isn't an `ACQUIRE` command with exactly four fields.
+    Format,
+    /// Ticket ID isn't an integer.
+    IdInvalid,
+    /// Major parameter is neither `-` nor an integer.
+    MajorParameterInvalid,
+    /// Method is neither `-` nor a method name such as `GET`.
+    MethodInvalid,
+    /// Path name is empty or contains characters other than ASCII letters.
+    PathInvalid,
+}
+
+/// Request for a ticket sent by a [`TcpRatelimiter`] to its server.
+///
+/// The request is encoded as a line via its [`Display`] implementation, and
+/// servers can parse the line via its [`FromStr`] implementation. Refer to
+/// [`TcpRatelimiter`] for the protocol.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_http::{ratelimiting::TicketRequest, request::Method};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let request = "ACQUIRE 7 DELETE ChannelsIdMessagesId 123".parse::<TicketRequest>()?;
+///
+/// assert_eq!(7, request.id);
+/// assert_eq!(Some(Method::Delete), request.method);
+/// assert_eq!("ChannelsIdMessagesId", request.path);
+/// assert_eq!(Some(123), request.major_parameter);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketRequest {
+    /// ID of the ticket, unique to the connection.
+    pub id: u64,
+    /// Major parameter of the path, as returned by [`Path::major_parameter`].
+    pub major_parameter: Option<u64>,
+    /// Method of the request if the path's ratelimit depends on it, such as
+    /// for [`Path::ChannelsIdMessagesId`].
+    pub method: Option<Method>,
+    /// Name of the path, as returned by [`Path::name`].
+    pub path: String,
+}
+
+impl TicketRequest {
+    /// Create a request for a ticket to make a request to a path.
+    pub fn new(id: u64, path: &Path) -> Self {
+        let method = match path {
+            Path::ChannelsIdMessagesId(method, _) => Some(*method),
+            _ => None,
+        };
+
+        Self {
+            id,
+            major_parameter: path.major_parameter(),
+            method,
+            path: path.name().to_owned(),
+        }
+    }
+}
+
+impl Display for TicketRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("ACQUIRE ")?;
+        Display::fmt(&self.id, f)?;
+        f.write_str(" ")?;
+        f.write_str(self.method.map_or("-", Method::name))?;
+        f.write_str(" ")?;
+        f.write_str(&self.path)?;
+        f.write_str(" ")?;
+
+        match self.major_parameter {
+            Some(major_parameter) => Display::fmt(&major_parameter, f),
+            None => f.write_str("-"),
+        }
+    }
+}
+
+impl FromStr for TicketRequest {
+    type Err = TicketRequestParseError;
+
+    /// Parse a request line, without its trailing newline.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |kind| TicketRequestParseError { kind, source: None };
+
+        let fields = s.split(' ').collect::<Vec<_>>();
+
+        let (id, method, path, major_parameter) = match fields.as_slice() {
+            ["ACQUIRE", id, method, path, major_parameter] => (id, method, path, major_parameter),
+            _ => return Err(error(TicketRequestParseErrorType::Format)),
+        };
+
+        let id = id.parse().map_err(|source| TicketRequestParseError {
+            kind: TicketRequestParseErrorType::IdInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+        let method = match *method {
+            "-" => None,
+            "DELETE" => Some(Method::Delete),
+            "GET" => Some(Method::Get),
+            "PATCH" => Some(Method::Patch),
+            "POST" => Some(Method::Post),
+            "PUT" => Some(Method::Put),
+            _ => return Err(error(TicketRequestParseErrorType::MethodInvalid)),
+        };
+
+        if path.is_empty() || !path.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            return Err(error(TicketRequestParseErrorType::PathInvalid));
+        }
+
+        let major_parameter = match *major_parameter {
+            "-" => None,
+            major_parameter => {
+                Some(
+                    major_parameter
+                        .parse()
+                        .map_err(|source| TicketRequestParseError {
+                            kind: TicketRequestParseErrorType::MajorParameterInvalid,
+                            source: Some(Box::new(source)),
+                        })?,
+                )
+            }
+        };
+
+        Ok(Self {
+            id,
+            major_parameter,
+            method,
+            path: (*path).to_owned(),
+        })
+    }
+}
+
+/// [`Ratelimiter`] backed by a remote server over a line-based TCP protocol.
+///
+/// This allows multiple processes making requests with the same token to
+/// share ratelimits by asking one server for tickets. A single connection is
+/// made to the server and is re-established on the next ticket if it's lost.
+///
+/// # Protocol
+///
+/// Every message is a single line of ASCII text terminated by a newline.
+/// Tickets are identified by an ID unique to the connection.
+///
+/// To request a ticket the client sends the ticket ID, the request's method if
+/// the path's ratelimit depends on it, the [name] of the request's [`Path`],
+/// and its [major parameter]. Absent fields are sent as `-`:
+///
+/// ```text
+/// ACQUIRE <id> <method> <path> <major parameter>
+/// ACQUIRE 3 - ChannelsIdMessages 123
+/// ACQUIRE 4 DELETE ChannelsIdMessagesId 123
+/// ACQUIRE 5 - Guilds -
+/// ```
+///
+/// Servers can parse these lines into a [`TicketRequest`].
+///
+/// The server responds once the request can be made:
+///
+/// ```text
+/// GO <id>
+/// ```
+///
+/// After the response has been received the client releases the ticket,
+/// sending the ratelimit headers of the response, if any:
+///
+/// ```text
+/// RELEASE <id>
+/// RELEASE <id> NONE
+/// RELEASE <id> GLOBAL <reset after seconds>
+/// RELEASE <id> PRESENT <global> <limit> <remaining> <reset> <reset after> <bucket>
+/// ```
+///
+/// A plain `RELEASE` means that the request failed or that no ratelimit
+/// information is known. `NONE` means that the path isn't ratelimited.
+/// `PRESENT` contains the fields of [`RatelimitHeaders::Present`], where
+/// `global` is either `true` or `false` and `bucket` is `-` if absent.
+///
+/// If the connection is lost then tickets that haven't been granted are
+/// cancelled.
+///
+/// [major parameter]: Path::major_parameter
+/// [name]: Path::name
+///
+/// # Examples
+///
+/// Create a client that shares ratelimits through a server at
+/// `ratelimiter.internal:7000`:
+///
+/// ```rust,no_run
+/// use twilight_http::{ratelimiting::TcpRatelimiter, Client};
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .ratelimiter(Some(Box::new(TcpRatelimiter::new("ratelimiter.internal:7000"))))
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct TcpRatelimiter(Arc<Inner>);
+
+impl TcpRatelimiter {
+    /// Create a new ratelimiter connecting to a server at an address, such as
+    /// `127.0.0.1:7000`.
+    ///
+    /// The connection is lazily made when the first ticket is requested.
+    pub fn new(address: impl Into<String>) -> Self {
+        Self(Arc::new(Inner {
+            address: address.into(),
+            connection: Mutex::new(None),
+            next_id: AtomicU64::new(0),
+        }))
+    }
+}
+
+impl Ratelimiter for TcpRatelimiter {
+    fn ticket(&'_ self, path: Path) -> GetTicketFuture<'_> {
+        Box::pin(async move {
+            let id = self.0.next_id.fetch_add(1, Ordering::Relaxed);
+            let (grant_tx, grant_rx) = oneshot::channel();
+
+            self.0
+                .acquire(id, grant_tx, format!("{}\n", TicketRequest::new(id, &path)))
+                .await?;
+
+            let (tx, rx) = oneshot::channel();
+            tokio::spawn(Arc::clone(&self.0).hold(id, grant_rx, tx));
+
+            Ok(rx)
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    address: String,
+    connection: Mutex<Option<Connection>>,
+    next_id: AtomicU64,
+}
+
+impl Inner {
+    /// Maximum amount of time to wait for the response headers of a granted
+    /// ticket, matching the in-memory ratelimiter.
+    const WAIT: Duration = Duration::from_secs(10);
+
+    /// Send a request for a ticket, connecting to the server if needed.
+    async fn acquire(&self, id: u64, grant: Sender<()>, line: String) -> Result<(), IoError> {
+        let mut guard = self.connection.lock().await;
+
+        if guard.as_ref().map_or(true, Connection::is_closed) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("connecting to ratelimiter at {}", self.address);
+
+            guard.replace(Connection::connect(&self.address).await?);
+        }
+
+        if let Some(connection) = guard.as_mut() {
+            connection
+                .pending
+                .lock()
+                .expect("pending grants poisoned")
+                .insert(id, grant);
+
+            if let Err(source) = connection.writer.write_all(line.as_bytes()).await {
+                guard.take();
+
+                return Err(source);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Wait for a ticket to be granted, hand it to the client, and release it
+    /// once the client reports the response's headers.
+    async fn hold(self: Arc<Self>, id: u64, grant: Receiver<()>, tx: Sender<TicketSender>) {
+        // If the connection was lost then the grant is dropped, which cancels
+        // the request by dropping `tx`.
+        if grant.await.is_err() {
+            return;
+        }
+
+        let (headers_tx, headers_rx) = oneshot::channel();
+
+        let headers = if tx.send(headers_tx).is_ok() {
+            timeout(Self::WAIT, headers_rx)
+                .await
+                .ok()
+                .and_then(Result::ok)
+                .flatten()
+        } else {
+            None
+        };
+
+        let line = release_line(id, headers.as_ref());
+        let mut guard = self.connection.lock().await;
+
+        if let Some(connection) = guard.as_mut() {
+            if !connection.is_closed()
+                && connection.writer.write_all(line.as_bytes()).await.is_err()
+            {
+                guard.take();
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Connection {
+    closed: Arc<AtomicBool>,
+    pending: PendingGrants,
+    writer: OwnedWriteHalf,
+}
+
+impl Connection {
+    async fn connect(address: &str) -> Result<Self, IoError> {
+        let stream = TcpStream::connect(address).await?;
+        stream.set_nodelay(true)?;
+
+        let (reader, writer) = stream.into_split();
+        let closed = Arc::new(AtomicBool::new(false));
+        let pending = PendingGrants::default();
+
+        tokio::spawn(read_grants(
+            reader,
+            Arc::clone(&closed),
+            Arc::clone(&pending),
+        ));
+
+        Ok(Self {
+            closed,
+            pending,
+            writer,
+        })
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+}
+
+/// Read grants from the server until the connection is closed.
+async fn read_grants(reader: OwnedReadHalf, closed: Arc<AtomicBool>, pending: PendingGrants) {
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        let id = match line
+            .strip_prefix("GO ")
+            .and_then(|id| id.trim().parse::<u64>().ok())
+        {
+            Some(id) => id,
+            None => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("ratelimiter sent an invalid line: {:?}", line);
+
+                continue;
+            }
+        };
+
+        let grant = pending.lock().expect("pending grants poisoned").remove(&id);
+
+        if let Some(grant) = grant {
+            let _sent = grant.send(());
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!("ratelimiter connection closed");
+
+    closed.store(true, Ordering::Relaxed);
+    pending.lock().expect("pending grants poisoned").clear();
+}
+
+fn release_line(id: u64, headers: Option<&RatelimitHeaders>) -> String {
+    match headers {
+        None => format!("RELEASE {}\n", id),
+        Some(RatelimitHeaders::None) => format!("RELEASE {} NONE\n", id),
+        Some(RatelimitHeaders::GlobalLimited { reset_after }) => {
+            format!("RELEASE {} GLOBAL {}\n", id, reset_after)
+        }
+        Some(RatelimitHeaders::Present {
+            bucket,
+            global,
+            limit,
+            remaining,
+            reset,
+            reset_after,
+        }) => format!(
+            "RELEASE {} PRESENT {} {} {} {} {} {}\n",
+            id,
+            global,
+            limit,
+            remaining,
+            reset,
+            reset_after,
+            bucket.as_deref().unwrap_or("-"),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        release_line, Ratelimiter, TcpRatelimiter, TicketRequest, TicketRequestParseError,
+        TicketRequestParseErrorType,
+    };
+    use crate::{ratelimiting::RatelimitHeaders, request::Method, routing::Path};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, mem};
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+        sync::mpsc::{self, UnboundedReceiver},
+    };
+
+    assert_impl_all!(TcpRatelimiter: Clone, Debug, Ratelimiter, Send, Sync);
+    assert_impl_all!(TicketRequestParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(TicketRequestParseError: Error, Send, Sync);
+    assert_impl_all!(TicketRequest: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    /// Start a stand-in server granting every ticket immediately, returning
+    /// its address and the lines it receives.
+    async fn server() -> Result<(String, UnboundedReceiver<String>), Box<dyn Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?.to_string();
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let tx = tx.clone();
+
+                tokio::spawn(async move {
+                    let (reader, mut writer) = stream.into_split();
+                    let mut lines = BufReader::new(reader).lines();
+
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        if line.starts_with("ACQUIRE ") {
+                            let request = line.parse::<TicketRequest>().expect("valid request");
+                            let grant = format!("GO {}\n", request.id);
+
+                            if writer.write_all(grant.as_bytes()).await.is_err() {
+                                return;
+                            }
+                        }
+
+                        let _sent = tx.send(line);
+                    }
+                });
+            }
+        });
+
+        Ok((address, rx))
+    }
+
+    #[tokio::test]
+    async fn test_ticket_round_trip() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (address, mut lines) = server().await?;
+        let ratelimiter = TcpRatelimiter::new(address);
+
+        let tx = ratelimiter.ticket(Path::ChannelsId(1)).await?.await?;
+        assert_eq!(
+            Some("ACQUIRE 0 - ChannelsId 1"),
+            lines.recv().await.as_deref()
+        );
+
+        let headers = RatelimitHeaders::Present {
+            bucket: Some("abcd".to_owned()),
+            global: false,
+            limit: 5,
+            remaining: 4,
+            reset: 1_000,
+            reset_after: 500,
+        };
+        assert!(tx.send(Some(headers)).is_ok());
+        assert_eq!(
+            Some("RELEASE 0 PRESENT false 5 4 1000 500 abcd"),
+            lines.recv().await.as_deref()
+        );
+
+        let tx = ratelimiter.ticket(Path::Guilds).await?.await?;
+        assert_eq!(Some("ACQUIRE 1 - Guilds -"), lines.recv().await.as_deref());
+        drop(tx);
+        assert_eq!(Some("RELEASE 1"), lines.recv().await.as_deref());
+
+        let tx = ratelimiter
+            .ticket(Path::ChannelsIdMessagesId(Method::Delete, 2))
+            .await?
+            .await?;
+        assert_eq!(
+            Some("ACQUIRE 2 DELETE ChannelsIdMessagesId 2"),
+            lines.recv().await.as_deref()
+        );
+        drop(tx);
+        assert_eq!(Some("RELEASE 2"), lines.recv().await.as_deref());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unreachable() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?.to_string();
+        drop(listener);
+
+        let ratelimiter = TcpRatelimiter::new(address);
+        assert!(ratelimiter.ticket(Path::Guilds).await.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ticket_request_parse() -> Result<(), TicketRequestParseError> {
+        let request = TicketRequest::new(4, &Path::ChannelsIdMessagesId(Method::Get, 5));
+        assert_eq!("ACQUIRE 4 GET ChannelsIdMessagesId 5", request.to_string());
+        assert_eq!(request, request.to_string().parse()?);
+
+        let request = TicketRequest::new(6, &Path::Guilds);
+        assert_eq!(request, "ACQUIRE 6 - Guilds -".parse()?);
+
+        let invalid = [
+            ("ACQUIRE 6 - Guilds", TicketRequestParseErrorType::Format),
+            (
+                "ACQUIRE 6 - Guilds - -",
+                TicketRequestParseErrorType::Format,
+            ),
+            ("ACQUIRE  6 - Guilds -", TicketRequestParseErrorType::Format),
+            ("RELEASE 6 - Guilds -", TicketRequestParseErrorType::Format),
+            (
+                "ACQUIRE x - Guilds -",
+                TicketRequestParseErrorType::IdInvalid,
+            ),
+            (
+                "ACQUIRE 6 get Guilds -",
+                TicketRequestParseErrorType::MethodInvalid,
+            ),
+            (
+                "ACQUIRE 6 - Guilds(1) -",
+                TicketRequestParseErrorType::PathInvalid,
+            ),
+            ("ACQUIRE 6 - - -", TicketRequestParseErrorType::PathInvalid),
+            (
+                "ACQUIRE 6 - Guilds 1x",
+                TicketRequestParseErrorType::MajorParameterInvalid,
+            ),
+        ];
+
+        for (line, kind) in &invalid {
+            let error = line.parse::<TicketRequest>().unwrap_err();
+            assert_eq!(
+                mem::discriminant(kind),
+                mem::discriminant(error.kind()),
+                "{}",
+                line,
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_release_line() {
+        assert_eq!("RELEASE 3\n", release_line(3, None));
+        assert_eq!(
+            "RELEASE 3 NONE\n",
+            release_line(3, Some(&RatelimitHeaders::None))
+        );
+        assert_eq!(
+            "RELEASE 3 GLOBAL 10\n",
+            release_line(
+                3,
+                Some(&RatelimitHeaders::GlobalLimited { reset_after: 10 })
+            )
+        );
+    }
+}
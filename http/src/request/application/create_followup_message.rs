@@ -1,11 +1,18 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::{Form, Pending, Request},
+    error::Error as HttpError,
+    request::{
+        validate, AttachmentFile, Form, PartialAttachment, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageFlags},
@@ -14,11 +21,78 @@ use twilight_model::{
     id::ApplicationId,
 };
 
+/// The error created when a followup message can not be created as configured.
+#[derive(Debug)]
+pub struct CreateFollowupMessageError {
+    kind: CreateFollowupMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateFollowupMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateFollowupMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateFollowupMessageErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateFollowupMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateFollowupMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateFollowupMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateFollowupMessageError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateFollowupMessageErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct CreateFollowupMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
@@ -60,9 +134,10 @@ pub(crate) struct CreateFollowupMessageFields {
 /// [`file`]: Self::file
 pub struct CreateFollowupMessage<'a> {
     pub(crate) fields: CreateFollowupMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, Option<Message>>>,
     http: &'a Client,
+    options: RequestOptions,
     token: String,
     application_id: ApplicationId,
 }
@@ -78,6 +153,7 @@ impl<'a> CreateFollowupMessage<'a> {
             files: Vec::new(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             token: token.into(),
             application_id,
         }
@@ -90,6 +166,22 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
+    /// Attach a file to the followup message, whose contents may be streamed.
+    ///
+    /// This method is repeatable.
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the followup message, whose contents may be streamed.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -97,6 +189,34 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Calling this method multiple times will clear previous calls.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateFollowupMessageErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(
+        mut self,
+        components: Vec<Component>,
+    ) -> Result<Self, CreateFollowupMessageError> {
+        if let Err(source) = validate::components(&components) {
+            return Err(CreateFollowupMessageError {
+                kind: CreateFollowupMessageErrorType::ComponentsInvalid { components },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints.
@@ -128,7 +248,7 @@ impl<'a> CreateFollowupMessage<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -219,8 +339,9 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn start(&mut self) -> Result<(), HttpError> {
         let mut request = Request::builder(Route::ExecuteWebhook {
+            thread_id: None,
             token: self.token.clone(),
             wait: None,
             webhook_id: self.application_id.0,
@@ -229,14 +350,15 @@ impl<'a> CreateFollowupMessage<'a> {
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
 
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+            for (index, file) in self.files.iter().enumerate() {
+                form.attachment(format!("files[{}]", index).as_bytes(), file);
             }
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                self.fields.attachments = PartialAttachment::from_files(&self.files);
+                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -245,8 +367,9 @@ impl<'a> CreateFollowupMessage<'a> {
             request = request.json(&self.fields)?;
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -3,21 +3,24 @@ use crate::{
     error::Error as HttpError,
     request::{
         application::{InteractionError, InteractionErrorType},
-        validate, Pending, Request,
+        validate, Pending, Request, RequestOptions,
     },
     routing::Route,
 };
 use twilight_model::{
-    application::command::{Command, CommandOption},
+    application::command::{Command, CommandOption, CommandType},
     id::ApplicationId,
 };
 
 /// Create a new global command.
 ///
 /// The name must be between 3 and 32 characters in length, and the description
-/// must be between 1 and 100 characters in length. Creating a command with the
-/// same name as an already-existing global command will overwwrite the old
-/// command. See [the discord docs] for more information.
+/// must be between 1 and 100 characters in length. Names of
+/// [`CommandType::User`] and [`CommandType::Message`] commands must be between
+/// 1 and 32 characters in length, may contain spaces and upper case characters,
+/// and have an empty description. Creating a command with the same name as an
+/// already-existing global command will overwrite the old command. See
+/// [the discord docs] for more information.
 ///
 /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#create-global-application-command
 pub struct CreateGlobalCommand<'a> {
@@ -26,6 +29,7 @@ pub struct CreateGlobalCommand<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     optional_option_added: bool,
+    options: RequestOptions,
 }
 
 impl<'a> CreateGlobalCommand<'a> {
@@ -34,16 +38,17 @@ impl<'a> CreateGlobalCommand<'a> {
         application_id: ApplicationId,
         name: impl Into<String>,
         description: impl Into<String>,
+        kind: CommandType,
     ) -> Result<Self, InteractionError> {
         let name = name.into();
         let description = description.into();
 
-        if !validate::command_name(&name) {
+        if !validate::command_name(kind, &name) {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandNameValidationFailed { name },
             });
         }
-        if !validate::command_description(&description) {
+        if !kind.is_context_menu() && !validate::command_description(&description) {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandDescriptionValidationFailed { description },
             });
@@ -57,12 +62,14 @@ impl<'a> CreateGlobalCommand<'a> {
                 default_permission: None,
                 description,
                 id: None,
+                kind,
                 options: vec![],
             },
             application_id,
             fut: None,
             http,
             optional_option_added: false,
+            options: RequestOptions::default(),
         })
     }
 
@@ -74,7 +81,16 @@ impl<'a> CreateGlobalCommand<'a> {
     ///
     /// Retuns an [`InteractionErrorType::CommandOptionsRequiredFirst`]
     /// if a required option was added after an optional option.
+    ///
+    /// Returns an [`InteractionErrorType::CommandOptionsNotAllowed`] if the
+    /// command is a context menu command.
     pub fn add_command_option(mut self, option: CommandOption) -> Result<Self, InteractionError> {
+        if self.command.kind.is_context_menu() {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandOptionsNotAllowed { option },
+            });
+        }
+
         if !self.optional_option_added && !option.is_required() {
             self.optional_option_added = true
         }
@@ -103,8 +119,9 @@ impl<'a> CreateGlobalCommand<'a> {
         })
         .json(&self.command)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ApplicationId, MessageId};
@@ -26,6 +26,7 @@ pub struct DeleteFollowupMessage<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
     token: String,
     application_id: ApplicationId,
 }
@@ -41,6 +42,7 @@ impl<'a> DeleteFollowupMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
             token: token.into(),
             application_id,
         }
@@ -49,6 +51,7 @@ impl<'a> DeleteFollowupMessage<'a> {
     fn request(&self) -> Result<Request, Error> {
         let request = Request::from_route(Route::DeleteWebhookMessage {
             message_id: self.message_id.0,
+            thread_id: None,
             token: self.token.clone(),
             webhook_id: self.application_id.0,
         });
@@ -58,7 +61,8 @@ impl<'a> DeleteFollowupMessage<'a> {
 
     fn start(&mut self) -> Result<(), Error> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -81,6 +85,7 @@ mod tests {
 
         let expected = Request::from_route(Route::DeleteWebhookMessage {
             message_id: 2,
+            thread_id: None,
             token: "token".to_owned(),
             webhook_id: 1,
         });
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ApplicationId, CommandId};
@@ -12,6 +12,7 @@ pub struct DeleteGlobalCommand<'a> {
     command_id: CommandId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> DeleteGlobalCommand<'a> {
@@ -25,6 +26,7 @@ impl<'a> DeleteGlobalCommand<'a> {
             command_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -34,7 +36,8 @@ impl<'a> DeleteGlobalCommand<'a> {
             command_id: self.command_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ApplicationId, CommandId, GuildId};
@@ -13,6 +13,7 @@ pub struct DeleteGuildCommand<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> DeleteGuildCommand<'a> {
@@ -28,6 +29,7 @@ impl<'a> DeleteGuildCommand<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -38,7 +40,8 @@ impl<'a> DeleteGuildCommand<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::ApplicationId;
@@ -30,6 +30,7 @@ pub struct DeleteOriginalResponse<'a> {
     application_id: ApplicationId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
     token: String,
 }
 
@@ -43,6 +44,7 @@ impl<'a> DeleteOriginalResponse<'a> {
             application_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
             token: token.into(),
         }
     }
@@ -58,7 +60,8 @@ impl<'a> DeleteOriginalResponse<'a> {
 
     fn start(&mut self) -> Result<(), Error> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
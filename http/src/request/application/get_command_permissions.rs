@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -16,6 +16,7 @@ pub struct GetCommandPermissions<'a> {
     guild_id: GuildId,
     fut: Option<Pending<'a, GuildCommandPermissions>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetCommandPermissions<'a> {
@@ -31,6 +32,7 @@ impl<'a> GetCommandPermissions<'a> {
             guild_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -41,7 +43,8 @@ impl<'a> GetCommandPermissions<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
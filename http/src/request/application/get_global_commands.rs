@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{application::command::Command, id::ApplicationId};
@@ -11,6 +11,7 @@ pub struct GetGlobalCommands<'a> {
     application_id: ApplicationId,
     fut: Option<Pending<'a, Vec<Command>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGlobalCommands<'a> {
@@ -19,6 +20,7 @@ impl<'a> GetGlobalCommands<'a> {
             application_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -27,7 +29,8 @@ impl<'a> GetGlobalCommands<'a> {
             application_id: self.application_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
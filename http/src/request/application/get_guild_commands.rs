@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -15,6 +15,7 @@ pub struct GetGuildCommands<'a> {
     guild_id: GuildId,
     fut: Option<Pending<'a, Vec<Command>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildCommands<'a> {
@@ -24,6 +25,7 @@ impl<'a> GetGuildCommands<'a> {
             guild_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -33,7 +35,8 @@ impl<'a> GetGuildCommands<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
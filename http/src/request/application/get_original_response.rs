@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{channel::Message, id::ApplicationId};
@@ -30,6 +30,7 @@ pub struct GetOriginalResponse<'a> {
     application_id: ApplicationId,
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
+    options: RequestOptions,
     token: String,
 }
 
@@ -43,6 +44,7 @@ impl<'a> GetOriginalResponse<'a> {
             application_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
             token: token.into(),
         }
     }
@@ -58,7 +60,9 @@ impl<'a> GetOriginalResponse<'a> {
 
     fn start(&mut self) -> Result<(), Error> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{application::callback::InteractionResponse, id::InteractionId};
@@ -10,6 +10,7 @@ use twilight_model::{application::callback::InteractionResponse, id::Interaction
 pub struct InteractionCallback<'a> {
     interaction_id: InteractionId,
     interaction_token: String,
+    options: RequestOptions,
     response: InteractionResponse,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
@@ -25,6 +26,7 @@ impl<'a> InteractionCallback<'a> {
         Self {
             interaction_id,
             interaction_token: interaction_token.into(),
+            options: RequestOptions::default(),
             response,
             fut: None,
             http,
@@ -38,8 +40,9 @@ impl<'a> InteractionCallback<'a> {
         })
         .json(&self.response)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
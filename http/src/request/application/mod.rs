@@ -14,6 +14,7 @@ mod interaction_callback;
 mod set_command_permissions;
 mod set_global_commands;
 mod set_guild_commands;
+mod sync_commands;
 mod update_command_permissions;
 mod update_followup_message;
 mod update_global_command;
@@ -21,7 +22,9 @@ mod update_guild_command;
 mod update_original_response;
 
 pub use self::{
-    create_followup_message::CreateFollowupMessage,
+    create_followup_message::{
+        CreateFollowupMessage, CreateFollowupMessageError, CreateFollowupMessageErrorType,
+    },
     create_global_command::CreateGlobalCommand,
     create_guild_command::CreateGuildCommand,
     delete_followup_message::DeleteFollowupMessage,
@@ -37,6 +40,7 @@ pub use self::{
     set_command_permissions::SetCommandPermissions,
     set_global_commands::SetGlobalCommands,
     set_guild_commands::SetGuildCommands,
+    sync_commands::{CommandChange, CommandSyncPlan, SyncCommands},
     update_command_permissions::UpdateCommandPermissions,
     update_followup_message::{
         UpdateFollowupMessage, UpdateFollowupMessageError, UpdateFollowupMessageErrorType,
@@ -71,6 +75,8 @@ pub enum InteractionErrorType {
     CommandDescriptionValidationFailed { description: String },
     /// Required command options have to be passed before optional ones.
     CommandOptionsRequiredFirst { option: CommandOption },
+    /// Options were added to a context menu command, which can't have any.
+    CommandOptionsNotAllowed { option: CommandOption },
     /// More than 10 permission overwrites were set.
     TooManyCommandPermissions,
 }
@@ -103,7 +109,7 @@ impl Display for InteractionError {
                 f.write_str("application id not present")
             }
             InteractionErrorType::CommandNameValidationFailed { .. } => {
-                f.write_str("command name must be between 3 and 32 characters")
+                f.write_str("command name must be between 3 and 32 characters, or between 1 and 32 characters for context menu commands")
             }
             InteractionErrorType::CommandDescriptionValidationFailed { .. } => {
                 f.write_str("command description must be between 1 and 100 characters")
@@ -111,6 +117,9 @@ impl Display for InteractionError {
             InteractionErrorType::CommandOptionsRequiredFirst { .. } => {
                 f.write_str("optional command options must be added after required")
             }
+            InteractionErrorType::CommandOptionsNotAllowed { .. } => {
+                f.write_str("context menu commands can't have options")
+            }
             InteractionErrorType::TooManyCommandPermissions { .. } => {
                 f.write_str("more than 10 permission overwrites were set")
             }
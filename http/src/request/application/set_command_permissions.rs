@@ -3,7 +3,7 @@ use crate::{
     error::Error,
     request::{
         application::{InteractionError, InteractionErrorType},
-        validate, Pending, Request,
+        validate, Pending, Request, RequestOptions,
     },
     routing::Route,
 };
@@ -30,6 +30,7 @@ pub struct SetCommandPermissions<'a> {
     fields: Vec<PartialGuildCommandPermissions>,
     fut: Option<Pending<'a, CommandPermissions>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> SetCommandPermissions<'a> {
@@ -71,6 +72,7 @@ impl<'a> SetCommandPermissions<'a> {
             fields,
             fut: None,
             http,
+            options: RequestOptions::default(),
         })
     }
 
@@ -81,8 +83,9 @@ impl<'a> SetCommandPermissions<'a> {
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{application::command::Command, id::ApplicationId};
@@ -15,6 +15,7 @@ pub struct SetGlobalCommands<'a> {
     application_id: ApplicationId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> SetGlobalCommands<'a> {
@@ -28,6 +29,7 @@ impl<'a> SetGlobalCommands<'a> {
             application_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -37,8 +39,9 @@ impl<'a> SetGlobalCommands<'a> {
         })
         .json(&self.commands)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -19,6 +19,7 @@ pub struct SetGuildCommands<'a> {
     guild_id: GuildId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> SetGuildCommands<'a> {
@@ -34,6 +35,7 @@ impl<'a> SetGuildCommands<'a> {
             guild_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -44,8 +46,9 @@ impl<'a> SetGuildCommands<'a> {
         })
         .json(&self.commands)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
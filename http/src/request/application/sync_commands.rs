@@ -0,0 +1,544 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, RequestOptions},
+    routing::Route,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use twilight_model::{
+    application::command::Command,
+    id::{ApplicationId, CommandId, GuildId},
+};
+
+/// Change to the registered commands needed to match the local commands.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CommandChange {
+    /// Command is not registered and will be created.
+    Create {
+        /// Local command to create.
+        command: Command,
+    },
+    /// Command is registered but not defined locally, and will be deleted.
+    Delete {
+        /// ID of the registered command.
+        id: CommandId,
+        /// Name of the registered command.
+        name: String,
+    },
+    /// Command is registered but differs from the local command, and will be
+    /// updated.
+    Update {
+        /// Local command to update the registered command to.
+        command: Command,
+        /// ID of the registered command.
+        id: CommandId,
+    },
+}
+
+impl Display for CommandChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Create { command } => {
+                f.write_str("create `")?;
+                f.write_str(&command.name)?;
+
+                f.write_str("`")
+            }
+            Self::Delete { id, name } => {
+                f.write_str("delete `")?;
+                f.write_str(name)?;
+                f.write_str("` (")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(")")
+            }
+            Self::Update { command, id } => {
+                f.write_str("update `")?;
+                f.write_str(&command.name)?;
+                f.write_str("` (")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// Changes needed to make the registered commands match the local commands.
+///
+/// Displaying the plan lists each change on its own line, which is useful as
+/// a report of a dry run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommandSyncPlan {
+    changes: Vec<CommandChange>,
+}
+
+impl CommandSyncPlan {
+    /// Compute the changes between local and registered commands.
+    ///
+    /// Commands are matched by their type and name. Deletions are listed
+    /// first, then updates, and then creations.
+    pub(crate) fn diff(local: &[Command], registered: Vec<Command>) -> Self {
+        let mut deletes = Vec::new();
+        let mut updates = Vec::new();
+        let mut matched = Vec::new();
+
+        for existing in registered {
+            let id = match existing.id {
+                Some(id) => id,
+                None => continue,
+            };
+
+            match local
+                .iter()
+                .position(|command| same_key(command, &existing))
+            {
+                Some(index) => {
+                    matched.push(index);
+
+                    if !same_definition(&local[index], &existing) {
+                        updates.push(CommandChange::Update {
+                            command: local[index].clone(),
+                            id,
+                        });
+                    }
+                }
+                None => deletes.push(CommandChange::Delete {
+                    id,
+                    name: existing.name,
+                }),
+            }
+        }
+
+        let creates = local
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !matched.contains(index))
+            .map(|(_, command)| CommandChange::Create {
+                command: command.clone(),
+            });
+
+        let mut changes = deletes;
+        changes.extend(updates);
+        changes.extend(creates);
+
+        Self { changes }
+    }
+
+    /// Changes in the order they are applied.
+    pub fn changes(&self) -> &[CommandChange] {
+        &self.changes
+    }
+
+    /// Whether the registered commands already match the local commands.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for CommandSyncPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.changes.is_empty() {
+            return f.write_str("no changes");
+        }
+
+        for (index, change) in self.changes.iter().enumerate() {
+            if index > 0 {
+                f.write_str("\n")?;
+            }
+
+            Display::fmt(change, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Synchronize the registered commands of an application with local
+/// definitions.
+///
+/// Unlike [`SetGlobalCommands`] and [`SetGuildCommands`], which overwrite all
+/// commands, this fetches the registered commands, computes the
+/// [`CommandSyncPlan`] of changes, and only creates, updates, or deletes the
+/// commands that differ. Discord limits the number of commands that can be
+/// created per day, so only creating new commands avoids hitting that limit
+/// when deploying often.
+///
+/// Use [`plan`] to perform a dry run, [`apply`] to apply a plan, or [`exec`]
+/// to do both.
+///
+/// # Examples
+///
+/// Report the changes that would be made before making them:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     application::command::{Command, CommandType},
+///     id::ApplicationId,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+/// client.set_application_id(ApplicationId(1));
+///
+/// let commands = vec![Command {
+///     application_id: None,
+///     guild_id: None,
+///     name: "ping".to_owned(),
+///     default_permission: None,
+///     description: "Ping the bot".to_owned(),
+///     id: None,
+///     kind: CommandType::ChatInput,
+///     options: Vec::new(),
+/// }];
+///
+/// let sync = client.sync_global_commands(commands)?;
+/// let plan = sync.plan().await?;
+/// println!("{}", plan);
+///
+/// sync.apply(&plan).await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`SetGlobalCommands`]: super::SetGlobalCommands
+/// [`SetGuildCommands`]: super::SetGuildCommands
+/// [`apply`]: Self::apply
+/// [`exec`]: Self::exec
+/// [`plan`]: Self::plan
+pub struct SyncCommands<'a> {
+    application_id: ApplicationId,
+    commands: Vec<Command>,
+    guild_id: Option<GuildId>,
+    http: &'a Client,
+    options: RequestOptions,
+}
+
+impl<'a> SyncCommands<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        commands: Vec<Command>,
+    ) -> Self {
+        Self {
+            application_id,
+            commands,
+            guild_id,
+            http,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Fetch the registered commands and compute the changes needed to match
+    /// the local commands, without making them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registered commands could not be fetched.
+    pub async fn plan(&self) -> Result<CommandSyncPlan, Error> {
+        let route = match self.guild_id {
+            Some(guild_id) => Route::GetGuildCommands {
+                application_id: self.application_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::GetGlobalCommands {
+                application_id: self.application_id.0,
+            },
+        };
+
+        let registered = self
+            .http
+            .request::<Vec<Command>>(self.options.apply(Request::from_route(route)))
+            .await?;
+
+        Ok(CommandSyncPlan::diff(&self.commands, registered))
+    }
+
+    /// Apply the changes of a plan, in order.
+    ///
+    /// Changes are applied one at a time. If a change fails then the changes
+    /// before it have already been applied and the changes after it are not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a change could not be applied.
+    pub async fn apply(&self, plan: &CommandSyncPlan) -> Result<(), Error> {
+        for change in &plan.changes {
+            let request = match change {
+                CommandChange::Create { command } => {
+                    Request::builder(self.route_create()).json(command)?.build()
+                }
+                CommandChange::Delete { id, .. } => Request::from_route(self.route_delete(*id)),
+                CommandChange::Update { command, id } => Request::builder(self.route_update(*id))
+                    .json(command)?
+                    .build(),
+            };
+
+            self.http.verify(self.options.apply(request)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Compute the changes needed to match the local commands and apply them,
+    /// returning the applied plan.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registered commands could not be fetched or a
+    /// change could not be applied.
+    pub async fn exec(&self) -> Result<CommandSyncPlan, Error> {
+        let plan = self.plan().await?;
+        self.apply(&plan).await?;
+
+        Ok(plan)
+    }
+
+    fn route_create(&self) -> Route {
+        match self.guild_id {
+            Some(guild_id) => Route::CreateGuildCommand {
+                application_id: self.application_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::CreateGlobalCommand {
+                application_id: self.application_id.0,
+            },
+        }
+    }
+
+    fn route_delete(&self, command_id: CommandId) -> Route {
+        match self.guild_id {
+            Some(guild_id) => Route::DeleteGuildCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::DeleteGlobalCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+            },
+        }
+    }
+
+    fn route_update(&self, command_id: CommandId) -> Route {
+        match self.guild_id {
+            Some(guild_id) => Route::UpdateGuildCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::UpdateGlobalCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+            },
+        }
+    }
+}
+
+request_options!(SyncCommands<'_>);
+
+/// Whether two commands are the same command, which is when they have the
+/// same type and name.
+fn same_key(local: &Command, registered: &Command) -> bool {
+    local.kind == registered.kind && local.name == registered.name
+}
+
+/// Whether the definition of a local command matches a registered command,
+/// ignoring fields set by Discord.
+fn same_definition(local: &Command, registered: &Command) -> bool {
+    // Commands are enabled by default, which Discord returns explicitly.
+    local.default_permission.unwrap_or(true) == registered.default_permission.unwrap_or(true)
+        && local.description == registered.description
+        && local.options == registered.options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandChange, CommandSyncPlan};
+    use crate::{
+        client::Client,
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+    };
+    use hyper::{Method, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::command::{BaseCommandOptionData, Command, CommandOption, CommandType},
+        id::{ApplicationId, CommandId},
+    };
+
+    assert_impl_all!(CommandChange: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CommandSyncPlan: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn command(name: &str, description: &str) -> Command {
+        Command {
+            application_id: None,
+            guild_id: None,
+            name: name.to_owned(),
+            default_permission: None,
+            description: description.to_owned(),
+            id: None,
+            kind: CommandType::ChatInput,
+            options: Vec::new(),
+        }
+    }
+
+    fn registered(id: u64, mut command: Command) -> Command {
+        command.application_id = Some(ApplicationId(1));
+        command.default_permission = Some(true);
+        command.id = Some(CommandId(id));
+
+        command
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut ban = command("ban", "Ban a user");
+        ban.options.push(CommandOption::User(BaseCommandOptionData {
+            description: "User to ban".to_owned(),
+            name: "user".to_owned(),
+            required: true,
+        }));
+        let local = vec![
+            command("ping", "Ping the bot"),
+            ban.clone(),
+            command("kick", "Kick a user"),
+        ];
+        let mut report = command("Report", "");
+        report.kind = CommandType::Message;
+
+        let plan = CommandSyncPlan::diff(
+            &local,
+            vec![
+                registered(1, command("ban", "Ban a user")),
+                registered(2, command("ping", "Ping the bot")),
+                registered(3, report),
+            ],
+        );
+
+        assert_eq!(
+            [
+                CommandChange::Delete {
+                    id: CommandId(3),
+                    name: "Report".to_owned(),
+                },
+                CommandChange::Update {
+                    command: ban,
+                    id: CommandId(1),
+                },
+                CommandChange::Create {
+                    command: command("kick", "Kick a user"),
+                },
+            ],
+            plan.changes()
+        );
+        assert_eq!(
+            "delete `Report` (3)\nupdate `ban` (1)\ncreate `kick`",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn test_diff_unchanged() {
+        let mut disabled = command("admin", "Admin commands");
+        disabled.default_permission = Some(false);
+
+        let plan = CommandSyncPlan::diff(
+            &[command("ping", "Ping the bot"), disabled.clone()],
+            vec![
+                registered(1, command("ping", "Ping the bot")),
+                registered(2, command("admin", "Admin commands")),
+            ],
+        );
+
+        assert_eq!(
+            [CommandChange::Update {
+                command: disabled,
+                id: CommandId(2),
+            }],
+            plan.changes()
+        );
+
+        let plan = CommandSyncPlan::diff(
+            &[command("ping", "Ping the bot")],
+            vec![registered(1, command("ping", "Ping the bot"))],
+        );
+
+        assert!(plan.is_empty());
+        assert_eq!("no changes", plan.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_exec() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::GetGlobalCommands { application_id: 1 },
+            MockResponse::json(&vec![
+                registered(2, command("ping", "Ping the bot")),
+                registered(3, command("old", "Old command")),
+                registered(4, command("echo", "Echo a message")),
+            ])
+            .unwrap(),
+        );
+        transport.respond(
+            &Route::DeleteGlobalCommand {
+                application_id: 1,
+                command_id: 3,
+            },
+            MockResponse::new(StatusCode::NO_CONTENT),
+        );
+        transport.respond(
+            &Route::UpdateGlobalCommand {
+                application_id: 1,
+                command_id: 4,
+            },
+            MockResponse::new(StatusCode::OK),
+        );
+        transport.respond(
+            &Route::CreateGlobalCommand { application_id: 1 },
+            MockResponse::new(StatusCode::CREATED),
+        );
+
+        let client = Client::builder()
+            .token("token")
+            .transport(Box::new(transport.clone()))
+            .build();
+        client.set_application_id(ApplicationId(1));
+
+        let plan = client
+            .sync_global_commands(vec![
+                command("ping", "Ping the bot"),
+                command("echo", "Repeat a message"),
+                command("new", "New command"),
+            ])
+            .unwrap()
+            .exec()
+            .await
+            .unwrap();
+
+        assert_eq!(3, plan.changes().len());
+        assert_eq!(0, transport.remaining());
+
+        let requests = transport.requests();
+        let methods = requests
+            .iter()
+            .map(|request| (request.method.clone(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            [
+                (Method::GET, "applications/1/commands"),
+                (Method::DELETE, "applications/1/commands/3"),
+                (Method::PATCH, "applications/1/commands/4"),
+                (Method::POST, "applications/1/commands"),
+            ],
+            methods.as_slice()
+        );
+
+        let created = serde_json::from_slice::<Command>(&requests[3].body).unwrap();
+        assert_eq!(command("new", "New command"), created);
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     error::Error,
     request::{
         application::{InteractionError, InteractionErrorType},
-        validate, Pending, Request,
+        validate, Pending, Request, RequestOptions,
     },
     routing::Route,
 };
@@ -31,6 +31,7 @@ pub struct UpdateCommandPermissions<'a> {
     fields: UpdateCommandPermissionsFields,
     fut: Option<Pending<'a, Vec<CommandPermissions>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateCommandPermissions<'a> {
@@ -54,6 +55,7 @@ impl<'a> UpdateCommandPermissions<'a> {
             fields: UpdateCommandPermissionsFields { permissions },
             fut: None,
             http,
+            options: RequestOptions::default(),
         })
     }
 
@@ -65,8 +67,9 @@ impl<'a> UpdateCommandPermissions<'a> {
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
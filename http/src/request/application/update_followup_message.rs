@@ -3,7 +3,10 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Form, NullableField, Pending, Request},
+    request::{
+        validate, AttachmentEntry, AttachmentFile, Form, NullableField, PartialAttachment, Pending,
+        Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
@@ -12,6 +15,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Attachment},
     id::{ApplicationId, MessageId},
 };
@@ -51,6 +55,9 @@ impl UpdateFollowupMessageError {
 impl Display for UpdateFollowupMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateFollowupMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("message components are invalid")
+            }
             UpdateFollowupMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("message content is invalid")
             }
@@ -78,6 +85,13 @@ impl Error for UpdateFollowupMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateFollowupMessageErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -108,7 +122,9 @@ struct UpdateFollowupMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<AllowedMentions>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    attachments: Vec<Attachment>,
+    attachments: Vec<AttachmentEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -154,10 +170,11 @@ struct UpdateFollowupMessageFields {
 /// [`DeleteFollowupMessage`]: super::DeleteFollowupMessage
 pub struct UpdateFollowupMessage<'a> {
     fields: UpdateFollowupMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
     token: String,
     application_id: ApplicationId,
 }
@@ -181,6 +198,7 @@ impl<'a> UpdateFollowupMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
             token: token.into(),
             application_id,
         }
@@ -198,7 +216,9 @@ impl<'a> UpdateFollowupMessage<'a> {
     /// If called, all unspecified attachments will be removed from the message.
     /// If not called, all attachments will be kept.
     pub fn attachment(mut self, attachment: Attachment) -> Self {
-        self.fields.attachments.push(attachment);
+        self.fields
+            .attachments
+            .push(AttachmentEntry::Existing(attachment));
 
         self
     }
@@ -210,11 +230,74 @@ impl<'a> UpdateFollowupMessage<'a> {
     pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
         self.fields
             .attachments
-            .extend(attachments.into_iter().collect::<Vec<Attachment>>());
+            .extend(attachments.into_iter().map(AttachmentEntry::Existing));
+
+        self
+    }
+
+    /// Attach a file to the followup message, whose contents may be streamed.
+    ///
+    /// The [description] of the file is only sent if the existing attachments
+    /// to keep are specified with [`attachment`] or [`attachments`], because
+    /// sending it removes all other attachments from the message.
+    ///
+    /// This method is repeatable.
+    ///
+    /// [`attachment`]: Self::attachment
+    /// [`attachments`]: Self::attachments
+    /// [description]: crate::request::AttachmentFile::description
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the followup message, whose contents may be streamed.
+    ///
+    /// Refer to [`attachment_file`] for how the descriptions of the files are
+    /// sent.
+    ///
+    /// [`attachment_file`]: Self::attachment_file
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
 
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateFollowupMessageErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateFollowupMessageError> {
+        if let Some(components_ref) = components.as_deref() {
+            if let Err(source) = validate::components(components_ref) {
+                return Err(UpdateFollowupMessageError {
+                    kind: UpdateFollowupMessageErrorType::ComponentsInvalid {
+                        components: components.expect("components are known to be present"),
+                    },
+                    source: Some(Box::new(source)),
+                });
+            }
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -334,7 +417,7 @@ impl<'a> UpdateFollowupMessage<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -369,6 +452,7 @@ impl<'a> UpdateFollowupMessage<'a> {
     fn request(&mut self) -> Result<Request, HttpError> {
         let mut request = Request::builder(Route::UpdateWebhookMessage {
             message_id: self.message_id.0,
+            thread_id: None,
             token: self.token.clone(),
             webhook_id: self.application_id.0,
         });
@@ -376,13 +460,24 @@ impl<'a> UpdateFollowupMessage<'a> {
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
 
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+            for (index, file) in self.files.iter().enumerate() {
+                form.attachment(format!("files[{}]", index).as_bytes(), file);
             }
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
+                // Sending the list of attachments removes the ones that
+                // aren't in it, so it's only sent if the attachments to keep
+                // were specified.
+                if !self.fields.attachments.is_empty() {
+                    if let Some(attachments) = PartialAttachment::from_files(&self.files) {
+                        self.fields
+                            .attachments
+                            .extend(attachments.into_iter().map(AttachmentEntry::New));
+                    }
+                }
+
                 let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
@@ -397,7 +492,8 @@ impl<'a> UpdateFollowupMessage<'a> {
 
     fn start(&mut self) -> Result<(), HttpError> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -31,6 +31,7 @@ pub struct UpdateGlobalCommand<'a> {
     application_id: ApplicationId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateGlobalCommand<'a> {
@@ -45,6 +46,7 @@ impl<'a> UpdateGlobalCommand<'a> {
             fields: UpdateGlobalCommandFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -80,8 +82,9 @@ impl<'a> UpdateGlobalCommand<'a> {
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -32,6 +32,7 @@ pub struct UpdateGuildCommand<'a> {
     guild_id: GuildId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateGuildCommand<'a> {
@@ -48,6 +49,7 @@ impl<'a> UpdateGuildCommand<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -84,8 +86,9 @@ impl<'a> UpdateGuildCommand<'a> {
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
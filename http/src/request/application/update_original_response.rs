@@ -3,7 +3,10 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Form, NullableField, Pending, Request},
+    request::{
+        validate, AttachmentEntry, AttachmentFile, Form, NullableField, PartialAttachment, Pending,
+        Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
@@ -12,6 +15,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Attachment, Message},
     id::ApplicationId,
 };
@@ -51,6 +55,9 @@ impl UpdateOriginalResponseError {
 impl Display for UpdateOriginalResponseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateOriginalResponseErrorType::ComponentsInvalid { .. } => {
+                f.write_str("message components are invalid")
+            }
             UpdateOriginalResponseErrorType::ContentInvalid { .. } => {
                 f.write_str("message content is invalid")
             }
@@ -78,6 +85,13 @@ impl Error for UpdateOriginalResponseError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateOriginalResponseErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -108,7 +122,9 @@ struct UpdateOriginalResponseFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<AllowedMentions>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    attachments: Vec<Attachment>,
+    attachments: Vec<AttachmentEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -155,9 +171,10 @@ struct UpdateOriginalResponseFields {
 pub struct UpdateOriginalResponse<'a> {
     application_id: ApplicationId,
     fields: UpdateOriginalResponseFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
+    options: RequestOptions,
     token: String,
 }
 
@@ -179,6 +196,7 @@ impl<'a> UpdateOriginalResponse<'a> {
             files: Vec::new(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             token: interaction_token.into(),
         }
     }
@@ -195,7 +213,9 @@ impl<'a> UpdateOriginalResponse<'a> {
     /// If called, all unspecified attachments will be removed from the message.
     /// If not called, all attachments will be kept.
     pub fn attachment(mut self, attachment: Attachment) -> Self {
-        self.fields.attachments.push(attachment);
+        self.fields
+            .attachments
+            .push(AttachmentEntry::Existing(attachment));
 
         self
     }
@@ -207,11 +227,74 @@ impl<'a> UpdateOriginalResponse<'a> {
     pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
         self.fields
             .attachments
-            .extend(attachments.into_iter().collect::<Vec<Attachment>>());
+            .extend(attachments.into_iter().map(AttachmentEntry::Existing));
+
+        self
+    }
+
+    /// Attach a file to the original response, whose contents may be streamed.
+    ///
+    /// The [description] of the file is only sent if the existing attachments
+    /// to keep are specified with [`attachment`] or [`attachments`], because
+    /// sending it removes all other attachments from the message.
+    ///
+    /// This method is repeatable.
+    ///
+    /// [`attachment`]: Self::attachment
+    /// [`attachments`]: Self::attachments
+    /// [description]: crate::request::AttachmentFile::description
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the original response, whose contents may be streamed.
+    ///
+    /// Refer to [`attachment_file`] for how the descriptions of the files are
+    /// sent.
+    ///
+    /// [`attachment_file`]: Self::attachment_file
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
 
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateOriginalResponseErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateOriginalResponseError> {
+        if let Some(components_ref) = components.as_deref() {
+            if let Err(source) = validate::components(components_ref) {
+                return Err(UpdateOriginalResponseError {
+                    kind: UpdateOriginalResponseErrorType::ComponentsInvalid {
+                        components: components.expect("components are known to be present"),
+                    },
+                    source: Some(Box::new(source)),
+                });
+            }
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -332,7 +415,7 @@ impl<'a> UpdateOriginalResponse<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -373,13 +456,24 @@ impl<'a> UpdateOriginalResponse<'a> {
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
 
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+            for (index, file) in self.files.iter().enumerate() {
+                form.attachment(format!("files[{}]", index).as_bytes(), file);
             }
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
+                // Sending the list of attachments removes the ones that
+                // aren't in it, so it's only sent if the attachments to keep
+                // were specified.
+                if !self.fields.attachments.is_empty() {
+                    if let Some(attachments) = PartialAttachment::from_files(&self.files) {
+                        self.fields
+                            .attachments
+                            .extend(attachments.into_iter().map(AttachmentEntry::New));
+                    }
+                }
+
                 let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
@@ -394,7 +488,8 @@ impl<'a> UpdateOriginalResponse<'a> {
 
     fn start(&mut self) -> Result<(), HttpError> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
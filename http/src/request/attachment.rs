@@ -0,0 +1,505 @@
+use futures_util::stream::Stream;
+use hyper::body::Bytes;
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, ReadBuf};
+use twilight_model::channel::Attachment;
+
+/// Stream of chunks of the contents of a file.
+pub(crate) type FileStream =
+    Pin<Box<dyn Stream<Item = Result<Bytes, Box<dyn Error + Send + Sync>>> + Send>>;
+
+/// Stream shared by the clones of a file, which can only be read once.
+pub(crate) type SharedFileStream = Arc<Mutex<Option<FileStream>>>;
+
+/// Size of the chunks read from readers.
+const CHUNK_SIZE: usize = 8192;
+
+/// Prefix of the names of files that are marked as spoilers.
+const SPOILER_PREFIX: &str = "SPOILER_";
+
+/// Contents of a file, either in memory or streamed.
+#[derive(Clone)]
+pub(crate) enum FileSource {
+    Bytes(Bytes),
+    Stream(SharedFileStream),
+}
+
+/// File to upload as an attachment of a message.
+///
+/// The contents of a file can be in memory, or be streamed from an
+/// [`AsyncRead`] or a [`Stream`] of bytes, so that large files don't have to
+/// be loaded into memory. The length of streamed files must be known
+/// beforehand, so that the size of all attachments of a message can be
+/// checked against the client's [attachment size limit] before the request
+/// is sent.
+///
+/// Streamed files can only be sent once, so requests uploading them can't
+/// be retried.
+///
+/// # Examples
+///
+/// Upload a file from the filesystem as a spoiler:
+///
+/// ```no_run
+/// use tokio::fs::File;
+/// use twilight_http::{request::AttachmentFile, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+///
+/// let file = File::open("video.mp4").await?;
+/// let len = file.metadata().await?.len();
+///
+/// client
+///     .create_message(ChannelId(1))
+///     .attachment_file(
+///         AttachmentFile::from_reader("video.mp4", len, file)
+///             .description("a cat chasing a laser pointer")
+///             .spoiler(true),
+///     )
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [attachment size limit]: crate::client::ClientBuilder::attachment_size_limit
+#[derive(Clone)]
+pub struct AttachmentFile {
+    description: Option<String>,
+    filename: String,
+    len: u64,
+    pub(crate) source: FileSource,
+    spoiler: bool,
+}
+
+impl AttachmentFile {
+    /// Create a file from its contents in memory.
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        let bytes = Bytes::from(bytes.into());
+
+        Self::new(
+            filename.into(),
+            bytes.len() as u64,
+            FileSource::Bytes(bytes),
+        )
+    }
+
+    /// Create a file streamed from a reader, such as a file on the
+    /// filesystem.
+    ///
+    /// The length is the number of bytes that the reader yields. Sending the
+    /// file fails if the reader yields a different number of bytes.
+    pub fn from_reader(
+        filename: impl Into<String>,
+        len: u64,
+        reader: impl AsyncRead + Send + 'static,
+    ) -> Self {
+        Self::from_stream(
+            filename,
+            len,
+            ReaderStream {
+                reader: Box::pin(reader),
+            },
+        )
+    }
+
+    /// Create a file streamed from a stream of chunks of bytes, such as the
+    /// body of a response being forwarded.
+    ///
+    /// The length is the total number of bytes of the chunks. Sending the
+    /// file fails if the stream yields a different number of bytes.
+    pub fn from_stream<E: Into<Box<dyn Error + Send + Sync>>>(
+        filename: impl Into<String>,
+        len: u64,
+        stream: impl Stream<Item = Result<Bytes, E>> + Send + 'static,
+    ) -> Self {
+        let stream = LengthStream {
+            failed: false,
+            inner: Box::pin(stream),
+            remaining: len,
+        };
+
+        Self::new(
+            filename.into(),
+            len,
+            FileSource::Stream(Arc::new(Mutex::new(Some(Box::pin(stream))))),
+        )
+    }
+
+    const fn new(filename: String, len: u64, source: FileSource) -> Self {
+        Self {
+            description: None,
+            filename,
+            len,
+            source,
+            spoiler: false,
+        }
+    }
+
+    /// Set the description of the file, used as alternative text for images.
+    ///
+    /// When updating a message, the description is only sent if the existing
+    /// attachments to keep are specified, because sending it removes all
+    /// other attachments from the message.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+
+        self
+    }
+
+    /// Set whether the file is marked as a spoiler.
+    ///
+    /// Defaults to false.
+    pub const fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+
+        self
+    }
+
+    /// Name of the file, including the spoiler prefix if the file is marked
+    /// as a spoiler.
+    pub fn filename(&self) -> String {
+        if self.spoiler && !self.filename.starts_with(SPOILER_PREFIX) {
+            format!("{}{}", SPOILER_PREFIX, self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+
+    /// Whether the contents of the file are streamed.
+    pub const fn is_streamed(&self) -> bool {
+        matches!(self.source, FileSource::Stream(_))
+    }
+
+    /// Length of the contents of the file in bytes.
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the file is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Debug for AttachmentFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AttachmentFile")
+            .field("description", &self.description)
+            .field("filename", &self.filename)
+            .field("len", &self.len)
+            .field("spoiler", &self.spoiler)
+            .field("streamed", &self.is_streamed())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Information about a file uploaded with a message, referring to its part of
+/// the multipart form by ID.
+#[derive(Serialize)]
+pub(crate) struct PartialAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    filename: String,
+    id: usize,
+}
+
+impl PartialAttachment {
+    /// Information about the files of a message, if any of them has a
+    /// description.
+    ///
+    /// Without descriptions the information isn't needed, so it isn't sent.
+    pub(crate) fn from_files(files: &[AttachmentFile]) -> Option<Vec<Self>> {
+        if files.iter().all(|file| file.description.is_none()) {
+            return None;
+        }
+
+        Some(
+            files
+                .iter()
+                .enumerate()
+                .map(|(id, file)| Self {
+                    description: file.description.clone(),
+                    filename: file.filename(),
+                    id,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Attachment of a message being updated, which is either an existing
+/// attachment to keep or a new file.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub(crate) enum AttachmentEntry {
+    Existing(Attachment),
+    New(PartialAttachment),
+}
+
+/// Stream reading chunks from a reader.
+struct ReaderStream<R> {
+    reader: Pin<Box<R>>,
+}
+
+impl<R: AsyncRead> Stream for ReaderStream<R> {
+    type Item = Result<Bytes, IoError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut chunk = [0; CHUNK_SIZE];
+        let mut buf = ReadBuf::new(&mut chunk);
+
+        match self.reader.as_mut().poll_read(cx, &mut buf) {
+            Poll::Ready(Ok(())) if buf.filled().is_empty() => Poll::Ready(None),
+            Poll::Ready(Ok(())) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(buf.filled())))),
+            Poll::Ready(Err(source)) => Poll::Ready(Some(Err(source))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Stream failing if the stream it wraps yields a different number of bytes
+/// than expected.
+///
+/// The stream ends after yielding an error.
+struct LengthStream<S> {
+    failed: bool,
+    inner: Pin<Box<S>>,
+    remaining: u64,
+}
+
+impl<E: Into<Box<dyn Error + Send + Sync>>, S: Stream<Item = Result<Bytes, E>>> Stream
+    for LengthStream<S>
+{
+    type Item = Result<Bytes, Box<dyn Error + Send + Sync>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.failed {
+            return Poll::Ready(None);
+        }
+
+        let item = match self.inner.as_mut().poll_next(cx) {
+            Poll::Ready(item) => item,
+            Poll::Pending => return Poll::Pending,
+        };
+
+        let item: Option<Self::Item> = match item {
+            Some(Ok(chunk)) => {
+                let len = chunk.len() as u64;
+
+                if len > self.remaining {
+                    Some(Err(Box::new(LengthMismatch { longer: true })))
+                } else {
+                    self.remaining -= len;
+
+                    Some(Ok(chunk))
+                }
+            }
+            Some(Err(source)) => Some(Err(source.into())),
+            None if self.remaining > 0 => Some(Err(Box::new(LengthMismatch { longer: false }))),
+            None => None,
+        };
+
+        self.failed = matches!(item, Some(Err(_)));
+
+        Poll::Ready(item)
+    }
+}
+
+/// Streamed file yielded a different number of bytes than its length.
+#[derive(Debug)]
+struct LengthMismatch {
+    longer: bool,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.longer {
+            f.write_str("streamed file is longer than its length")
+        } else {
+            f.write_str("streamed file is shorter than its length")
+        }
+    }
+}
+
+impl Error for LengthMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttachmentFile, PartialAttachment};
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+    };
+    use futures_util::stream::{self, StreamExt};
+    use hyper::{body::Bytes, header::CONTENT_LENGTH, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, io::Error as IoError};
+    use twilight_model::{
+        channel::Attachment,
+        id::{AttachmentId, MessageId, WebhookId},
+    };
+
+    assert_impl_all!(AttachmentFile: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_filename() {
+        let file = AttachmentFile::from_bytes("cat.png", vec![1, 2, 3]);
+        assert_eq!("cat.png", file.filename());
+        assert_eq!(3, file.len());
+        assert!(!file.is_streamed());
+
+        let file = file.spoiler(true);
+        assert_eq!("SPOILER_cat.png", file.filename());
+    }
+
+    #[test]
+    fn test_partial_attachments() {
+        let files = [
+            AttachmentFile::from_bytes("a.png", vec![]),
+            AttachmentFile::from_bytes("b.png", vec![]),
+        ];
+        assert!(PartialAttachment::from_files(&files).is_none());
+
+        let files = [files[0].clone(), files[1].clone().description("b")];
+        let attachments = PartialAttachment::from_files(&files).unwrap();
+
+        assert_eq!(
+            r#"[{"filename":"a.png","id":0},{"description":"b","filename":"b.png","id":1}]"#,
+            serde_json::to_string(&attachments).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_length() {
+        let chunks = || {
+            stream::iter(vec![
+                Ok::<_, IoError>(Bytes::from_static(b"ab")),
+                Ok(Bytes::from_static(b"cd")),
+            ])
+        };
+
+        for (len, valid) in &[(3, false), (4, true), (5, false)] {
+            let file = AttachmentFile::from_stream("file", *len, chunks());
+            assert!(file.is_streamed());
+
+            let mut stream = match file.source {
+                super::FileSource::Stream(stream) => stream.lock().unwrap().take().unwrap(),
+                super::FileSource::Bytes(_) => unreachable!(),
+            };
+
+            let mut result = Ok(());
+
+            while let Some(chunk) = stream.next().await {
+                if let Err(source) = chunk {
+                    result = Err(source);
+                }
+            }
+
+            assert_eq!(*valid, result.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload() {
+        let transport = MockTransport::new();
+        let route = Route::UpdateWebhookMessage {
+            message_id: 2,
+            thread_id: None,
+            token: "token".to_owned(),
+            webhook_id: 1,
+        };
+        transport.respond(&route, MockResponse::new(StatusCode::OK));
+
+        let client = Client::builder()
+            .attachment_size_limit(6)
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let file = || {
+            AttachmentFile::from_stream(
+                "a.txt",
+                4,
+                stream::iter(vec![Ok::<_, IoError>(Bytes::from_static(b"abcd"))]),
+            )
+            .description("a")
+        };
+
+        client
+            .update_webhook_message(WebhookId(1), "token", MessageId(2))
+            .attachment_file(file())
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert_eq!(
+            body.len().to_string(),
+            requests[0].headers[CONTENT_LENGTH].to_str().unwrap()
+        );
+        assert!(body.contains("filename=\"a.txt\"\r\n\r\nabcd"));
+        assert!(!body.contains("attachments"));
+
+        transport.respond(&route, MockResponse::new(StatusCode::OK));
+        client
+            .update_webhook_message(WebhookId(1), "token", MessageId(2))
+            .attachment(Attachment {
+                content_type: None,
+                filename: "b.txt".to_owned(),
+                height: None,
+                id: AttachmentId(3),
+                proxy_url: "https://media.discordapp.net/b.txt".to_owned(),
+                size: 1,
+                url: "https://cdn.discordapp.com/b.txt".to_owned(),
+                width: None,
+            })
+            .attachment_file(file())
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        let body = String::from_utf8_lossy(&requests[1].body);
+        assert!(body.contains(r#""filename":"b.txt","id":"3""#));
+        assert!(body.contains(r#"{"description":"a","filename":"a.txt","id":0}]}"#));
+
+        let error = client
+            .update_webhook_message(WebhookId(1), "token", MessageId(2))
+            .attachment_files(vec![file(), file()])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::AttachmentsTooLarge { len: 8, limit: 6 }
+        ));
+        assert_eq!(2, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_reader() {
+        let file = AttachmentFile::from_reader("file", 5, &b"hello"[..]);
+
+        let mut stream = match file.source {
+            super::FileSource::Stream(stream) => stream.lock().unwrap().take().unwrap(),
+            super::FileSource::Bytes(_) => unreachable!(),
+        };
+
+        let mut contents = Vec::new();
+
+        while let Some(chunk) = stream.next().await {
+            contents.extend_from_slice(&chunk.unwrap());
+        }
+
+        assert_eq!(b"hello", contents.as_slice());
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 };
 use hyper::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 /// Builder to create a customized request.
 ///
@@ -73,6 +73,8 @@ impl RequestBuilder {
             method,
             path,
             path_str: Cow::Owned(path_and_query),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         })
     }
@@ -123,6 +125,21 @@ impl RequestBuilder {
         Ok(self.body(bytes))
     }
 
+    /// Set the timeout of the request, overriding the client's timeout.
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.0.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set whether time spent waiting on the ratelimiter counts against the
+    /// timeout, overriding the client's setting.
+    pub const fn timeout_includes_ratelimit(mut self, include: bool) -> Self {
+        self.0.timeout_includes_ratelimit = Some(include);
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -134,7 +151,7 @@ impl RequestBuilder {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Request {
     /// The body of the request, if any.
     pub body: Option<Vec<u8>>,
@@ -148,6 +165,11 @@ pub struct Request {
     pub path: Path,
     /// The URI path to request.
     pub path_str: Cow<'static, str>,
+    /// Timeout of the request, overriding the client's timeout.
+    pub(crate) timeout: Option<Duration>,
+    /// Whether time spent waiting on the ratelimiter counts against the
+    /// timeout, overriding the client's setting.
+    pub(crate) timeout_includes_ratelimit: Option<bool>,
     /// Whether to use the client's authorization token in the request.
     pub(crate) use_authorization_token: bool,
 }
@@ -173,6 +195,8 @@ impl Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -230,10 +254,23 @@ impl Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
 
+    /// Timeout of the request, if it overrides the client's timeout.
+    pub const fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Whether time spent waiting on the ratelimiter counts against the
+    /// timeout, if it overrides the client's setting.
+    pub const fn timeout_includes_ratelimit(&self) -> Option<bool> {
+        self.timeout_includes_ratelimit
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
@@ -249,6 +286,8 @@ impl From<Route> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -263,6 +302,8 @@ impl From<(Vec<u8>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -277,6 +318,8 @@ impl From<(Form, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -291,6 +334,8 @@ impl From<(Vec<u8>, Form, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -305,6 +350,8 @@ impl From<(HeaderMap<HeaderValue>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -319,6 +366,8 @@ impl From<(Vec<u8>, HeaderMap<HeaderValue>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -333,6 +382,8 @@ impl From<(Form, HeaderMap<HeaderValue>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
+            timeout_includes_ratelimit: None,
             use_authorization_token: true,
         }
     }
@@ -360,6 +411,8 @@ mod tests {
         assert_eq!(Method::Post, builder.0.method);
         assert_eq!(Path::Guilds, builder.0.path);
         assert_eq!("guilds", builder.0.path_str.as_ref());
+        assert!(builder.0.timeout.is_none());
+        assert!(builder.0.timeout_includes_ratelimit.is_none());
         assert!(builder.0.use_authorization_token);
 
         Ok(())
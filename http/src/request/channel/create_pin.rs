@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ChannelId, MessageId};
@@ -12,6 +12,7 @@ pub struct CreatePin<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -22,6 +23,7 @@ impl<'a> CreatePin<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -36,8 +38,9 @@ impl<'a> CreatePin<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
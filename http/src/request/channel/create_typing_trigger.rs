@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::ChannelId;
@@ -11,6 +11,7 @@ pub struct CreateTypingTrigger<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> CreateTypingTrigger<'a> {
@@ -19,6 +20,7 @@ impl<'a> CreateTypingTrigger<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -27,7 +29,8 @@ impl<'a> CreateTypingTrigger<'a> {
             channel_id: self.channel_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{channel::Channel, id::ChannelId};
@@ -11,6 +11,7 @@ pub struct DeleteChannel<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, Channel>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -20,6 +21,7 @@ impl<'a> DeleteChannel<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -33,8 +35,9 @@ impl<'a> DeleteChannel<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
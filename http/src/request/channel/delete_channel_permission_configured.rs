@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::ChannelId;
@@ -13,6 +13,7 @@ pub struct DeleteChannelPermissionConfigured<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
     target_id: u64,
 }
@@ -23,6 +24,7 @@ impl<'a> DeleteChannelPermissionConfigured<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
             target_id,
         }
@@ -38,8 +40,9 @@ impl<'a> DeleteChannelPermissionConfigured<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
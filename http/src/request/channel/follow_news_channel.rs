@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -18,6 +18,7 @@ pub struct FollowNewsChannel<'a> {
     fields: FollowNewsChannelFields,
     fut: Option<Pending<'a, FollowedChannel>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> FollowNewsChannel<'a> {
@@ -31,6 +32,7 @@ impl<'a> FollowNewsChannel<'a> {
             fut: None,
             http,
             fields: FollowNewsChannelFields { webhook_channel_id },
+            options: RequestOptions::default(),
         }
     }
 
@@ -41,7 +43,8 @@ impl<'a> FollowNewsChannel<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{channel::Channel, id::ChannelId};
@@ -29,6 +29,7 @@ pub struct GetChannel<'a> {
     channel_id: ChannelId,
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetChannel<'a> {
@@ -37,6 +38,7 @@ impl<'a> GetChannel<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -45,7 +47,9 @@ impl<'a> GetChannel<'a> {
             channel_id: self.channel_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,9 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{self, validate, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{
+        self, validate, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
@@ -114,6 +116,7 @@ pub struct CreateInvite<'a> {
     fields: CreateInviteFields,
     fut: Option<Pending<'a, Invite>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -124,6 +127,7 @@ impl<'a> CreateInvite<'a> {
             fields: CreateInviteFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -256,8 +260,9 @@ impl<'a> CreateInvite<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
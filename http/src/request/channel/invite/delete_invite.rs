@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 
@@ -16,6 +16,7 @@ pub struct DeleteInvite<'a> {
     code: String,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -25,6 +26,7 @@ impl<'a> DeleteInvite<'a> {
             code: code.into(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -38,8 +40,9 @@ impl<'a> DeleteInvite<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
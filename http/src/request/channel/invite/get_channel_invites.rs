@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{id::ChannelId, invite::Invite};
@@ -17,6 +17,7 @@ pub struct GetChannelInvites<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, Vec<Invite>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetChannelInvites<'a> {
@@ -25,6 +26,7 @@ impl<'a> GetChannelInvites<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -33,7 +35,8 @@ impl<'a> GetChannelInvites<'a> {
             channel_id: self.channel_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::invite::Invite;
@@ -41,6 +41,7 @@ pub struct GetInvite<'a> {
     fields: GetInviteFields,
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetInvite<'a> {
@@ -50,6 +51,7 @@ impl<'a> GetInvite<'a> {
             fields: GetInviteFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -74,7 +76,9 @@ impl<'a> GetInvite<'a> {
             with_expiration: self.fields.with_expiration,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -4,7 +4,7 @@ use crate::{
     request::{
         multipart::Form,
         validate::{self, EmbedValidationError},
-        Pending, Request,
+        AttachmentFile, PartialAttachment, Pending, Request, RequestOptions,
     },
     routing::Route,
 };
@@ -14,6 +14,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageReference},
@@ -62,6 +63,9 @@ impl CreateMessageError {
 impl Display for CreateMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            CreateMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             CreateMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("the message content is invalid")
             }
@@ -91,6 +95,13 @@ impl Error for CreateMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum CreateMessageErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -107,6 +118,10 @@ pub enum CreateMessageErrorType {
 
 #[derive(Default, Serialize)]
 pub(crate) struct CreateMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -146,9 +161,10 @@ pub(crate) struct CreateMessageFields {
 pub struct CreateMessage<'a> {
     channel_id: ChannelId,
     pub(crate) fields: CreateMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> CreateMessage<'a> {
@@ -162,6 +178,7 @@ impl<'a> CreateMessage<'a> {
             files: Vec::new(),
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -172,6 +189,47 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Attach a file to the message, whose contents may be streamed.
+    ///
+    /// This method is repeatable.
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the message, whose contents may be streamed.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Calling this method multiple times will clear previous calls.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateMessageErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, CreateMessageError> {
+        if let Err(source) = validate::components(&components) {
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::ComponentsInvalid { components },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// The maximum length is 2000 UTF-16 characters.
@@ -271,7 +329,7 @@ impl<'a> CreateMessage<'a> {
     ///
     /// The file is raw binary data. It can be an image, or any other kind of file.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -344,13 +402,14 @@ impl<'a> CreateMessage<'a> {
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
 
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+            for (index, file) in self.files.iter().enumerate() {
+                form.attachment(format!("files[{}]", index).as_bytes(), file);
             }
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
+                self.fields.attachments = PartialAttachment::from_files(&self.files);
                 let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
@@ -360,8 +419,9 @@ impl<'a> CreateMessage<'a> {
             request = request.json(&self.fields)?;
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -15,6 +15,7 @@ pub struct CrosspostMessage<'a> {
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
 }
 
 impl<'a> CrosspostMessage<'a> {
@@ -24,6 +25,7 @@ impl<'a> CrosspostMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -33,7 +35,8 @@ impl<'a> CrosspostMessage<'a> {
             message_id: self.message_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
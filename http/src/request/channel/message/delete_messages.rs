@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -24,6 +24,7 @@ pub struct DeleteMessages<'a> {
     fields: DeleteMessagesFields,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -40,6 +41,7 @@ impl<'a> DeleteMessages<'a> {
             },
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -54,8 +56,9 @@ impl<'a> DeleteMessages<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
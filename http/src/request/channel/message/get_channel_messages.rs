@@ -2,7 +2,11 @@ use super::GetChannelMessagesConfigured;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{
+        paginator::Direction,
+        validate::{self, GET_CHANNEL_MESSAGES_LIMIT_MAX},
+        Paginator, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use std::{
@@ -110,6 +114,7 @@ pub struct GetChannelMessages<'a> {
     fields: GetChannelMessagesFields,
     fut: Option<Pending<'a, Vec<Message>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetChannelMessages<'a> {
@@ -119,6 +124,7 @@ impl<'a> GetChannelMessages<'a> {
             fields: GetChannelMessagesFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -175,6 +181,45 @@ impl<'a> GetChannelMessages<'a> {
         Ok(self)
     }
 
+    /// Stream all messages in the channel, from newest to oldest.
+    ///
+    /// Each page is requested with the configured [`limit`], or 100 messages
+    /// if it isn't set.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve the latest 250 messages in a channel:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::ChannelId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let messages = client
+    ///     .channel_messages(ChannelId(123))
+    ///     .paginate()
+    ///     .total(250)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Message> {
+        paginate(
+            self.http,
+            self.channel_id,
+            None,
+            Direction::Before,
+            self.fields.limit,
+            self.options,
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetMessages {
             after: None,
@@ -184,10 +229,45 @@ impl<'a> GetChannelMessages<'a> {
             limit: self.fields.limit,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
 }
 
 poll_req!(GetChannelMessages<'_>, Vec<Message>);
+
+/// Create a paginator over a channel's messages, starting at a cursor.
+pub(super) fn paginate(
+    http: &Client,
+    channel_id: ChannelId,
+    cursor: Option<MessageId>,
+    direction: Direction,
+    limit: Option<u64>,
+    options: RequestOptions,
+) -> Paginator<'_, Message> {
+    Paginator::new(
+        cursor.map(|id| id.0),
+        direction,
+        limit.unwrap_or(GET_CHANNEL_MESSAGES_LIMIT_MAX),
+        |message: &Message| message.id.0,
+        move |cursor, limit| {
+            let (after, around, before) = match direction {
+                Direction::After => (cursor, None, None),
+                Direction::Before => (None, None, cursor),
+                Direction::Once => (None, cursor, None),
+            };
+
+            let request = Request::from_route(Route::GetMessages {
+                after,
+                around,
+                before,
+                channel_id: channel_id.0,
+                limit: Some(limit),
+            });
+
+            Box::pin(http.request(options.apply(request)))
+        },
+    )
+}
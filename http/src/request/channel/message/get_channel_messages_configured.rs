@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{paginator::Direction, validate, Paginator, Pending, Request, RequestOptions},
     routing::Route,
 };
 use std::{
@@ -87,6 +87,7 @@ pub struct GetChannelMessagesConfigured<'a> {
     fields: GetChannelMessagesConfiguredFields,
     fut: Option<Pending<'a, Vec<Message>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetChannelMessagesConfigured<'a> {
@@ -106,6 +107,7 @@ impl<'a> GetChannelMessagesConfigured<'a> {
             fields: GetChannelMessagesConfiguredFields { limit },
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -129,6 +131,38 @@ impl<'a> GetChannelMessagesConfigured<'a> {
         Ok(self)
     }
 
+    /// Stream all messages from the configured message.
+    ///
+    /// Discord returns each page from newest to oldest. Pages before the
+    /// message, or before the latest message if none is configured, continue
+    /// from the oldest message of the previous page, so messages are streamed
+    /// from newest to oldest. Pages after the message continue from the
+    /// newest message of the previous page, so while later pages contain newer
+    /// messages each page is still newest to oldest. Only a single page of
+    /// messages around the message is retrieved, since there's no cursor to
+    /// continue from.
+    ///
+    /// Each page is requested with the configured [`limit`], or 100 messages
+    /// if it isn't set.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Message> {
+        let (cursor, direction) = match (self.after, self.around, self.before) {
+            (Some(after), _, _) => (Some(after), Direction::After),
+            (_, Some(around), _) => (Some(around), Direction::Once),
+            (_, _, before) => (before, Direction::Before),
+        };
+
+        super::get_channel_messages::paginate(
+            self.http,
+            self.channel_id,
+            cursor,
+            direction,
+            self.fields.limit,
+            self.options,
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetMessages {
             after: self.after.map(|x| x.0),
@@ -138,7 +172,8 @@ impl<'a> GetChannelMessagesConfigured<'a> {
             limit: self.fields.limit,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
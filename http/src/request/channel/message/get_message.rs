@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -15,6 +15,7 @@ pub struct GetMessage<'a> {
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
 }
 
 impl<'a> GetMessage<'a> {
@@ -24,6 +25,7 @@ impl<'a> GetMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -33,7 +35,9 @@ impl<'a> GetMessage<'a> {
             message_id: self.message_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
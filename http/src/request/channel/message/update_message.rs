@@ -3,7 +3,7 @@ use crate::{
     error::Error as HttpError,
     request::{
         validate::{self, EmbedValidationError},
-        NullableField, Pending, Request,
+        NullableField, Pending, Request, RequestOptions,
     },
     routing::Route,
 };
@@ -13,6 +13,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageFlags},
@@ -61,6 +62,9 @@ impl UpdateMessageError {
 impl Display for UpdateMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             UpdateMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("the message content is invalid")
             }
@@ -90,6 +94,13 @@ impl Error for UpdateMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateMessageErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -120,6 +131,8 @@ struct UpdateMessageFields {
     //   `"content": null` in the JSON;
     // - None: Don't serialize the field at all, not modifying the state.
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
@@ -170,6 +183,7 @@ pub struct UpdateMessage<'a> {
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateMessage<'a> {
@@ -180,6 +194,7 @@ impl<'a> UpdateMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -205,6 +220,40 @@ impl<'a> UpdateMessage<'a> {
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateMessageErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateMessageError> {
+        if let Some(components_ref) = components.as_deref() {
+            if let Err(source) = validate::components(components_ref) {
+                return Err(UpdateMessageError {
+                    kind: UpdateMessageErrorType::ComponentsInvalid {
+                        components: components.expect("components are known to be present"),
+                    },
+                    source: Some(Box::new(source)),
+                });
+            }
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -345,7 +394,8 @@ impl<'a> UpdateMessage<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
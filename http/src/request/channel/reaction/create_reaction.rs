@@ -2,7 +2,7 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ChannelId, MessageId};
@@ -37,6 +37,7 @@ pub struct CreateReaction<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
 }
 
 impl<'a> CreateReaction<'a> {
@@ -52,6 +53,7 @@ impl<'a> CreateReaction<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -66,7 +68,8 @@ impl<'a> CreateReaction<'a> {
     fn start(&mut self) -> Result<(), Error> {
         let request = self.request();
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
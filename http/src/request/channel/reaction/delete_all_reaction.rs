@@ -2,7 +2,7 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ChannelId, MessageId};
@@ -14,6 +14,7 @@ pub struct DeleteAllReaction<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
 }
 
 impl<'a> DeleteAllReaction<'a> {
@@ -29,6 +30,7 @@ impl<'a> DeleteAllReaction<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -39,7 +41,8 @@ impl<'a> DeleteAllReaction<'a> {
             emoji: self.emoji.display().to_string(),
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -2,7 +2,7 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{ChannelId, MessageId};
@@ -14,6 +14,7 @@ pub struct DeleteReaction<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
     target_user: String,
 }
 
@@ -31,6 +32,7 @@ impl<'a> DeleteReaction<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
             target_user: target_user.into(),
         }
     }
@@ -43,7 +45,8 @@ impl<'a> DeleteReaction<'a> {
             user: self.target_user.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -2,7 +2,11 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{
+        paginator::Direction,
+        validate::{self, GET_REACTIONS_LIMIT_MAX},
+        Paginator, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use std::{
@@ -70,8 +74,11 @@ struct GetReactionsFields {
 
 /// Get a list of users that reacted to a message with an `emoji`.
 ///
-/// This endpoint is limited to 100 users maximum, so if a message has more than 100 reactions,
-/// requests must be chained until all reactions are retireved.
+/// This endpoint is limited to 100 users maximum, so if a message has more
+/// than 100 reactions, requests must be chained until all reactions are
+/// retireved. Use [`paginate`] to do so automatically.
+///
+/// [`paginate`]: Self::paginate
 pub struct GetReactions<'a> {
     channel_id: ChannelId,
     emoji: RequestReactionType,
@@ -79,6 +86,7 @@ pub struct GetReactions<'a> {
     fut: Option<Pending<'a, Vec<User>>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
 }
 
 impl<'a> GetReactions<'a> {
@@ -95,6 +103,7 @@ impl<'a> GetReactions<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -126,6 +135,38 @@ impl<'a> GetReactions<'a> {
         Ok(self)
     }
 
+    /// Stream all users that reacted with the emoji.
+    ///
+    /// Each page is requested with the configured [`limit`], or 100 users if
+    /// it isn't set.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, User> {
+        let channel_id = self.channel_id;
+        let emoji = self.emoji.display().to_string();
+        let http = self.http;
+        let message_id = self.message_id;
+        let options = self.options;
+
+        Paginator::new(
+            self.fields.after.map(|id| id.0),
+            Direction::After,
+            self.fields.limit.unwrap_or(GET_REACTIONS_LIMIT_MAX),
+            |user: &User| user.id.0,
+            move |after, limit| {
+                let request = Request::from_route(Route::GetReactionUsers {
+                    after,
+                    channel_id: channel_id.0,
+                    emoji: emoji.clone(),
+                    limit: Some(limit),
+                    message_id: message_id.0,
+                });
+
+                Box::pin(http.request(options.apply(request)))
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetReactionUsers {
             after: self.fields.after.map(|x| x.0),
@@ -135,7 +176,8 @@ impl<'a> GetReactions<'a> {
             message_id: self.message_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
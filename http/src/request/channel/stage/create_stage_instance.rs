@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -83,6 +83,7 @@ pub struct CreateStageInstance<'a> {
     fields: CreateStageInstanceFields,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> CreateStageInstance<'a> {
@@ -114,6 +115,7 @@ impl<'a> CreateStageInstance<'a> {
             },
             fut: None,
             http,
+            options: RequestOptions::default(),
         })
     }
 
@@ -129,7 +131,8 @@ impl<'a> CreateStageInstance<'a> {
             .json(&self.fields)?
             .build();
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::ChannelId;
@@ -13,6 +13,7 @@ pub struct DeleteStageInstance<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> DeleteStageInstance<'a> {
@@ -21,6 +22,7 @@ impl<'a> DeleteStageInstance<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -29,7 +31,8 @@ impl<'a> DeleteStageInstance<'a> {
             channel_id: self.channel_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{channel::StageInstance, id::ChannelId};
@@ -11,6 +11,7 @@ pub struct GetStageInstance<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, Option<StageInstance>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetStageInstance<'a> {
@@ -19,6 +20,7 @@ impl<'a> GetStageInstance<'a> {
             channel_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -27,7 +29,8 @@ impl<'a> GetStageInstance<'a> {
             channel_id: self.channel_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -84,6 +84,7 @@ pub struct UpdateStageInstance<'a> {
     fields: UpdateStageInstanceFields,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateStageInstance<'a> {
@@ -93,6 +94,7 @@ impl<'a> UpdateStageInstance<'a> {
             fields: UpdateStageInstanceFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -128,7 +130,8 @@ impl<'a> UpdateStageInstance<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -0,0 +1,175 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{validate, Pending, Request, RequestOptions},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, Channel, ChannelType},
+    id::ChannelId,
+};
+
+/// The thread can not be created as configured.
+#[derive(Debug)]
+pub struct CreateThreadError {
+    kind: CreateThreadErrorType,
+}
+
+impl CreateThreadError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateThreadErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateThreadErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for CreateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateThreadErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            CreateThreadErrorType::TypeInvalid { .. } => {
+                f.write_str("the channel type is not a thread type")
+            }
+        }
+    }
+}
+
+impl Error for CreateThreadError {}
+
+/// Type of [`CreateThreadError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateThreadErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 characters or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The channel type is not a thread type.
+    TypeInvalid {
+        /// Provided type.
+        kind: ChannelType,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateThreadFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    name: String,
+}
+
+/// Create a thread that is not connected to a message.
+///
+/// Creating a [`GuildPrivateThread`] requires the guild to be boosted to tier
+/// 2.
+///
+/// [`GuildPrivateThread`]: ChannelType::GuildPrivateThread
+pub struct CreateThread<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFields,
+    fut: Option<Pending<'a, Channel>>,
+    http: &'a Client,
+    options: RequestOptions,
+}
+
+impl<'a> CreateThread<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        Self::_new(http, channel_id, name.into(), kind)
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: String,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        if !validate::channel_name(&name) {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::NameInvalid { name },
+            });
+        }
+
+        if !kind.is_thread() {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::TypeInvalid { kind },
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFields {
+                auto_archive_duration: None,
+                invitable: None,
+                kind,
+                name,
+            },
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        })
+    }
+
+    /// Set the duration of inactivity after which the thread is archived.
+    pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
+        self.fields
+            .auto_archive_duration
+            .replace(auto_archive_duration);
+
+        self
+    }
+
+    /// Whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only applicable to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable.replace(invitable);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = Request::builder(Route::CreateThread {
+            channel_id: self.channel_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThread<'_>, Channel);
@@ -0,0 +1,156 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{validate, Pending, Request, RequestOptions},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, Channel},
+    id::{ChannelId, MessageId},
+};
+
+/// The thread can not be created as configured.
+#[derive(Debug)]
+pub struct CreateThreadFromMessageError {
+    kind: CreateThreadFromMessageErrorType,
+}
+
+impl CreateThreadFromMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateThreadFromMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateThreadFromMessageErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for CreateThreadFromMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateThreadFromMessageErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateThreadFromMessageError {}
+
+/// Type of [`CreateThreadFromMessageError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateThreadFromMessageErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 characters or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateThreadFromMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    name: String,
+}
+
+/// Create a thread from an existing message.
+///
+/// The ID of the thread is the same as the ID of the message it was created
+/// from. When created in a news channel the thread is a
+/// [`GuildNewsThread`], and otherwise a [`GuildPublicThread`].
+///
+/// [`GuildNewsThread`]: twilight_model::channel::ChannelType::GuildNewsThread
+/// [`GuildPublicThread`]: twilight_model::channel::ChannelType::GuildPublicThread
+pub struct CreateThreadFromMessage<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFromMessageFields,
+    fut: Option<Pending<'a, Channel>>,
+    http: &'a Client,
+    message_id: MessageId,
+    options: RequestOptions,
+}
+
+impl<'a> CreateThreadFromMessage<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+    ) -> Result<Self, CreateThreadFromMessageError> {
+        Self::_new(http, channel_id, message_id, name.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: String,
+    ) -> Result<Self, CreateThreadFromMessageError> {
+        if !validate::channel_name(&name) {
+            return Err(CreateThreadFromMessageError {
+                kind: CreateThreadFromMessageErrorType::NameInvalid { name },
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFromMessageFields {
+                auto_archive_duration: None,
+                name,
+            },
+            fut: None,
+            http,
+            message_id,
+            options: RequestOptions::default(),
+        })
+    }
+
+    /// Set the duration of inactivity after which the thread is archived.
+    pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
+        self.fields
+            .auto_archive_duration
+            .replace(auto_archive_duration);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = Request::builder(Route::CreateThreadFromMessage {
+            channel_id: self.channel_id.0,
+            message_id: self.message_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThreadFromMessage<'_>, Channel);
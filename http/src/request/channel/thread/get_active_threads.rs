@@ -0,0 +1,42 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request, RequestOptions},
+    routing::Route,
+};
+use twilight_model::{channel::thread::ThreadsListing, id::GuildId};
+
+/// Get the active threads of a guild.
+///
+/// Includes public and private threads, ordered by their ID in descending
+/// order.
+pub struct GetActiveThreads<'a> {
+    fut: Option<Pending<'a, ThreadsListing>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    options: RequestOptions,
+}
+
+impl<'a> GetActiveThreads<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            options: RequestOptions::default(),
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = Request::from_route(Route::GetActiveThreads {
+            guild_id: self.guild_id.0,
+        });
+
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetActiveThreads<'_>, ThreadsListing);
@@ -0,0 +1,138 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{validate, Pending, Request, RequestOptions},
+    routing::Route,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+/// The error created when the threads can not be retrieved as configured.
+#[derive(Debug)]
+pub struct GetJoinedPrivateArchivedThreadsError {
+    kind: GetJoinedPrivateArchivedThreadsErrorType,
+}
+
+impl GetJoinedPrivateArchivedThreadsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetJoinedPrivateArchivedThreadsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetJoinedPrivateArchivedThreadsErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for GetJoinedPrivateArchivedThreadsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetJoinedPrivateArchivedThreadsErrorType::LimitInvalid { .. } => {
+                f.write_str("the limit is invalid")
+            }
+        }
+    }
+}
+
+impl Error for GetJoinedPrivateArchivedThreadsError {}
+
+/// Type of [`GetJoinedPrivateArchivedThreadsError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetJoinedPrivateArchivedThreadsErrorType {
+    /// The maximum number of threads to retrieve is 0 or more than 100.
+    LimitInvalid {
+        /// Provided maximum number of threads to retrieve.
+        limit: u64,
+    },
+}
+
+/// Get the archived private threads of a channel that the current user has
+/// joined.
+///
+/// Threads are ordered by their ID in descending order.
+///
+/// Requires the [`READ_MESSAGE_HISTORY`] permission.
+///
+/// [`READ_MESSAGE_HISTORY`]: twilight_model::guild::Permissions::READ_MESSAGE_HISTORY
+pub struct GetJoinedPrivateArchivedThreads<'a> {
+    before: Option<ChannelId>,
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+    limit: Option<u64>,
+    options: RequestOptions,
+}
+
+impl<'a> GetJoinedPrivateArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            before: None,
+            channel_id,
+            fut: None,
+            http,
+            limit: None,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Get threads with an ID before this thread ID.
+    pub fn before(mut self, before: ChannelId) -> Self {
+        self.before.replace(before);
+
+        self
+    }
+
+    /// Set the maximum number of threads to retrieve.
+    ///
+    /// The minimum is 1 and the maximum is 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GetJoinedPrivateArchivedThreadsErrorType::LimitInvalid`] error type if the amount
+    /// is 0 or greater than 100.
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetJoinedPrivateArchivedThreadsError> {
+        if !validate::get_archived_threads_limit(limit) {
+            return Err(GetJoinedPrivateArchivedThreadsError {
+                kind: GetJoinedPrivateArchivedThreadsErrorType::LimitInvalid { limit },
+            });
+        }
+
+        self.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = Request::from_route(Route::GetJoinedPrivateArchivedThreads {
+            before: self.before.map(|id| id.0),
+            channel_id: self.channel_id.0,
+            limit: self.limit,
+        });
+
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetJoinedPrivateArchivedThreads<'_>, ThreadsListing);
@@ -0,0 +1,43 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request, RequestOptions},
+    routing::Route,
+};
+use twilight_model::{channel::thread::ThreadMember, id::ChannelId};
+
+/// Get the members of a thread.
+///
+/// Requires the [`GUILD_MEMBERS`] privileged intent.
+///
+/// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
+pub struct GetThreadMembers<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, Vec<ThreadMember>>>,
+    http: &'a Client,
+    options: RequestOptions,
+}
+
+impl<'a> GetThreadMembers<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = Request::from_route(Route::GetThreadMembers {
+            channel_id: self.channel_id.0,
+        });
+
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetThreadMembers<'_>, Vec<ThreadMember>);
@@ -0,0 +1,41 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request, RequestOptions},
+    routing::Route,
+};
+use twilight_model::id::ChannelId;
+
+/// Add the current user to a thread.
+///
+/// The thread must not be archived.
+pub struct JoinThread<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    options: RequestOptions,
+}
+
+impl<'a> JoinThread<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = Request::from_route(Route::JoinThread {
+            channel_id: self.channel_id.0,
+        });
+
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(JoinThread<'_>, ());
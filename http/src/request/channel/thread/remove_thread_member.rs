@@ -0,0 +1,47 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request, RequestOptions},
+    routing::Route,
+};
+use twilight_model::id::{ChannelId, UserId};
+
+/// Remove a member from a thread.
+///
+/// Requires the [`MANAGE_THREADS`] permission, unless the thread is private and
+/// the current user created it. The thread must not be archived.
+///
+/// [`MANAGE_THREADS`]: twilight_model::guild::Permissions::MANAGE_THREADS
+pub struct RemoveThreadMember<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    options: RequestOptions,
+    user_id: UserId,
+}
+
+impl<'a> RemoveThreadMember<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId, user_id: UserId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+            user_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = Request::from_route(Route::RemoveThreadMember {
+            channel_id: self.channel_id.0,
+            user_id: self.user_id.0,
+        });
+
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
+
+        Ok(())
+    }
+}
+
+poll_req!(RemoveThreadMember<'_>, ());
@@ -3,6 +3,7 @@ use crate::{
     error::Error as HttpError,
     request::{
         self, validate, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request,
+        RequestOptions,
     },
     routing::Route,
 };
@@ -118,6 +119,7 @@ pub struct UpdateChannel<'a> {
     fields: UpdateChannelFields,
     fut: Option<Pending<'a, Channel>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -128,6 +130,7 @@ impl<'a> UpdateChannel<'a> {
             fields: UpdateChannelFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -302,8 +305,9 @@ impl<'a> UpdateChannel<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -25,6 +25,7 @@ pub struct UpdateChannelPermissionConfigured<'a> {
     fields: UpdateChannelPermissionConfiguredFields,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    options: RequestOptions,
     target_id: u64,
     reason: Option<String>,
 }
@@ -55,6 +56,7 @@ impl<'a> UpdateChannelPermissionConfigured<'a> {
             },
             fut: None,
             http,
+            options: RequestOptions::default(),
             target_id,
             reason: None,
         }
@@ -77,7 +79,8 @@ impl<'a> UpdateChannelPermissionConfigured<'a> {
     fn start(&mut self) -> Result<(), Error> {
         let request = self.request()?;
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -37,6 +37,7 @@ pub struct CreateWebhook<'a> {
     fields: CreateWebhookFields,
     fut: Option<Pending<'a, Webhook>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -50,6 +51,7 @@ impl<'a> CreateWebhook<'a> {
             },
             fut: None,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -77,8 +79,9 @@ impl<'a> CreateWebhook<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::WebhookId;
@@ -16,6 +16,7 @@ pub struct DeleteWebhook<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     id: WebhookId,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -26,6 +27,7 @@ impl<'a> DeleteWebhook<'a> {
             fut: None,
             http,
             id,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -47,8 +49,9 @@ impl<'a> DeleteWebhook<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
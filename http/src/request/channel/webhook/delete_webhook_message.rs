@@ -1,10 +1,10 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
-use twilight_model::id::{MessageId, WebhookId};
+use twilight_model::id::{ChannelId, MessageId, WebhookId};
 
 /// Delete a message created by a webhook.
 ///
@@ -28,7 +28,9 @@ pub struct DeleteWebhookMessage<'a> {
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
     reason: Option<String>,
+    thread_id: Option<ChannelId>,
     token: String,
     webhook_id: WebhookId,
 }
@@ -44,15 +46,25 @@ impl<'a> DeleteWebhookMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
             reason: None,
+            thread_id: None,
             token: token.into(),
             webhook_id,
         }
     }
 
+    /// Specify the thread the message is in.
+    pub fn thread_id(mut self, thread_id: ChannelId) -> Self {
+        self.thread_id.replace(thread_id);
+
+        self
+    }
+
     fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::DeleteWebhookMessage {
             message_id: self.message_id.0,
+            thread_id: self.thread_id.map(|id| id.0),
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         })
@@ -67,7 +79,8 @@ impl<'a> DeleteWebhookMessage<'a> {
 
     fn start(&mut self) -> Result<(), Error> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -98,6 +111,7 @@ mod tests {
 
         let expected = Request::from_route(Route::DeleteWebhookMessage {
             message_id: 2,
+            thread_id: None,
             token: "token".to_owned(),
             webhook_id: 1,
         });
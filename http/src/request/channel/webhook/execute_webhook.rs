@@ -1,26 +1,98 @@
 use crate::{
     client::Client,
-    error::{Error, ErrorType},
-    request::{Form, PendingOption, Request},
+    error::{Error as HttpError, ErrorType},
+    request::{
+        validate, AttachmentFile, Form, PartialAttachment, PendingOption, Request, RequestOptions,
+    },
     routing::Route,
 };
 use hyper::StatusCode;
 use serde::Serialize;
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Message},
-    id::WebhookId,
+    id::{ChannelId, WebhookId},
 };
 
+/// The error created when a webhook can not be executed as configured.
+#[derive(Debug)]
+pub struct ExecuteWebhookError {
+    kind: ExecuteWebhookErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ExecuteWebhookError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ExecuteWebhookErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ExecuteWebhookErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ExecuteWebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ExecuteWebhookErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
+        }
+    }
+}
+
+impl Error for ExecuteWebhookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ExecuteWebhookError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExecuteWebhookErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct ExecuteWebhookFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
@@ -63,9 +135,11 @@ pub(crate) struct ExecuteWebhookFields {
 /// [`file`]: Self::file
 pub struct ExecuteWebhook<'a> {
     pub(crate) fields: ExecuteWebhookFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
+    options: RequestOptions,
+    thread_id: Option<ChannelId>,
     token: String,
     webhook_id: WebhookId,
 }
@@ -77,6 +151,8 @@ impl<'a> ExecuteWebhook<'a> {
             files: Vec::new(),
             fut: None,
             http,
+            options: RequestOptions::default(),
+            thread_id: None,
             token: token.into(),
             webhook_id,
         }
@@ -89,6 +165,22 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Attach a file to the webhook, whose contents may be streamed.
+    ///
+    /// This method is repeatable.
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the webhook, whose contents may be streamed.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -96,6 +188,33 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Calling this method multiple times will clear previous calls.
+    ///
+    /// Only webhooks owned by an application may send components.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExecuteWebhookErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, ExecuteWebhookError> {
+        if let Err(source) = validate::components(&components) {
+            return Err(ExecuteWebhookError {
+                kind: ExecuteWebhookErrorType::ComponentsInvalid { components },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints, same as a message.
@@ -116,7 +235,7 @@ impl<'a> ExecuteWebhook<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -145,7 +264,7 @@ impl<'a> ExecuteWebhook<'a> {
     /// ```rust,no_run
     /// use twilight_embed_builder::EmbedBuilder;
     /// # use twilight_http::Client;
-    /// use twilight_model::id::{MessageId, WebhookId};
+    /// use twilight_model::id::{ChannelId, MessageId, WebhookId};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -189,6 +308,15 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Execute the webhook in a thread of its channel.
+    ///
+    /// The thread is automatically unarchived.
+    pub fn thread_id(mut self, thread_id: ChannelId) -> Self {
+        self.thread_id.replace(thread_id);
+
+        self
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         self.fields.tts.replace(tts);
@@ -213,8 +341,9 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn start(&mut self) -> Result<(), HttpError> {
         let mut request = Request::builder(Route::ExecuteWebhook {
+            thread_id: self.thread_id.map(|id| id.0),
             token: self.token.clone(),
             wait: self.fields.wait,
             webhook_id: self.webhook_id.0,
@@ -227,14 +356,15 @@ impl<'a> ExecuteWebhook<'a> {
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
 
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+            for (index, file) in self.files.iter().enumerate() {
+                form.attachment(format!("files[{}]", index).as_bytes(), file);
             }
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                self.fields.attachments = PartialAttachment::from_files(&self.files);
+                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -243,22 +373,25 @@ impl<'a> ExecuteWebhook<'a> {
             request = request.json(&self.fields)?;
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
 }
 
+request_options!(ExecuteWebhook<'_>);
+
 impl Future for ExecuteWebhook<'_> {
-    type Output = Result<Option<Message>, Error>;
+    type Output = Result<Option<Message>, HttpError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         loop {
             if let Some(fut) = self.as_mut().fut.as_mut() {
                 let bytes = match fut.as_mut().poll(cx) {
                     Poll::Ready(Ok(bytes)) => bytes,
-                    Poll::Ready(Err(Error {
+                    Poll::Ready(Err(HttpError {
                         kind: ErrorType::Response { status, .. },
                         source: None,
                     })) if status == StatusCode::NOT_FOUND => {
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{channel::Webhook, id::WebhookId};
@@ -17,6 +17,7 @@ pub struct GetWebhook<'a> {
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
     id: WebhookId,
+    options: RequestOptions,
 }
 
 impl<'a> GetWebhook<'a> {
@@ -26,6 +27,7 @@ impl<'a> GetWebhook<'a> {
             fut: None,
             http,
             id,
+            options: RequestOptions::default(),
         }
     }
 
@@ -49,8 +51,9 @@ impl<'a> GetWebhook<'a> {
             request = request.use_authorization_token(false);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,12 +1,12 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
     channel::Message,
-    id::{MessageId, WebhookId},
+    id::{ChannelId, MessageId, WebhookId},
 };
 
 /// Get a webhook message by [`WebhookId`], token, and [`MessageId`].
@@ -17,6 +17,8 @@ pub struct GetWebhookMessage<'a> {
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
+    thread_id: Option<ChannelId>,
     token: String,
     webhook_id: WebhookId,
 }
@@ -32,21 +34,33 @@ impl<'a> GetWebhookMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
+            thread_id: None,
             token: token.into(),
             webhook_id,
         }
     }
 
+    /// Specify the thread the message is in.
+    pub fn thread_id(mut self, thread_id: ChannelId) -> Self {
+        self.thread_id.replace(thread_id);
+
+        self
+    }
+
     fn start(&mut self) -> Result<(), Error> {
         let request = Request::builder(Route::GetWebhookMessage {
             message_id: self.message_id.0,
+            thread_id: self.thread_id.map(|id| id.0),
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         })
         .use_authorization_token(false)
         .build();
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -11,10 +11,14 @@ mod update_webhook;
 mod update_webhook_with_token;
 
 pub use self::{
-    create_webhook::CreateWebhook, delete_webhook::DeleteWebhook,
-    delete_webhook_message::DeleteWebhookMessage, execute_webhook::ExecuteWebhook,
-    get_channel_webhooks::GetChannelWebhooks, get_webhook::GetWebhook,
-    get_webhook_message::GetWebhookMessage, update_webhook::UpdateWebhook,
+    create_webhook::CreateWebhook,
+    delete_webhook::DeleteWebhook,
+    delete_webhook_message::DeleteWebhookMessage,
+    execute_webhook::{ExecuteWebhook, ExecuteWebhookError, ExecuteWebhookErrorType},
+    get_channel_webhooks::GetChannelWebhooks,
+    get_webhook::GetWebhook,
+    get_webhook_message::GetWebhookMessage,
+    update_webhook::UpdateWebhook,
     update_webhook_message::UpdateWebhookMessage,
     update_webhook_with_token::UpdateWebhookWithToken,
 };
@@ -1,7 +1,9 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request},
+    request::{
+        self, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
@@ -25,6 +27,7 @@ pub struct UpdateWebhook<'a> {
     fields: UpdateWebhookFields,
     fut: Option<Pending<'a, Webhook>>,
     http: &'a Client,
+    options: RequestOptions,
     webhook_id: WebhookId,
     reason: Option<String>,
 }
@@ -36,6 +39,7 @@ impl<'a> UpdateWebhook<'a> {
             fields: UpdateWebhookFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             webhook_id,
             reason: None,
         }
@@ -83,8 +87,9 @@ impl<'a> UpdateWebhook<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
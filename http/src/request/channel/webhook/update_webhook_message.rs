@@ -4,7 +4,8 @@ use crate::{
     client::Client,
     error::Error as HttpError,
     request::{
-        self, validate, AuditLogReason, AuditLogReasonError, Form, NullableField, Pending, Request,
+        self, validate, AttachmentEntry, AttachmentFile, AuditLogReason, AuditLogReasonError, Form,
+        NullableField, PartialAttachment, Pending, Request, RequestOptions,
     },
     routing::Route,
 };
@@ -14,8 +15,9 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Attachment},
-    id::{MessageId, WebhookId},
+    id::{ChannelId, MessageId, WebhookId},
 };
 
 /// A webhook's message can not be updated as configured.
@@ -53,6 +55,9 @@ impl UpdateWebhookMessageError {
 impl Display for UpdateWebhookMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateWebhookMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("message components are invalid")
+            }
             UpdateWebhookMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("message content is invalid")
             }
@@ -80,6 +85,13 @@ impl Error for UpdateWebhookMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateWebhookMessageErrorType {
+    /// Provided components are invalid.
+    ///
+    /// The source error contains details about which component is invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -110,7 +122,9 @@ struct UpdateWebhookMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<AllowedMentions>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    attachments: Vec<Attachment>,
+    attachments: Vec<AttachmentEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -153,11 +167,13 @@ struct UpdateWebhookMessageFields {
 /// [`DeleteWebhookMessage`]: super::DeleteWebhookMessage
 pub struct UpdateWebhookMessage<'a> {
     fields: UpdateWebhookMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
+    options: RequestOptions,
     reason: Option<String>,
+    thread_id: Option<ChannelId>,
     token: String,
     webhook_id: WebhookId,
 }
@@ -181,7 +197,9 @@ impl<'a> UpdateWebhookMessage<'a> {
             fut: None,
             http,
             message_id,
+            options: RequestOptions::default(),
             reason: None,
+            thread_id: None,
             token: token.into(),
             webhook_id,
         }
@@ -199,7 +217,9 @@ impl<'a> UpdateWebhookMessage<'a> {
     /// If called, all unspecified attachments will be removed from the message.
     /// If not called, all attachments will be kept.
     pub fn attachment(mut self, attachment: Attachment) -> Self {
-        self.fields.attachments.push(attachment);
+        self.fields
+            .attachments
+            .push(AttachmentEntry::Existing(attachment));
 
         self
     }
@@ -211,11 +231,74 @@ impl<'a> UpdateWebhookMessage<'a> {
     pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
         self.fields
             .attachments
-            .extend(attachments.into_iter().collect::<Vec<Attachment>>());
+            .extend(attachments.into_iter().map(AttachmentEntry::Existing));
+
+        self
+    }
+
+    /// Attach a file to the message, whose contents may be streamed.
+    ///
+    /// The [description] of the file is only sent if the existing attachments
+    /// to keep are specified with [`attachment`] or [`attachments`], because
+    /// sending it removes all other attachments from the message.
+    ///
+    /// This method is repeatable.
+    ///
+    /// [`attachment`]: Self::attachment
+    /// [`attachments`]: Self::attachments
+    /// [description]: crate::request::AttachmentFile::description
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
 
         self
     }
 
+    /// Attach multiple files to the message, whose contents may be streamed.
+    ///
+    /// Refer to [`attachment_file`] for how the descriptions of the files are
+    /// sent.
+    ///
+    /// [`attachment_file`]: Self::attachment_file
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// Refer to [the discord docs] for the limits of components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateWebhookMessageErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateWebhookMessageError> {
+        if let Some(components_ref) = components.as_deref() {
+            if let Err(source) = validate::components(components_ref) {
+                return Err(UpdateWebhookMessageError {
+                    kind: UpdateWebhookMessageErrorType::ComponentsInvalid {
+                        components: components.expect("components are known to be present"),
+                    },
+                    source: Some(Box::new(source)),
+                });
+            }
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -329,7 +412,7 @@ impl<'a> UpdateWebhookMessage<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -361,9 +444,17 @@ impl<'a> UpdateWebhookMessage<'a> {
         self
     }
 
+    /// Specify the thread the message is in.
+    pub fn thread_id(mut self, thread_id: ChannelId) -> Self {
+        self.thread_id.replace(thread_id);
+
+        self
+    }
+
     fn request(&mut self) -> Result<Request, HttpError> {
         let mut request = Request::builder(Route::UpdateWebhookMessage {
             message_id: self.message_id.0,
+            thread_id: self.thread_id.map(|id| id.0),
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         })
@@ -372,13 +463,24 @@ impl<'a> UpdateWebhookMessage<'a> {
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
 
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+            for (index, file) in self.files.iter().enumerate() {
+                form.attachment(format!("files[{}]", index).as_bytes(), file);
             }
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
+                // Sending the list of attachments removes the ones that
+                // aren't in it, so it's only sent if the attachments to keep
+                // were specified.
+                if !self.fields.attachments.is_empty() {
+                    if let Some(attachments) = PartialAttachment::from_files(&self.files) {
+                        self.fields
+                            .attachments
+                            .extend(attachments.into_iter().map(AttachmentEntry::New));
+                    }
+                }
+
                 let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
@@ -397,7 +499,8 @@ impl<'a> UpdateWebhookMessage<'a> {
 
     fn start(&mut self) -> Result<(), HttpError> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
@@ -428,6 +531,8 @@ mod tests {
     fn test_request() {
         let client = Client::new("token");
         let mut builder = UpdateWebhookMessage::new(&client, WebhookId(1), "token", MessageId(2))
+            .components(Some(Vec::new()))
+            .expect("empty components are valid")
             .content(Some("test".to_owned()))
             .expect("'test' content couldn't be set")
             .reason("reason")
@@ -437,12 +542,14 @@ mod tests {
         let body = UpdateWebhookMessageFields {
             allowed_mentions: None,
             attachments: Vec::new(),
+            components: Some(NullableField::Value(Vec::new())),
             content: Some(NullableField::Value("test".to_owned())),
             embeds: None,
             payload_json: None,
         };
         let route = Route::UpdateWebhookMessage {
             message_id: 2,
+            thread_id: None,
             token: "token".to_owned(),
             webhook_id: 1,
         };
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{NullableField, Pending, Request},
+    request::{NullableField, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -20,6 +20,7 @@ pub struct UpdateWebhookWithToken<'a> {
     fields: UpdateWebhookWithTokenFields,
     fut: Option<Pending<'a, Webhook>>,
     http: &'a Client,
+    options: RequestOptions,
     token: String,
     webhook_id: WebhookId,
 }
@@ -30,6 +31,7 @@ impl<'a> UpdateWebhookWithToken<'a> {
             fields: UpdateWebhookWithTokenFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
             token: token.into(),
             webhook_id,
         }
@@ -68,7 +70,8 @@ impl<'a> UpdateWebhookWithToken<'a> {
         .use_authorization_token(false)
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{GetGatewayAuthed, Pending, Request},
+    request::{GetGatewayAuthed, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::gateway::connection_info::ConnectionInfo;
@@ -43,11 +43,16 @@ use twilight_model::gateway::connection_info::ConnectionInfo;
 pub struct GetGateway<'a> {
     fut: Option<Pending<'a, ConnectionInfo>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGateway<'a> {
     pub(crate) fn new(http: &'a Client) -> Self {
-        Self { fut: None, http }
+        Self {
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
     }
 
     /// Call to authenticate this request.
@@ -61,7 +66,8 @@ impl<'a> GetGateway<'a> {
     fn start(&mut self) -> Result<(), Error> {
         let request = Request::from_route(Route::GetGateway);
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::gateway::connection_info::BotConnectionInfo;
@@ -13,17 +13,23 @@ use twilight_model::gateway::connection_info::BotConnectionInfo;
 pub struct GetGatewayAuthed<'a> {
     fut: Option<Pending<'a, BotConnectionInfo>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGatewayAuthed<'a> {
     pub(crate) fn new(http: &'a Client) -> Self {
-        Self { fut: None, http }
+        Self {
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
     }
 
     fn start(&mut self) -> Result<(), Error> {
         let request = Request::from_route(Route::GetGatewayBot);
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::oauth::CurrentApplicationInfo;
@@ -9,17 +9,23 @@ use twilight_model::oauth::CurrentApplicationInfo;
 pub struct GetUserApplicationInfo<'a> {
     fut: Option<Pending<'a, CurrentApplicationInfo>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetUserApplicationInfo<'a> {
     pub(crate) fn new(http: &'a Client) -> Self {
-        Self { fut: None, http }
+        Self {
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
     }
 
     fn start(&mut self) -> Result<(), Error> {
         let request = Request::from_route(Route::GetCurrentUserApplicationInfo);
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
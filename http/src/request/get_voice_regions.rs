@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::voice::VoiceRegion;
@@ -10,17 +10,23 @@ use twilight_model::voice::VoiceRegion;
 pub struct GetVoiceRegions<'a> {
     fut: Option<Pending<'a, Vec<VoiceRegion>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetVoiceRegions<'a> {
     pub(crate) fn new(http: &'a Client) -> Self {
-        Self { fut: None, http }
+        Self {
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
     }
 
     fn start(&mut self) -> Result<(), Error> {
         let request = Request::from_route(Route::GetVoiceRegions);
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{validate, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use std::{
@@ -95,6 +95,7 @@ pub struct CreateBan<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     user_id: UserId,
 }
 
@@ -105,6 +106,7 @@ impl<'a> CreateBan<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             user_id,
         }
     }
@@ -137,7 +139,8 @@ impl<'a> CreateBan<'a> {
             user_id: self.user_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
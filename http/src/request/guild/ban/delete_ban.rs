@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{GuildId, UserId};
@@ -30,6 +30,7 @@ pub struct DeleteBan<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     user_id: UserId,
     reason: Option<String>,
 }
@@ -40,6 +41,7 @@ impl<'a> DeleteBan<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             user_id,
             reason: None,
         }
@@ -55,8 +57,9 @@ impl<'a> DeleteBan<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -16,6 +16,7 @@ pub struct GetBan<'a> {
     fut: Option<PendingOption<'a>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     user_id: UserId,
 }
 
@@ -25,6 +26,7 @@ impl<'a> GetBan<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             user_id,
         }
     }
@@ -35,7 +37,9 @@ impl<'a> GetBan<'a> {
             user_id: self.user_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -1,10 +1,76 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::{Pending, Request},
+    error::Error as HttpError,
+    request::{
+        paginator::Direction,
+        validate::{self, GET_GUILD_BANS_LIMIT_MAX},
+        Paginator, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
-use twilight_model::{guild::Ban, id::GuildId};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::Ban,
+    id::{GuildId, UserId},
+};
+
+/// The error created when the bans can not be retrieved as configured.
+#[derive(Debug)]
+pub struct GetBansError {
+    kind: GetBansErrorType,
+}
+
+impl GetBansError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetBansErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (GetBansErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for GetBansError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetBansErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetBansError {}
+
+/// Type of [`GetBansError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetBansErrorType {
+    /// The limit is either 0 or more than 1000.
+    LimitInvalid {
+        /// Provided maximum number of bans to get.
+        limit: u64,
+    },
+}
+
+#[derive(Default)]
+struct GetBansFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+}
 
 /// Retrieve the bans for a guild.
 ///
@@ -26,26 +92,117 @@ use twilight_model::{guild::Ban, id::GuildId};
 /// # Ok(()) }
 /// ```
 pub struct GetBans<'a> {
+    fields: GetBansFields,
     fut: Option<Pending<'a, Vec<Ban>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetBans<'a> {
     pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
         Self {
+            fields: GetBansFields::default(),
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    /// Get bans of users with an ID after this user ID.
+    pub fn after(mut self, user_id: UserId) -> Self {
+        self.fields.after.replace(user_id);
+
+        self
+    }
+
+    /// Get bans of users with an ID before this user ID.
+    pub fn before(mut self, user_id: UserId) -> Self {
+        self.fields.before.replace(user_id);
+
+        self
+    }
+
+    /// Set the maximum number of bans to retrieve.
+    ///
+    /// The minimum is 1 and the maximum is 1000.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GetBansErrorType::LimitInvalid`] error type if the
+    /// `limit` is 0 or greater than 1000.
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetBansError> {
+        if !validate::get_guild_bans_limit(limit) {
+            return Err(GetBansError {
+                kind: GetBansErrorType::LimitInvalid { limit },
+            });
+        }
+
+        self.fields.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    /// Stream all bans of the guild.
+    ///
+    /// Bans are streamed in ascending order of user ID, bounded by [`before`]
+    /// if it is set. If only [`before`] is set then bans are streamed in
+    /// descending order from it.
+    ///
+    /// Each page is requested with the configured [`limit`], or 1000 bans if
+    /// it isn't set.
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Ban> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let options = self.options;
+        let GetBansFields {
+            after,
+            before,
+            limit,
+        } = self.fields;
+
+        let (cursor, direction) = match (after, before) {
+            (None, Some(before)) => (Some(before), Direction::Before),
+            (after, _) => (after, Direction::After),
+        };
+
+        Paginator::new(
+            cursor.map(|id| id.0),
+            direction,
+            limit.unwrap_or(GET_GUILD_BANS_LIMIT_MAX),
+            |ban: &Ban| ban.user.id.0,
+            move |cursor, limit| {
+                let (after, before) = match direction {
+                    Direction::Before => (None, cursor),
+                    _ => (cursor, before.map(|id| id.0)),
+                };
+
+                let request = Request::from_route(Route::GetBans {
+                    after,
+                    before,
+                    guild_id: guild_id.0,
+                    limit: Some(limit),
+                });
+
+                Box::pin(http.request(options.apply(request)))
+            },
+        )
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetBans {
+            after: self.fields.after.map(|x| x.0),
+            before: self.fields.before.map(|x| x.0),
             guild_id: self.guild_id.0,
+            limit: self.fields.limit,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
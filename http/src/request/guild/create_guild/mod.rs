@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -240,6 +240,7 @@ pub struct CreateGuild<'a> {
     fields: CreateGuildFields,
     fut: Option<Pending<'a, PartialGuild>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> CreateGuild<'a> {
@@ -270,6 +271,7 @@ impl<'a> CreateGuild<'a> {
             },
             fut: None,
             http,
+            options: RequestOptions::default(),
         })
     }
 
@@ -480,7 +482,8 @@ impl<'a> CreateGuild<'a> {
             .json(&self.fields)?
             .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
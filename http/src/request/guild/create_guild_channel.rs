@@ -1,7 +1,9 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{self, validate, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{
+        self, validate, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
@@ -116,6 +118,7 @@ pub struct CreateGuildChannel<'a> {
     fut: Option<Pending<'a, GuildChannel>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -155,6 +158,7 @@ impl<'a> CreateGuildChannel<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         })
     }
@@ -287,8 +291,9 @@ impl<'a> CreateGuildChannel<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
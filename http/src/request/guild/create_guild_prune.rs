@@ -1,7 +1,9 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{self, validate, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{
+        self, validate, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use std::{
@@ -82,6 +84,7 @@ pub struct CreateGuildPrune<'a> {
     guild_id: GuildId,
     fut: Option<Pending<'a, Option<GuildPrune>>>,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -92,6 +95,7 @@ impl<'a> CreateGuildPrune<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -144,8 +148,9 @@ impl<'a> CreateGuildPrune<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
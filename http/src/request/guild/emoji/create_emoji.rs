@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -30,6 +30,7 @@ pub struct CreateEmoji<'a> {
     fields: CreateEmojiFields,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -49,6 +50,7 @@ impl<'a> CreateEmoji<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -74,8 +76,9 @@ impl<'a> CreateEmoji<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{EmojiId, GuildId};
@@ -12,6 +12,7 @@ pub struct DeleteEmoji<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -22,6 +23,7 @@ impl<'a> DeleteEmoji<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -36,8 +38,9 @@ impl<'a> DeleteEmoji<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
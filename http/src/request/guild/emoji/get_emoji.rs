@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -34,6 +34,7 @@ pub struct GetEmoji<'a> {
     fut: Option<PendingOption<'a>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetEmoji<'a> {
@@ -43,6 +44,7 @@ impl<'a> GetEmoji<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -52,7 +54,9 @@ impl<'a> GetEmoji<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{guild::Emoji, id::GuildId};
@@ -29,6 +29,7 @@ pub struct GetEmojis<'a> {
     fut: Option<Pending<'a, Vec<Emoji>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetEmojis<'a> {
@@ -37,6 +38,7 @@ impl<'a> GetEmojis<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -45,7 +47,8 @@ impl<'a> GetEmojis<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -25,6 +25,7 @@ pub struct UpdateEmoji<'a> {
     fut: Option<Pending<'a, Emoji>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -36,6 +37,7 @@ impl<'a> UpdateEmoji<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -65,8 +67,9 @@ impl<'a> UpdateEmoji<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,11 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{
+        paginator::Direction,
+        validate::{self, GET_AUDIT_LOG_LIMIT_MAX},
+        Paginator, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use std::{
@@ -9,7 +13,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    guild::audit_log::{AuditLog, AuditLogEvent},
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEvent},
     id::{GuildId, UserId},
 };
 
@@ -93,6 +97,7 @@ pub struct GetAuditLog<'a> {
     fut: Option<Pending<'a, Option<AuditLog>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetAuditLog<'a> {
@@ -102,6 +107,7 @@ impl<'a> GetAuditLog<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -148,6 +154,47 @@ impl<'a> GetAuditLog<'a> {
         self
     }
 
+    /// Stream all audit log entries matching the filters, from newest to
+    /// oldest.
+    ///
+    /// Only the entries are streamed; the users, webhooks, and integrations
+    /// referenced by them are not included.
+    ///
+    /// Each page is requested with the configured [`limit`], or 100 entries
+    /// if it isn't set.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, AuditLogEntry> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let options = self.options;
+        let action_type = self.fields.action_type.map(|x| x as u64);
+        let user_id = self.fields.user_id.map(|x| x.0);
+
+        Paginator::new(
+            self.fields.before,
+            Direction::Before,
+            self.fields.limit.unwrap_or(GET_AUDIT_LOG_LIMIT_MAX),
+            |entry: &AuditLogEntry| entry.id.0,
+            move |before, limit| {
+                let request = Request::from_route(Route::GetAuditLogs {
+                    action_type,
+                    before,
+                    guild_id: guild_id.0,
+                    limit: Some(limit),
+                    user_id,
+                });
+                let request = options.apply(request);
+
+                Box::pin(async move {
+                    let audit_log = http.request::<Option<AuditLog>>(request).await?;
+
+                    Ok(audit_log.map_or_else(Vec::new, |log| log.audit_log_entries))
+                })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetAuditLogs {
             action_type: self.fields.action_type.map(|x| x as u64),
@@ -157,7 +204,8 @@ impl<'a> GetAuditLog<'a> {
             user_id: self.fields.user_id.map(|x| x.0),
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
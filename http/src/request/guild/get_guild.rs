@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{guild::Guild, id::GuildId};
@@ -17,6 +17,7 @@ pub struct GetGuild<'a> {
     fut: Option<Pending<'a, Option<Guild>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuild<'a> {
@@ -26,6 +27,7 @@ impl<'a> GetGuild<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -43,7 +45,8 @@ impl<'a> GetGuild<'a> {
             with_counts: self.fields.with_counts,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{id::GuildId, invite::Invite};
@@ -15,6 +15,7 @@ pub struct GetGuildInvites<'a> {
     fut: Option<Pending<'a, Vec<Invite>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildInvites<'a> {
@@ -23,6 +24,7 @@ impl<'a> GetGuildInvites<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -31,7 +33,8 @@ impl<'a> GetGuildInvites<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use std::{
@@ -77,6 +77,7 @@ pub struct GetGuildPruneCount<'a> {
     fut: Option<Pending<'a, GuildPrune>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildPruneCount<'a> {
@@ -86,6 +87,7 @@ impl<'a> GetGuildPruneCount<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -126,7 +128,8 @@ impl<'a> GetGuildPruneCount<'a> {
             include_roles: self.fields.include_roles.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::{Error, ErrorType},
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use hyper::StatusCode;
@@ -23,6 +23,7 @@ pub struct GetGuildVanityUrl<'a> {
     fut: Option<PendingOption<'a>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildVanityUrl<'a> {
@@ -31,6 +32,7 @@ impl<'a> GetGuildVanityUrl<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -39,12 +41,16 @@ impl<'a> GetGuildVanityUrl<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
 }
 
+request_options!(GetGuildVanityUrl<'_>);
+
 impl Future for GetGuildVanityUrl<'_> {
     type Output = Result<Option<String>, Error>;
 
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{id::GuildId, voice::VoiceRegion};
@@ -13,6 +13,7 @@ pub struct GetGuildVoiceRegions<'a> {
     fut: Option<Pending<'a, Vec<VoiceRegion>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildVoiceRegions<'a> {
@@ -21,6 +22,7 @@ impl<'a> GetGuildVoiceRegions<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -29,7 +31,8 @@ impl<'a> GetGuildVoiceRegions<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
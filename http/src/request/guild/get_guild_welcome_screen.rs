@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{id::GuildId, invite::WelcomeScreen};
@@ -11,6 +11,7 @@ pub struct GetGuildWelcomeScreen<'a> {
     fut: Option<PendingOption<'a>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildWelcomeScreen<'a> {
@@ -19,6 +20,7 @@ impl<'a> GetGuildWelcomeScreen<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -27,7 +29,9 @@ impl<'a> GetGuildWelcomeScreen<'a> {
             guild_id: self.guild_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
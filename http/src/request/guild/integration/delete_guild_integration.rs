@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{GuildId, IntegrationId};
@@ -12,6 +12,7 @@ pub struct DeleteGuildIntegration<'a> {
     guild_id: GuildId,
     http: &'a Client,
     integration_id: IntegrationId,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -22,6 +23,7 @@ impl<'a> DeleteGuildIntegration<'a> {
             guild_id,
             http,
             integration_id,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -36,8 +38,9 @@ impl<'a> DeleteGuildIntegration<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
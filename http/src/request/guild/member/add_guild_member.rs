@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, PendingOption, Request},
+    request::{validate, PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -87,6 +87,7 @@ pub struct AddGuildMember<'a> {
     fut: Option<PendingOption<'a>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     user_id: UserId,
 }
 
@@ -118,6 +119,7 @@ impl<'a> AddGuildMember<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             user_id,
         }
     }
@@ -177,12 +179,16 @@ impl<'a> AddGuildMember<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
 }
 
+request_options!(AddGuildMember<'_>);
+
 impl Future for AddGuildMember<'_> {
     type Output = Result<Option<PartialMember>, HttpError>;
 
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{GuildId, RoleId, UserId};
@@ -31,6 +31,7 @@ pub struct AddRoleToMember<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     role_id: RoleId,
     user_id: UserId,
     reason: Option<String>,
@@ -47,6 +48,7 @@ impl<'a> AddRoleToMember<'a> {
             fut: None,
             guild_id: guild_id.into(),
             http,
+            options: RequestOptions::default(),
             role_id: role_id.into(),
             user_id: user_id.into(),
             reason: None,
@@ -64,8 +66,9 @@ impl<'a> AddRoleToMember<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
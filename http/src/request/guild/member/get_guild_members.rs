@@ -1,7 +1,11 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{
+        paginator::Direction,
+        validate::{self, GET_GUILD_MEMBERS_LIMIT_MAX},
+        Paginator, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use hyper::body::Bytes;
@@ -85,8 +89,9 @@ struct GetGuildMembersFields {
 
 /// Get the members of a guild, by id.
 ///
-/// The upper limit to this request is 1000. If more than 1000 members are needed, the requests
-/// must be chained. Discord defaults the limit to 1.
+/// The upper limit to this request is 1000. If more than 1000 members are
+/// needed, the requests must be chained, which [`paginate`] does automatically.
+/// Discord defaults the limit to 1.
 ///
 /// # Examples
 ///
@@ -105,11 +110,14 @@ struct GetGuildMembersFields {
 /// let members = client.guild_members(guild_id).after(user_id).await?;
 /// # Ok(()) }
 /// ```
+///
+/// [`paginate`]: Self::paginate
 pub struct GetGuildMembers<'a> {
     fields: GetGuildMembersFields,
     fut: Option<Pending<'a, Bytes>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetGuildMembers<'a> {
@@ -119,6 +127,7 @@ impl<'a> GetGuildMembers<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -156,6 +165,41 @@ impl<'a> GetGuildMembers<'a> {
         self
     }
 
+    /// Stream all members of the guild.
+    ///
+    /// Each page is requested with the configured [`limit`], or 1000 members
+    /// if it isn't set.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Member> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let options = self.options;
+        let presences = self.fields.presences;
+
+        Paginator::new(
+            self.fields.after.map(|id| id.0),
+            Direction::After,
+            self.fields.limit.unwrap_or(GET_GUILD_MEMBERS_LIMIT_MAX),
+            |member: &Member| member.user.id.0,
+            move |after, limit| {
+                let request = Request::from_route(Route::GetGuildMembers {
+                    after,
+                    guild_id: guild_id.0,
+                    limit: Some(limit),
+                    presences,
+                });
+                let request = options.apply(request);
+
+                Box::pin(async move {
+                    let bytes = http.request_bytes(request).await?;
+
+                    parse_members(guild_id, &bytes)
+                })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetGuildMembers {
             after: self.fields.after.map(|x| x.0),
@@ -164,12 +208,16 @@ impl<'a> GetGuildMembers<'a> {
             presences: self.fields.presences,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
 }
 
+request_options!(GetGuildMembers<'_>);
+
 impl Future for GetGuildMembers<'_> {
     type Output = Result<Vec<Member>, HttpError>;
 
@@ -183,23 +231,27 @@ impl Future for GetGuildMembers<'_> {
         match fut.as_mut().poll(cx) {
             Poll::Ready(res) => {
                 let bytes = res?;
-                let mut members = Vec::new();
-
-                let values =
-                    crate::json::from_bytes::<Vec<Value>>(&bytes).map_err(HttpError::json)?;
 
-                for value in values {
-                    let member_deserializer = MemberDeserializer::new(self.guild_id);
-                    members.push(
-                        member_deserializer
-                            .deserialize(value)
-                            .map_err(HttpError::json)?,
-                    );
-                }
-
-                Poll::Ready(Ok(members))
+                Poll::Ready(parse_members(self.guild_id, &bytes))
             }
             Poll::Pending => Poll::Pending,
         }
     }
 }
+
+/// Deserialize a list of members, since they don't contain their guild's ID.
+fn parse_members(guild_id: GuildId, bytes: &Bytes) -> Result<Vec<Member>, HttpError> {
+    let values = crate::json::from_bytes::<Vec<Value>>(bytes).map_err(HttpError::json)?;
+    let mut members = Vec::with_capacity(values.len());
+
+    for value in values {
+        let member_deserializer = MemberDeserializer::new(guild_id);
+        members.push(
+            member_deserializer
+                .deserialize(value)
+                .map_err(HttpError::json)?,
+        );
+    }
+
+    Ok(members)
+}
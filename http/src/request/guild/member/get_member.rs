@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::{Error, ErrorType},
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use hyper::StatusCode;
@@ -26,6 +26,7 @@ pub struct GetMember<'a> {
     fut: Option<PendingOption<'a>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     user_id: UserId,
 }
 
@@ -35,6 +36,7 @@ impl<'a> GetMember<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             user_id,
         }
     }
@@ -45,12 +47,16 @@ impl<'a> GetMember<'a> {
             user_id: self.user_id.0,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
 }
 
+request_options!(GetMember<'_>);
+
 impl Future for GetMember<'_> {
     type Output = Result<Option<Member>, Error>;
 
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{GuildId, RoleId, UserId};
@@ -11,6 +11,7 @@ pub struct RemoveRoleFromMember<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     role_id: RoleId,
     user_id: UserId,
     reason: Option<String>,
@@ -27,6 +28,7 @@ impl<'a> RemoveRoleFromMember<'a> {
             fut: None,
             guild_id: guild_id.into(),
             http,
+            options: RequestOptions::default(),
             role_id: role_id.into(),
             user_id: user_id.into(),
             reason: None,
@@ -44,8 +46,9 @@ impl<'a> RemoveRoleFromMember<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
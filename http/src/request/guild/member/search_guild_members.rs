@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use hyper::body::Bytes;
@@ -113,6 +113,7 @@ pub struct SearchGuildMembers<'a> {
     fut: Option<Pending<'a, Bytes>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> SearchGuildMembers<'a> {
@@ -126,6 +127,7 @@ impl<'a> SearchGuildMembers<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -158,12 +160,16 @@ impl<'a> SearchGuildMembers<'a> {
             query: self.fields.query.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
 }
 
+request_options!(SearchGuildMembers<'_>);
+
 impl Future for SearchGuildMembers<'_> {
     type Output = Result<Vec<Member>, HttpError>;
 
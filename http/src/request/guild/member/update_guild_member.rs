@@ -3,6 +3,7 @@ use crate::{
     error::Error as HttpError,
     request::{
         self, validate, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request,
+        RequestOptions,
     },
     routing::Route,
 };
@@ -101,6 +102,7 @@ pub struct UpdateGuildMember<'a> {
     fut: Option<Pending<'a, Bytes>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     user_id: UserId,
     reason: Option<String>,
 }
@@ -112,6 +114,7 @@ impl<'a> UpdateGuildMember<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             user_id,
             reason: None,
         }
@@ -192,7 +195,9 @@ impl<'a> UpdateGuildMember<'a> {
 
     fn start(&mut self) -> Result<(), HttpError> {
         let request = self.request()?;
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -207,6 +212,8 @@ impl<'a> AuditLogReason for UpdateGuildMember<'a> {
     }
 }
 
+request_options!(UpdateGuildMember<'_>);
+
 impl Future for UpdateGuildMember<'_> {
     type Output = Result<Member, HttpError>;
 
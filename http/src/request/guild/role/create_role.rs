@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -48,6 +48,7 @@ pub struct CreateRole<'a> {
     fut: Option<Pending<'a, Role>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -58,6 +59,7 @@ impl<'a> CreateRole<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -109,8 +111,9 @@ impl<'a> CreateRole<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
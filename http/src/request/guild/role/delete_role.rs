@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::id::{GuildId, RoleId};
@@ -11,6 +11,7 @@ pub struct DeleteRole<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     role_id: RoleId,
     reason: Option<String>,
 }
@@ -21,6 +22,7 @@ impl<'a> DeleteRole<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             role_id,
             reason: None,
         }
@@ -36,8 +38,9 @@ impl<'a> DeleteRole<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.verify(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,9 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{self, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request},
+    request::{
+        self, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use serde::Serialize;
@@ -30,6 +32,7 @@ pub struct UpdateRole<'a> {
     fut: Option<Pending<'a, Role>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     role_id: RoleId,
     reason: Option<String>,
 }
@@ -41,6 +44,7 @@ impl<'a> UpdateRole<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             role_id,
             reason: None,
         }
@@ -96,8 +100,9 @@ impl<'a> UpdateRole<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{
@@ -16,6 +16,7 @@ pub struct UpdateRolePositions<'a> {
     fut: Option<Pending<'a, Vec<Role>>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     roles: Vec<(RoleId, u64)>,
 }
 
@@ -29,6 +30,7 @@ impl<'a> UpdateRolePositions<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             roles: roles.collect(),
         }
     }
@@ -40,7 +42,8 @@ impl<'a> UpdateRolePositions<'a> {
         .json(&self.roles)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
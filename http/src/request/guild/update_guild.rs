@@ -3,6 +3,7 @@ use crate::{
     error::Error as HttpError,
     request::{
         self, validate, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request,
+        RequestOptions,
     },
     routing::Route,
 };
@@ -116,6 +117,7 @@ pub struct UpdateGuild<'a> {
     fut: Option<Pending<'a, PartialGuild>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     reason: Option<String>,
 }
 
@@ -126,6 +128,7 @@ impl<'a> UpdateGuild<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             reason: None,
         }
     }
@@ -350,8 +353,9 @@ impl<'a> UpdateGuild<'a> {
             request = request.headers(request::audit_header(reason)?)
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        self.fut.replace(Box::pin(
+            self.http.request(self.options.apply(request.build())),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -39,6 +39,7 @@ pub struct UpdateGuildChannelPositions<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     positions: Vec<Position>,
 }
 
@@ -54,6 +55,7 @@ impl<'a> UpdateGuildChannelPositions<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             positions,
         }
     }
@@ -65,7 +67,8 @@ impl<'a> UpdateGuildChannelPositions<'a> {
         .json(&self.positions)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
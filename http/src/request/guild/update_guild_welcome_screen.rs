@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -30,6 +30,7 @@ pub struct UpdateGuildWelcomeScreen<'a> {
     fut: Option<Pending<'a, WelcomeScreen>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateGuildWelcomeScreen<'a> {
@@ -39,6 +40,7 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -77,7 +79,8 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
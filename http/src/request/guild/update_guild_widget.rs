@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{NullableField, Pending, Request},
+    request::{NullableField, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -24,6 +24,7 @@ pub struct UpdateGuildWidget<'a> {
     fut: Option<Pending<'a, GuildWidget>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateGuildWidget<'a> {
@@ -33,6 +34,7 @@ impl<'a> UpdateGuildWidget<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -60,7 +62,8 @@ impl<'a> UpdateGuildWidget<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
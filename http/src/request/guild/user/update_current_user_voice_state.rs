@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{NullableField, Pending, Request},
+    request::{NullableField, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -22,6 +22,7 @@ pub struct UpdateCurrentUserVoiceState<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateCurrentUserVoiceState<'a> {
@@ -35,6 +36,7 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -83,7 +85,8 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.verify(request)));
+        self.fut
+            .replace(Box::pin(self.http.verify(self.options.apply(request))));
 
         Ok(())
     }
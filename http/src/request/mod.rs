@@ -1,5 +1,51 @@
+/// Implement methods setting the `RequestOptions` of a request builder.
+///
+/// The builder must have an `options` field.
+macro_rules! request_options {
+    ($ty: ty) => {
+        impl $ty {
+            /// Set a header to send with the request.
+            ///
+            /// This takes precedence over headers set by the request itself,
+            /// but not over the client's default headers.
+            #[must_use]
+            pub fn header(
+                mut self,
+                name: hyper::header::HeaderName,
+                value: hyper::header::HeaderValue,
+            ) -> Self {
+                self.options.header(name, value);
+
+                self
+            }
+
+            /// Set the timeout of the request, overriding the client's
+            /// timeout.
+            ///
+            /// The request can also be cancelled at any time by dropping it.
+            #[must_use]
+            pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.options.timeout(timeout);
+
+                self
+            }
+
+            /// Set whether time spent waiting on the ratelimiter counts
+            /// against the timeout, overriding the client's setting.
+            #[must_use]
+            pub fn timeout_includes_ratelimit(mut self, include: bool) -> Self {
+                self.options.timeout_includes_ratelimit(include);
+
+                self
+            }
+        }
+    };
+}
+
 macro_rules! poll_req {
     ($ty: ty, $ret: ty) => {
+        request_options!($ty);
+
         impl std::future::Future for $ty {
             type Output = ::std::result::Result<$ret, $crate::error::Error>;
 
@@ -21,6 +67,8 @@ macro_rules! poll_req {
     };
 
     (opt, $ty: ty, $ret: ty) => {
+        request_options!($ty);
+
         impl std::future::Future for $ty {
             type Output = ::std::result::Result<Option<$ret>, $crate::error::Error>;
 
@@ -62,6 +110,7 @@ pub mod prelude;
 pub mod template;
 pub mod user;
 
+mod attachment;
 mod audit_reason;
 mod base;
 mod get_gateway;
@@ -69,9 +118,12 @@ mod get_gateway_authed;
 mod get_user_application;
 mod get_voice_regions;
 mod multipart;
+mod options;
+mod paginator;
 mod validate;
 
 pub use self::{
+    attachment::AttachmentFile,
     audit_reason::{AuditLogReason, AuditLogReasonError},
     base::{Request, RequestBuilder},
     get_gateway::GetGateway,
@@ -79,6 +131,12 @@ pub use self::{
     get_user_application::GetUserApplicationInfo,
     get_voice_regions::GetVoiceRegions,
     multipart::Form,
+    paginator::Paginator,
+};
+
+pub(crate) use self::{
+    attachment::{AttachmentEntry, PartialAttachment},
+    options::RequestOptions,
 };
 
 use crate::error::{Error, ErrorType};
@@ -111,6 +169,26 @@ pub enum Method {
 }
 
 impl Method {
+    /// Whether sending a request with the method multiple times has the same
+    /// effect as sending it once.
+    ///
+    /// `DELETE`, `GET`, and `PUT` are idempotent, while `PATCH` and `POST`
+    /// are not.
+    pub const fn is_idempotent(self) -> bool {
+        matches!(self, Self::Delete | Self::Get | Self::Put)
+    }
+
+    /// Name of the method, such as `GET`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Delete => "DELETE",
+            Self::Get => "GET",
+            Self::Patch => "PATCH",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+        }
+    }
+
     pub(crate) const fn into_hyper(self) -> HyperMethod {
         match self {
             Self::Delete => HyperMethod::DELETE,
@@ -188,4 +266,13 @@ mod tests {
         assert_eq!(HyperMethod::POST, Method::Post.into_hyper());
         assert_eq!(HyperMethod::PUT, Method::Put.into_hyper());
     }
+
+    #[test]
+    fn test_method_idempotency() {
+        assert!(Method::Delete.is_idempotent());
+        assert!(Method::Get.is_idempotent());
+        assert!(!Method::Patch.is_idempotent());
+        assert!(!Method::Post.is_idempotent());
+        assert!(Method::Put.is_idempotent());
+    }
 }
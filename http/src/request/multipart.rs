@@ -1,9 +1,30 @@
+use super::attachment::{AttachmentFile, FileSource, FileStream, SharedFileStream};
+use futures_util::stream::Stream;
+use hyper::{body::Bytes, Body};
 use rand::{distributions::Alphanumeric, Rng};
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
-#[derive(Debug)]
+/// Part of the body of a form.
+#[derive(Clone)]
+enum Segment {
+    Bytes(Bytes),
+    Stream(SharedFileStream),
+}
+
+#[derive(Clone)]
 pub struct Form {
+    attachments_len: u64,
     boundary: [u8; 15],
     buffer: Vec<u8>,
+    len: u64,
+    segments: Vec<Segment>,
 }
 
 impl Form {
@@ -11,12 +32,36 @@ impl Form {
         Self::default()
     }
 
-    pub fn build(mut self) -> Vec<u8> {
+    /// Build the body of the form.
+    ///
+    /// Returns `None` if the contents of a streamed file were already sent.
+    pub(crate) fn into_body(mut self) -> Option<Body> {
         self.buffer.extend(b"\r\n");
         self.boundary();
         self.buffer.extend(b"--");
+        self.flush();
+
+        if self.segments.len() == 1 {
+            if let Some(Segment::Bytes(bytes)) = self.segments.pop() {
+                return Some(Body::from(bytes));
+            }
+        }
 
-        self.buffer
+        let mut parts = VecDeque::with_capacity(self.segments.len());
+
+        for segment in self.segments {
+            parts.push_back(match segment {
+                Segment::Bytes(bytes) => Part::Bytes(bytes),
+                Segment::Stream(stream) => Part::Stream(stream.lock().ok()?.take()?),
+            });
+        }
+
+        Some(Body::wrap_stream(FormStream { parts }))
+    }
+
+    /// Total length of the files of the form in bytes.
+    pub fn attachments_len(&self) -> u64 {
+        self.attachments_len
     }
 
     pub fn content_type(&self) -> Vec<u8> {
@@ -29,11 +74,51 @@ impl Form {
         content_type
     }
 
+    /// Length of the body of the form in bytes.
+    pub fn len(&self) -> u64 {
+        // The closing boundary is `\r\n--{boundary}--`.
+        self.len + self.buffer.len() as u64 + 6 + self.boundary.len() as u64
+    }
+
+    /// Whether the form has a file whose contents are streamed, which can only
+    /// be sent once.
+    pub(crate) fn is_streamed(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Stream(_)))
+    }
+
+    /// Whether the form has no parts.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0 && self.buffer.is_empty()
+    }
+
+    /// Add a file whose contents may be streamed.
+    pub fn attachment(&mut self, name: &[u8], file: &AttachmentFile) -> &mut Self {
+        self.start();
+        self.name(name);
+        self.filename(file.filename().as_bytes());
+        self.buffer.extend(b"\r\n\r\n");
+        self.attachments_len += file.len();
+
+        match &file.source {
+            FileSource::Bytes(bytes) => self.buffer.extend(bytes),
+            FileSource::Stream(stream) => {
+                self.flush();
+                self.len += file.len();
+                self.segments.push(Segment::Stream(stream.clone()));
+            }
+        }
+
+        self
+    }
+
     pub fn file(&mut self, name: &[u8], filename: &[u8], data: &[u8]) -> &mut Self {
         self.start();
         self.name(name);
         self.filename(filename);
         self.data(data);
+        self.attachments_len += data.len() as u64;
 
         self
     }
@@ -82,6 +167,27 @@ impl Form {
         self.buffer.extend(b"\r\n\r\n");
         self.buffer.extend(data);
     }
+
+    /// Move the buffered bytes into a segment.
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let buffer = mem::take(&mut self.buffer);
+        self.len += buffer.len() as u64;
+        self.segments.push(Segment::Bytes(Bytes::from(buffer)));
+    }
+}
+
+impl Debug for Form {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Form")
+            .field("attachments_len", &self.attachments_len)
+            .field("boundary", &self.boundary)
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for Form {
@@ -94,8 +200,120 @@ impl Default for Form {
         }
 
         Self {
+            attachments_len: 0,
             boundary,
             buffer: Vec::new(),
+            len: 0,
+            segments: Vec::new(),
+        }
+    }
+}
+
+/// Part of the body of a form being sent.
+enum Part {
+    Bytes(Bytes),
+    Stream(FileStream),
+}
+
+/// Stream of the body of a form with streamed files.
+struct FormStream {
+    parts: VecDeque<Part>,
+}
+
+impl Stream for FormStream {
+    type Item = Result<Bytes, Box<dyn Error + Send + Sync>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let poll = match self.parts.front_mut() {
+                Some(Part::Bytes(bytes)) => {
+                    let bytes = mem::take(bytes);
+                    self.parts.pop_front();
+
+                    return Poll::Ready(Some(Ok(bytes)));
+                }
+                Some(Part::Stream(stream)) => stream.as_mut().poll_next(cx),
+                None => return Poll::Ready(None),
+            };
+
+            match poll {
+                Poll::Ready(None) => {
+                    self.parts.pop_front();
+                }
+                other => return other,
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Form;
+    use crate::request::AttachmentFile;
+    use futures_util::stream;
+    use hyper::body::{self, Bytes};
+    use std::{convert::Infallible, io::Error as IoError};
+
+    fn file_part(boundary: &str, name: &str, filename: &str, data: &str) -> String {
+        format!(
+            "\r\n--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\r\n{}",
+            boundary, name, filename, data,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_streamed_body() {
+        let mut form = Form::new();
+        let boundary = String::from_utf8(form.boundary.to_vec()).unwrap();
+
+        form.attachment(
+            b"files[0]",
+            &AttachmentFile::from_stream(
+                "a.txt",
+                6,
+                stream::iter(vec![
+                    Ok::<_, IoError>(Bytes::from_static(b"abc")),
+                    Ok(Bytes::from_static(b"def")),
+                ]),
+            ),
+        );
+        form.attachment(
+            b"files[1]",
+            &AttachmentFile::from_bytes("b.txt", "ghi").spoiler(true),
+        );
+        form.payload_json(b"{}");
+
+        let expected = format!(
+            "{}{}\r\n--{}\r\nContent-Disposition: form-data; name=\"payload_json\"\
+            \r\nContent-Type: application/json\r\n\r\n{{}}\r\n--{}--",
+            file_part(&boundary, "files[0]", "a.txt", "abcdef"),
+            file_part(&boundary, "files[1]", "SPOILER_b.txt", "ghi"),
+            boundary,
+            boundary,
+        );
+
+        assert_eq!(9, form.attachments_len());
+        assert_eq!(expected.len() as u64, form.len());
+
+        // Clones share streams, which can only be sent once.
+        let clone = form.clone();
+        let body = body::to_bytes(form.into_body().unwrap()).await.unwrap();
+        assert_eq!(expected.as_bytes(), body.as_ref());
+        assert!(clone.into_body().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_error() {
+        let mut form = Form::new();
+        form.attachment(
+            b"files[0]",
+            &AttachmentFile::from_stream(
+                "a.txt",
+                4,
+                stream::iter(vec![Ok::<_, Infallible>(Bytes::from_static(b"abc"))]),
+            ),
+        );
+
+        assert!(body::to_bytes(form.into_body().unwrap()).await.is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use super::Request;
+use hyper::header::{HeaderMap, HeaderName, HeaderValue};
+use std::time::Duration;
+
+/// Options set on a request builder, overriding the client's configuration
+/// for a single request.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RequestOptions {
+    headers: HeaderMap<HeaderValue>,
+    timeout: Option<Duration>,
+    timeout_includes_ratelimit: Option<bool>,
+}
+
+impl RequestOptions {
+    pub fn header(&mut self, name: HeaderName, value: HeaderValue) {
+        self.headers.insert(name, value);
+    }
+
+    pub fn timeout(&mut self, timeout: Duration) {
+        self.timeout.replace(timeout);
+    }
+
+    pub fn timeout_includes_ratelimit(&mut self, include: bool) {
+        self.timeout_includes_ratelimit.replace(include);
+    }
+
+    /// Apply the options to a request, taking precedence over the request's
+    /// own headers.
+    pub fn apply(&self, mut request: Request) -> Request {
+        if !self.headers.is_empty() {
+            let headers = request.headers.get_or_insert_with(HeaderMap::new);
+
+            for (name, value) in &self.headers {
+                headers.insert(name, value.clone());
+            }
+        }
+
+        if let Some(timeout) = self.timeout {
+            request.timeout.replace(timeout);
+        }
+
+        if let Some(include) = self.timeout_includes_ratelimit {
+            request.timeout_includes_ratelimit.replace(include);
+        }
+
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestOptions;
+    use crate::{request::Request, routing::Route};
+    use hyper::header::{HeaderMap, HeaderValue, CONTENT_LANGUAGE, IF_MATCH};
+    use std::time::Duration;
+
+    #[test]
+    fn test_apply() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_LANGUAGE, HeaderValue::from_static("en"));
+        headers.insert(IF_MATCH, HeaderValue::from_static("a"));
+        let request = Request::from((headers, Route::GetGateway));
+
+        let mut options = RequestOptions::default();
+        options.header(IF_MATCH, HeaderValue::from_static("b"));
+        options.timeout(Duration::from_secs(30));
+        options.timeout_includes_ratelimit(false);
+
+        let request = options.apply(request);
+        let headers = request.headers.as_ref().unwrap();
+        assert_eq!("en", headers[CONTENT_LANGUAGE]);
+        assert_eq!("b", headers[IF_MATCH]);
+        assert_eq!(Some(Duration::from_secs(30)), request.timeout());
+        assert_eq!(Some(false), request.timeout_includes_ratelimit());
+    }
+
+    #[test]
+    fn test_apply_empty() {
+        let request = RequestOptions::default().apply(Request::from_route(Route::GetGateway));
+
+        assert!(request.headers.is_none());
+        assert!(request.timeout().is_none());
+        assert!(request.timeout_includes_ratelimit().is_none());
+    }
+}
@@ -0,0 +1,280 @@
+use super::Pending;
+use crate::error::Error;
+use futures_util::stream::Stream;
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Function to request a page with a cursor and limit.
+type FetchPage<'a, T> = Box<dyn FnMut(Option<u64>, u64) -> Pending<'a, Vec<T>> + Send + 'a>;
+
+/// Direction in which pages are retrieved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Direction {
+    /// Retrieve items with IDs after the cursor.
+    After,
+    /// Retrieve items with IDs before the cursor.
+    Before,
+    /// Retrieve a single page.
+    Once,
+}
+
+/// Stream of items across all pages of a list endpoint.
+///
+/// Created by the `paginate` method of request builders with cursors, such as
+/// [`GetChannelMessages::paginate`]. Pages are requested one at a time as the
+/// stream is polled, advancing the cursor to the last retrieved item. The
+/// stream ends when Discord returns a page with fewer items than requested,
+/// or when the [`total`] number of items has been yielded.
+///
+/// If a request fails then the error is yielded and the stream ends.
+///
+/// # Examples
+///
+/// Count the number of members in a guild:
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::id::GuildId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+///
+/// let mut members = client.guild_members(GuildId(1)).paginate();
+/// let mut count = 0;
+///
+/// while let Some(member) = members.next().await {
+///     member?;
+///     count += 1;
+/// }
+///
+/// println!("guild has {} members", count);
+/// # Ok(()) }
+/// ```
+///
+/// [`GetChannelMessages::paginate`]: super::channel::message::GetChannelMessages::paginate
+/// [`total`]: Self::total
+pub struct Paginator<'a, T> {
+    buffer: VecDeque<T>,
+    cursor: Option<u64>,
+    direction: Direction,
+    done: bool,
+    fetch: FetchPage<'a, T>,
+    fut: Option<Pending<'a, Vec<T>>>,
+    id: fn(&T) -> u64,
+    page_size: u64,
+    remaining: Option<u64>,
+    requested: u64,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    pub(crate) fn new(
+        cursor: Option<u64>,
+        direction: Direction,
+        page_size: u64,
+        id: fn(&T) -> u64,
+        fetch: impl FnMut(Option<u64>, u64) -> Pending<'a, Vec<T>> + Send + 'a,
+    ) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            cursor,
+            direction,
+            done: false,
+            fetch: Box::new(fetch),
+            fut: None,
+            id,
+            page_size,
+            remaining: None,
+            requested: 0,
+        }
+    }
+
+    /// Set the maximum number of items to yield across all pages.
+    ///
+    /// The last page is requested with a smaller limit if fewer items are
+    /// needed.
+    pub fn total(mut self, total: u64) -> Self {
+        self.remaining.replace(total);
+
+        self
+    }
+
+    /// Advance the cursor past a retrieved page.
+    fn advance(&mut self, page: &[T]) {
+        let ids = page.iter().map(self.id);
+
+        let cursor = match self.direction {
+            Direction::After => ids.max(),
+            Direction::Before => ids.min(),
+            Direction::Once => None,
+        };
+
+        if cursor.is_none() || (page.len() as u64) < self.requested {
+            self.done = true;
+        }
+
+        self.cursor = cursor;
+    }
+}
+
+// Items are never pinned, only moved in and out of the buffer.
+impl<T> Unpin for Paginator<'_, T> {}
+
+impl<T> Stream for Paginator<'_, T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.remaining == Some(0) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = self.buffer.pop_front() {
+                if let Some(remaining) = self.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            if self.fut.is_none() {
+                let limit = match self.remaining {
+                    Some(remaining) if remaining < self.page_size => remaining,
+                    _ => self.page_size,
+                };
+                let cursor = self.cursor;
+
+                let fut = (self.fetch)(cursor, limit);
+                self.fut.replace(fut);
+                self.requested = limit;
+            }
+
+            let fut = self.fut.as_mut().expect("future is created");
+
+            match fut.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => {
+                    self.fut.take();
+                    self.advance(&page);
+                    self.buffer.extend(page);
+                }
+                Poll::Ready(Err(source)) => {
+                    self.fut.take();
+                    self.done = true;
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Paginator};
+    use futures_util::{future, StreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::TryFrom,
+        sync::{Arc, Mutex},
+    };
+
+    assert_impl_all!(Paginator<'_, u64>: Send);
+
+    /// Cursor and limit of each requested page.
+    type Requests = Arc<Mutex<Vec<(Option<u64>, u64)>>>;
+
+    /// Create a paginator over the IDs 1 through `count`, recording the
+    /// cursor and limit of every page request.
+    fn paginator(
+        count: u64,
+        direction: Direction,
+        page_size: u64,
+    ) -> (Paginator<'static, u64>, Requests) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        let paginator = Paginator::new(
+            None,
+            direction,
+            page_size,
+            |id| *id,
+            move |cursor, limit| {
+                recorded.lock().unwrap().push((cursor, limit));
+                let take = usize::try_from(limit).unwrap_or(usize::MAX);
+
+                let page = match direction {
+                    Direction::Before => (1..=count)
+                        .rev()
+                        .filter(|id| cursor.is_none_or(|cursor| *id < cursor))
+                        .take(take)
+                        .collect(),
+                    _ => (1..=count)
+                        .filter(|id| cursor.is_none_or(|cursor| *id > cursor))
+                        .take(take)
+                        .collect(),
+                };
+
+                Box::pin(future::ok(page))
+            },
+        );
+
+        (paginator, requests)
+    }
+
+    #[tokio::test]
+    async fn test_after_stops_on_short_page() {
+        let (paginator, requests) = paginator(5, Direction::After, 2);
+
+        let items = paginator.map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(vec![1, 2, 3, 4, 5], items);
+        assert_eq!(
+            vec![(None, 2), (Some(2), 2), (Some(4), 2)],
+            *requests.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_before() {
+        let (paginator, requests) = paginator(4, Direction::Before, 2);
+
+        let items = paginator.map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(vec![4, 3, 2, 1], items);
+        // The last page was full, so an empty page is needed to know there
+        // are no more items.
+        assert_eq!(
+            vec![(None, 2), (Some(3), 2), (Some(1), 2)],
+            *requests.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_total() {
+        let (paginator, requests) = paginator(10, Direction::After, 4);
+
+        let items = paginator
+            .total(6)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], items);
+        assert_eq!(vec![(None, 4), (Some(4), 2)], *requests.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_once() {
+        let (paginator, requests) = paginator(10, Direction::Once, 3);
+
+        let items = paginator.map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(vec![1, 2, 3], items);
+        assert_eq!(1, requests.lock().unwrap().len());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -84,6 +84,7 @@ pub struct CreateGuildFromTemplate<'a> {
     fields: CreateGuildFromTemplateFields,
     fut: Option<Pending<'a, Guild>>,
     http: &'a Client,
+    options: RequestOptions,
     template_code: String,
 }
 
@@ -111,6 +112,7 @@ impl<'a> CreateGuildFromTemplate<'a> {
             fields: CreateGuildFromTemplateFields { name, icon: None },
             fut: None,
             http,
+            options: RequestOptions::default(),
             template_code,
         })
     }
@@ -139,7 +141,8 @@ impl<'a> CreateGuildFromTemplate<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
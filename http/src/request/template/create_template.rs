@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -94,6 +94,7 @@ pub struct CreateTemplate<'a> {
     fut: Option<Pending<'a, Template>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> CreateTemplate<'a> {
@@ -124,6 +125,7 @@ impl<'a> CreateTemplate<'a> {
             guild_id,
             fut: None,
             http,
+            options: RequestOptions::default(),
         })
     }
 
@@ -158,7 +160,8 @@ impl<'a> CreateTemplate<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
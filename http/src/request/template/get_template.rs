@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::template::Template;
@@ -10,6 +10,7 @@ use twilight_model::template::Template;
 pub struct GetTemplate<'a> {
     fut: Option<Pending<'a, Template>>,
     http: &'a Client,
+    options: RequestOptions,
     template_code: String,
 }
 
@@ -22,6 +23,7 @@ impl<'a> GetTemplate<'a> {
         Self {
             fut: None,
             http,
+            options: RequestOptions::default(),
             template_code,
         }
     }
@@ -31,7 +33,8 @@ impl<'a> GetTemplate<'a> {
             template_code: self.template_code.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::{id::GuildId, template::Template};
@@ -11,6 +11,7 @@ pub struct SyncTemplate<'a> {
     fut: Option<Pending<'a, Template>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     template_code: String,
 }
 
@@ -28,6 +29,7 @@ impl<'a> SyncTemplate<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             template_code,
         }
     }
@@ -38,7 +40,8 @@ impl<'a> SyncTemplate<'a> {
             template_code: self.template_code.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -86,6 +86,7 @@ pub struct UpdateTemplate<'a> {
     fut: Option<Pending<'a, Template>>,
     guild_id: GuildId,
     http: &'a Client,
+    options: RequestOptions,
     template_code: String,
 }
 
@@ -107,6 +108,7 @@ impl<'a> UpdateTemplate<'a> {
             fut: None,
             guild_id,
             http,
+            options: RequestOptions::default(),
             template_code,
         }
     }
@@ -167,7 +169,8 @@ impl<'a> UpdateTemplate<'a> {
         .json(&self.fields)?
         .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
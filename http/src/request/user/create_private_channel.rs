@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -19,6 +19,7 @@ pub struct CreatePrivateChannel<'a> {
     fields: CreatePrivateChannelFields,
     fut: Option<Pending<'a, PrivateChannel>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> CreatePrivateChannel<'a> {
@@ -27,6 +28,7 @@ impl<'a> CreatePrivateChannel<'a> {
             fields: CreatePrivateChannelFields { recipient_id },
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
     fn start(&mut self) -> Result<(), Error> {
@@ -34,7 +36,8 @@ impl<'a> CreatePrivateChannel<'a> {
             .json(&self.fields)?
             .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
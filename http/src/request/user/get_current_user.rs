@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::user::CurrentUser;
@@ -10,11 +10,16 @@ use twilight_model::user::CurrentUser;
 pub struct GetCurrentUser<'a> {
     fut: Option<Pending<'a, CurrentUser>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetCurrentUser<'a> {
     pub(crate) fn new(http: &'a Client) -> Self {
-        Self { fut: None, http }
+        Self {
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
     }
 
     fn start(&mut self) -> Result<(), Error> {
@@ -22,7 +27,8 @@ impl<'a> GetCurrentUser<'a> {
             target_user: "@me".to_owned(),
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{Pending, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::user::Connection;
@@ -12,17 +12,23 @@ use twilight_model::user::Connection;
 pub struct GetCurrentUserConnections<'a> {
     fut: Option<Pending<'a, Vec<Connection>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetCurrentUserConnections<'a> {
     pub(crate) fn new(http: &'a Client) -> Self {
-        Self { fut: None, http }
+        Self {
+            fut: None,
+            http,
+            options: RequestOptions::default(),
+        }
     }
 
     fn start(&mut self) -> Result<(), Error> {
         let request = Request::from_route(Route::GetUserConnections);
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
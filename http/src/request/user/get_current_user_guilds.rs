@@ -1,7 +1,11 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{
+        paginator::Direction,
+        validate::{self, GET_CURRENT_USER_GUILDS_LIMIT_MAX},
+        Paginator, Pending, Request, RequestOptions,
+    },
     routing::Route,
 };
 use std::{
@@ -99,6 +103,7 @@ pub struct GetCurrentUserGuilds<'a> {
     fields: GetCurrentUserGuildsFields,
     fut: Option<Pending<'a, Vec<CurrentUserGuild>>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> GetCurrentUserGuilds<'a> {
@@ -111,6 +116,7 @@ impl<'a> GetCurrentUserGuilds<'a> {
             },
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -150,6 +156,53 @@ impl<'a> GetCurrentUserGuilds<'a> {
         Ok(self)
     }
 
+    /// Stream all of the current user's guilds.
+    ///
+    /// Guilds are streamed in ascending order of ID, bounded by [`before`] if
+    /// it is set. If only [`before`] is set then guilds are streamed in
+    /// descending order from it.
+    ///
+    /// Each page is requested with the configured [`limit`], or 100 guilds if
+    /// it isn't set.
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, CurrentUserGuild> {
+        let http = self.http;
+        let options = self.options;
+        let GetCurrentUserGuildsFields {
+            after,
+            before,
+            limit,
+        } = self.fields;
+
+        let (cursor, direction) = match (after, before) {
+            (None, Some(before)) => (Some(before), Direction::Before),
+            (after, _) => (after, Direction::After),
+        };
+
+        Paginator::new(
+            cursor.map(|id| id.0),
+            direction,
+            limit.unwrap_or(GET_CURRENT_USER_GUILDS_LIMIT_MAX),
+            |guild: &CurrentUserGuild| guild.id.0,
+            move |cursor, limit| {
+                let (after, before) = match direction {
+                    Direction::Before => (None, cursor),
+                    _ => (cursor, before.map(|id| id.0)),
+                };
+
+                let request = Request::from_route(Route::GetGuilds {
+                    after,
+                    before,
+                    limit: Some(limit),
+                });
+
+                Box::pin(http.request(options.apply(request)))
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetGuilds {
             after: self.fields.after.map(|x| x.0),
@@ -157,7 +210,8 @@ impl<'a> GetCurrentUserGuilds<'a> {
             limit: self.fields.limit,
         });
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{PendingOption, Request},
+    request::{PendingOption, Request, RequestOptions},
     routing::Route,
 };
 use twilight_model::user::User;
@@ -10,6 +10,7 @@ use twilight_model::user::User;
 pub struct GetUser<'a> {
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
+    options: RequestOptions,
     target_user: String,
 }
 
@@ -18,6 +19,7 @@ impl<'a> GetUser<'a> {
         Self {
             fut: None,
             http,
+            options: RequestOptions::default(),
             target_user: target_user.into(),
         }
     }
@@ -27,7 +29,9 @@ impl<'a> GetUser<'a> {
             target_user: self.target_user.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(
+            self.http.request_bytes(self.options.apply(request)),
+        ));
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, NullableField, Pending, Request},
+    request::{validate, NullableField, Pending, Request, RequestOptions},
     routing::Route,
 };
 use serde::Serialize;
@@ -83,6 +83,7 @@ pub struct UpdateCurrentUser<'a> {
     fields: UpdateCurrentUserFields,
     fut: Option<Pending<'a, User>>,
     http: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> UpdateCurrentUser<'a> {
@@ -91,6 +92,7 @@ impl<'a> UpdateCurrentUser<'a> {
             fields: UpdateCurrentUserFields::default(),
             fut: None,
             http,
+            options: RequestOptions::default(),
         }
     }
 
@@ -138,7 +140,8 @@ impl<'a> UpdateCurrentUser<'a> {
             .json(&self.fields)?
             .build();
 
-        self.fut.replace(Box::pin(self.http.request(request)));
+        self.fut
+            .replace(Box::pin(self.http.request(self.options.apply(request))));
 
         Ok(())
     }
@@ -5,9 +5,296 @@
 /// different modules.
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::embed::Embed;
+use twilight_model::{
+    application::{
+        command::CommandType,
+        component::{ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu},
+    },
+    channel::embed::Embed,
+};
+
+/// A component is not valid.
+///
+/// Referenced values are used from [the Discord docs][docs].
+///
+/// [docs]: https://discord.com/developers/docs/interactions/message-components
+#[derive(Debug)]
+pub struct ComponentValidationError {
+    kind: ComponentValidationErrorType,
+}
+
+impl ComponentValidationError {
+    /// The maximum number of components in an action row.
+    pub const ACTION_ROW_COMPONENT_COUNT: usize = 5;
+
+    /// The maximum button label length in codepoints.
+    pub const BUTTON_LABEL_LENGTH: usize = 80;
+
+    /// The maximum number of root components in a message.
+    pub const COMPONENT_COUNT: usize = 5;
+
+    /// The maximum custom ID length in codepoints.
+    pub const CUSTOM_ID_LENGTH: usize = 100;
+
+    /// The maximum number of values that can be chosen in a select menu.
+    pub const SELECT_MENU_VALUES_LIMIT: u8 = 25;
+
+    /// The maximum number of options in a select menu.
+    pub const SELECT_MENU_OPTION_COUNT: usize = 25;
+
+    /// The maximum select menu option description length in codepoints.
+    pub const SELECT_MENU_OPTION_DESCRIPTION_LENGTH: usize = 100;
+
+    /// The maximum select menu option label length in codepoints.
+    pub const SELECT_MENU_OPTION_LABEL_LENGTH: usize = 100;
+
+    /// The maximum select menu option value length in codepoints.
+    pub const SELECT_MENU_OPTION_VALUE_LENGTH: usize = 100;
+
+    /// The maximum select menu placeholder length in codepoints.
+    pub const SELECT_MENU_PLACEHOLDER_LENGTH: usize = 100;
+
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ComponentValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ComponentValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ComponentValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ComponentValidationErrorType::ActionRowComponentCount { count } => {
+                f.write_str("an action row has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" components, but the max is ")?;
+
+                Display::fmt(&Self::ACTION_ROW_COMPONENT_COUNT, f)
+            }
+            ComponentValidationErrorType::ButtonLabelLength { chars } => {
+                f.write_str("a button label is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::BUTTON_LABEL_LENGTH, f)
+            }
+            ComponentValidationErrorType::ButtonStyle { style } => {
+                f.write_str("a button of style ")?;
+                Debug::fmt(style, f)?;
+
+                if *style == ButtonStyle::Link {
+                    f.write_str(" must have a url and no custom id")
+                } else {
+                    f.write_str(" must have a custom id and no url")
+                }
+            }
+            ComponentValidationErrorType::ComponentCount { count } => {
+                f.write_str("there are ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" root components, but the max is ")?;
+
+                Display::fmt(&Self::COMPONENT_COUNT, f)
+            }
+            ComponentValidationErrorType::CustomIdLength { chars } => {
+                f.write_str("a custom id is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::CUSTOM_ID_LENGTH, f)
+            }
+            ComponentValidationErrorType::InvalidChildComponent { kind } => {
+                f.write_str("a '")?;
+                f.write_str(kind.name())?;
+
+                f.write_str("' component may not be placed in an action row")
+            }
+            ComponentValidationErrorType::InvalidRootComponent { kind } => {
+                f.write_str("a '")?;
+                f.write_str(kind.name())?;
+
+                f.write_str("' component may not be a root component")
+            }
+            ComponentValidationErrorType::SelectMenuNotAlone => {
+                f.write_str("a select menu must be the only component in its action row")
+            }
+            ComponentValidationErrorType::SelectMenuOptionCount { count } => {
+                f.write_str("a select menu has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" options, but it must have at least 1 and at most ")?;
+
+                Display::fmt(&Self::SELECT_MENU_OPTION_COUNT, f)
+            }
+            ComponentValidationErrorType::SelectMenuOptionDescriptionLength { chars } => {
+                f.write_str("a select menu option description is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_MENU_OPTION_DESCRIPTION_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectMenuOptionLabelLength { chars } => {
+                f.write_str("a select menu option label is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_MENU_OPTION_LABEL_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectMenuOptionValueLength { chars } => {
+                f.write_str("a select menu option value is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_MENU_OPTION_VALUE_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectMenuPlaceholderLength { chars } => {
+                f.write_str("a select menu placeholder is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_MENU_PLACEHOLDER_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectMenuValues { max, min } => {
+                f.write_str("a select menu's minimum and maximum values are ")?;
+                Debug::fmt(min, f)?;
+                f.write_str(" and ")?;
+                Debug::fmt(max, f)?;
+                f.write_str(", but the maximum must be at least the minimum and between 1 and ")?;
+
+                Display::fmt(&Self::SELECT_MENU_VALUES_LIMIT, f)
+            }
+        }
+    }
+}
+
+impl Error for ComponentValidationError {}
+
+/// Type of [`ComponentValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ComponentValidationErrorType {
+    /// An action row has more than
+    /// [the maximum][`ACTION_ROW_COMPONENT_COUNT`] number of components.
+    ///
+    /// [`ACTION_ROW_COMPONENT_COUNT`]: ComponentValidationError::ACTION_ROW_COMPONENT_COUNT
+    ActionRowComponentCount {
+        /// The number of components that were provided.
+        count: usize,
+    },
+    /// A button label is larger than [the maximum][`BUTTON_LABEL_LENGTH`].
+    ///
+    /// [`BUTTON_LABEL_LENGTH`]: ComponentValidationError::BUTTON_LABEL_LENGTH
+    ButtonLabelLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A button doesn't have the fields required by its style.
+    ///
+    /// Buttons of the [`ButtonStyle::Link`] style must have a URL and no
+    /// custom ID, while buttons of other styles must have a custom ID and no
+    /// URL.
+    ButtonStyle {
+        /// Style of the button.
+        style: ButtonStyle,
+    },
+    /// There are more than [the maximum][`COMPONENT_COUNT`] number of root
+    /// components.
+    ///
+    /// [`COMPONENT_COUNT`]: ComponentValidationError::COMPONENT_COUNT
+    ComponentCount {
+        /// The number of components that were provided.
+        count: usize,
+    },
+    /// A custom ID is larger than [the maximum][`CUSTOM_ID_LENGTH`].
+    ///
+    /// [`CUSTOM_ID_LENGTH`]: ComponentValidationError::CUSTOM_ID_LENGTH
+    CustomIdLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A component was placed in an action row that can't be, such as another
+    /// action row.
+    InvalidChildComponent {
+        /// Type of the component.
+        kind: ComponentType,
+    },
+    /// A component that isn't an action row was provided as a root component.
+    InvalidRootComponent {
+        /// Type of the component.
+        kind: ComponentType,
+    },
+    /// A select menu shares its action row with other components.
+    SelectMenuNotAlone,
+    /// A select menu has no options or more than
+    /// [the maximum][`SELECT_MENU_OPTION_COUNT`] number of options.
+    ///
+    /// [`SELECT_MENU_OPTION_COUNT`]: ComponentValidationError::SELECT_MENU_OPTION_COUNT
+    SelectMenuOptionCount {
+        /// The number of options that were provided.
+        count: usize,
+    },
+    /// A select menu option description is larger than
+    /// [the maximum][`SELECT_MENU_OPTION_DESCRIPTION_LENGTH`].
+    ///
+    /// [`SELECT_MENU_OPTION_DESCRIPTION_LENGTH`]: ComponentValidationError::SELECT_MENU_OPTION_DESCRIPTION_LENGTH
+    SelectMenuOptionDescriptionLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A select menu option label is larger than
+    /// [the maximum][`SELECT_MENU_OPTION_LABEL_LENGTH`].
+    ///
+    /// [`SELECT_MENU_OPTION_LABEL_LENGTH`]: ComponentValidationError::SELECT_MENU_OPTION_LABEL_LENGTH
+    SelectMenuOptionLabelLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A select menu option value is larger than
+    /// [the maximum][`SELECT_MENU_OPTION_VALUE_LENGTH`].
+    ///
+    /// [`SELECT_MENU_OPTION_VALUE_LENGTH`]: ComponentValidationError::SELECT_MENU_OPTION_VALUE_LENGTH
+    SelectMenuOptionValueLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A select menu placeholder is larger than
+    /// [the maximum][`SELECT_MENU_PLACEHOLDER_LENGTH`].
+    ///
+    /// [`SELECT_MENU_PLACEHOLDER_LENGTH`]: ComponentValidationError::SELECT_MENU_PLACEHOLDER_LENGTH
+    SelectMenuPlaceholderLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The minimum or maximum number of values of a select menu is larger
+    /// than [the limit][`SELECT_MENU_VALUES_LIMIT`], the maximum is 0, or the
+    /// minimum is larger than the maximum.
+    ///
+    /// [`SELECT_MENU_VALUES_LIMIT`]: ComponentValidationError::SELECT_MENU_VALUES_LIMIT
+    SelectMenuValues {
+        /// Provided maximum number of values.
+        max: Option<u8>,
+        /// Provided minimum number of values.
+        min: Option<u8>,
+    },
+}
 
 /// An embed is not valid.
 ///
@@ -217,6 +504,173 @@ fn _channel_name(value: &str) -> bool {
     (1..=100).contains(&len)
 }
 
+pub fn components(components: &[Component]) -> Result<(), ComponentValidationError> {
+    if components.len() > ComponentValidationError::COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCount {
+                count: components.len(),
+            },
+        });
+    }
+
+    for component in components {
+        match component {
+            Component::ActionRow(action_row) => self::action_row(action_row)?,
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn action_row(action_row: &ActionRow) -> Result<(), ComponentValidationError> {
+    let count = action_row.components.len();
+
+    if count > ComponentValidationError::ACTION_ROW_COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ActionRowComponentCount { count },
+        });
+    }
+
+    for component in &action_row.components {
+        match component {
+            Component::Button(button) => self::button(button)?,
+            Component::SelectMenu(select_menu) => {
+                if count > 1 {
+                    return Err(ComponentValidationError {
+                        kind: ComponentValidationErrorType::SelectMenuNotAlone,
+                    });
+                }
+
+                self::select_menu(select_menu)?;
+            }
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidChildComponent {
+                        kind: other.kind(),
+                    },
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn button(button: &Button) -> Result<(), ComponentValidationError> {
+    // <https://discord.com/developers/docs/interactions/message-components#button-object-button-structure>
+    let valid = if button.style == ButtonStyle::Link {
+        button.custom_id.is_none() && button.url.is_some()
+    } else {
+        button.custom_id.is_some() && button.url.is_none()
+    };
+
+    if !valid {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ButtonStyle {
+                style: button.style,
+            },
+        });
+    }
+
+    if let Some(custom_id) = button.custom_id.as_ref() {
+        self::custom_id(custom_id)?;
+    }
+
+    if let Some(label) = button.label.as_ref() {
+        let chars = label.chars().count();
+
+        if chars > ComponentValidationError::BUTTON_LABEL_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::ButtonLabelLength { chars },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn custom_id(custom_id: &str) -> Result<(), ComponentValidationError> {
+    let chars = custom_id.chars().count();
+
+    if chars > ComponentValidationError::CUSTOM_ID_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::CustomIdLength { chars },
+        });
+    }
+
+    Ok(())
+}
+
+fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationError> {
+    // <https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-menu-structure>
+    self::custom_id(&select_menu.custom_id)?;
+
+    let count = select_menu.options.len();
+
+    if count == 0 || count > ComponentValidationError::SELECT_MENU_OPTION_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::SelectMenuOptionCount { count },
+        });
+    }
+
+    if let Some(placeholder) = select_menu.placeholder.as_ref() {
+        let chars = placeholder.chars().count();
+
+        if chars > ComponentValidationError::SELECT_MENU_PLACEHOLDER_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectMenuPlaceholderLength { chars },
+            });
+        }
+    }
+
+    let max = select_menu.max_values;
+    let min = select_menu.min_values;
+    let limit = ComponentValidationError::SELECT_MENU_VALUES_LIMIT;
+
+    if max.map_or(false, |max| max == 0 || max > limit)
+        || min.map_or(false, |min| min > limit || min > max.unwrap_or(1))
+    {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::SelectMenuValues { max, min },
+        });
+    }
+
+    for option in &select_menu.options {
+        let chars = option.label.chars().count();
+
+        if chars > ComponentValidationError::SELECT_MENU_OPTION_LABEL_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectMenuOptionLabelLength { chars },
+            });
+        }
+
+        let chars = option.value.chars().count();
+
+        if chars > ComponentValidationError::SELECT_MENU_OPTION_VALUE_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectMenuOptionValueLength { chars },
+            });
+        }
+
+        if let Some(description) = option.description.as_ref() {
+            let chars = description.chars().count();
+
+            if chars > ComponentValidationError::SELECT_MENU_OPTION_DESCRIPTION_LENGTH {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::SelectMenuOptionDescriptionLength { chars },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn content_limit(value: impl AsRef<str>) -> bool {
     _content_limit(value.as_ref())
 }
@@ -318,33 +772,64 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
     Ok(())
 }
 
+/// Maximum number of archived threads that can be retrieved at once.
+pub const GET_ARCHIVED_THREADS_LIMIT_MAX: u64 = 100;
+
+pub const fn get_archived_threads_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/channel#list-public-archived-threads-query-string-params>
+    value >= 1 && value <= GET_ARCHIVED_THREADS_LIMIT_MAX
+}
+
+/// Maximum number of audit log entries that can be retrieved at once.
+pub const GET_AUDIT_LOG_LIMIT_MAX: u64 = 100;
+
 pub const fn get_audit_log_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/audit-log#get-guild-audit-log-query-string-parameters>
-    value >= 1 && value <= 100
+    value >= 1 && value <= GET_AUDIT_LOG_LIMIT_MAX
 }
 
+/// Maximum number of messages that can be retrieved at once.
+pub const GET_CHANNEL_MESSAGES_LIMIT_MAX: u64 = 100;
+
 pub const fn get_channel_messages_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/channel#get-channel-messages-query-string-params>
-    value >= 1 && value <= 100
+    value >= 1 && value <= GET_CHANNEL_MESSAGES_LIMIT_MAX
 }
 
+/// Maximum number of guilds that can be retrieved at once.
+pub const GET_CURRENT_USER_GUILDS_LIMIT_MAX: u64 = 100;
+
 pub const fn get_current_user_guilds_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/user#get-current-user-guilds-query-string-params>
-    value >= 1 && value <= 100
+    value >= 1 && value <= GET_CURRENT_USER_GUILDS_LIMIT_MAX
 }
 
+/// Maximum number of bans that can be retrieved at once.
+pub const GET_GUILD_BANS_LIMIT_MAX: u64 = 1000;
+
+pub const fn get_guild_bans_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild#get-guild-bans-query-string-params>
+    value >= 1 && value <= GET_GUILD_BANS_LIMIT_MAX
+}
+
+/// Maximum number of members that can be retrieved at once.
+pub const GET_GUILD_MEMBERS_LIMIT_MAX: u64 = 1000;
+
 pub const fn get_guild_members_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/guild#list-guild-members-query-string-params>
-    value >= 1 && value <= 1000
+    value >= 1 && value <= GET_GUILD_MEMBERS_LIMIT_MAX
 }
 
 pub const fn search_guild_members_limit(value: u64) -> bool {
     value > 0 && value <= 1000
 }
 
+/// Maximum number of reaction users that can be retrieved at once.
+pub const GET_REACTIONS_LIMIT_MAX: u64 = 100;
+
 pub const fn get_reactions_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/channel#get-reactions-query-string-params>
-    value >= 1 && value <= 100
+    value >= 1 && value <= GET_REACTIONS_LIMIT_MAX
 }
 
 pub fn guild_name(value: impl AsRef<str>) -> bool {
@@ -429,15 +914,22 @@ fn _stage_topic(value: &str) -> bool {
     (0..=120).contains(&len)
 }
 
-pub fn command_name(value: impl AsRef<str>) -> bool {
-    _command_name(value.as_ref())
+pub fn command_name(kind: CommandType, value: impl AsRef<str>) -> bool {
+    _command_name(kind, value.as_ref())
 }
 
-fn _command_name(value: &str) -> bool {
+fn _command_name(kind: CommandType, value: &str) -> bool {
     let len = value.chars().count();
 
-    // https://discord.com/developers/docs/interactions/slash-commands#applicationcommandoption
-    (3..=32).contains(&len)
+    match kind {
+        // https://discord.com/developers/docs/interactions/slash-commands#applicationcommandoption
+        CommandType::ChatInput => (3..=32).contains(&len),
+        // Context menu command names may contain spaces and upper case
+        // characters.
+        //
+        // https://discord.com/developers/docs/interactions/application-commands#user-commands
+        CommandType::User | CommandType::Message => (1..=32).contains(&len),
+    }
 }
 
 pub fn command_description(value: impl AsRef<str>) -> bool {
@@ -459,7 +951,44 @@ pub fn command_permissions(len: usize) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::{
+        application::component::SelectMenuOption,
+        channel::embed::{EmbedAuthor, EmbedField, EmbedFooter},
+    };
+
+    fn button(custom_id: &str) -> Component {
+        Component::Button(Button {
+            custom_id: Some(custom_id.to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("label".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        })
+    }
+
+    fn select_menu(options: usize) -> SelectMenu {
+        SelectMenu {
+            custom_id: "select".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: (0..options)
+                .map(|idx| SelectMenuOption {
+                    default: false,
+                    description: None,
+                    emoji: None,
+                    label: idx.to_string(),
+                    value: idx.to_string(),
+                })
+                .collect(),
+            placeholder: None,
+        }
+    }
+
+    fn row(components: Vec<Component>) -> Component {
+        Component::ActionRow(ActionRow { components })
+    }
 
     fn base_embed() -> Embed {
         Embed {
@@ -497,6 +1026,141 @@ mod tests {
         assert!(!channel_name("a".repeat(101)));
     }
 
+    #[test]
+    fn test_command_name() {
+        assert!(command_name(CommandType::ChatInput, "ping"));
+        assert!(command_name(CommandType::ChatInput, "a".repeat(32)));
+        assert!(command_name(CommandType::User, "A"));
+        assert!(command_name(CommandType::Message, "Report Message"));
+
+        assert!(!command_name(CommandType::ChatInput, "a"));
+        assert!(!command_name(CommandType::ChatInput, "a".repeat(33)));
+        assert!(!command_name(CommandType::User, ""));
+        assert!(!command_name(CommandType::Message, "a".repeat(33)));
+    }
+
+    #[test]
+    fn test_components() {
+        assert!(components(&[]).is_ok());
+        assert!(components(&[row(vec![button("a"); 5])]).is_ok());
+        assert!(components(&vec![row(vec![button("a")]); 5]).is_ok());
+
+        assert!(matches!(
+            components(&vec![row(vec![button("a")]); 6])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ComponentCount { count: 6 }
+        ));
+        assert!(matches!(
+            components(&[row(vec![button("a"); 6])]).unwrap_err().kind(),
+            ComponentValidationErrorType::ActionRowComponentCount { count: 6 }
+        ));
+        assert!(matches!(
+            components(&[button("a")]).unwrap_err().kind(),
+            ComponentValidationErrorType::InvalidRootComponent {
+                kind: ComponentType::Button
+            }
+        ));
+        assert!(matches!(
+            components(&[row(vec![row(Vec::new())])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::ActionRow
+            }
+        ));
+        assert!(matches!(
+            components(&[row(vec![button(&"a".repeat(101))])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::CustomIdLength { chars: 101 }
+        ));
+    }
+
+    #[test]
+    fn test_components_button() {
+        let link = Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: Some("a".repeat(80)),
+            style: ButtonStyle::Link,
+            url: Some("https://twilight.rs".to_owned()),
+        };
+        assert!(components(&[row(vec![Component::Button(link.clone())])]).is_ok());
+
+        let mut invalid = link.clone();
+        invalid.custom_id.replace("a".to_owned());
+        assert!(matches!(
+            components(&[row(vec![Component::Button(invalid)])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ButtonStyle {
+                style: ButtonStyle::Link
+            }
+        ));
+
+        let mut invalid = link;
+        invalid.label.replace("a".repeat(81));
+        assert!(matches!(
+            components(&[row(vec![Component::Button(invalid)])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ButtonLabelLength { chars: 81 }
+        ));
+    }
+
+    #[test]
+    fn test_components_select_menu() {
+        let valid = |menu: SelectMenu| components(&[row(vec![Component::SelectMenu(menu)])]);
+
+        assert!(valid(select_menu(1)).is_ok());
+        assert!(valid(select_menu(25)).is_ok());
+        assert!(matches!(
+            valid(select_menu(0)).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMenuOptionCount { count: 0 }
+        ));
+        assert!(matches!(
+            valid(select_menu(26)).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMenuOptionCount { count: 26 }
+        ));
+
+        let mut menu = select_menu(5);
+        menu.max_values.replace(5);
+        menu.min_values.replace(2);
+        assert!(valid(menu.clone()).is_ok());
+
+        menu.min_values.replace(6);
+        assert!(matches!(
+            valid(menu).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMenuValues { .. }
+        ));
+
+        let mut menu = select_menu(1);
+        menu.placeholder.replace("a".repeat(101));
+        assert!(matches!(
+            valid(menu).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMenuPlaceholderLength { chars: 101 }
+        ));
+
+        let mut menu = select_menu(1);
+        menu.options[0].label = "a".repeat(101);
+        assert!(matches!(
+            valid(menu).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMenuOptionLabelLength { chars: 101 }
+        ));
+
+        assert!(matches!(
+            components(&[row(vec![
+                Component::SelectMenu(select_menu(1)),
+                button("a")
+            ])])
+            .unwrap_err()
+            .kind(),
+            ComponentValidationErrorType::SelectMenuNotAlone
+        ));
+    }
+
     #[test]
     fn test_content_limit() {
         assert!(content_limit(""));
@@ -695,6 +1359,15 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_get_archived_threads_limit() {
+        assert!(get_archived_threads_limit(1));
+        assert!(get_archived_threads_limit(100));
+
+        assert!(!get_archived_threads_limit(0));
+        assert!(!get_archived_threads_limit(101));
+    }
+
     #[test]
     fn test_get_audit_log_limit() {
         assert!(get_audit_log_limit(1));
@@ -722,6 +1395,15 @@ mod tests {
         assert!(!get_current_user_guilds_limit(101));
     }
 
+    #[test]
+    fn test_get_guild_bans_limit() {
+        assert!(get_guild_bans_limit(1));
+        assert!(get_guild_bans_limit(1000));
+
+        assert!(!get_guild_bans_limit(0));
+        assert!(!get_guild_bans_limit(1001));
+    }
+
     #[test]
     fn test_get_guild_members_limit() {
         assert!(get_guild_members_limit(1));
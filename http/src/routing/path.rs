@@ -70,7 +70,8 @@ pub enum PathParseErrorType {
 }
 
 /// An enum representing a path, most useful for ratelimiting implementations.
-// If adding to this enum, be sure to add to the `TryFrom` impl.
+// If adding to this enum, be sure to add to the `TryFrom` impl and
+// `major_parameter`.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Path {
@@ -99,6 +100,8 @@ pub enum Path {
     /// Operating on an individual channel's message's reactions while
     /// specifying the user ID and emoji type.
     ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Operating on a thread created from a channel's message.
+    ChannelsIdMessagesIdThreads(u64),
     /// Operating on a channel's permission overwrites by ID.
     ChannelsIdPermissionsOverwriteId(u64),
     /// Operating on a channel's pins.
@@ -107,8 +110,15 @@ pub enum Path {
     ChannelsIdPinsMessageId(u64),
     /// Operating on a group DM's recipients.
     ChannelsIdRecipients(u64),
+    /// Operating on a thread's members.
+    ChannelsIdThreadMembers(u64),
+    /// Operating on a channel's threads.
+    ChannelsIdThreads(u64),
     /// Operating on a channel's typing indicator.
     ChannelsIdTyping(u64),
+    /// Operating on a channel's archived private threads that the current user
+    /// has joined.
+    ChannelsIdUsersMeThreads(u64),
     /// Operating on a channel's webhooks.
     ChannelsIdWebhooks(u64),
     /// Operating on a channel's followers.
@@ -145,6 +155,8 @@ pub enum Path {
     GuildsIdRolesId(u64),
     GuildsIdTemplates(u64),
     GuildsIdTemplatesCode(u64),
+    /// Operating on a guild's active threads.
+    GuildsIdThreads(u64),
     GuildsIdVanityUrl(u64),
     GuildsIdVoiceStates(u64),
     GuildsIdWelcomeScreen(u64),
@@ -155,6 +167,10 @@ pub enum Path {
     StageInstances,
     UsersId,
     OauthApplicationsMe,
+    /// Operating on OAuth2 access tokens.
+    OauthToken,
+    /// Operating on the revocation of OAuth2 access tokens.
+    OauthTokenRevoke,
     UsersIdConnections,
     UsersIdChannels,
     /// Operating on the state of a guild that the user is in.
@@ -169,6 +185,186 @@ pub enum Path {
     WebhooksId(u64),
 }
 
+impl Path {
+    /// Major parameter of the path, if it has one.
+    ///
+    /// Major parameters are the channel, guild, or webhook IDs of a path.
+    /// Paths with the same bucket hash but different major parameters have
+    /// separate ratelimits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::routing::Path;
+    ///
+    /// assert_eq!(Some(123), Path::ChannelsIdMessages(123).major_parameter());
+    /// assert_eq!(None, Path::Guilds.major_parameter());
+    /// ```
+    pub const fn major_parameter(&self) -> Option<u64> {
+        match self {
+            Self::ChannelsId(id)
+            | Self::ChannelsIdInvites(id)
+            | Self::ChannelsIdMessages(id)
+            | Self::ChannelsIdMessagesBulkDelete(id)
+            | Self::ChannelsIdMessagesIdCrosspost(id)
+            | Self::ChannelsIdMessagesIdReactions(id)
+            | Self::ChannelsIdMessagesIdReactionsUserIdType(id)
+            | Self::ChannelsIdMessagesIdThreads(id)
+            | Self::ChannelsIdPermissionsOverwriteId(id)
+            | Self::ChannelsIdPins(id)
+            | Self::ChannelsIdPinsMessageId(id)
+            | Self::ChannelsIdRecipients(id)
+            | Self::ChannelsIdThreadMembers(id)
+            | Self::ChannelsIdThreads(id)
+            | Self::ChannelsIdTyping(id)
+            | Self::ChannelsIdUsersMeThreads(id)
+            | Self::ChannelsIdWebhooks(id)
+            | Self::ChannelsIdFollowers(id)
+            | Self::GuildsId(id)
+            | Self::GuildsIdBans(id)
+            | Self::GuildsIdBansId(id)
+            | Self::GuildsIdAuditLogs(id)
+            | Self::GuildsIdBansUserId(id)
+            | Self::GuildsIdChannels(id)
+            | Self::GuildsIdWidget(id)
+            | Self::GuildsIdEmojis(id)
+            | Self::GuildsIdEmojisId(id)
+            | Self::GuildsIdIntegrations(id)
+            | Self::GuildsIdIntegrationsId(id)
+            | Self::GuildsIdIntegrationsIdSync(id)
+            | Self::GuildsIdInvites(id)
+            | Self::GuildsIdMembers(id)
+            | Self::GuildsIdMembersId(id)
+            | Self::GuildsIdMembersIdRolesId(id)
+            | Self::GuildsIdMembersMeNick(id)
+            | Self::GuildsIdMembersSearch(id)
+            | Self::GuildsIdPreview(id)
+            | Self::GuildsIdPrune(id)
+            | Self::GuildsIdRegions(id)
+            | Self::GuildsIdRoles(id)
+            | Self::GuildsIdRolesId(id)
+            | Self::GuildsIdTemplates(id)
+            | Self::GuildsIdTemplatesCode(id)
+            | Self::GuildsIdThreads(id)
+            | Self::GuildsIdVanityUrl(id)
+            | Self::GuildsIdVoiceStates(id)
+            | Self::GuildsIdWelcomeScreen(id)
+            | Self::GuildsIdWebhooks(id)
+            | Self::InteractionCallback(id)
+            | Self::WebhooksIdTokenMessagesId(id)
+            | Self::WebhooksId(id)
+            | Self::ChannelsIdMessagesId(_, id) => Some(*id),
+            Self::ApplicationCommand(_)
+            | Self::ApplicationCommandId(_)
+            | Self::ApplicationGuildCommand(_)
+            | Self::ApplicationGuildCommandId(_)
+            | Self::Gateway
+            | Self::GatewayBot
+            | Self::Guilds
+            | Self::InvitesCode
+            | Self::StageInstances
+            | Self::UsersId
+            | Self::OauthApplicationsMe
+            | Self::OauthToken
+            | Self::OauthTokenRevoke
+            | Self::UsersIdConnections
+            | Self::UsersIdChannels
+            | Self::UsersIdGuilds
+            | Self::UsersIdGuildsId
+            | Self::VoiceRegions => None,
+        }
+    }
+
+    /// Name of the path, without any of its parameters.
+    ///
+    /// This is suitable for grouping requests by route, such as when
+    /// recording metrics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::routing::Path;
+    ///
+    /// assert_eq!("ChannelsIdMessages", Path::ChannelsIdMessages(123).name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::ApplicationCommand(_) => "ApplicationCommand",
+            Self::ApplicationCommandId(_) => "ApplicationCommandId",
+            Self::ApplicationGuildCommand(_) => "ApplicationGuildCommand",
+            Self::ApplicationGuildCommandId(_) => "ApplicationGuildCommandId",
+            Self::ChannelsId(_) => "ChannelsId",
+            Self::ChannelsIdInvites(_) => "ChannelsIdInvites",
+            Self::ChannelsIdMessages(_) => "ChannelsIdMessages",
+            Self::ChannelsIdMessagesBulkDelete(_) => "ChannelsIdMessagesBulkDelete",
+            Self::ChannelsIdMessagesId(_, _) => "ChannelsIdMessagesId",
+            Self::ChannelsIdMessagesIdCrosspost(_) => "ChannelsIdMessagesIdCrosspost",
+            Self::ChannelsIdMessagesIdReactions(_) => "ChannelsIdMessagesIdReactions",
+            Self::ChannelsIdMessagesIdReactionsUserIdType(_) => {
+                "ChannelsIdMessagesIdReactionsUserIdType"
+            }
+            Self::ChannelsIdMessagesIdThreads(_) => "ChannelsIdMessagesIdThreads",
+            Self::ChannelsIdPermissionsOverwriteId(_) => "ChannelsIdPermissionsOverwriteId",
+            Self::ChannelsIdPins(_) => "ChannelsIdPins",
+            Self::ChannelsIdPinsMessageId(_) => "ChannelsIdPinsMessageId",
+            Self::ChannelsIdRecipients(_) => "ChannelsIdRecipients",
+            Self::ChannelsIdThreadMembers(_) => "ChannelsIdThreadMembers",
+            Self::ChannelsIdThreads(_) => "ChannelsIdThreads",
+            Self::ChannelsIdTyping(_) => "ChannelsIdTyping",
+            Self::ChannelsIdUsersMeThreads(_) => "ChannelsIdUsersMeThreads",
+            Self::ChannelsIdWebhooks(_) => "ChannelsIdWebhooks",
+            Self::ChannelsIdFollowers(_) => "ChannelsIdFollowers",
+            Self::Gateway => "Gateway",
+            Self::GatewayBot => "GatewayBot",
+            Self::Guilds => "Guilds",
+            Self::GuildsId(_) => "GuildsId",
+            Self::GuildsIdBans(_) => "GuildsIdBans",
+            Self::GuildsIdBansId(_) => "GuildsIdBansId",
+            Self::GuildsIdAuditLogs(_) => "GuildsIdAuditLogs",
+            Self::GuildsIdBansUserId(_) => "GuildsIdBansUserId",
+            Self::GuildsIdChannels(_) => "GuildsIdChannels",
+            Self::GuildsIdWidget(_) => "GuildsIdWidget",
+            Self::GuildsIdEmojis(_) => "GuildsIdEmojis",
+            Self::GuildsIdEmojisId(_) => "GuildsIdEmojisId",
+            Self::GuildsIdIntegrations(_) => "GuildsIdIntegrations",
+            Self::GuildsIdIntegrationsId(_) => "GuildsIdIntegrationsId",
+            Self::GuildsIdIntegrationsIdSync(_) => "GuildsIdIntegrationsIdSync",
+            Self::GuildsIdInvites(_) => "GuildsIdInvites",
+            Self::GuildsIdMembers(_) => "GuildsIdMembers",
+            Self::GuildsIdMembersId(_) => "GuildsIdMembersId",
+            Self::GuildsIdMembersIdRolesId(_) => "GuildsIdMembersIdRolesId",
+            Self::GuildsIdMembersMeNick(_) => "GuildsIdMembersMeNick",
+            Self::GuildsIdMembersSearch(_) => "GuildsIdMembersSearch",
+            Self::GuildsIdPreview(_) => "GuildsIdPreview",
+            Self::GuildsIdPrune(_) => "GuildsIdPrune",
+            Self::GuildsIdRegions(_) => "GuildsIdRegions",
+            Self::GuildsIdRoles(_) => "GuildsIdRoles",
+            Self::GuildsIdRolesId(_) => "GuildsIdRolesId",
+            Self::GuildsIdTemplates(_) => "GuildsIdTemplates",
+            Self::GuildsIdTemplatesCode(_) => "GuildsIdTemplatesCode",
+            Self::GuildsIdThreads(_) => "GuildsIdThreads",
+            Self::GuildsIdVanityUrl(_) => "GuildsIdVanityUrl",
+            Self::GuildsIdVoiceStates(_) => "GuildsIdVoiceStates",
+            Self::GuildsIdWelcomeScreen(_) => "GuildsIdWelcomeScreen",
+            Self::GuildsIdWebhooks(_) => "GuildsIdWebhooks",
+            Self::InvitesCode => "InvitesCode",
+            Self::InteractionCallback(_) => "InteractionCallback",
+            Self::StageInstances => "StageInstances",
+            Self::UsersId => "UsersId",
+            Self::OauthApplicationsMe => "OauthApplicationsMe",
+            Self::OauthToken => "OauthToken",
+            Self::OauthTokenRevoke => "OauthTokenRevoke",
+            Self::UsersIdConnections => "UsersIdConnections",
+            Self::UsersIdChannels => "UsersIdChannels",
+            Self::UsersIdGuilds => "UsersIdGuilds",
+            Self::UsersIdGuildsId => "UsersIdGuildsId",
+            Self::VoiceRegions => "VoiceRegions",
+            Self::WebhooksIdTokenMessagesId(_) => "WebhooksIdTokenMessagesId",
+            Self::WebhooksId(_) => "WebhooksId",
+        }
+    }
+}
+
 impl FromStr for Path {
     type Err = PathParseError;
 
@@ -243,13 +439,25 @@ impl FromStr for Path {
             ["channels", id, "messages", _, "reactions", _, _] => {
                 ChannelsIdMessagesIdReactionsUserIdType(parse_id(id)?)
             }
+            ["channels", id, "messages", _, "threads"] => {
+                ChannelsIdMessagesIdThreads(parse_id(id)?)
+            }
             ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(parse_id(id)?),
             ["channels", id, "pins"] => ChannelsIdPins(parse_id(id)?),
             ["channels", id, "pins", _] => ChannelsIdPinsMessageId(parse_id(id)?),
             ["channels", id, "recipients"] | ["channels", id, "recipients", _] => {
                 ChannelsIdRecipients(parse_id(id)?)
             }
+            ["channels", id, "thread-members"] | ["channels", id, "thread-members", _] => {
+                ChannelsIdThreadMembers(parse_id(id)?)
+            }
+            ["channels", id, "threads"] | ["channels", id, "threads", "archived", _] => {
+                ChannelsIdThreads(parse_id(id)?)
+            }
             ["channels", id, "typing"] => ChannelsIdTyping(parse_id(id)?),
+            ["channels", id, "users", "@me", "threads", "archived", "private"] => {
+                ChannelsIdUsersMeThreads(parse_id(id)?)
+            }
             ["channels", id, "webhooks"] | ["channels", id, "webhooks", _] => {
                 ChannelsIdWebhooks(parse_id(id)?)
             }
@@ -282,6 +490,7 @@ impl FromStr for Path {
             ["guilds", id, "roles", _] => GuildsIdRolesId(parse_id(id)?),
             ["guilds", id, "templates"] => GuildsIdTemplates(parse_id(id)?),
             ["guilds", id, "templates", _] => GuildsIdTemplatesCode(parse_id(id)?),
+            ["guilds", id, "threads", "active"] => GuildsIdThreads(parse_id(id)?),
             ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(parse_id(id)?),
             ["guilds", id, "voice-states", _] => GuildsIdVoiceStates(parse_id(id)?),
             ["guilds", id, "welcome-screen"] => GuildsIdWelcomeScreen(parse_id(id)?),
@@ -290,6 +499,8 @@ impl FromStr for Path {
             ["interactions", id, _, "callback"] => InteractionCallback(parse_id(id)?),
             ["stage-instances", _] => StageInstances,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -350,6 +561,23 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::ChannelsIdThreadMembers(123),
+            Path::from_str("/channels/123/thread-members/@me")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreads(123),
+            Path::from_str("/channels/123/threads/archived/public")?
+        );
+        assert_eq!(
+            Path::GuildsIdThreads(123),
+            Path::from_str("/guilds/123/threads/active")?
+        );
+        assert_eq!(Path::OauthToken, Path::from_str("/oauth2/token")?);
+        assert_eq!(
+            Path::OauthTokenRevoke,
+            Path::from_str("/oauth2/token/revoke")?
+        );
 
         Ok(())
     }
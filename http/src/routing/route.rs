@@ -16,6 +16,13 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to add a member to a thread.
+    AddThreadMember {
+        /// ID of the thread.
+        channel_id: u64,
+        /// ID of the member.
+        user_id: u64,
+    },
     /// Route information to create a ban on a user in a guild.
     CreateBan {
         /// The number of days' worth of the user's messages to delete in the
@@ -87,6 +94,9 @@ pub enum Route {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to exchange an OAuth2 authorization code or refresh
+    /// token for an access token.
+    CreateOauthToken,
     /// Route information to create a private channel.
     CreatePrivateChannel,
     /// Route information to create a reaction on a message.
@@ -110,6 +120,18 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a thread in a channel.
+    CreateThread {
+        /// ID of the channel.
+        channel_id: u64,
+    },
+    /// Route information to create a thread from a message.
+    CreateThreadFromMessage {
+        /// ID of the channel.
+        channel_id: u64,
+        /// ID of the message.
+        message_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -256,6 +278,7 @@ pub enum Route {
     /// Route information to delete a message created by a webhook.
     DeleteWebhookMessage {
         message_id: u64,
+        thread_id: Option<u64>,
         token: String,
         webhook_id: u64,
     },
@@ -268,6 +291,8 @@ pub enum Route {
     },
     /// Route information to execute a webhook by ID and token.
     ExecuteWebhook {
+        /// ID of the thread to send the message in.
+        thread_id: Option<u64>,
         /// The token of the webhook.
         token: String,
         /// Whether to wait for a message response.
@@ -280,6 +305,11 @@ pub enum Route {
         /// The ID of the channel to follow.
         channel_id: u64,
     },
+    /// Route information to get the active threads in a guild.
+    GetActiveThreads {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a paginated list of audit logs in a guild.
     GetAuditLogs {
         /// The type of action to get audit logs for.
@@ -302,8 +332,14 @@ pub enum Route {
     },
     /// Route information to get a guild's bans.
     GetBans {
+        /// The minimum ID of users to get bans for.
+        after: Option<u64>,
+        /// The maximum ID of users to get bans for.
+        before: Option<u64>,
         /// The ID of the guild.
         guild_id: u64,
+        /// The maximum number of bans to get.
+        limit: Option<u64>,
     },
     /// Route information to get a channel.
     GetChannel {
@@ -481,6 +517,16 @@ pub enum Route {
         /// Whether to retrieve the expiration date of the invite.
         with_expiration: bool,
     },
+    /// Route information to get the archived private threads in a channel
+    /// that the current user has joined.
+    GetJoinedPrivateArchivedThreads {
+        /// Optional ID of a thread to get threads before.
+        before: Option<u64>,
+        /// ID of the channel.
+        channel_id: u64,
+        /// Optional maximum number of threads to return.
+        limit: Option<u64>,
+    },
     /// Route information to get a member.
     GetMember {
         /// The ID of the guild.
@@ -513,6 +559,24 @@ pub enum Route {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to get the archived private threads in a channel.
+    GetPrivateArchivedThreads {
+        /// Optional timestamp to get threads archived before.
+        before: Option<String>,
+        /// ID of the channel.
+        channel_id: u64,
+        /// Optional maximum number of threads to return.
+        limit: Option<u64>,
+    },
+    /// Route information to get the archived public threads in a channel.
+    GetPublicArchivedThreads {
+        /// Optional timestamp to get threads archived before.
+        before: Option<String>,
+        /// ID of the channel.
+        channel_id: u64,
+        /// Optional maximum number of threads to return.
+        limit: Option<u64>,
+    },
     /// Route information to get the users who reacted to a message with a
     /// specified emoji.
     GetReactionUsers {
@@ -542,6 +606,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get the members of a thread.
+    GetThreadMembers {
+        /// ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to get the current user.
     GetUser {
         /// The ID of the target user. This can be `@me` to specify the current
@@ -566,6 +635,8 @@ pub enum Route {
     GetWebhookMessage {
         /// ID of the message.
         message_id: u64,
+        /// ID of the thread the message is in.
+        thread_id: Option<u64>,
         /// Token of the webhook.
         token: String,
         /// ID of the webhook.
@@ -578,11 +649,21 @@ pub enum Route {
         /// The token for the interaction.
         interaction_token: String,
     },
+    /// Route information to add the current user to a thread.
+    JoinThread {
+        /// ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to leave the guild.
     LeaveGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to remove the current user from a thread.
+    LeaveThread {
+        /// ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to pin a message to a channel.
     PinMessage {
         /// The ID of the channel.
@@ -606,6 +687,15 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to remove a member from a thread.
+    RemoveThreadMember {
+        /// ID of the thread.
+        channel_id: u64,
+        /// ID of the member.
+        user_id: u64,
+    },
+    /// Route information to revoke an OAuth2 access or refresh token.
+    RevokeOauthToken,
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -794,6 +884,7 @@ pub enum Route {
     /// Route information to update a message created by a webhook.
     UpdateWebhookMessage {
         message_id: u64,
+        thread_id: Option<u64>,
         token: String,
         webhook_id: u64,
     },
@@ -904,10 +995,13 @@ impl Route {
             | Self::DeleteWebhookMessage { .. }
             | Self::DeleteWebhook { .. }
             | Self::LeaveGuild { .. }
+            | Self::LeaveThread { .. }
             | Self::RemoveMember { .. }
             | Self::RemoveMemberRole { .. }
+            | Self::RemoveThreadMember { .. }
             | Self::UnpinMessage { .. } => Method::Delete,
-            Self::GetAuditLogs { .. }
+            Self::GetActiveThreads { .. }
+            | Self::GetAuditLogs { .. }
             | Self::GetBan { .. }
             | Self::GetBans { .. }
             | Self::GetGatewayBot
@@ -939,14 +1033,18 @@ impl Route {
             | Self::GetInteractionOriginal { .. }
             | Self::GetInvite { .. }
             | Self::GetInviteWithExpiration { .. }
+            | Self::GetJoinedPrivateArchivedThreads { .. }
             | Self::GetMember { .. }
             | Self::GetMessage { .. }
             | Self::GetMessages { .. }
             | Self::GetPins { .. }
+            | Self::GetPrivateArchivedThreads { .. }
+            | Self::GetPublicArchivedThreads { .. }
             | Self::GetReactionUsers { .. }
             | Self::GetStageInstance { .. }
             | Self::GetTemplate { .. }
             | Self::GetTemplates { .. }
+            | Self::GetThreadMembers { .. }
             | Self::GetUserConnections
             | Self::GetUserPrivateChannels
             | Self::GetUser { .. }
@@ -986,10 +1084,13 @@ impl Route {
             | Self::CreateGuildPrune { .. }
             | Self::CreateInvite { .. }
             | Self::CreateMessage { .. }
+            | Self::CreateOauthToken
             | Self::CreatePrivateChannel
             | Self::CreateRole { .. }
             | Self::CreateStageInstance { .. }
             | Self::CreateTemplate { .. }
+            | Self::CreateThread { .. }
+            | Self::CreateThreadFromMessage { .. }
             | Self::CreateTypingTrigger { .. }
             | Self::CreateWebhook { .. }
             | Self::CrosspostMessage { .. }
@@ -997,11 +1098,14 @@ impl Route {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
+            | Self::RevokeOauthToken
             | Self::SyncGuildIntegration { .. } => Method::Post,
             Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
+            | Self::AddThreadMember { .. }
             | Self::CreateBan { .. }
             | Self::CreateReaction { .. }
+            | Self::JoinThread { .. }
             | Self::PinMessage { .. }
             | Self::SetCommandPermissions { .. }
             | Self::SetGlobalCommands { .. }
@@ -1022,16 +1126,19 @@ impl Route {
     ///
     /// ```
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use twilight_http::{ratelimiting::Ratelimiter, routing::Route};
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use twilight_http::{
+    ///     ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    ///     routing::Route,
+    /// };
     ///
-    /// let ratelimiter = Ratelimiter::new();
+    /// let ratelimiter = InMemoryRatelimiter::new();
     /// let route = Route::CreateMessage {
     ///     channel_id: 123,
     ///  };
     ///
     /// // Take a ticket from the ratelimiter.
-    /// let rx = ratelimiter.get(route.path()).await;
+    /// let rx = ratelimiter.ticket(route.path()).await?;
     ///
     /// // Wait to be told that a request can be made...
     /// let _tx = rx.await;
@@ -1051,6 +1158,13 @@ impl Route {
             Self::AddMemberRole { guild_id, .. } | Self::RemoveMemberRole { guild_id, .. } => {
                 Path::GuildsIdMembersIdRolesId(*guild_id)
             }
+            Self::AddThreadMember { channel_id, .. }
+            | Self::GetThreadMembers { channel_id }
+            | Self::JoinThread { channel_id }
+            | Self::LeaveThread { channel_id }
+            | Self::RemoveThreadMember { channel_id, .. } => {
+                Path::ChannelsIdThreadMembers(*channel_id)
+            }
             Self::CreateBan { guild_id, .. } | Self::DeleteBan { guild_id, .. } => {
                 Path::GuildsIdBansUserId(*guild_id)
             }
@@ -1085,6 +1199,7 @@ impl Route {
             Self::CreateMessage { channel_id } | Self::GetMessages { channel_id, .. } => {
                 Path::ChannelsIdMessages(*channel_id)
             }
+            Self::CreateOauthToken => Path::OauthToken,
             Self::CreatePrivateChannel | Self::GetUserPrivateChannels => Path::UsersIdChannels,
             Self::CreateReaction { channel_id, .. } | Self::DeleteReaction { channel_id, .. } => {
                 Path::ChannelsIdMessagesIdReactionsUserIdType(*channel_id)
@@ -1099,6 +1214,14 @@ impl Route {
             Self::CreateTemplate { guild_id } | Self::GetTemplates { guild_id } => {
                 Path::GuildsIdTemplates(*guild_id)
             }
+            Self::CreateThread { channel_id }
+            | Self::GetPrivateArchivedThreads { channel_id, .. }
+            | Self::GetPublicArchivedThreads { channel_id, .. } => {
+                Path::ChannelsIdThreads(*channel_id)
+            }
+            Self::CreateThreadFromMessage { channel_id, .. } => {
+                Path::ChannelsIdMessagesIdThreads(*channel_id)
+            }
             Self::CreateTypingTrigger { channel_id } => Path::ChannelsIdTyping(*channel_id),
             Self::CreateWebhook { channel_id } | Self::GetChannelWebhooks { channel_id } => {
                 Path::ChannelsIdWebhooks(*channel_id)
@@ -1156,7 +1279,7 @@ impl Route {
             Self::FollowNewsChannel { channel_id } => Path::ChannelsIdFollowers(*channel_id),
             Self::GetAuditLogs { guild_id, .. } => Path::GuildsIdAuditLogs(*guild_id),
             Self::GetBan { guild_id, .. } => Path::GuildsIdBansId(*guild_id),
-            Self::GetBans { guild_id } => Path::GuildsIdBans(*guild_id),
+            Self::GetBans { guild_id, .. } => Path::GuildsIdBans(*guild_id),
             Self::GetGatewayBot => Path::GatewayBot,
             Self::GetChannel { channel_id } | Self::UpdateChannel { channel_id } => {
                 Path::ChannelsId(*channel_id)
@@ -1168,6 +1291,7 @@ impl Route {
             | Self::UpdateCommandPermissions { application_id, .. } => {
                 Path::ApplicationGuildCommandId(*application_id)
             }
+            Self::RevokeOauthToken => Path::OauthTokenRevoke,
             Self::GetCurrentUserApplicationInfo => Path::OauthApplicationsMe,
             Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetEmoji { guild_id, .. } | Self::UpdateEmoji { guild_id, .. } => {
@@ -1196,6 +1320,10 @@ impl Route {
             Self::GetPins { channel_id } | Self::PinMessage { channel_id, .. } => {
                 Path::ChannelsIdPins(*channel_id)
             }
+            Self::GetActiveThreads { guild_id } => Path::GuildsIdThreads(*guild_id),
+            Self::GetJoinedPrivateArchivedThreads { channel_id, .. } => {
+                Path::ChannelsIdUsersMeThreads(*channel_id)
+            }
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
             Self::InteractionCallback { interaction_id, .. } => {
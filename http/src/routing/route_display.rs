@@ -69,6 +69,20 @@ impl Display for RouteDisplay<'_> {
 
                 Display::fmt(role_id, f)
             }
+            Route::AddThreadMember {
+                channel_id,
+                user_id,
+            }
+            | Route::RemoveThreadMember {
+                channel_id,
+                user_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/thread-members/")?;
+
+                Display::fmt(user_id, f)
+            }
             Route::CreateBan {
                 guild_id,
                 delete_message_days,
@@ -202,6 +216,7 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/messages")
             }
+            Route::CreateOauthToken => f.write_str("oauth2/token"),
             Route::CreatePrivateChannel | Route::GetUserPrivateChannels => {
                 f.write_str("users/@me/channels")
             }
@@ -234,6 +249,23 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/templates")
             }
+            Route::CreateThread { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/threads")
+            }
+            Route::CreateThreadFromMessage {
+                channel_id,
+                message_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/messages/")?;
+                Display::fmt(message_id, f)?;
+
+                f.write_str("/threads")
+            }
             Route::CreateTypingTrigger { channel_id } => {
                 f.write_str("channels/")?;
                 Display::fmt(channel_id, f)?;
@@ -467,16 +499,19 @@ impl Display for RouteDisplay<'_> {
             }
             Route::DeleteWebhookMessage {
                 message_id,
+                thread_id,
                 token,
                 webhook_id,
             }
             | Route::GetWebhookMessage {
                 message_id,
+                thread_id,
                 token,
                 webhook_id,
             }
             | Route::UpdateWebhookMessage {
                 message_id,
+                thread_id,
                 token,
                 webhook_id,
             } => {
@@ -485,8 +520,14 @@ impl Display for RouteDisplay<'_> {
                 f.write_str("/")?;
                 f.write_str(token)?;
                 f.write_str("/messages/")?;
+                Display::fmt(message_id, f)?;
 
-                Display::fmt(message_id, f)
+                if let Some(thread_id) = thread_id {
+                    f.write_str("?thread_id=")?;
+                    Display::fmt(thread_id, f)?;
+                }
+
+                Ok(())
             }
             Route::DeleteWebhook { token, webhook_id }
             | Route::GetWebhook { token, webhook_id }
@@ -502,6 +543,7 @@ impl Display for RouteDisplay<'_> {
                 Ok(())
             }
             Route::ExecuteWebhook {
+                thread_id,
                 token,
                 wait,
                 webhook_id,
@@ -511,8 +553,17 @@ impl Display for RouteDisplay<'_> {
                 f.write_str("/")?;
                 f.write_str(token)?;
 
+                if let Some(thread_id) = thread_id {
+                    f.write_str("?thread_id=")?;
+                    Display::fmt(thread_id, f)?;
+                }
+
                 if let Some(wait) = wait {
-                    f.write_str("?wait=")?;
+                    f.write_str(if thread_id.is_some() {
+                        "&wait="
+                    } else {
+                        "?wait="
+                    })?;
                     f.write_str(if *wait { "true" } else { "false" })?;
                 }
 
@@ -524,6 +575,12 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/followers")
             }
+            Route::GetActiveThreads { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/threads/active")
+            }
             Route::GetAuditLogs {
                 action_type,
                 before,
@@ -557,11 +614,32 @@ impl Display for RouteDisplay<'_> {
 
                 Ok(())
             }
-            Route::GetBans { guild_id } => {
+            Route::GetBans {
+                after,
+                before,
+                guild_id,
+                limit,
+            } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
+                f.write_str("/bans?")?;
+
+                if let Some(after) = after {
+                    f.write_str("after=")?;
+                    Display::fmt(after, f)?;
+                }
 
-                f.write_str("/bans")
+                if let Some(before) = before {
+                    f.write_str("&before=")?;
+                    Display::fmt(before, f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
             }
             Route::GetGatewayBot => f.write_str("gateway/bot"),
             Route::GetCommandPermissions {
@@ -584,6 +662,7 @@ impl Display for RouteDisplay<'_> {
                 f.write_str("/permissions")
             }
             Route::GetCurrentUserApplicationInfo => f.write_str("/oauth2/applications/@me"),
+            Route::RevokeOauthToken => f.write_str("oauth2/token/revoke"),
             Route::UpdateCurrentUser => f.write_str("users/@me"),
             Route::GetGateway => f.write_str("gateway"),
             Route::GetGuild {
@@ -628,7 +707,7 @@ impl Display for RouteDisplay<'_> {
             } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
-                f.write_str("members?")?;
+                f.write_str("/members?")?;
 
                 if let Some(after) = after {
                     f.write_str("after=")?;
@@ -767,6 +846,27 @@ impl Display for RouteDisplay<'_> {
 
                 Ok(())
             }
+            Route::GetJoinedPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/users/@me/threads/archived/private?")?;
+
+                if let Some(before) = before {
+                    f.write_str("before=")?;
+                    Display::fmt(before, f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
             Route::GetMessages {
                 channel_id,
                 after,
@@ -806,6 +906,37 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/pins")
             }
+            Route::GetPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            }
+            | Route::GetPublicArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                if matches!(self.0, Route::GetPrivateArchivedThreads { .. }) {
+                    f.write_str("/threads/archived/private?")?;
+                } else {
+                    f.write_str("/threads/archived/public?")?;
+                }
+
+                if let Some(before) = before {
+                    f.write_str("before=")?;
+                    Display::fmt(&utf8_percent_encode(before, NON_ALPHANUMERIC), f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
             Route::GetReactionUsers {
                 after,
                 channel_id,
@@ -833,6 +964,12 @@ impl Display for RouteDisplay<'_> {
 
                 Ok(())
             }
+            Route::GetThreadMembers { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/thread-members")
+            }
             Route::GetUserConnections => f.write_str("users/@me/connections"),
             Route::GetUser { target_user } => {
                 f.write_str("users/")?;
@@ -851,6 +988,12 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/callback")
             }
+            Route::JoinThread { channel_id } | Route::LeaveThread { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/thread-members/@me")
+            }
             Route::LeaveGuild { guild_id } => {
                 f.write_str("users/@me/guilds/")?;
 
@@ -932,6 +1075,74 @@ mod tests {
 
     assert_impl_all!(RouteDisplay<'_>: Clone, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
 
+    #[test]
+    fn test_get_bans() {
+        let route = Route::GetBans {
+            after: Some(2),
+            before: None,
+            guild_id: 1,
+            limit: Some(1000),
+        };
+
+        assert_eq!(
+            "guilds/1/bans?after=2&limit=1000",
+            route.display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_get_guild_members() {
+        let route = Route::GetGuildMembers {
+            after: Some(2),
+            guild_id: 1,
+            limit: Some(1000),
+            presences: None,
+        };
+
+        assert_eq!(
+            "guilds/1/members?after=2&limit=1000",
+            route.display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_execute_webhook() {
+        let route = Route::ExecuteWebhook {
+            thread_id: Some(3),
+            token: "token".to_owned(),
+            wait: Some(true),
+            webhook_id: 1,
+        };
+
+        assert_eq!(
+            "webhooks/1/token?thread_id=3&wait=true",
+            route.display().to_string()
+        );
+
+        let route = Route::ExecuteWebhook {
+            thread_id: None,
+            token: "token".to_owned(),
+            wait: Some(false),
+            webhook_id: 1,
+        };
+
+        assert_eq!("webhooks/1/token?wait=false", route.display().to_string());
+    }
+
+    #[test]
+    fn test_get_public_archived_threads() {
+        let route = Route::GetPublicArchivedThreads {
+            before: Some("2021-09-19T14:17:32+00:00".to_owned()),
+            channel_id: 1,
+            limit: Some(2),
+        };
+
+        assert_eq!(
+            "channels/1/threads/archived/public?before=2021%2D09%2D19T14%3A17%3A32%2B00%3A00&limit=2",
+            route.display().to_string()
+        );
+    }
+
     #[test]
     fn test_set_guild_commands() {
         let route = Route::SetGuildCommands {
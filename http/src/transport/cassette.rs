@@ -0,0 +1,827 @@
+//! Recording responses to a cassette and replaying them.
+//!
+//! A [`RecordingTransport`] sends requests with another transport and records
+//! each request and its response into a [`Cassette`], which can be saved to a
+//! file. A [`ReplayTransport`] then serves the recorded responses, so that
+//! tests can use realistic responses from Discord without making requests.
+//!
+//! # Examples
+//!
+//! Record the requests of a test into a cassette:
+//!
+//! ```rust,no_run
+//! use twilight_http::{
+//!     transport::{HyperTransport, RecordingTransport},
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let transport = RecordingTransport::new(Box::new(HyperTransport::new()));
+//!
+//! let client = Client::builder()
+//!     .token("my token")
+//!     .transport(Box::new(transport.clone()))
+//!     .build();
+//!
+//! client.current_user().await?;
+//!
+//! transport.cassette().save("tests/cassettes/current_user.json")?;
+//! # Ok(()) }
+//! ```
+//!
+//! And then replay it in CI:
+//!
+//! ```rust,no_run
+//! use twilight_http::{
+//!     transport::{Cassette, ReplayTransport},
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let cassette = Cassette::load("tests/cassettes/current_user.json")?;
+//!
+//! let client = Client::builder()
+//!     .token("my token")
+//!     .ratelimiter(None)
+//!     .transport(Box::new(ReplayTransport::new(cassette)))
+//!     .build();
+//!
+//! let user = client.current_user().await?;
+//! # Ok(()) }
+//! ```
+
+use super::{Transport, TransportFuture};
+use crate::API_VERSION;
+use hyper::{
+    body,
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    http::request::Parts,
+    Body, Request, Response, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The error returned when a cassette can not be loaded, saved, or replayed.
+#[derive(Debug)]
+pub struct CassetteError {
+    kind: CassetteErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CassetteError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CassetteErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CassetteErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CassetteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CassetteErrorType::Deserializing => f.write_str("failed to deserialize the cassette"),
+            CassetteErrorType::Reading => f.write_str("failed to read the cassette file"),
+            CassetteErrorType::Serializing => f.write_str("failed to serialize the cassette"),
+            CassetteErrorType::Unmatched { method, path } => {
+                f.write_str("no recorded interaction matches ")?;
+                f.write_str(method)?;
+                f.write_str(" ")?;
+
+                f.write_str(path)
+            }
+            CassetteErrorType::Writing => f.write_str("failed to write the cassette file"),
+        }
+    }
+}
+
+impl Error for CassetteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CassetteError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CassetteErrorType {
+    /// Cassette file is not a valid cassette.
+    Deserializing,
+    /// Cassette file could not be read.
+    Reading,
+    /// Cassette could not be serialized.
+    Serializing,
+    /// Request being replayed doesn't match any remaining recorded
+    /// interaction.
+    Unmatched {
+        /// Method of the request.
+        method: String,
+        /// Path of the request, relative to the API version.
+        path: String,
+    },
+    /// Cassette file could not be written.
+    Writing,
+}
+
+/// Recorded request and its response.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Interaction {
+    /// Recorded request.
+    pub request: RecordedRequest,
+    /// Recorded response to the request.
+    pub response: RecordedResponse,
+}
+
+/// Request recorded in a cassette.
+///
+/// The `Authorization` header is never recorded, and the tokens of webhooks
+/// and interactions are redacted from the path. The randomly generated
+/// boundary of multipart bodies is replaced with a fixed one, so that the
+/// request can be matched when it's replayed.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RecordedRequest {
+    /// Body of the request.
+    ///
+    /// Bodies that aren't valid UTF-8, such as attachments, are recorded
+    /// lossily.
+    pub body: String,
+    /// Headers of the request.
+    pub headers: BTreeMap<String, String>,
+    /// Method of the request.
+    pub method: String,
+    /// Path of the request relative to the API version, including the query.
+    pub path: String,
+}
+
+/// Response recorded in a cassette.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RecordedResponse {
+    /// Body of the response.
+    pub body: String,
+    /// Headers of the response.
+    pub headers: BTreeMap<String, String>,
+    /// Status code of the response.
+    pub status: u16,
+}
+
+/// Recorded interactions, in the order they happened.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Cassette {
+    /// Recorded interactions.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Load a cassette from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CassetteErrorType::Reading`] error type if the file could
+    /// not be read.
+    ///
+    /// Returns a [`CassetteErrorType::Deserializing`] error type if the file
+    /// is not a valid cassette.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        let bytes = fs::read(path).map_err(|source| CassetteError {
+            kind: CassetteErrorType::Reading,
+            source: Some(Box::new(source)),
+        })?;
+
+        serde_json::from_slice(&bytes).map_err(|source| CassetteError {
+            kind: CassetteErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Save the cassette to a JSON file, replacing it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CassetteErrorType::Serializing`] error type if the
+    /// cassette could not be serialized.
+    ///
+    /// Returns a [`CassetteErrorType::Writing`] error type if the file could
+    /// not be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CassetteError> {
+        let bytes = serde_json::to_vec_pretty(self).map_err(|source| CassetteError {
+            kind: CassetteErrorType::Serializing,
+            source: Some(Box::new(source)),
+        })?;
+
+        fs::write(path, bytes).map_err(|source| CassetteError {
+            kind: CassetteErrorType::Writing,
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+/// [`Transport`] recording requests sent with another transport and their
+/// responses.
+///
+/// The `Authorization` header isn't recorded, and the tokens of webhooks and
+/// interactions in paths and OAuth credentials and tokens in bodies are
+/// replaced with `REDACTED`, so that cassettes can be committed.
+///
+/// The transport can be cloned to keep a handle to it after passing it to the
+/// client. Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Clone, Debug)]
+pub struct RecordingTransport {
+    cassette: Arc<Mutex<Cassette>>,
+    inner: Arc<dyn Transport>,
+}
+
+impl RecordingTransport {
+    /// Create a new recording transport sending requests with another
+    /// transport.
+    pub fn new(inner: Box<dyn Transport>) -> Self {
+        Self {
+            cassette: Arc::default(),
+            inner: Arc::from(inner),
+        }
+    }
+
+    /// Cassette of the interactions recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Cassette> {
+        self.cassette.lock().expect("cassette poisoned")
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: Request<Body>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body::to_bytes(body).await?;
+            let mut recorded_headers = headers(&parts.headers);
+            let recorded_body = request_body(
+                &String::from_utf8_lossy(&body),
+                recorded_headers
+                    .get(CONTENT_TYPE.as_str())
+                    .map(String::as_str),
+            );
+
+            if let Some(content_type) = recorded_headers.get_mut(CONTENT_TYPE.as_str()) {
+                if let Some(boundary) = boundary(content_type) {
+                    *content_type = content_type.replace(boundary, BOUNDARY);
+                }
+            }
+
+            let recorded_request = RecordedRequest {
+                body: recorded_body,
+                headers: recorded_headers,
+                method: parts.method.to_string(),
+                path: path(&parts),
+            };
+
+            let response = self
+                .inner
+                .send(Request::from_parts(parts, Body::from(body)))
+                .await?;
+
+            let (parts, body) = response.into_parts();
+            let body = body::to_bytes(body).await?;
+            let recorded_response = RecordedResponse {
+                body: redact_json(&String::from_utf8_lossy(&body)).into_owned(),
+                headers: headers(&parts.headers),
+                status: parts.status.as_u16(),
+            };
+
+            self.lock().interactions.push(Interaction {
+                request: recorded_request,
+                response: recorded_response,
+            });
+
+            Ok(Response::from_parts(parts, Body::from(body)))
+        })
+    }
+}
+
+/// [`Transport`] replaying the responses of a [`Cassette`].
+///
+/// Each recorded response is returned once, to the first request with the
+/// same method, path, and body as the recorded request, in the order they were
+/// recorded. The boundaries of multipart bodies are ignored when comparing
+/// them. Requests without a matching interaction fail with a
+/// [`CassetteErrorType::Unmatched`] error type, which the client returns as an
+/// [`ErrorType::ReplayUnmatched`] and never retries.
+///
+/// Recorded ratelimit headers are replayed as well, so consider disabling the
+/// client's ratelimiter to avoid waiting on ratelimits that no longer apply.
+///
+/// [`ErrorType::ReplayUnmatched`]: crate::error::ErrorType::ReplayUnmatched
+#[derive(Clone, Debug)]
+pub struct ReplayTransport {
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl ReplayTransport {
+    /// Create a new replay transport serving the responses of a cassette.
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            interactions: Arc::new(Mutex::new(cassette.interactions)),
+        }
+    }
+
+    /// Number of recorded interactions that haven't been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Interaction>> {
+        self.interactions.lock().expect("interactions poisoned")
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: Request<Body>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body::to_bytes(body).await?;
+            let body = String::from_utf8_lossy(&body);
+            let content_type = parts
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok());
+            let body = request_body(&body, content_type);
+            let method = parts.method.as_str();
+            let path = path(&parts);
+
+            let interaction = {
+                let mut interactions = self.lock();
+
+                interactions
+                    .iter()
+                    .position(|interaction| {
+                        interaction.request.method == method
+                            && interaction.request.path == path
+                            && request_body(
+                                &interaction.request.body,
+                                interaction
+                                    .request
+                                    .headers
+                                    .get(CONTENT_TYPE.as_str())
+                                    .map(String::as_str),
+                            ) == body
+                    })
+                    .map(|position| interactions.remove(position))
+            };
+
+            let recorded = match interaction {
+                Some(interaction) => interaction.response,
+                None => {
+                    return Err(Box::new(CassetteError {
+                        kind: CassetteErrorType::Unmatched {
+                            method: method.to_owned(),
+                            path,
+                        },
+                        source: None,
+                    }) as Box<dyn Error + Send + Sync>)
+                }
+            };
+
+            let mut response = Response::new(Body::from(recorded.body));
+            *response.status_mut() = StatusCode::from_u16(recorded.status)?;
+
+            for (name, value) in recorded.headers {
+                response.headers_mut().insert(
+                    HeaderName::from_bytes(name.as_bytes())?,
+                    HeaderValue::from_str(&value)?,
+                );
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// Headers to record, excluding the authorization header.
+fn headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| **name != AUTHORIZATION)
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+        .collect()
+}
+
+/// Whether an error returned by a transport is a [`ReplayTransport`] failing
+/// to match a request.
+pub(crate) fn is_unmatched(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    matches!(
+        error.downcast_ref::<CassetteError>(),
+        Some(CassetteError {
+            kind: CassetteErrorType::Unmatched { .. },
+            ..
+        })
+    )
+}
+
+/// Boundary replacing the randomly generated boundaries of multipart bodies.
+const BOUNDARY: &str = "cassetteboundary";
+
+/// Replacement of the tokens of webhooks and interactions in paths, and of
+/// secret fields in bodies.
+const REDACTED_TOKEN: &str = "REDACTED";
+
+/// Fields of form bodies containing OAuth credentials or tokens.
+const SECRET_FORM_FIELDS: &[&str] = &[
+    "access_token",
+    "client_secret",
+    "code",
+    "refresh_token",
+    "token",
+];
+
+/// Keys of JSON bodies containing OAuth credentials or tokens.
+///
+/// Unlike in forms, `code` isn't a secret in JSON bodies, such as those of
+/// invites.
+const SECRET_JSON_KEYS: &[&str] = &["access_token", "client_secret", "refresh_token"];
+
+/// Boundary of a multipart body, given the value of its `Content-Type` header.
+fn boundary(content_type: &str) -> Option<&str> {
+    content_type
+        .strip_prefix("multipart/form-data; boundary=")
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Body with its multipart boundary, if any, replaced with [`BOUNDARY`].
+fn multipart_body<'a>(body: &'a str, content_type: Option<&str>) -> Cow<'a, str> {
+    match content_type.and_then(boundary) {
+        Some(boundary) => Cow::Owned(body.replace(boundary, BOUNDARY)),
+        None => Cow::Borrowed(body),
+    }
+}
+
+/// Request body as it's recorded and compared when replaying, with its
+/// multipart boundary normalized and its secret fields redacted.
+fn request_body(body: &str, content_type: Option<&str>) -> String {
+    let body = multipart_body(body, content_type);
+
+    let is_form = content_type
+        .is_some_and(|content_type| content_type.starts_with("application/x-www-form-urlencoded"));
+
+    if is_form {
+        redact_form(&body)
+    } else {
+        redact_json(&body).into_owned()
+    }
+}
+
+/// Form body with the values of [`SECRET_FORM_FIELDS`] replaced with
+/// [`REDACTED_TOKEN`].
+fn redact_form(body: &str) -> String {
+    body.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if SECRET_FORM_FIELDS.contains(&name) => {
+                format!("{}={}", name, REDACTED_TOKEN)
+            }
+            _ => pair.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// JSON body with the string values of [`SECRET_JSON_KEYS`] replaced with
+/// [`REDACTED_TOKEN`].
+///
+/// Bodies that aren't JSON or contain no secrets are returned as they are.
+fn redact_json(body: &str) -> Cow<'_, str> {
+    let mut value = match serde_json::from_str::<Value>(body) {
+        Ok(value) => value,
+        Err(_) => return Cow::Borrowed(body),
+    };
+
+    if !redact_value(&mut value) {
+        return Cow::Borrowed(body);
+    }
+
+    serde_json::to_string(&value).map_or(Cow::Borrowed(body), Cow::Owned)
+}
+
+/// Redact the secrets of a JSON value, returning whether any were redacted.
+fn redact_value(value: &mut Value) -> bool {
+    let mut redacted = false;
+
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                redacted |= redact_value(value);
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map {
+                if value.is_string() && SECRET_JSON_KEYS.contains(&key.as_str()) {
+                    *value = Value::String(REDACTED_TOKEN.to_owned());
+                    redacted = true;
+                } else {
+                    redacted |= redact_value(value);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    redacted
+}
+
+/// Path of a request relative to the API version, including the query.
+///
+/// The tokens of webhooks and interactions are replaced with
+/// [`REDACTED_TOKEN`].
+fn path(parts: &Parts) -> String {
+    let prefix = format!("/api/v{}/", API_VERSION);
+    let path = parts.uri.path_and_query().map_or("", |path| path.as_str());
+    let path = path.strip_prefix(prefix.as_str()).unwrap_or(path);
+
+    redact(path)
+}
+
+/// Redact the token following the ID in `webhooks/{id}/{token}` and
+/// `interactions/{id}/{token}` paths.
+pub(crate) fn redact(path: &str) -> String {
+    let (path, query) = match path.find('?') {
+        Some(index) => path.split_at(index),
+        None => (path, ""),
+    };
+
+    let mut segments = path.split('/').collect::<Vec<_>>();
+
+    for index in 0..segments.len() {
+        if matches!(segments[index], "interactions" | "webhooks") && index + 2 < segments.len() {
+            segments[index + 2] = REDACTED_TOKEN;
+        }
+    }
+
+    let mut redacted = segments.join("/");
+    redacted.push_str(query);
+
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        redact, redact_json, Cassette, CassetteError, CassetteErrorType, Interaction,
+        RecordedRequest, RecordedResponse, RecordingTransport, ReplayTransport,
+    };
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        oauth2::OAuth2Client,
+        routing::Route,
+        transport::{MockResponse, MockTransport, Transport},
+        API_VERSION,
+    };
+    use hyper::{body, header::CONTENT_TYPE, Body, Request, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{collections::BTreeMap, error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::connection_info::ConnectionInfo,
+        id::{ApplicationId, ChannelId, MessageId},
+    };
+
+    assert_impl_all!(Cassette: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(CassetteError: Debug, Error, Send, Sync);
+    assert_impl_all!(RecordingTransport: Clone, Debug, Send, Sync, Transport);
+    assert_impl_all!(ReplayTransport: Clone, Debug, Send, Sync, Transport);
+
+    fn client(transport: Box<dyn Transport>) -> Client {
+        Client::builder()
+            .token("token")
+            .transport(transport)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mock = MockTransport::new();
+        mock.respond(
+            &Route::GetGateway,
+            MockResponse::json(&ConnectionInfo {
+                url: "wss://gateway.discord.gg".to_owned(),
+            })?,
+        );
+
+        let recording = RecordingTransport::new(Box::new(mock));
+        let info = client(Box::new(recording.clone())).gateway().await?;
+        assert_eq!("wss://gateway.discord.gg", info.url);
+
+        let cassette = recording.cassette();
+        assert_eq!(1, cassette.interactions.len());
+        let interaction = &cassette.interactions[0];
+        assert_eq!("GET", interaction.request.method);
+        assert_eq!("gateway", interaction.request.path);
+        assert!(!interaction.request.headers.contains_key("authorization"));
+        assert_eq!(200, interaction.response.status);
+
+        let path = std::env::temp_dir().join(format!(
+            "twilight-http-cassette-{}.json",
+            std::process::id()
+        ));
+        cassette.save(&path)?;
+        let loaded = Cassette::load(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(cassette, loaded);
+
+        let replay = ReplayTransport::new(loaded);
+        let info = client(Box::new(replay.clone())).gateway().await?;
+        assert_eq!("wss://gateway.discord.gg", info.url);
+        assert_eq!(0, replay.remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_replay_unmatched() {
+        let replay = ReplayTransport::new(Cassette {
+            interactions: vec![Interaction {
+                request: RecordedRequest {
+                    body: String::new(),
+                    headers: BTreeMap::new(),
+                    method: "GET".to_owned(),
+                    path: "gateway".to_owned(),
+                },
+                response: RecordedResponse {
+                    body: r#"{"url":"wss://gateway.discord.gg"}"#.to_owned(),
+                    headers: BTreeMap::new(),
+                    status: 200,
+                },
+            }],
+        });
+
+        let error = client(Box::new(replay.clone()))
+            .delete_message(ChannelId(1), MessageId(2))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::ReplayUnmatched));
+
+        let source = error.into_source().unwrap();
+        let source = source.downcast_ref::<CassetteError>().unwrap();
+        assert!(matches!(
+            source.kind(),
+            CassetteErrorType::Unmatched { method, path }
+            if method == "DELETE" && path == "channels/1/messages/2"
+        ));
+        assert_eq!(1, replay.remaining());
+    }
+
+    fn multipart(boundary: &str) -> Request<Body> {
+        Request::post(format!(
+            "https://discord.com/api/v{}/webhooks/1/secret?wait=true",
+            API_VERSION,
+        ))
+        .header(
+            CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", boundary),
+        )
+        .body(Body::from(format!(
+            "--{0}\r\nContent-Disposition: form-data; name=\"payload_json\"\r\n\r\n{{}}\r\n--{0}--",
+            boundary,
+        )))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replay_multipart() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mock = MockTransport::new();
+        mock.respond(
+            &Route::ExecuteWebhook {
+                thread_id: None,
+                token: "secret".to_owned(),
+                wait: Some(true),
+                webhook_id: 1,
+            },
+            MockResponse::new(StatusCode::NO_CONTENT),
+        );
+
+        let recording = RecordingTransport::new(Box::new(mock));
+        recording.send(multipart("aaaaaaaaaaaaaaa")).await?;
+
+        let cassette = recording.cassette();
+        let request = &cassette.interactions[0].request;
+        assert_eq!("webhooks/1/REDACTED?wait=true", request.path);
+        assert!(!request.body.contains("aaaaaaaaaaaaaaa"));
+        assert!(!request.headers[CONTENT_TYPE.as_str()].contains("aaaaaaaaaaaaaaa"));
+
+        let replay = ReplayTransport::new(cassette);
+        let response = replay.send(multipart("bbbbbbbbbbbbbbb")).await?;
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+        assert!(body::to_bytes(response.into_body()).await?.is_empty());
+        assert_eq!(0, replay.remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_record_oauth2_secrets() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mock = MockTransport::new();
+        mock.respond(
+            &Route::CreateOauthToken,
+            MockResponse::json(&serde_json::json!({
+                "access_token": "access",
+                "expires_in": 604_800,
+                "refresh_token": "refresh",
+                "scope": "identify",
+                "token_type": "Bearer",
+            }))?,
+        );
+
+        let recording = RecordingTransport::new(Box::new(mock));
+        let http = client(Box::new(recording.clone()));
+        let oauth = OAuth2Client::with_client(
+            http,
+            ApplicationId(1),
+            "secret",
+            "https://example.com/callback",
+        );
+        oauth.exchange_code("code").await?;
+
+        let cassette = recording.cassette();
+        let interaction = &cassette.interactions[0];
+        assert_eq!(
+            "client_id=1&client_secret=REDACTED&code=REDACTED&grant_type=authorization_code\
+            &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback",
+            interaction.request.body
+        );
+        assert!(!interaction.response.body.contains("access\""));
+        assert!(!interaction.response.body.contains("refresh\""));
+        assert!(interaction.response.body.contains("identify"));
+
+        // Requests with different secrets match the redacted recording.
+        let replay = ReplayTransport::new(cassette);
+        let oauth = OAuth2Client::with_client(
+            client(Box::new(replay.clone())),
+            ApplicationId(1),
+            "other secret",
+            "https://example.com/callback",
+        );
+        let token = oauth.exchange_code("other code").await?;
+        assert_eq!("REDACTED", token.access_token);
+        assert_eq!(0, replay.remaining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_redact_json() {
+        assert_eq!(
+            r#"[{"refresh_token":"REDACTED"}]"#,
+            redact_json(r#"[{"refresh_token":"refresh"}]"#)
+        );
+        assert_eq!(
+            r#"{"code":"invite","access_token":null}"#,
+            redact_json(r#"{"code":"invite","access_token":null}"#)
+        );
+        assert_eq!("not json", redact_json("not json"));
+    }
+
+    #[test]
+    fn test_redact() {
+        assert_eq!("webhooks/1/REDACTED", redact("webhooks/1/token"));
+        assert_eq!(
+            "webhooks/1/REDACTED/messages/2?thread_id=3",
+            redact("webhooks/1/token/messages/2?thread_id=3"),
+        );
+        assert_eq!(
+            "interactions/1/REDACTED/callback",
+            redact("interactions/1/token/callback"),
+        );
+        assert_eq!("webhooks/1", redact("webhooks/1"));
+        assert_eq!("channels/1/webhooks", redact("channels/1/webhooks"));
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let error = Cassette::load("/nonexistent/cassette.json").unwrap_err();
+        assert!(matches!(error.kind(), CassetteErrorType::Reading));
+    }
+}
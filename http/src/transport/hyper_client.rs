@@ -0,0 +1,62 @@
+use super::{Transport, TransportFuture};
+use hyper::{
+    client::{Client as HyperClient, HttpConnector},
+    Body, Request,
+};
+
+#[cfg(feature = "hyper-rustls")]
+type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
+#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
+type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
+
+/// [`Transport`] sending requests over the network with hyper.
+///
+/// This is the transport used by default. Connections are made over TLS
+/// using rustls or native-tls, depending on the enabled features.
+#[derive(Clone, Debug)]
+pub struct HyperTransport {
+    http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+}
+
+impl HyperTransport {
+    /// Create a new hyper transport.
+    pub fn new() -> Self {
+        #[cfg(feature = "rustls-native-roots")]
+        let connector = hyper_rustls::HttpsConnector::with_native_roots();
+        #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
+        let connector = hyper_rustls::HttpsConnector::with_webpki_roots();
+        #[cfg(all(
+            feature = "hyper-tls",
+            not(feature = "rustls-native-roots"),
+            not(feature = "rustls-webpki-roots")
+        ))]
+        let connector = hyper_tls::HttpsConnector::new();
+
+        Self {
+            http: hyper::client::Builder::default().build(connector),
+        }
+    }
+}
+
+impl Default for HyperTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: Request<Body>) -> TransportFuture<'_> {
+        let fut = self.http.request(request);
+
+        Box::pin(async move { Ok(fut.await?) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HyperTransport, Transport};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(HyperTransport: Clone, Debug, Default, Send, Sync, Transport);
+}
@@ -0,0 +1,633 @@
+//! [`Transport`] returning canned responses, for testing without the network.
+
+use super::{Transport, TransportFuture};
+use crate::{error::Error as HttpError, routing::Route, API_VERSION};
+use hyper::{
+    body,
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
+    Body, Method as HyperMethod, Request, Response, StatusCode,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The error returned by the [`MockTransport`] if a request can not be
+/// responded to.
+#[derive(Debug)]
+pub struct MockTransportError {
+    kind: MockTransportErrorType,
+}
+
+impl MockTransportError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MockTransportErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MockTransportErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for MockTransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            MockTransportErrorType::NoResponse { method, path } => {
+                f.write_str("no mock response for ")?;
+                f.write_str(method.as_str())?;
+                f.write_str(" ")?;
+
+                f.write_str(path)
+            }
+        }
+    }
+}
+
+impl Error for MockTransportError {}
+
+/// Type of [`MockTransportError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MockTransportErrorType {
+    /// No response was registered for the request's method and route.
+    NoResponse {
+        /// Method of the request.
+        method: HyperMethod,
+        /// Path of the request, relative to the API version.
+        path: String,
+    },
+}
+
+/// Request received by the [`MockTransport`].
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    /// Body of the request.
+    pub body: Vec<u8>,
+    /// Headers of the request.
+    pub headers: HeaderMap,
+    /// Method of the request.
+    pub method: HyperMethod,
+    /// Path of the request relative to the API version, including the query,
+    /// such as `channels/1/messages?limit=10`.
+    pub path: String,
+}
+
+/// Canned response returned by the [`MockTransport`].
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    body: Vec<u8>,
+    headers: HeaderMap,
+    status: StatusCode,
+}
+
+impl MockResponse {
+    /// Create a new response with a status code and an empty body.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: HeaderMap::new(),
+            status,
+        }
+    }
+
+    /// Create a successful response with a value serialized as JSON as the
+    /// body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::Json`] error type if the value could not be
+    /// serialized.
+    ///
+    /// [`ErrorType::Json`]: crate::error::ErrorType::Json
+    pub fn json(value: &impl Serialize) -> Result<Self, HttpError> {
+        let body = crate::json::to_vec(value).map_err(HttpError::json)?;
+
+        Ok(Self::new(StatusCode::OK)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .body(body))
+    }
+
+    /// Create an error response in the format of Discord's API errors, with
+    /// an [error code] and message.
+    ///
+    /// [error code]: crate::api_error::ErrorCode
+    pub fn error(status: StatusCode, code: u64, message: &str) -> Self {
+        let body = serde_json::json!({
+            "code": code,
+            "message": message,
+        });
+
+        Self::new(status)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .body(body.to_string())
+    }
+
+    /// Set the body of the response.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+
+        self
+    }
+
+    /// Set a header of the response.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+
+        self
+    }
+
+    fn into_response(self) -> Response<Body> {
+        let mut response = Response::new(Body::from(self.body));
+        *response.headers_mut() = self.headers;
+        *response.status_mut() = self.status;
+
+        response
+    }
+}
+
+/// [`Transport`] returning canned responses to requests matching a method
+/// and route.
+///
+/// Responses are registered with [`respond`], and each is returned once to
+/// the first request matching its method and route, in the order they were
+/// registered. Requests without a matching response fail with a
+/// [`MockTransportErrorType::NoResponse`] error type, which the client
+/// returns as an [`ErrorType::RequestError`].
+///
+/// Every request is recorded and can be inspected via [`requests`]. The
+/// transport can be cloned to keep a handle to it after passing it to the
+/// client.
+///
+/// # Examples
+///
+/// Test that the gateway URL is retrieved:
+///
+/// ```rust
+/// use twilight_http::{
+///     routing::Route,
+///     transport::{MockResponse, MockTransport},
+///     Client,
+/// };
+/// use twilight_model::gateway::connection_info::ConnectionInfo;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let transport = MockTransport::new();
+/// transport.respond(
+///     &Route::GetGateway,
+///     MockResponse::json(&ConnectionInfo {
+///         url: "wss://gateway.discord.gg".to_owned(),
+///     })?,
+/// );
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .transport(Box::new(transport.clone()))
+///     .build();
+///
+/// let info = client.gateway().await?;
+/// assert_eq!("wss://gateway.discord.gg", info.url);
+/// assert_eq!("gateway", transport.requests()[0].path);
+/// # Ok(()) }
+/// ```
+///
+/// [`ErrorType::RequestError`]: crate::error::ErrorType::RequestError
+/// [`requests`]: Self::requests
+/// [`respond`]: Self::respond
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport(Arc<Mutex<Inner>>);
+
+impl MockTransport {
+    /// Create a new mock transport without any responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a response to the next request to a route.
+    pub fn respond(&self, route: &Route, response: MockResponse) -> &Self {
+        let method = route.method().into_hyper();
+        let path = route.display().to_string();
+
+        self.lock().responses.push((method, path, response));
+
+        self
+    }
+
+    /// Requests received so far, in the order they were received.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Number of registered responses that haven't been returned yet.
+    pub fn remaining(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.0.lock().expect("mock transport poisoned")
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request<Body>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body::to_bytes(body).await?;
+
+            let prefix = format!("/api/v{}/", API_VERSION);
+            let path = parts.uri.path_and_query().map_or("", |path| path.as_str());
+            let path = path
+                .strip_prefix(prefix.as_str())
+                .unwrap_or(path)
+                .to_owned();
+
+            let method = parts.method;
+
+            let mut inner = self.lock();
+            inner.requests.push(MockRequest {
+                body: body.to_vec(),
+                headers: parts.headers,
+                method: method.clone(),
+                path: path.clone(),
+            });
+
+            let position = inner
+                .responses
+                .iter()
+                .position(|(route_method, route, _)| *route_method == method && *route == path);
+
+            match position {
+                Some(position) => Ok(inner.responses.remove(position).2.into_response()),
+                None => Err(Box::new(MockTransportError {
+                    kind: MockTransportErrorType::NoResponse { method, path },
+                }) as Box<dyn Error + Send + Sync>),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    requests: Vec<MockRequest>,
+    responses: Vec<(HyperMethod, String, MockResponse)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockResponse, MockTransport, MockTransportError, MockTransportErrorType};
+    use crate::{
+        api_error::{ApiError, ErrorCode},
+        client::{Client, RetryPolicy},
+        error::ErrorType,
+        ratelimiting::{GetTicketFuture, Ratelimiter},
+        request::AttachmentFile,
+        routing::{Path, Route},
+        transport::{Transport, TransportFuture},
+    };
+    use hyper::{
+        header::{HeaderName, HeaderValue, CONTENT_TYPE},
+        Body, Request, StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use tokio::{sync::oneshot, time};
+    use twilight_model::{
+        gateway::connection_info::ConnectionInfo,
+        id::{ChannelId, MessageId},
+    };
+
+    assert_impl_all!(MockTransport: Clone, Debug, Default, Send, Sync, Transport);
+    assert_impl_all!(MockTransportError: Debug, Error, Send, Sync);
+    assert_impl_all!(MockTransportErrorType: Debug, Send, Sync);
+    assert_impl_all!(MockResponse: Clone, Debug, Send, Sync);
+
+    fn client(transport: &MockTransport) -> Client {
+        Client::builder()
+            .token("token")
+            .transport(Box::new(transport.clone()))
+            .build()
+    }
+
+    /// Transport responding through a [`MockTransport`] after a delay.
+    #[derive(Debug)]
+    struct SlowTransport(MockTransport, Duration);
+
+    impl Transport for SlowTransport {
+        fn send(&self, request: Request<Body>) -> TransportFuture<'_> {
+            Box::pin(async move {
+                time::sleep(self.1).await;
+
+                self.0.send(request).await
+            })
+        }
+    }
+
+    /// Ratelimiter granting tickets after a delay.
+    #[derive(Debug)]
+    struct SlowRatelimiter(Duration);
+
+    impl Ratelimiter for SlowRatelimiter {
+        fn ticket(&self, _: Path) -> GetTicketFuture<'_> {
+            Box::pin(async move {
+                time::sleep(self.0).await;
+
+                let (tx, rx) = oneshot::channel();
+                let (headers_tx, _) = oneshot::channel();
+                let _res = tx.send(headers_tx);
+
+                Ok(rx)
+            })
+        }
+    }
+
+    fn gateway() -> Result<MockResponse, Box<dyn Error + Send + Sync>> {
+        Ok(MockResponse::json(&ConnectionInfo {
+            url: "wss://gateway.discord.gg".to_owned(),
+        })?)
+    }
+
+    #[tokio::test]
+    async fn test_canned_response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transport = MockTransport::new();
+        transport.respond(&Route::GetGateway, gateway()?);
+        let client = client(&transport);
+
+        let info = client.gateway().await?;
+        assert_eq!("wss://gateway.discord.gg", info.url);
+        assert_eq!(0, transport.remaining());
+
+        let requests = transport.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("gateway", requests[0].path);
+        assert_eq!(
+            Some("Bot token"),
+            requests[0]
+                .headers
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_header() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transport = MockTransport::new();
+        transport.respond(&Route::GetGateway, gateway()?);
+        let client = client(&transport);
+
+        client
+            .gateway()
+            .header(
+                HeaderName::from_static("x-audit-log-reason"),
+                HeaderValue::from_static("test"),
+            )
+            .await?;
+
+        let requests = transport.requests();
+        assert_eq!(
+            Some("test"),
+            requests[0]
+                .headers
+                .get("x-audit-log-reason")
+                .and_then(|value| value.to_str().ok())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_request_timeout() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transport = MockTransport::new();
+        transport
+            .respond(&Route::GetGateway, gateway()?)
+            .respond(&Route::GetGateway, gateway()?);
+
+        let client = Client::builder()
+            .ratelimiter(None)
+            .token("token")
+            .transport(Box::new(SlowTransport(
+                transport.clone(),
+                Duration::from_secs(5),
+            )))
+            .build();
+
+        let error = client
+            .gateway()
+            .timeout(Duration::from_secs(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        client.gateway().timeout(Duration::from_secs(10)).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_includes_ratelimit() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transport = MockTransport::new();
+        transport
+            .respond(&Route::GetGateway, gateway()?)
+            .respond(&Route::GetGateway, gateway()?);
+
+        // Waiting on the ratelimiter and the response each take less than
+        // the timeout, but together take longer.
+        let client = Client::builder()
+            .ratelimiter(Some(Box::new(SlowRatelimiter(Duration::from_secs(3)))))
+            .token("token")
+            .transport(Box::new(SlowTransport(
+                transport.clone(),
+                Duration::from_secs(3),
+            )))
+            .build();
+
+        let error = client
+            .gateway()
+            .timeout(Duration::from_secs(5))
+            .timeout_includes_ratelimit(true)
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        client
+            .gateway()
+            .timeout(Duration::from_secs(5))
+            .timeout_includes_ratelimit(false)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_no_response() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::GetGateway,
+            MockResponse::new(StatusCode::NO_CONTENT),
+        );
+        let client = client(&transport);
+
+        let error = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestError));
+
+        let source = error.into_source().unwrap();
+        let source = source.downcast_ref::<MockTransportError>().unwrap();
+        assert!(matches!(
+            source.kind(),
+            MockTransportErrorType::NoResponse { path, .. }
+            if path == "channels/1/messages/2"
+        ));
+        assert_eq!(1, transport.remaining());
+    }
+
+    #[tokio::test]
+    async fn test_api_error() {
+        let transport = MockTransport::new();
+        transport.respond(
+            &Route::DeleteMessage {
+                channel_id: 1,
+                message_id: 2,
+            },
+            MockResponse::error(StatusCode::NOT_FOUND, 10008, "Unknown Message"),
+        );
+        let client = client(&transport);
+
+        let error = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response {
+                error: ApiError::General(general),
+                status: StatusCode::NOT_FOUND,
+                ..
+            } if general.code == ErrorCode::UnknownMessage
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retry() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                &Route::GetGateway,
+                MockResponse::error(StatusCode::BAD_GATEWAY, 0, "Bad Gateway"),
+            )
+            .respond(&Route::GetGateway, gateway()?);
+
+        let client = Client::builder()
+            .token("token")
+            .retry_policy(
+                RetryPolicy::new().backoff(Duration::from_millis(0), Duration::from_millis(0)),
+            )
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let info = client.gateway().await?;
+        assert_eq!("wss://gateway.discord.gg", info.url);
+        assert_eq!(2, transport.requests().len());
+
+        Ok(())
+    }
+
+    /// Discord's edge responds to 502s with an HTML page rather than an API
+    /// error, which must still be retried.
+    #[tokio::test]
+    async fn test_retry_html_body() -> Result<(), Box<dyn Error + Send + Sync>> {
+        const HTML: &str = "<html><head><title>502 Bad Gateway</title></head></html>";
+
+        let bad_gateway = MockResponse::new(StatusCode::BAD_GATEWAY)
+            .header(CONTENT_TYPE, HeaderValue::from_static("text/html"))
+            .body(HTML);
+
+        let transport = MockTransport::new();
+        transport
+            .respond(&Route::GetGateway, bad_gateway.clone())
+            .respond(&Route::GetGateway, gateway()?);
+
+        let client = Client::builder()
+            .token("token")
+            .retry_policy(
+                RetryPolicy::new().backoff(Duration::from_millis(0), Duration::from_millis(0)),
+            )
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let info = client.gateway().await?;
+        assert_eq!("wss://gateway.discord.gg", info.url);
+        assert_eq!(2, transport.requests().len());
+
+        // Without a retry policy the status is still available.
+        transport.respond(&Route::GetGateway, bad_gateway);
+        let error = self::client(&transport).gateway().await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::UnexpectedResponse {
+                body,
+                status: StatusCode::BAD_GATEWAY,
+            } if body == HTML.as_bytes()
+        ));
+
+        Ok(())
+    }
+
+    /// Streamed files can only be sent once, so a failed upload returns the
+    /// original error instead of being retried.
+    #[tokio::test]
+    async fn test_retry_streamed_attachment() {
+        let route = Route::CreateMessage { channel_id: 1 };
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                &route,
+                MockResponse::error(StatusCode::BAD_GATEWAY, 0, "Bad Gateway"),
+            )
+            .respond(&route, MockResponse::error(StatusCode::NOT_FOUND, 0, ""));
+
+        let client = Client::builder()
+            .token("token")
+            .retry_policy(
+                RetryPolicy::new()
+                    .backoff(Duration::from_millis(0), Duration::from_millis(0))
+                    .non_idempotent(true),
+            )
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let file = AttachmentFile::from_reader("hello.txt", 5, &b"hello"[..]);
+        let error = client
+            .create_message(ChannelId(1))
+            .attachment_file(file)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response {
+                status: StatusCode::BAD_GATEWAY,
+                ..
+            }
+        ));
+        assert_eq!(1, transport.requests().len());
+        assert_eq!(1, transport.remaining());
+    }
+}
@@ -0,0 +1,59 @@
+//! Transports sending HTTP requests built by the client.
+//!
+//! The [`Client`] builds each request, including its headers and body, and
+//! hands it to a [`Transport`] to send. By default the [`HyperTransport`] is
+//! used, which sends requests over the network with hyper.
+//!
+//! A custom transport can be configured via [`ClientBuilder::transport`],
+//! such as the [`MockTransport`], which returns canned responses so that code
+//! making requests can be tested without network access. Real responses can
+//! be recorded with the [`RecordingTransport`] and later replayed with the
+//! [`ReplayTransport`].
+//!
+//! [`Client`]: crate::Client
+//! [`ClientBuilder::transport`]: crate::client::ClientBuilder::transport
+
+pub mod cassette;
+pub mod mock;
+
+mod hyper_client;
+
+pub use self::{
+    cassette::{Cassette, RecordingTransport, ReplayTransport},
+    hyper_client::HyperTransport,
+    mock::{MockRequest, MockResponse, MockTransport},
+};
+
+use hyper::{Body, Request, Response};
+use std::{error::Error, fmt::Debug, future::Future, pin::Pin};
+
+/// Future returned by [`Transport::send`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, Box<dyn Error + Send + Sync>>> + Send + 'a>>;
+
+/// Transport sending requests made by the HTTP client.
+///
+/// Requests are passed to the transport after they have been ratelimited,
+/// and the client's timeout applies to the returned future. Refer to the
+/// [module-level] documentation for more information.
+///
+/// [module-level]: crate::transport
+pub trait Transport: Debug + Send + Sync {
+    /// Send a request, resolving to the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent or no response was
+    /// received, such as due to a connection error.
+    fn send(&self, request: Request<Body>) -> TransportFuture<'_>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transport;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+
+    assert_impl_all!(dyn Transport: Debug, Send, Sync);
+    assert_obj_safe!(Transport);
+}
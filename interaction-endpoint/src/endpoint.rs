@@ -0,0 +1,401 @@
+//! Endpoint receiving interactions as a [`hyper`] service.
+
+use crate::key::PublicKey;
+use hyper::{
+    body::{Bytes, HttpBody},
+    header::{HeaderValue, CONTENT_LENGTH, CONTENT_TYPE},
+    service::Service,
+    Body, Method, Request, Response, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use twilight_model::application::{callback::InteractionResponse, interaction::Interaction};
+
+/// Maximum length of a request body in bytes.
+///
+/// Bodies are read before their signature can be verified, so longer bodies
+/// are rejected without being read.
+pub const MAX_BODY_LENGTH: usize = 1024 * 1024;
+
+/// Name of the header containing the signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the timestamp of a request.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Endpoint that receives interactions sent by Discord over HTTP.
+///
+/// Requests are verified with the [`PublicKey`] of the application and
+/// deserialized into [`Interaction`]s. [`Interaction::Ping`]s are answered
+/// with an [`InteractionResponse::Pong`], and all other interactions are
+/// passed to the handler, whose returned [`InteractionResponse`] is sent as
+/// the response.
+///
+/// Requests are answered with the following status codes if they can't be
+/// handled:
+///
+/// - `405 Method Not Allowed` if the method isn't `POST`;
+/// - `413 Payload Too Large` if the body is longer than [`MAX_BODY_LENGTH`];
+/// - `401 Unauthorized` if the signature headers are missing or the signature
+///   isn't valid;
+/// - `400 Bad Request` if the body isn't a valid interaction.
+///
+/// The endpoint is a [`Service`], so it can be served by hyper directly.
+/// Cloning it is cheap.
+///
+/// # Examples
+///
+/// Serve an endpoint responding to every command with a message:
+///
+/// ```no_run
+/// use hyper::{service::make_service_fn, Server};
+/// use std::convert::Infallible;
+/// use twilight_interaction_endpoint::{InteractionEndpoint, PublicKey};
+/// use twilight_model::application::{
+///     callback::{CallbackData, InteractionResponse},
+///     interaction::Interaction,
+/// };
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = PublicKey::from_hex(std::env::var("DISCORD_PUBLIC_KEY")?)?;
+/// let endpoint = InteractionEndpoint::new(key, |interaction: Interaction| async move {
+///     InteractionResponse::ChannelMessageWithSource(CallbackData {
+///         allowed_mentions: None,
+///         components: None,
+///         content: Some("Hello!".to_owned()),
+///         embeds: Vec::new(),
+///         flags: None,
+///         tts: None,
+///     })
+/// });
+///
+/// let make_service = make_service_fn(move |_| {
+///     let endpoint = endpoint.clone();
+///
+///     async move { Ok::<_, Infallible>(endpoint) }
+/// });
+///
+/// Server::bind(&([127, 0, 0, 1], 3030).into())
+///     .serve(make_service)
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct InteractionEndpoint<F> {
+    handler: Arc<F>,
+    key: PublicKey,
+}
+
+impl<F, Fut> InteractionEndpoint<F>
+where
+    F: Fn(Interaction) -> Fut,
+    Fut: Future<Output = InteractionResponse>,
+{
+    /// Create a new endpoint verifying requests with a key and passing
+    /// interactions to a handler.
+    pub fn new(key: PublicKey, handler: F) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            key,
+        }
+    }
+
+    /// Handle a request, returning the response to send.
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        if request.method() != Method::POST {
+            return empty(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let (parts, body) = request.into_parts();
+
+        let content_length = parts
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+
+        if content_length.is_some_and(|length| length > MAX_BODY_LENGTH as u64) {
+            return empty(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        let (signature, timestamp) = match (
+            parts.headers.get(SIGNATURE_HEADER),
+            parts.headers.get(TIMESTAMP_HEADER),
+        ) {
+            (Some(signature), Some(timestamp)) => (signature, timestamp),
+            _ => return empty(StatusCode::UNAUTHORIZED),
+        };
+
+        let body = match read_body(body).await {
+            Ok(body) => body,
+            Err(status) => return empty(status),
+        };
+
+        if self.key.verify(signature, timestamp, &body).is_err() {
+            return empty(StatusCode::UNAUTHORIZED);
+        }
+
+        let interaction = match serde_json::from_slice::<Interaction>(&body) {
+            Ok(interaction) => interaction,
+            Err(_) => return empty(StatusCode::BAD_REQUEST),
+        };
+
+        let response = match interaction {
+            Interaction::Ping(_) => InteractionResponse::Pong,
+            other => (self.handler)(other).await,
+        };
+
+        match serde_json::to_vec(&response) {
+            Ok(bytes) => {
+                let mut response = Response::new(Body::from(bytes));
+                response
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+                response
+            }
+            Err(_) => empty(StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+}
+
+impl<F> Clone for InteractionEndpoint<F> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: Arc::clone(&self.handler),
+            key: self.key,
+        }
+    }
+}
+
+impl<F> Debug for InteractionEndpoint<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionEndpoint")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, Fut> Service<Request<Body>> for InteractionEndpoint<F>
+where
+    F: Fn(Interaction) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = InteractionResponse> + Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let endpoint = self.clone();
+
+        Box::pin(async move { Ok(endpoint.handle(request).await) })
+    }
+}
+
+/// Read a body, failing with the status code to respond with if it's longer
+/// than [`MAX_BODY_LENGTH`] or couldn't be read.
+async fn read_body(mut body: Body) -> Result<Bytes, StatusCode> {
+    let mut bytes = Vec::new();
+
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        if bytes.len() + chunk.len() > MAX_BODY_LENGTH {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(Bytes::from(bytes))
+}
+
+/// Response with a status code and no body.
+fn empty(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InteractionEndpoint, MAX_BODY_LENGTH, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+    use crate::key::PublicKey;
+    use ed25519_dalek::{Keypair, PublicKey as DalekPublicKey, SecretKey, Signer};
+    use hyper::{body, header::CONTENT_LENGTH, Body, Method, Request, Response, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, future::Future};
+    use twilight_model::application::{
+        callback::{CallbackData, InteractionResponse},
+        interaction::Interaction,
+    };
+
+    assert_impl_all!(InteractionEndpoint<fn(Interaction) -> std::future::Ready<InteractionResponse>>: Clone, Debug, Send, Sync);
+
+    const PING: &str = r#"{
+        "application_id": "100",
+        "id": "500",
+        "token": "interaction token",
+        "type": 1,
+        "version": 1
+    }"#;
+
+    const COMMAND: &str = r#"{
+        "application_id": "100",
+        "channel_id": "200",
+        "data": {
+            "id": "300",
+            "name": "ping"
+        },
+        "id": "500",
+        "token": "interaction token",
+        "type": 2,
+        "user": {
+            "avatar": null,
+            "discriminator": "1111",
+            "id": "600",
+            "username": "username"
+        },
+        "version": 1
+    }"#;
+
+    fn keypair() -> Keypair {
+        let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public = DalekPublicKey::from(&secret);
+
+        Keypair { secret, public }
+    }
+
+    fn endpoint() -> InteractionEndpoint<
+        impl Fn(Interaction) -> std::future::Ready<InteractionResponse> + Send + Sync + 'static,
+    > {
+        let key = PublicKey::from_bytes(keypair().public.as_bytes()).unwrap();
+
+        InteractionEndpoint::new(key, |interaction| {
+            let content = match interaction {
+                Interaction::ApplicationCommand(command) => command.data.name,
+                other => panic!("unexpected interaction {:?}", other),
+            };
+
+            std::future::ready(InteractionResponse::ChannelMessageWithSource(
+                CallbackData {
+                    allowed_mentions: None,
+                    components: None,
+                    content: Some(content),
+                    embeds: Vec::new(),
+                    flags: None,
+                    tts: None,
+                },
+            ))
+        })
+    }
+
+    fn signed(body: &str, timestamp: &str) -> Request<Body> {
+        let signature = keypair().sign(format!("{}{}", timestamp, body).as_bytes());
+
+        Request::builder()
+            .method(Method::POST)
+            .header(SIGNATURE_HEADER, hex::encode(signature.to_bytes()))
+            .header(TIMESTAMP_HEADER, timestamp)
+            .body(Body::from(body.to_owned()))
+            .unwrap()
+    }
+
+    async fn body(response: impl Future<Output = Response<Body>>) -> (StatusCode, String) {
+        let response = response.await;
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_ping() {
+        let endpoint = endpoint();
+
+        let (status, body) = body(endpoint.handle(signed(PING, "1234"))).await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(r#"{"type":1}"#, body);
+    }
+
+    #[tokio::test]
+    async fn test_command() {
+        let endpoint = endpoint();
+
+        let (status, body) = body(endpoint.handle(signed(COMMAND, "1234"))).await;
+        assert_eq!(StatusCode::OK, status);
+
+        let value = serde_json::from_str::<serde_json::Value>(&body).unwrap();
+        assert_eq!(4, value["type"]);
+        assert_eq!("ping", value["data"]["content"]);
+    }
+
+    #[tokio::test]
+    async fn test_rejected() {
+        let endpoint = endpoint();
+
+        let mut request = signed(PING, "1234");
+        *request.method_mut() = Method::GET;
+        let response = endpoint.handle(request).await;
+        assert_eq!(StatusCode::METHOD_NOT_ALLOWED, response.status());
+
+        let mut request = signed(PING, "1234");
+        request.headers_mut().remove(SIGNATURE_HEADER);
+        let response = endpoint.handle(request).await;
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+        let mut request = signed(PING, "1234");
+        request
+            .headers_mut()
+            .insert(TIMESTAMP_HEADER, "1235".parse().unwrap());
+        let response = endpoint.handle(request).await;
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+        let response = endpoint.handle(signed("{}", "1234")).await;
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_too_large() {
+        let endpoint = endpoint();
+
+        let mut request = signed(PING, "1234");
+        request
+            .headers_mut()
+            .insert(CONTENT_LENGTH, (MAX_BODY_LENGTH + 1).into());
+        let response = endpoint.handle(request).await;
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+        // Bodies without a length are limited while they're read.
+        let (mut sender, body) = Body::channel();
+        let mut request = signed(PING, "1234");
+        *request.body_mut() = body;
+
+        tokio::spawn(async move {
+            let chunk = vec![b' '; MAX_BODY_LENGTH / 4];
+
+            for _ in 0..5 {
+                if sender.send_data(chunk.clone().into()).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let response = endpoint.handle(request).await;
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+        let body = PING.to_owned() + &" ".repeat(MAX_BODY_LENGTH - PING.len());
+        let response = endpoint.handle(signed(&body, "1234")).await;
+        assert_eq!(StatusCode::OK, response.status());
+    }
+}
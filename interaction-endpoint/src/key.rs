@@ -0,0 +1,264 @@
+//! Verify the signatures of requests with the public key of an application.
+
+use ed25519_dalek::{PublicKey as DalekPublicKey, Signature, Verifier};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Parsing a [`PublicKey`] failed.
+#[derive(Debug)]
+pub struct PublicKeyError {
+    kind: PublicKeyErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PublicKeyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PublicKeyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PublicKeyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for PublicKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PublicKeyErrorType::Decoding => f.write_str("public key is not valid hex"),
+            PublicKeyErrorType::Invalid => f.write_str("public key is not a valid ed25519 key"),
+        }
+    }
+}
+
+impl Error for PublicKeyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PublicKeyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PublicKeyErrorType {
+    /// Public key is not valid hex.
+    Decoding,
+    /// Public key is not a valid ed25519 public key.
+    Invalid,
+}
+
+/// Verifying the signature of a request failed.
+#[derive(Debug)]
+pub struct VerifyError {
+    kind: VerifyErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl VerifyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &VerifyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (VerifyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            VerifyErrorType::SignatureDecoding => f.write_str("signature is not valid hex"),
+            VerifyErrorType::SignatureInvalid => f.write_str("signature is not a valid signature"),
+            VerifyErrorType::SignatureMismatch => {
+                f.write_str("signature does not match the timestamp and body")
+            }
+        }
+    }
+}
+
+impl Error for VerifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`VerifyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VerifyErrorType {
+    /// Signature is not valid hex.
+    SignatureDecoding,
+    /// Signature is not a valid ed25519 signature.
+    SignatureInvalid,
+    /// Signature was not made by the key for the timestamp and body.
+    SignatureMismatch,
+}
+
+/// Public key of an application, used to verify that requests were sent by
+/// Discord.
+///
+/// The key can be found in the "General Information" tab of the application
+/// in the developer portal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PublicKey(DalekPublicKey);
+
+impl PublicKey {
+    /// Parse a public key from its hex representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublicKeyErrorType::Decoding`] error type if the key is not
+    /// valid hex.
+    ///
+    /// Returns a [`PublicKeyErrorType::Invalid`] error type if the key is not
+    /// a valid ed25519 public key.
+    pub fn from_hex(key: impl AsRef<[u8]>) -> Result<Self, PublicKeyError> {
+        let bytes = hex::decode(key).map_err(|source| PublicKeyError {
+            kind: PublicKeyErrorType::Decoding,
+            source: Some(Box::new(source)),
+        })?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Create a public key from its raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublicKeyErrorType::Invalid`] error type if the bytes are
+    /// not a valid ed25519 public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        DalekPublicKey::from_bytes(bytes)
+            .map(Self)
+            .map_err(|source| PublicKeyError {
+                kind: PublicKeyErrorType::Invalid,
+                source: Some(Box::new(source)),
+            })
+    }
+
+    /// Verify the hex encoded signature of a request, sent in the
+    /// `X-Signature-Ed25519` header, for its timestamp, sent in the
+    /// `X-Signature-Timestamp` header, and its body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::SignatureDecoding`] error type if the
+    /// signature is not valid hex.
+    ///
+    /// Returns a [`VerifyErrorType::SignatureInvalid`] error type if the
+    /// signature is not a valid ed25519 signature.
+    ///
+    /// Returns a [`VerifyErrorType::SignatureMismatch`] error type if the
+    /// signature was not made by this key for the timestamp and body.
+    pub fn verify(
+        &self,
+        signature: impl AsRef<[u8]>,
+        timestamp: impl AsRef<[u8]>,
+        body: &[u8],
+    ) -> Result<(), VerifyError> {
+        let bytes = hex::decode(signature).map_err(|source| VerifyError {
+            kind: VerifyErrorType::SignatureDecoding,
+            source: Some(Box::new(source)),
+        })?;
+        let signature = Signature::try_from(bytes.as_slice()).map_err(|source| VerifyError {
+            kind: VerifyErrorType::SignatureInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+        let timestamp = timestamp.as_ref();
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp);
+        message.extend_from_slice(body);
+
+        self.0
+            .verify(&message, &signature)
+            .map_err(|source| VerifyError {
+                kind: VerifyErrorType::SignatureMismatch,
+                source: Some(Box::new(source)),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublicKey, PublicKeyError, PublicKeyErrorType, VerifyError, VerifyErrorType};
+    use ed25519_dalek::{Keypair, PublicKey as DalekPublicKey, SecretKey, Signer};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(PublicKey: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PublicKeyErrorType: Debug, Send, Sync);
+    assert_impl_all!(PublicKeyError: Error, Send, Sync);
+    assert_impl_all!(VerifyErrorType: Debug, Send, Sync);
+    assert_impl_all!(VerifyError: Error, Send, Sync);
+
+    fn keypair() -> Keypair {
+        let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public = DalekPublicKey::from(&secret);
+
+        Keypair { secret, public }
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let keypair = keypair();
+        let key = PublicKey::from_hex(hex::encode(keypair.public.as_bytes())).unwrap();
+        assert_eq!(PublicKey(keypair.public), key);
+
+        assert!(matches!(
+            PublicKey::from_hex("not hex").unwrap_err().kind(),
+            PublicKeyErrorType::Decoding
+        ));
+        assert!(matches!(
+            PublicKey::from_hex("abcd").unwrap_err().kind(),
+            PublicKeyErrorType::Invalid
+        ));
+    }
+
+    #[test]
+    fn test_verify() {
+        let keypair = keypair();
+        let key = PublicKey(keypair.public);
+        let signature = hex::encode(keypair.sign(b"1234{}").to_bytes());
+
+        assert!(key.verify(&signature, "1234", b"{}").is_ok());
+        assert!(matches!(
+            key.verify(&signature, "1235", b"{}").unwrap_err().kind(),
+            VerifyErrorType::SignatureMismatch
+        ));
+        assert!(matches!(
+            key.verify("zz", "1234", b"{}").unwrap_err().kind(),
+            VerifyErrorType::SignatureDecoding
+        ));
+        assert!(matches!(
+            key.verify("abcd", "1234", b"{}").unwrap_err().kind(),
+            VerifyErrorType::SignatureInvalid
+        ));
+    }
+}
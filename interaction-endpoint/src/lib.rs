@@ -0,0 +1,65 @@
+//! # twilight-interaction-endpoint
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-interaction-endpoint` receives interactions over HTTP instead of
+//! the gateway for the [`twilight-rs`] ecosystem.
+//!
+//! When an application has an interactions endpoint URL configured, Discord
+//! sends interactions to it as `POST` requests signed with the application's
+//! ed25519 key. [`InteractionEndpoint`] verifies the `X-Signature-Ed25519`
+//! and `X-Signature-Timestamp` headers of requests against the
+//! application's [`PublicKey`], answers pings, and passes all other
+//! interactions to a handler returning the [`InteractionResponse`] to send.
+//!
+//! The endpoint is a [`hyper`] service, so it can be served by a hyper
+//! server or called directly with requests, such as signed fixtures in
+//! tests.
+//!
+//! ## Examples
+//!
+//! Verify a request by hand:
+//!
+//! ```rust
+//! use twilight_interaction_endpoint::PublicKey;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let key = PublicKey::from_hex(
+//!     "ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c",
+//! )?;
+//!
+//! assert!(key.verify("00", "1234", b"{}").is_err());
+//! # Ok(()) }
+//! ```
+//!
+//! [`InteractionResponse`]: twilight_model::application::callback::InteractionResponse
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.49+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod endpoint;
+pub mod key;
+
+pub use self::{
+    endpoint::{InteractionEndpoint, MAX_BODY_LENGTH},
+    key::{PublicKey, PublicKeyError, PublicKeyErrorType, VerifyError, VerifyErrorType},
+};
@@ -0,0 +1,15 @@
+use crate::application::command::CommandOptionChoice;
+use serde::{Deserialize, Serialize};
+
+/// Data sent when responding to an [`Interaction`] of type
+/// [`ApplicationCommandAutocomplete`].
+///
+/// [`Interaction`]: crate::application::interaction::Interaction
+/// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Autocomplete {
+    /// List of suggested choices for the focused option.
+    ///
+    /// Up to 25 choices may be suggested.
+    pub choices: Vec<CommandOptionChoice>,
+}
@@ -1,21 +1,30 @@
-use crate::channel::{
-    embed::Embed,
-    message::{AllowedMentions, MessageFlags},
+use crate::{
+    application::component::Component,
+    channel::{
+        embed::Embed,
+        message::{AllowedMentions, MessageFlags},
+    },
 };
 
 use serde::{Deserialize, Serialize};
 
 /// Optional extra data sent when responding to an [`Interaction`] of type
-/// [`ApplicationCommand`].
+/// [`ApplicationCommand`] or [`MessageComponent`].
 ///
 /// This is used when intending to send a message in the response.
 ///
 /// [`Interaction`]: crate::application::interaction::Interaction
 /// [`ApplicationCommand`]: crate::application::interaction::Interaction::ApplicationCommand
+/// [`MessageComponent`]: crate::application::interaction::Interaction::MessageComponent
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct CallbackData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_mentions: Option<AllowedMentions>,
+    /// List of components to include in the message.
+    ///
+    /// When updating a message, an empty list removes its components.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -1,9 +1,12 @@
 //! Used when responding to interactions.
 
+mod autocomplete;
 mod callback_data;
 mod response_type;
 
-pub use self::{callback_data::CallbackData, response_type::ResponseType};
+pub use self::{
+    autocomplete::Autocomplete, callback_data::CallbackData, response_type::ResponseType,
+};
 
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
@@ -25,6 +28,14 @@ pub enum InteractionResponse {
     ChannelMessageWithSource(CallbackData),
     /// Acknowledges an interaction, showing a loading state.
     DeferredChannelMessageWithSource(CallbackData),
+    /// Acknowledges a message component interaction, updating the message it
+    /// is attached to later.
+    DeferredUpdateMessage,
+    /// Responds to a message component interaction by updating the message it
+    /// is attached to.
+    UpdateMessage(CallbackData),
+    /// Responds to an autocomplete interaction with suggested choices.
+    ApplicationCommandAutocompleteResult(Autocomplete),
 }
 
 impl InteractionResponse {
@@ -35,6 +46,11 @@ impl InteractionResponse {
             Self::DeferredChannelMessageWithSource(_) => {
                 ResponseType::DeferredChannelMessageWithSource
             }
+            Self::DeferredUpdateMessage => ResponseType::DeferredUpdateMessage,
+            Self::UpdateMessage(_) => ResponseType::UpdateMessage,
+            Self::ApplicationCommandAutocompleteResult(_) => {
+                ResponseType::ApplicationCommandAutocompleteResult
+            }
         }
     }
 }
@@ -45,6 +61,18 @@ impl<'de> Deserialize<'de> for InteractionResponse {
     }
 }
 
+/// Data of a response, whose type depends on the type of the response.
+///
+/// The data may be received before the type of the response, so the type of
+/// data is inferred from its fields. Autocomplete data is tried first because
+/// all of the fields of callback data are optional.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ResponseData {
+    Autocomplete(Autocomplete),
+    Callback(CallbackData),
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum ResponseField {
@@ -62,7 +90,7 @@ impl<'de> Visitor<'de> for ResponseVisitor {
     }
 
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
-        let mut data: Option<CallbackData> = None;
+        let mut data: Option<ResponseData> = None;
         let mut kind: Option<ResponseType> = None;
 
         let span = tracing::trace_span!("deserializing interaction response");
@@ -111,30 +139,55 @@ impl<'de> Visitor<'de> for ResponseVisitor {
         Ok(match kind {
             ResponseType::Pong => Self::Value::Pong,
             ResponseType::ChannelMessageWithSource => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
-
-                Self::Value::ChannelMessageWithSource(data)
+                Self::Value::ChannelMessageWithSource(callback_data(data)?)
             }
             ResponseType::DeferredChannelMessageWithSource => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
-
-                Self::Value::DeferredChannelMessageWithSource(data)
+                Self::Value::DeferredChannelMessageWithSource(callback_data(data)?)
             }
+            ResponseType::DeferredUpdateMessage => Self::Value::DeferredUpdateMessage,
+            ResponseType::UpdateMessage => Self::Value::UpdateMessage(callback_data(data)?),
+            ResponseType::ApplicationCommandAutocompleteResult => match data {
+                Some(ResponseData::Autocomplete(data)) => {
+                    Self::Value::ApplicationCommandAutocompleteResult(data)
+                }
+                Some(ResponseData::Callback(_)) => {
+                    return Err(DeError::custom("expected autocomplete data"))
+                }
+                None => return Err(DeError::missing_field("data")),
+            },
         })
     }
 }
 
+fn callback_data<E: DeError>(data: Option<ResponseData>) -> Result<CallbackData, E> {
+    match data {
+        Some(ResponseData::Callback(data)) => Ok(data),
+        Some(ResponseData::Autocomplete(_)) => Err(DeError::custom("expected callback data")),
+        None => Err(DeError::missing_field("data")),
+    }
+}
+
 impl Serialize for InteractionResponse {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
-            Self::Pong => {
+            Self::Pong | Self::DeferredUpdateMessage => {
                 let mut state = serializer.serialize_struct("InteractionResponse", 1)?;
 
                 state.serialize_field("type", &self.kind())?;
 
                 state.end()
             }
-            Self::ChannelMessageWithSource(data) | Self::DeferredChannelMessageWithSource(data) => {
+            Self::ChannelMessageWithSource(data)
+            | Self::DeferredChannelMessageWithSource(data)
+            | Self::UpdateMessage(data) => {
+                let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("data", &data)?;
+
+                state.end()
+            }
+            Self::ApplicationCommandAutocompleteResult(data) => {
                 let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
 
                 state.serialize_field("type", &self.kind())?;
@@ -148,14 +201,18 @@ impl Serialize for InteractionResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::{CallbackData, InteractionResponse};
-    use crate::channel::message::MessageFlags;
+    use super::{Autocomplete, CallbackData, InteractionResponse};
+    use crate::{
+        application::command::{CommandOptionChoice, Number},
+        channel::message::MessageFlags,
+    };
     use serde_test::Token;
 
     #[test]
     fn test_response() {
         let value = InteractionResponse::ChannelMessageWithSource(CallbackData {
             allowed_mentions: None,
+            components: None,
             content: Some("test".into()),
             embeds: Vec::new(),
             flags: Some(MessageFlags::EPHEMERAL),
@@ -187,4 +244,61 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_deferred_update_message() {
+        serde_test::assert_tokens(
+            &InteractionResponse::DeferredUpdateMessage,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(6),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_autocomplete_result() {
+        let value = InteractionResponse::ApplicationCommandAutocompleteResult(Autocomplete {
+            choices: vec![CommandOptionChoice::Number {
+                name: "one and a half".to_owned(),
+                value: Number(1.5),
+            }],
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(8),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "Autocomplete",
+                    len: 1,
+                },
+                Token::Str("choices"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "CommandOptionChoice",
+                    len: 2,
+                },
+                Token::Str("name"),
+                Token::Str("one and a half"),
+                Token::Str("value"),
+                Token::F64(1.5),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
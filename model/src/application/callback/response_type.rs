@@ -9,6 +9,9 @@ pub enum ResponseType {
     Pong = 1,
     ChannelMessageWithSource = 4,
     DeferredChannelMessageWithSource = 5,
+    DeferredUpdateMessage = 6,
+    UpdateMessage = 7,
+    ApplicationCommandAutocompleteResult = 8,
 }
 
 impl ResponseType {
@@ -17,6 +20,9 @@ impl ResponseType {
             Self::Pong => "Pong",
             Self::ChannelMessageWithSource => "ChannelMessageWithSource",
             Self::DeferredChannelMessageWithSource => "DeferredChannelMessageWithSource",
+            Self::DeferredUpdateMessage => "DeferredUpdateMessage",
+            Self::UpdateMessage => "UpdateMessage",
+            Self::ApplicationCommandAutocompleteResult => "ApplicationCommandAutocompleteResult",
         }
     }
 }
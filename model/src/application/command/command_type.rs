@@ -0,0 +1,81 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of a [`Command`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Command`]: super::Command
+/// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum CommandType {
+    /// Slash command, shown when a user types `/`.
+    ChatInput = 1,
+    /// Context menu command, shown when a user right clicks on a user.
+    User = 2,
+    /// Context menu command, shown when a user right clicks on a message.
+    Message = 3,
+}
+
+impl CommandType {
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::ChatInput => "ChatInput",
+            Self::User => "User",
+            Self::Message => "Message",
+        }
+    }
+
+    /// Whether the command is a context menu command.
+    pub const fn is_context_menu(self) -> bool {
+        matches!(self, Self::User | Self::Message)
+    }
+}
+
+impl Default for CommandType {
+    fn default() -> Self {
+        Self::ChatInput
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        CommandType: Clone,
+        Copy,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&CommandType::ChatInput, &[Token::U8(1)]);
+        serde_test::assert_tokens(&CommandType::User, &[Token::U8(2)]);
+        serde_test::assert_tokens(&CommandType::Message, &[Token::U8(3)]);
+    }
+
+    #[test]
+    fn test_kinds() {
+        assert_eq!("ChatInput", CommandType::ChatInput.kind());
+        assert_eq!("User", CommandType::User.kind());
+        assert_eq!("Message", CommandType::Message.kind());
+        assert!(!CommandType::ChatInput.is_context_menu());
+        assert!(CommandType::User.is_context_menu());
+        assert!(CommandType::Message.is_context_menu());
+    }
+}
@@ -2,11 +2,17 @@
 
 pub mod permissions;
 
+mod command_type;
+mod number;
 mod option;
 
-pub use self::option::{
-    BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
-    CommandOptionType, OptionsCommandOptionData,
+pub use self::{
+    command_type::CommandType,
+    number::Number,
+    option::{
+        BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
+        CommandOptionType, NumberCommandOptionData, OptionsCommandOptionData,
+    },
 };
 
 use crate::id::{ApplicationId, CommandId, GuildId};
@@ -15,8 +21,11 @@ use serde::{Deserialize, Serialize};
 /// Data sent to discord to create a command.
 ///
 /// [`CommandOption`]s that are required must be listed before optional ones.
-/// Command names must be lower case, matching the Regex `^[\w-]{1,32}$`. Refer
-/// to [the discord docs] for more information.
+/// Names of [`CommandType::ChatInput`] commands must be lower case, matching the
+/// Regex `^[\w-]{1,32}$`, while names of context menu commands may contain
+/// spaces and upper case characters. Context menu commands have an empty
+/// description and no options. Refer to [the discord docs] for more
+/// information.
 ///
 /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#applicationcommand
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -32,6 +41,11 @@ pub struct Command {
     pub description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<CommandId>,
+    /// Type of the command.
+    ///
+    /// Defaults to [`CommandType::ChatInput`] if not present.
+    #[serde(default, rename = "type")]
+    pub kind: CommandType,
     #[serde(default)]
     pub options: Vec<CommandOption>,
 }
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+/// Floating point number used by [`CommandOption::Number`] options.
+///
+/// Numbers are compared and hashed by their bit representation, so two `NaN`s
+/// with the same representation are equal. This allows types containing them
+/// to implement [`Eq`] and [`Hash`].
+///
+/// [`CommandOption::Number`]: super::CommandOption::Number
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Number(pub f64);
+
+impl Eq for Number {}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Number;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Number: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_number() {
+        serde_test::assert_tokens(&Number(2.5), &[Token::F64(2.5)]);
+        assert_eq!(Number(f64::NAN), Number(f64::NAN));
+        assert_ne!(Number(0.0), Number(-0.0));
+    }
+}
@@ -1,3 +1,4 @@
+use super::Number;
 use crate::util::is_false;
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
@@ -28,6 +29,8 @@ pub enum CommandOption {
     Channel(BaseCommandOptionData),
     Role(BaseCommandOptionData),
     Mentionable(BaseCommandOptionData),
+    Number(NumberCommandOptionData),
+    Attachment(BaseCommandOptionData),
 }
 
 impl CommandOption {
@@ -42,6 +45,8 @@ impl CommandOption {
             CommandOption::Channel(_) => CommandOptionType::Channel,
             CommandOption::Role(_) => CommandOptionType::Role,
             CommandOption::Mentionable(_) => CommandOptionType::Mentionable,
+            CommandOption::Number(_) => CommandOptionType::Number,
+            CommandOption::Attachment(_) => CommandOptionType::Attachment,
         }
     }
 
@@ -49,11 +54,13 @@ impl CommandOption {
         match self {
             CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => data.required,
             CommandOption::String(data) | CommandOption::Integer(data) => data.required,
+            CommandOption::Number(data) => data.required,
             CommandOption::Boolean(data)
             | CommandOption::User(data)
             | CommandOption::Channel(data)
             | CommandOption::Role(data)
-            | CommandOption::Mentionable(data) => data.required,
+            | CommandOption::Mentionable(data)
+            | CommandOption::Attachment(data) => data.required,
         }
     }
 }
@@ -66,9 +73,15 @@ impl<'de> Deserialize<'de> for CommandOption {
 
 #[derive(Serialize)]
 struct CommandOptionEnvelope<'ser> {
+    #[serde(skip_serializing_if = "is_false")]
+    autocomplete: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     choices: Option<&'ser [CommandOptionChoice]>,
     description: &'ser str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<Number>,
     name: &'ser str,
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'ser [CommandOption]>,
@@ -82,16 +95,33 @@ impl Serialize for CommandOption {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let envelope = match self {
             Self::SubCommand(data) | Self::SubCommandGroup(data) => CommandOptionEnvelope {
+                autocomplete: false,
                 choices: None,
                 description: data.description.as_ref(),
+                max_value: None,
+                min_value: None,
                 name: data.name.as_ref(),
                 options: Some(data.options.as_ref()),
                 required: data.required,
                 kind: self.kind(),
             },
             Self::String(data) | Self::Integer(data) => CommandOptionEnvelope {
-                choices: Some(data.choices.as_ref()),
+                autocomplete: data.autocomplete,
+                choices: choices(data.autocomplete, &data.choices),
+                description: data.description.as_ref(),
+                max_value: None,
+                min_value: None,
+                name: data.name.as_ref(),
+                options: None,
+                required: data.required,
+                kind: self.kind(),
+            },
+            Self::Number(data) => CommandOptionEnvelope {
+                autocomplete: data.autocomplete,
+                choices: choices(data.autocomplete, &data.choices),
                 description: data.description.as_ref(),
+                max_value: data.max_value,
+                min_value: data.min_value,
                 name: data.name.as_ref(),
                 options: None,
                 required: data.required,
@@ -101,9 +131,13 @@ impl Serialize for CommandOption {
             | Self::User(data)
             | Self::Channel(data)
             | Self::Role(data)
-            | Self::Mentionable(data) => CommandOptionEnvelope {
+            | Self::Mentionable(data)
+            | Self::Attachment(data) => CommandOptionEnvelope {
+                autocomplete: false,
                 choices: None,
                 description: data.description.as_ref(),
+                max_value: None,
+                min_value: None,
                 name: data.name.as_ref(),
                 options: None,
                 required: data.required,
@@ -115,11 +149,25 @@ impl Serialize for CommandOption {
     }
 }
 
+/// Choices to serialize for an option.
+///
+/// Options with autocomplete enabled can't have choices, so they are omitted.
+fn choices(autocomplete: bool, choices: &[CommandOptionChoice]) -> Option<&[CommandOptionChoice]> {
+    if autocomplete {
+        None
+    } else {
+        Some(choices)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum OptionField {
+    Autocomplete,
     Choices,
     Description,
+    MaxValue,
+    MinValue,
     Name,
     Options,
     Required,
@@ -137,9 +185,12 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
     #[allow(clippy::too_many_lines)]
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut autocomplete: Option<bool> = None;
         let mut choices: Option<Option<Vec<CommandOptionChoice>>> = None;
         let mut description: Option<String> = None;
         let mut kind: Option<CommandOptionType> = None;
+        let mut max_value: Option<Option<Number>> = None;
+        let mut min_value: Option<Option<Number>> = None;
         let mut name: Option<String> = None;
         let mut options: Option<Option<Vec<CommandOption>>> = None;
         let mut required: Option<bool> = None;
@@ -168,6 +219,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
             };
 
             match key {
+                OptionField::Autocomplete => {
+                    if autocomplete.is_some() {
+                        return Err(DeError::duplicate_field("autocomplete"));
+                    }
+
+                    autocomplete = Some(map.next_value()?);
+                }
                 OptionField::Choices => {
                     if choices.is_some() {
                         return Err(DeError::duplicate_field("choices"));
@@ -182,6 +240,20 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                     description = Some(map.next_value()?);
                 }
+                OptionField::MaxValue => {
+                    if max_value.is_some() {
+                        return Err(DeError::duplicate_field("max_value"));
+                    }
+
+                    max_value = Some(map.next_value()?);
+                }
+                OptionField::MinValue => {
+                    if min_value.is_some() {
+                        return Err(DeError::duplicate_field("min_value"));
+                    }
+
+                    min_value = Some(map.next_value()?);
+                }
                 OptionField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -224,6 +296,7 @@ impl<'de> Visitor<'de> for OptionVisitor {
             "common fields of all variants exist"
         );
 
+        let autocomplete = autocomplete.unwrap_or_default();
         let required = required.unwrap_or_default();
 
         Ok(match kind {
@@ -252,11 +325,11 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 })
             }
             CommandOptionType::String => {
-                let choices = choices
-                    .flatten()
-                    .ok_or_else(|| DeError::missing_field("choices"))?;
+                // Options with autocomplete enabled don't have choices.
+                let choices = choices.flatten().unwrap_or_default();
 
                 CommandOption::String(ChoiceCommandOptionData {
+                    autocomplete,
                     choices,
                     description,
                     name,
@@ -264,11 +337,11 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 })
             }
             CommandOptionType::Integer => {
-                let choices = choices
-                    .flatten()
-                    .ok_or_else(|| DeError::missing_field("choices"))?;
+                // Options with autocomplete enabled don't have choices.
+                let choices = choices.flatten().unwrap_or_default();
 
                 CommandOption::Integer(ChoiceCommandOptionData {
+                    autocomplete,
                     choices,
                     description,
                     name,
@@ -300,13 +373,32 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 name,
                 required,
             }),
+            CommandOptionType::Number => {
+                let choices = choices.flatten().unwrap_or_default();
+
+                CommandOption::Number(NumberCommandOptionData {
+                    autocomplete,
+                    choices,
+                    description,
+                    max_value: max_value.flatten(),
+                    min_value: min_value.flatten(),
+                    name,
+                    required,
+                })
+            }
+            CommandOptionType::Attachment => CommandOption::Attachment(BaseCommandOptionData {
+                description,
+                name,
+                required,
+            }),
         })
     }
 }
 
 /// Data supplied to a [`CommandOption`] of type [`Boolean`], [`User`],
-/// [`Channel`], [`Role`], or [`Mentionable`].
+/// [`Channel`], [`Role`], [`Mentionable`], or [`Attachment`].
 ///
+/// [`Attachment`]: CommandOption::Attachment
 /// [`Boolean`]: CommandOption::Boolean
 /// [`User`]: CommandOption::User
 /// [`Channel`]: CommandOption::Channel
@@ -352,6 +444,16 @@ pub struct OptionsCommandOptionData {
 /// [`Integer`]: CommandOption::Integer
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ChoiceCommandOptionData {
+    /// Whether the option's values are suggested by the application as the
+    /// user types.
+    ///
+    /// Suggestions are requested via an [`ApplicationCommandAutocomplete`]
+    /// interaction. Options with autocomplete enabled can't have [`choices`].
+    ///
+    /// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+    /// [`choices`]: Self::choices
+    #[serde(default)]
+    pub autocomplete: bool,
     /// Predetermined choices may be defined for a user to select.
     ///
     /// When completing this option, the user is prompted with a selector of all
@@ -367,6 +469,36 @@ pub struct ChoiceCommandOptionData {
     pub required: bool,
 }
 
+/// Data supplied to a [`CommandOption`] of type [`Number`].
+///
+/// [`Number`]: CommandOption::Number
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct NumberCommandOptionData {
+    /// Whether the option's values are suggested by the application as the
+    /// user types.
+    ///
+    /// Refer to [`ChoiceCommandOptionData::autocomplete`] for more
+    /// information.
+    #[serde(default)]
+    pub autocomplete: bool,
+    /// Predetermined choices may be defined for a user to select.
+    #[serde(default)]
+    pub choices: Vec<CommandOptionChoice>,
+    /// Description of the option. It must be 100 characters or less.
+    pub description: String,
+    /// Maximum value permitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<Number>,
+    /// Minimum value permitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<Number>,
+    /// Name of the option. It must be 32 characters or less.
+    pub name: String,
+    /// Whether or not the option is required to be completed by a user.
+    #[serde(default)]
+    pub required: bool,
+}
+
 /// Specifies an option that a user must choose from in a dropdown.
 ///
 /// Refer to [the discord docs] for more information.
@@ -377,6 +509,7 @@ pub struct ChoiceCommandOptionData {
 pub enum CommandOptionChoice {
     String { name: String, value: String },
     Int { name: String, value: i64 },
+    Number { name: String, value: Number },
 }
 
 /// Type of a [`CommandOption`].
@@ -394,6 +527,8 @@ pub enum CommandOptionType {
     Channel = 7,
     Role = 8,
     Mentionable = 9,
+    Number = 10,
+    Attachment = 11,
 }
 
 impl CommandOptionType {
@@ -408,6 +543,8 @@ impl CommandOptionType {
             CommandOptionType::Channel => "Channel",
             CommandOptionType::Role => "Role",
             CommandOptionType::Mentionable => "Mentionable",
+            CommandOptionType::Number => "Number",
+            CommandOptionType::Attachment => "Attachment",
         }
     }
 }
@@ -415,8 +552,9 @@ impl CommandOptionType {
 #[cfg(test)]
 mod tests {
     use super::{
-        super::Command, BaseCommandOptionData, ChoiceCommandOptionData, CommandOption,
-        CommandOptionChoice, OptionsCommandOptionData,
+        super::{Command, CommandType, Number},
+        BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
+        NumberCommandOptionData, OptionsCommandOptionData,
     };
     use crate::id::{ApplicationId, CommandId, GuildId};
     use serde_test::Token;
@@ -431,6 +569,7 @@ mod tests {
             default_permission: Some(true),
             description: "this command is a test".into(),
             id: Some(CommandId(200)),
+            kind: CommandType::ChatInput,
             options: vec![CommandOption::SubCommandGroup(OptionsCommandOptionData {
                 description: "sub group desc".into(),
                 name: "sub group name".into(),
@@ -439,6 +578,7 @@ mod tests {
                     name: "sub command name".into(),
                     options: vec![
                         CommandOption::String(ChoiceCommandOptionData {
+                            autocomplete: false,
                             choices: vec![CommandOptionChoice::String {
                                 name: "choicea".into(),
                                 value: "choice_a".into(),
@@ -448,6 +588,7 @@ mod tests {
                             required: false,
                         }),
                         CommandOption::Integer(ChoiceCommandOptionData {
+                            autocomplete: false,
                             choices: vec![CommandOptionChoice::Int {
                                 name: "choice2".into(),
                                 value: 2,
@@ -493,7 +634,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Command",
-                    len: 7,
+                    len: 8,
                 },
                 Token::Str("application_id"),
                 Token::Some,
@@ -516,6 +657,8 @@ mod tests {
                 Token::Some,
                 Token::NewtypeStruct { name: "CommandId" },
                 Token::Str("200"),
+                Token::Str("type"),
+                Token::U8(1),
                 Token::Str("options"),
                 Token::Seq { len: Some(1) },
                 Token::Struct {
@@ -658,4 +801,64 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_command_option_number_autocomplete() {
+        let value = vec![
+            CommandOption::Number(NumberCommandOptionData {
+                autocomplete: true,
+                choices: Vec::new(),
+                description: "number desc".into(),
+                max_value: Some(Number(10.5)),
+                min_value: Some(Number(0.5)),
+                name: "number".into(),
+                required: true,
+            }),
+            CommandOption::Attachment(BaseCommandOptionData {
+                description: "attachment desc".into(),
+                name: "attachment".into(),
+                required: false,
+            }),
+        ];
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "CommandOptionEnvelope",
+                    len: 7,
+                },
+                Token::Str("autocomplete"),
+                Token::Bool(true),
+                Token::Str("description"),
+                Token::Str("number desc"),
+                Token::Str("max_value"),
+                Token::Some,
+                Token::F64(10.5),
+                Token::Str("min_value"),
+                Token::Some,
+                Token::F64(0.5),
+                Token::Str("name"),
+                Token::Str("number"),
+                Token::Str("required"),
+                Token::Bool(true),
+                Token::Str("type"),
+                Token::U8(10),
+                Token::StructEnd,
+                Token::Struct {
+                    name: "CommandOptionEnvelope",
+                    len: 3,
+                },
+                Token::Str("description"),
+                Token::Str("attachment desc"),
+                Token::Str("name"),
+                Token::Str("attachment"),
+                Token::Str("type"),
+                Token::U8(11),
+                Token::StructEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,17 @@
+use super::Component;
+
+/// Non-interactive container of other components.
+///
+/// Action rows can't contain other action rows, and either contain up to 5
+/// [`Button`]s or a single [`SelectMenu`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Button`]: super::Button
+/// [`SelectMenu`]: super::SelectMenu
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#action-rows
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ActionRow {
+    /// List of components in the action row.
+    pub components: Vec<Component>,
+}
@@ -0,0 +1,525 @@
+//! Interactive message elements for use with [`Interaction`]s.
+//!
+//! Refer to [the discord docs] for more information.
+//!
+//! [`Interaction`]: crate::application::interaction::Interaction
+//! [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+
+mod action_row;
+mod button;
+mod component_type;
+mod select_menu;
+
+pub use self::{
+    action_row::ActionRow,
+    button::{Button, ButtonStyle},
+    component_type::ComponentType,
+    select_menu::{SelectMenu, SelectMenuOption},
+};
+
+use crate::channel::ReactionType;
+use serde::{
+    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Interactive message element.
+///
+/// Messages can contain up to 5 [`ActionRow`]s, which contain the other types
+/// of components.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#component-object
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Component {
+    ActionRow(ActionRow),
+    Button(Button),
+    SelectMenu(SelectMenu),
+}
+
+impl Component {
+    /// Type of component that this is.
+    pub const fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(_) => ComponentType::ActionRow,
+            Self::Button(_) => ComponentType::Button,
+            Self::SelectMenu(_) => ComponentType::SelectMenu,
+        }
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(action_row: ActionRow) -> Self {
+        Self::ActionRow(action_row)
+    }
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Self::Button(button)
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(select_menu: SelectMenu) -> Self {
+        Self::SelectMenu(select_menu)
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ComponentVisitor)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ComponentField {
+    Components,
+    CustomId,
+    Disabled,
+    Emoji,
+    Label,
+    MaxValues,
+    MinValues,
+    Options,
+    Placeholder,
+    Style,
+    Type,
+    Url,
+}
+
+struct ComponentVisitor;
+
+impl<'de> Visitor<'de> for ComponentVisitor {
+    type Value = Component;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("struct Component")
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut components: Option<Vec<Component>> = None;
+        let mut custom_id: Option<Option<String>> = None;
+        let mut disabled: Option<bool> = None;
+        let mut emoji: Option<Option<ReactionType>> = None;
+        let mut kind: Option<ComponentType> = None;
+        let mut label: Option<Option<String>> = None;
+        let mut max_values: Option<Option<u8>> = None;
+        let mut min_values: Option<Option<u8>> = None;
+        let mut options: Option<Vec<SelectMenuOption>> = None;
+        let mut placeholder: Option<Option<String>> = None;
+        let mut style: Option<ButtonStyle> = None;
+        let mut url: Option<Option<String>> = None;
+
+        let span = tracing::trace_span!("deserializing component");
+        let _span_enter = span.enter();
+
+        loop {
+            let span_child = tracing::trace_span!("iterating over component");
+            let _span_child_enter = span_child.enter();
+
+            let key = match map.next_key() {
+                Ok(Some(key)) => {
+                    tracing::trace!(?key, "found key");
+
+                    key
+                }
+                Ok(None) => break,
+                Err(why) => {
+                    // Encountered when we run into an unknown key.
+                    map.next_value::<IgnoredAny>()?;
+
+                    tracing::trace!("ran into an unknown key: {:?}", why);
+
+                    continue;
+                }
+            };
+
+            match key {
+                ComponentField::Components => {
+                    if components.is_some() {
+                        return Err(DeError::duplicate_field("components"));
+                    }
+
+                    components = Some(map.next_value()?);
+                }
+                ComponentField::CustomId => {
+                    if custom_id.is_some() {
+                        return Err(DeError::duplicate_field("custom_id"));
+                    }
+
+                    custom_id = Some(map.next_value()?);
+                }
+                ComponentField::Disabled => {
+                    if disabled.is_some() {
+                        return Err(DeError::duplicate_field("disabled"));
+                    }
+
+                    disabled = Some(map.next_value()?);
+                }
+                ComponentField::Emoji => {
+                    if emoji.is_some() {
+                        return Err(DeError::duplicate_field("emoji"));
+                    }
+
+                    emoji = Some(map.next_value()?);
+                }
+                ComponentField::Label => {
+                    if label.is_some() {
+                        return Err(DeError::duplicate_field("label"));
+                    }
+
+                    label = Some(map.next_value()?);
+                }
+                ComponentField::MaxValues => {
+                    if max_values.is_some() {
+                        return Err(DeError::duplicate_field("max_values"));
+                    }
+
+                    max_values = Some(map.next_value()?);
+                }
+                ComponentField::MinValues => {
+                    if min_values.is_some() {
+                        return Err(DeError::duplicate_field("min_values"));
+                    }
+
+                    min_values = Some(map.next_value()?);
+                }
+                ComponentField::Options => {
+                    if options.is_some() {
+                        return Err(DeError::duplicate_field("options"));
+                    }
+
+                    options = Some(map.next_value()?);
+                }
+                ComponentField::Placeholder => {
+                    if placeholder.is_some() {
+                        return Err(DeError::duplicate_field("placeholder"));
+                    }
+
+                    placeholder = Some(map.next_value()?);
+                }
+                ComponentField::Style => {
+                    if style.is_some() {
+                        return Err(DeError::duplicate_field("style"));
+                    }
+
+                    style = Some(map.next_value()?);
+                }
+                ComponentField::Type => {
+                    if kind.is_some() {
+                        return Err(DeError::duplicate_field("type"));
+                    }
+
+                    kind = Some(map.next_value()?);
+                }
+                ComponentField::Url => {
+                    if url.is_some() {
+                        return Err(DeError::duplicate_field("url"));
+                    }
+
+                    url = Some(map.next_value()?);
+                }
+            }
+        }
+
+        let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
+
+        tracing::trace!(?kind, "common fields of all variants exist");
+
+        Ok(match kind {
+            ComponentType::ActionRow => {
+                let components = components.ok_or_else(|| DeError::missing_field("components"))?;
+
+                Self::Value::ActionRow(ActionRow { components })
+            }
+            ComponentType::Button => {
+                let style = style.ok_or_else(|| DeError::missing_field("style"))?;
+
+                Self::Value::Button(Button {
+                    custom_id: custom_id.unwrap_or_default(),
+                    disabled: disabled.unwrap_or_default(),
+                    emoji: emoji.unwrap_or_default(),
+                    label: label.unwrap_or_default(),
+                    style,
+                    url: url.unwrap_or_default(),
+                })
+            }
+            ComponentType::SelectMenu => {
+                let custom_id = custom_id
+                    .flatten()
+                    .ok_or_else(|| DeError::missing_field("custom_id"))?;
+                let options = options.ok_or_else(|| DeError::missing_field("options"))?;
+
+                Self::Value::SelectMenu(SelectMenu {
+                    custom_id,
+                    disabled: disabled.unwrap_or_default(),
+                    max_values: max_values.unwrap_or_default(),
+                    min_values: min_values.unwrap_or_default(),
+                    options,
+                    placeholder: placeholder.unwrap_or_default(),
+                })
+            }
+        })
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(action_row) => {
+                let mut state = serializer.serialize_struct("Component", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("components", &action_row.components)?;
+
+                state.end()
+            }
+            Self::Button(button) => {
+                let len = 2
+                    + usize::from(button.custom_id.is_some())
+                    + usize::from(button.disabled)
+                    + usize::from(button.emoji.is_some())
+                    + usize::from(button.label.is_some())
+                    + usize::from(button.url.is_some());
+                let mut state = serializer.serialize_struct("Component", len)?;
+
+                state.serialize_field("type", &self.kind())?;
+
+                if button.custom_id.is_some() {
+                    state.serialize_field("custom_id", &button.custom_id)?;
+                }
+
+                if button.disabled {
+                    state.serialize_field("disabled", &button.disabled)?;
+                }
+
+                if button.emoji.is_some() {
+                    state.serialize_field("emoji", &button.emoji)?;
+                }
+
+                if button.label.is_some() {
+                    state.serialize_field("label", &button.label)?;
+                }
+
+                state.serialize_field("style", &button.style)?;
+
+                if button.url.is_some() {
+                    state.serialize_field("url", &button.url)?;
+                }
+
+                state.end()
+            }
+            Self::SelectMenu(select_menu) => {
+                let len = 3
+                    + usize::from(select_menu.disabled)
+                    + usize::from(select_menu.max_values.is_some())
+                    + usize::from(select_menu.min_values.is_some())
+                    + usize::from(select_menu.placeholder.is_some());
+                let mut state = serializer.serialize_struct("Component", len)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("custom_id", &select_menu.custom_id)?;
+
+                if select_menu.disabled {
+                    state.serialize_field("disabled", &select_menu.disabled)?;
+                }
+
+                if select_menu.max_values.is_some() {
+                    state.serialize_field("max_values", &select_menu.max_values)?;
+                }
+
+                if select_menu.min_values.is_some() {
+                    state.serialize_field("min_values", &select_menu.min_values)?;
+                }
+
+                state.serialize_field("options", &select_menu.options)?;
+
+                if select_menu.placeholder.is_some() {
+                    state.serialize_field("placeholder", &select_menu.placeholder)?;
+                }
+
+                state.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu, SelectMenuOption,
+    };
+    use crate::channel::ReactionType;
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(ActionRow: components);
+    assert_fields!(Button: custom_id, disabled, emoji, label, style, url);
+    assert_fields!(
+        SelectMenu: custom_id,
+        disabled,
+        max_values,
+        min_values,
+        options,
+        placeholder
+    );
+    assert_impl_all!(Component: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_components() {
+        let value = Component::ActionRow(ActionRow {
+            components: vec![Component::Button(Button {
+                custom_id: Some("button".to_owned()),
+                disabled: false,
+                emoji: Some(ReactionType::Unicode {
+                    name: "🦀".to_owned(),
+                }),
+                label: Some("crab".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+            })],
+        });
+
+        assert_eq!(ComponentType::ActionRow, value.kind());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("custom_id"),
+                Token::Some,
+                Token::Str("button"),
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("🦀"),
+                Token::StructEnd,
+                Token::Str("label"),
+                Token::Some,
+                Token::Str("crab"),
+                Token::Str("style"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_select_menu() {
+        let value = Component::SelectMenu(SelectMenu {
+            custom_id: "menu".to_owned(),
+            disabled: true,
+            max_values: Some(2),
+            min_values: None,
+            options: vec![SelectMenuOption {
+                default: false,
+                description: None,
+                emoji: None,
+                label: "option".to_owned(),
+                value: "1".to_owned(),
+            }],
+            placeholder: None,
+        });
+
+        let tokens = |custom_id: &[Token]| {
+            let mut tokens = vec![
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("custom_id"),
+            ];
+            tokens.extend_from_slice(custom_id);
+            tokens.extend_from_slice(&[
+                Token::Str("disabled"),
+                Token::Bool(true),
+                Token::Str("max_values"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "SelectMenuOption",
+                    len: 3,
+                },
+                Token::Str("default"),
+                Token::Bool(false),
+                Token::Str("label"),
+                Token::Str("option"),
+                Token::Str("value"),
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ]);
+
+            tokens
+        };
+
+        serde_test::assert_ser_tokens(&value, &tokens(&[Token::Str("menu")]));
+        serde_test::assert_de_tokens(&value, &tokens(&[Token::Some, Token::Str("menu")]));
+    }
+
+    #[test]
+    fn test_link_button() {
+        let value = Component::Button(Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: Some("twilight".to_owned()),
+            style: ButtonStyle::Link,
+            url: Some("https://twilight.rs".to_owned()),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 4,
+                },
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("label"),
+                Token::Some,
+                Token::Str("twilight"),
+                Token::Str("style"),
+                Token::U8(5),
+                Token::Str("url"),
+                Token::Some,
+                Token::Str("https://twilight.rs"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
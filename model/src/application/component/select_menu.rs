@@ -0,0 +1,46 @@
+use crate::channel::ReactionType;
+use serde::{Deserialize, Serialize};
+
+/// Dropdown-style interactive component that renders on messages.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#select-menus
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelectMenu {
+    /// Developer defined identifier, sent in the interaction when options are
+    /// selected.
+    pub custom_id: String,
+    /// Whether the select menu is disabled.
+    pub disabled: bool,
+    /// Maximum number of options that may be chosen.
+    pub max_values: Option<u8>,
+    /// Minimum number of options that must be chosen.
+    pub min_values: Option<u8>,
+    /// List of available options.
+    pub options: Vec<SelectMenuOption>,
+    /// Custom placeholder text if no option is selected.
+    pub placeholder: Option<String>,
+}
+
+/// Option of a [`SelectMenu`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectMenuOption {
+    /// Whether the option is selected by default.
+    #[serde(default)]
+    pub default: bool,
+    /// Additional description of the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Emoji displayed with the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// User-facing name of the option.
+    pub label: String,
+    /// Developer defined value of the option.
+    pub value: String,
+}
@@ -2,8 +2,18 @@ mod resolved;
 
 pub use self::resolved::{CommandInteractionDataResolved, InteractionChannel, InteractionMember};
 
-use crate::id::CommandId;
-use serde::{Deserialize, Serialize};
+use crate::{
+    application::command::{CommandOptionType, CommandType, Number},
+    channel::Message,
+    id::{CommandId, GenericId, MessageId, UserId},
+    user::User,
+};
+use serde::{
+    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Unexpected, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
 
 /// Data received when an [`ApplicationCommand`] interaction is executed.
 ///
@@ -15,6 +25,9 @@ use serde::{Deserialize, Serialize};
 pub struct CommandData {
     /// ID of the command.
     pub id: CommandId,
+    /// Type of the command.
+    #[serde(default, rename = "type")]
+    pub kind: CommandType,
     /// Name of the command.
     pub name: String,
     /// List of parsed options specified by the user.
@@ -22,18 +35,63 @@ pub struct CommandData {
     pub options: Vec<CommandDataOption>,
     /// Data sent if any of the options are discord types.
     pub resolved: Option<CommandInteractionDataResolved>,
+    /// ID of the user or message targeted by a context menu command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<GenericId>,
+}
+
+impl CommandData {
+    /// Message targeted by a [`CommandType::Message`] command.
+    ///
+    /// Returns `None` if the command is not a message command or the message
+    /// is not present in the resolved data.
+    pub fn target_message(&self) -> Option<&Message> {
+        if self.kind != CommandType::Message {
+            return None;
+        }
+
+        let id = MessageId(self.target_id?.0);
+
+        self.resolved
+            .as_ref()?
+            .messages
+            .iter()
+            .find(|message| message.id == id)
+    }
+
+    /// User targeted by a [`CommandType::User`] command.
+    ///
+    /// Returns `None` if the command is not a user command or the user is not
+    /// present in the resolved data. The user's guild member, if any, can be
+    /// found in the resolved [`members`].
+    ///
+    /// [`members`]: CommandInteractionDataResolved::members
+    pub fn target_user(&self) -> Option<&User> {
+        if self.kind != CommandType::User {
+            return None;
+        }
+
+        let id = UserId(self.target_id?.0);
+
+        self.resolved
+            .as_ref()?
+            .users
+            .iter()
+            .find(|user| user.id == id)
+    }
 }
 
 /// Data received when a user fills in a command option.
 ///
-/// Note: user, channel, role, and mentionable option types will be returned as
-/// a [`String`] option here.
+/// The variant is determined by the `type` of the option. User, channel, role,
+/// mentionable, and attachment option types will be returned as a [`String`]
+/// option here.
 ///
 /// Refer to [the discord docs] for more information.
 ///
+/// [`String`]: Self::String
 /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-applicationcommandinteractiondataoption
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommandDataOption {
     String {
         name: String,
@@ -43,13 +101,20 @@ pub enum CommandDataOption {
         name: String,
         value: i64,
     },
+    Number {
+        name: String,
+        value: Number,
+    },
     Boolean {
         name: String,
         value: bool,
     },
     SubCommand {
         name: String,
-        #[serde(default)]
+        options: Vec<CommandDataOption>,
+    },
+    SubCommandGroup {
+        name: String,
         options: Vec<CommandDataOption>,
     },
 }
@@ -59,8 +124,10 @@ impl CommandDataOption {
         match self {
             CommandDataOption::String { .. } => "String",
             CommandDataOption::Integer { .. } => "Integer",
+            CommandDataOption::Number { .. } => "Number",
             CommandDataOption::Boolean { .. } => "Boolean",
             CommandDataOption::SubCommand { .. } => "SubCommand",
+            CommandDataOption::SubCommandGroup { .. } => "SubCommandGroup",
         }
     }
 
@@ -68,8 +135,323 @@ impl CommandDataOption {
         match self {
             Self::String { name, .. }
             | Self::Integer { name, .. }
+            | Self::Number { name, .. }
             | Self::Boolean { name, .. }
-            | Self::SubCommand { name, .. } => name,
+            | Self::SubCommand { name, .. }
+            | Self::SubCommandGroup { name, .. } => name,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CommandDataOption {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(CommandDataOptionVisitor)
+    }
+}
+
+impl Serialize for CommandDataOption {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("CommandDataOption", 3)?;
+        state.serialize_field("name", self.name())?;
+
+        match self {
+            Self::String { value, .. } => {
+                state.serialize_field("type", &CommandOptionType::String)?;
+                state.serialize_field("value", value)?;
+            }
+            Self::Integer { value, .. } => {
+                state.serialize_field("type", &CommandOptionType::Integer)?;
+                state.serialize_field("value", value)?;
+            }
+            Self::Number { value, .. } => {
+                state.serialize_field("type", &CommandOptionType::Number)?;
+                state.serialize_field("value", value)?;
+            }
+            Self::Boolean { value, .. } => {
+                state.serialize_field("type", &CommandOptionType::Boolean)?;
+                state.serialize_field("value", value)?;
+            }
+            Self::SubCommand { options, .. } => {
+                state.serialize_field("type", &CommandOptionType::SubCommand)?;
+                state.serialize_field("options", options)?;
+            }
+            Self::SubCommandGroup { options, .. } => {
+                state.serialize_field("type", &CommandOptionType::SubCommandGroup)?;
+                state.serialize_field("options", options)?;
+            }
+        }
+
+        state.end()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum CommandDataOptionField {
+    Name,
+    Options,
+    Type,
+    Value,
+}
+
+/// Value of an option, before its type is known.
+///
+/// Numbers without a fractional part are deserialized as integers, and are
+/// converted to a [`Number`] if the option's type is a number.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OptionValue {
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+impl OptionValue {
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Self::Boolean(value) => Unexpected::Bool(*value),
+            Self::Integer(value) => Unexpected::Signed(*value),
+            Self::Number(value) => Unexpected::Float(*value),
+            Self::String(value) => Unexpected::Str(value),
         }
     }
 }
+
+struct CommandDataOptionVisitor;
+
+impl<'de> Visitor<'de> for CommandDataOptionVisitor {
+    type Value = CommandDataOption;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("struct CommandDataOption")
+    }
+
+    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut kind: Option<CommandOptionType> = None;
+        let mut name: Option<String> = None;
+        let mut options: Option<Vec<CommandDataOption>> = None;
+        let mut value: Option<OptionValue> = None;
+
+        loop {
+            let key = match map.next_key() {
+                Ok(Some(key)) => key,
+                Ok(None) => break,
+                Err(why) => {
+                    map.next_value::<IgnoredAny>()?;
+
+                    tracing::trace!("ran into an unknown key: {:?}", why);
+
+                    continue;
+                }
+            };
+
+            match key {
+                CommandDataOptionField::Name => {
+                    if name.is_some() {
+                        return Err(DeError::duplicate_field("name"));
+                    }
+
+                    name = Some(map.next_value()?);
+                }
+                CommandDataOptionField::Options => {
+                    if options.is_some() {
+                        return Err(DeError::duplicate_field("options"));
+                    }
+
+                    options = Some(map.next_value()?);
+                }
+                CommandDataOptionField::Type => {
+                    if kind.is_some() {
+                        return Err(DeError::duplicate_field("type"));
+                    }
+
+                    kind = Some(map.next_value()?);
+                }
+                CommandDataOptionField::Value => {
+                    if value.is_some() {
+                        return Err(DeError::duplicate_field("value"));
+                    }
+
+                    value = Some(map.next_value()?);
+                }
+            }
+        }
+
+        let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
+        let name = name.ok_or_else(|| DeError::missing_field("name"))?;
+
+        match kind {
+            CommandOptionType::SubCommand => {
+                return Ok(CommandDataOption::SubCommand {
+                    name,
+                    options: options.unwrap_or_default(),
+                });
+            }
+            CommandOptionType::SubCommandGroup => {
+                return Ok(CommandDataOption::SubCommandGroup {
+                    name,
+                    options: options.unwrap_or_default(),
+                });
+            }
+            _ => {}
+        }
+
+        let value = value.ok_or_else(|| DeError::missing_field("value"))?;
+
+        Ok(match (kind, value) {
+            (CommandOptionType::Boolean, OptionValue::Boolean(value)) => {
+                CommandDataOption::Boolean { name, value }
+            }
+            (CommandOptionType::Integer, OptionValue::Integer(value)) => {
+                CommandDataOption::Integer { name, value }
+            }
+            #[allow(clippy::cast_precision_loss)]
+            (CommandOptionType::Number, OptionValue::Integer(value)) => CommandDataOption::Number {
+                name,
+                value: Number(value as f64),
+            },
+            (CommandOptionType::Number, OptionValue::Number(value)) => CommandDataOption::Number {
+                name,
+                value: Number(value),
+            },
+            (
+                CommandOptionType::String
+                | CommandOptionType::User
+                | CommandOptionType::Channel
+                | CommandOptionType::Role
+                | CommandOptionType::Mentionable
+                | CommandOptionType::Attachment,
+                OptionValue::String(value),
+            ) => CommandDataOption::String { name, value },
+            (kind, value) => {
+                let expected = match kind {
+                    CommandOptionType::Boolean => "a boolean",
+                    CommandOptionType::Integer => "an integer",
+                    CommandOptionType::Number => "a number",
+                    _ => "a string",
+                };
+
+                return Err(DeError::invalid_type(value.unexpected(), &expected));
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandDataOption;
+    use crate::application::command::Number;
+    use serde_test::Token;
+
+    #[test]
+    fn test_number_with_integral_value() {
+        let value = CommandDataOption::Number {
+            name: "amount".to_owned(),
+            value: Number(5.0),
+        };
+
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 3,
+                },
+                Token::Str("name"),
+                Token::Str("amount"),
+                Token::Str("type"),
+                Token::U8(10),
+                Token::Str("value"),
+                Token::I64(5),
+                Token::StructEnd,
+            ],
+        );
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 3,
+                },
+                Token::Str("name"),
+                Token::Str("amount"),
+                Token::Str("type"),
+                Token::U8(10),
+                Token::Str("value"),
+                Token::F64(5.0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_dispatch_on_type() {
+        let option =
+            serde_json::from_str::<CommandDataOption>(r#"{"name":"count","type":4,"value":5}"#)
+                .unwrap();
+        assert_eq!(
+            CommandDataOption::Integer {
+                name: "count".to_owned(),
+                value: 5,
+            },
+            option
+        );
+
+        let option =
+            serde_json::from_str::<CommandDataOption>(r#"{"value":"3","type":6,"name":"user"}"#)
+                .unwrap();
+        assert_eq!(
+            CommandDataOption::String {
+                name: "user".to_owned(),
+                value: "3".to_owned(),
+            },
+            option
+        );
+
+        let option = serde_json::from_str::<CommandDataOption>(
+            r#"{"name":"group","type":2,"options":[{"name":"sub","type":1}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            CommandDataOption::SubCommandGroup {
+                name: "group".to_owned(),
+                options: vec![CommandDataOption::SubCommand {
+                    name: "sub".to_owned(),
+                    options: Vec::new(),
+                }],
+            },
+            option
+        );
+
+        assert!(serde_json::from_str::<CommandDataOption>(
+            r#"{"name":"count","type":4,"value":5.5}"#
+        )
+        .is_err());
+        assert!(
+            serde_json::from_str::<CommandDataOption>(r#"{"name":"count","value":5}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_sub_command_group_round_trip() {
+        let value = CommandDataOption::SubCommandGroup {
+            name: "group".to_owned(),
+            options: vec![CommandDataOption::SubCommand {
+                name: "sub".to_owned(),
+                options: vec![CommandDataOption::Boolean {
+                    name: "flag".to_owned(),
+                    value: true,
+                }],
+            }],
+        };
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            r#"{"name":"group","type":2,"options":[{"name":"sub","type":1,"options":[{"name":"flag","type":5,"value":true}]}]}"#,
+            json
+        );
+        assert_eq!(value, serde_json::from_str(&json).unwrap());
+    }
+}
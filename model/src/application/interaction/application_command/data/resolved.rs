@@ -1,7 +1,7 @@
 use crate::{
-    channel::ChannelType,
+    channel::{Attachment, ChannelType, Message},
     guild::{Permissions, Role},
-    id::{ChannelId, RoleId, UserId},
+    id::{AttachmentId, ChannelId, MessageId, RoleId, UserId},
     user::User,
 };
 use serde::{
@@ -16,8 +16,10 @@ use std::{
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CommandInteractionDataResolved {
+    pub attachments: Vec<Attachment>,
     pub channels: Vec<InteractionChannel>,
     pub members: Vec<InteractionMember>,
+    pub messages: Vec<Message>,
     pub roles: Vec<Role>,
     pub users: Vec<User>,
 }
@@ -31,8 +33,10 @@ impl<'de> Deserialize<'de> for CommandInteractionDataResolved {
 impl Serialize for CommandInteractionDataResolved {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let len = vec![
+            self.attachments.is_empty(),
             self.channels.is_empty(),
             self.members.is_empty(),
+            self.messages.is_empty(),
             self.roles.is_empty(),
             self.users.is_empty(),
         ]
@@ -42,6 +46,17 @@ impl Serialize for CommandInteractionDataResolved {
 
         let mut state = serializer.serialize_struct("CommandInteractionDataResolved", len)?;
 
+        if !self.attachments.is_empty() {
+            let map: HashMap<AttachmentId, &Attachment, RandomState> = self
+                .attachments
+                .iter()
+                .map(|a| a.id)
+                .zip(self.attachments.iter())
+                .collect();
+
+            state.serialize_field("attachments", &map)?;
+        }
+
         if !self.channels.is_empty() {
             let map: HashMap<ChannelId, &InteractionChannel, RandomState> = self
                 .channels
@@ -64,6 +79,17 @@ impl Serialize for CommandInteractionDataResolved {
             state.serialize_field("members", &map)?;
         }
 
+        if !self.messages.is_empty() {
+            let map: HashMap<MessageId, &Message, RandomState> = self
+                .messages
+                .iter()
+                .map(|m| m.id)
+                .zip(self.messages.iter())
+                .collect();
+
+            state.serialize_field("messages", &map)?;
+        }
+
         if !self.roles.is_empty() {
             let map: HashMap<RoleId, &Role, RandomState> = self
                 .roles
@@ -93,8 +119,10 @@ impl Serialize for CommandInteractionDataResolved {
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum ResolvedField {
+    Attachments,
     Channels,
     Members,
+    Messages,
     Roles,
     Users,
 }
@@ -109,8 +137,10 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
     }
 
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut attachments: Option<Vec<Attachment>> = None;
         let mut channels: Option<Vec<InteractionChannel>> = None;
         let mut members: Option<Vec<InteractionMember>> = None;
+        let mut messages: Option<Vec<Message>> = None;
         let mut roles: Option<Vec<Role>> = None;
         let mut users: Option<Vec<User>> = None;
 
@@ -122,6 +152,15 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
             };
 
             match key {
+                ResolvedField::Attachments => {
+                    if attachments.is_some() {
+                        return Err(DeError::duplicate_field("attachments"));
+                    }
+
+                    let map_attachments: HashMap<AttachmentId, Attachment> = map.next_value()?;
+
+                    attachments = Some(map_attachments.into_iter().map(|(_, v)| v).collect());
+                }
                 ResolvedField::Channels => {
                     if channels.is_some() {
                         return Err(DeError::duplicate_field("channels"));
@@ -154,6 +193,15 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
                             .collect(),
                     );
                 }
+                ResolvedField::Messages => {
+                    if messages.is_some() {
+                        return Err(DeError::duplicate_field("messages"));
+                    }
+
+                    let map_messages: HashMap<MessageId, Message> = map.next_value()?;
+
+                    messages = Some(map_messages.into_iter().map(|(_, v)| v).collect());
+                }
                 ResolvedField::Roles => {
                     if roles.is_some() {
                         return Err(DeError::duplicate_field("roles"));
@@ -176,8 +224,10 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
         }
 
         Ok(CommandInteractionDataResolved {
+            attachments: attachments.unwrap_or_default(),
             channels: channels.unwrap_or_default(),
             members: members.unwrap_or_default(),
+            messages: messages.unwrap_or_default(),
             roles: roles.unwrap_or_default(),
             users: users.unwrap_or_default(),
         })
@@ -235,6 +285,7 @@ mod tests {
     #[allow(clippy::too_many_lines)]
     fn test_data_resolved() {
         let value = CommandInteractionDataResolved {
+            attachments: Vec::new(),
             channels: vec![InteractionChannel {
                 id: ChannelId(100),
                 kind: ChannelType::GuildText,
@@ -249,6 +300,7 @@ mod tests {
                 premium_since: None,
                 roles: Vec::new(),
             }],
+            messages: Vec::new(),
             roles: vec![Role {
                 color: 0,
                 hoist: true,
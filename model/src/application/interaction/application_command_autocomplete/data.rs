@@ -0,0 +1,233 @@
+use crate::{application::command::CommandOptionType, id::CommandId, util::is_false};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Data received when an [`ApplicationCommandAutocomplete`] interaction is
+/// executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+/// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#autocomplete
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationCommandAutocompleteData {
+    /// ID of the command.
+    pub id: CommandId,
+    /// Name of the command.
+    pub name: String,
+    /// List of options filled in by the user so far.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandAutocompleteDataOption>,
+}
+
+impl ApplicationCommandAutocompleteData {
+    /// Option that the user is currently filling in, searching nested
+    /// options of subcommands.
+    pub fn focused(&self) -> Option<&ApplicationCommandAutocompleteDataOption> {
+        focused(&self.options)
+    }
+}
+
+/// Option filled in by the user in an [`ApplicationCommandAutocompleteData`].
+///
+/// Options that are still being filled in may not be valid for their type, so
+/// values are provided as they were received.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationCommandAutocompleteDataOption {
+    /// Whether this is the option that the user is currently filling in.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub focused: bool,
+    /// Type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// Name of the option.
+    pub name: String,
+    /// Nested options of a subcommand or subcommand group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandAutocompleteDataOption>,
+    /// Value of the option, if it has one.
+    ///
+    /// Numbers and booleans are converted to their string representation.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_value",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub value: Option<String>,
+}
+
+fn focused(
+    options: &[ApplicationCommandAutocompleteDataOption],
+) -> Option<&ApplicationCommandAutocompleteDataOption> {
+    options.iter().find_map(|option| {
+        if option.focused {
+            Some(option)
+        } else {
+            focused(&option.options)
+        }
+    })
+}
+
+fn deserialize_value<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    deserializer.deserialize_option(OptionalValueVisitor)
+}
+
+struct OptionalValueVisitor;
+
+impl<'de> Visitor<'de> for OptionalValueVisitor {
+    type Value = Option<String>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an optional boolean, number, or string")
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor).map(Some)
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a boolean, number, or string")
+    }
+
+    fn visit_bool<E: DeError>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_f64<E: DeError>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E: DeError>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.to_owned())
+    }
+
+    fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_u64<E: DeError>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApplicationCommandAutocompleteData, ApplicationCommandAutocompleteDataOption};
+    use crate::{application::command::CommandOptionType, id::CommandId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_autocomplete_data() {
+        let value = ApplicationCommandAutocompleteData {
+            id: CommandId(1),
+            name: "search".to_owned(),
+            options: vec![ApplicationCommandAutocompleteDataOption {
+                focused: false,
+                kind: CommandOptionType::SubCommand,
+                name: "track".to_owned(),
+                options: vec![
+                    ApplicationCommandAutocompleteDataOption {
+                        focused: false,
+                        kind: CommandOptionType::Integer,
+                        name: "limit".to_owned(),
+                        options: Vec::new(),
+                        value: Some("5".to_owned()),
+                    },
+                    ApplicationCommandAutocompleteDataOption {
+                        focused: true,
+                        kind: CommandOptionType::String,
+                        name: "query".to_owned(),
+                        options: Vec::new(),
+                        value: Some("twi".to_owned()),
+                    },
+                ],
+                value: None,
+            }],
+        };
+
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteData",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("search"),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteDataOption",
+                    len: 3,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("name"),
+                Token::Str("track"),
+                Token::Str("options"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteDataOption",
+                    len: 3,
+                },
+                Token::Str("type"),
+                Token::U8(4),
+                Token::Str("name"),
+                Token::Str("limit"),
+                Token::Str("value"),
+                Token::Some,
+                Token::I64(5),
+                Token::StructEnd,
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteDataOption",
+                    len: 4,
+                },
+                Token::Str("focused"),
+                Token::Bool(true),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("name"),
+                Token::Str("query"),
+                Token::Str("value"),
+                Token::Some,
+                Token::Str("twi"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!(
+            Some("query"),
+            value.focused().map(|option| option.name.as_str())
+        );
+    }
+}
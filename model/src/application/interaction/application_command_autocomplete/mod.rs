@@ -0,0 +1,52 @@
+mod data;
+
+pub use self::data::{
+    ApplicationCommandAutocompleteData, ApplicationCommandAutocompleteDataOption,
+};
+
+use super::InteractionType;
+use crate::{
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::Serialize;
+
+/// Data present in an [`Interaction`] of type [`ApplicationCommandAutocomplete`].
+///
+/// Sent while a user is filling in an option with autocomplete enabled. It
+/// must be responded to with an [`ApplicationCommandAutocompleteResult`].
+///
+/// [`Interaction`]: super::Interaction
+/// [`ApplicationCommandAutocomplete`]: super::Interaction::ApplicationCommandAutocomplete
+/// [`ApplicationCommandAutocompleteResult`]: crate::application::callback::InteractionResponse::ApplicationCommandAutocompleteResult
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct ApplicationCommandAutocomplete {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// The channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Data from the command being filled in.
+    pub data: ApplicationCommandAutocompleteData,
+    /// ID of the guild the interaction was triggered from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Kind of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the command is used in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the command is used in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
@@ -15,6 +15,8 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 pub enum InteractionType {
     Ping = 1,
     ApplicationCommand = 2,
+    MessageComponent = 3,
+    ApplicationCommandAutocomplete = 4,
 }
 
 impl InteractionType {
@@ -22,6 +24,8 @@ impl InteractionType {
         match self {
             Self::Ping => "Ping",
             Self::ApplicationCommand => "ApplicationCommand",
+            Self::MessageComponent => "MessageComponent",
+            Self::ApplicationCommandAutocomplete => "ApplicationCommandAutocomplete",
         }
     }
 }
@@ -46,6 +50,8 @@ impl TryFrom<u8> for InteractionType {
         match i {
             1 => Ok(Self::Ping),
             2 => Ok(Self::ApplicationCommand),
+            3 => Ok(Self::MessageComponent),
+            4 => Ok(Self::ApplicationCommandAutocomplete),
             other => Err(UnknownInteractionTypeError { value: other }),
         }
     }
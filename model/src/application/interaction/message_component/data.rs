@@ -0,0 +1,23 @@
+use crate::application::component::ComponentType;
+use serde::{Deserialize, Serialize};
+
+/// Data received when a [`MessageComponent`] interaction is executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`MessageComponent`]: crate::application::interaction::Interaction::MessageComponent
+/// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-object-interaction-data-structure
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MessageComponentInteractionData {
+    /// Type of the component.
+    pub component_type: ComponentType,
+    /// User defined identifier of the component.
+    pub custom_id: String,
+    /// Values selected by the user.
+    ///
+    /// Only used by [`SelectMenu`] components.
+    ///
+    /// [`SelectMenu`]: crate::application::component::SelectMenu
+    #[serde(default)]
+    pub values: Vec<String>,
+}
@@ -0,0 +1,49 @@
+mod data;
+
+pub use self::data::MessageComponentInteractionData;
+
+use super::InteractionType;
+use crate::{
+    channel::Message,
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::Serialize;
+
+/// Data present in an [`Interaction`] of type [`MessageComponent`].
+///
+/// [`Interaction`]: super::Interaction
+/// [`MessageComponent`]: super::Interaction::MessageComponent
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct MessageComponentInteraction {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// The channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Data from the invoked component.
+    pub data: MessageComponentInteractionData,
+    /// ID of the guild the interaction was triggered from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Kind of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the component is used in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Message that the component is attached to.
+    pub message: Message,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the component is used in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
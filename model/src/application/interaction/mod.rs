@@ -1,15 +1,25 @@
 //! Used when recieving interactions through gateway or webhooks.
 
 pub mod application_command;
+pub mod application_command_autocomplete;
+pub mod message_component;
+
 mod interaction_type;
 mod ping;
 
 pub use self::{
-    application_command::ApplicationCommand, interaction_type::InteractionType, ping::Ping,
+    application_command::ApplicationCommand,
+    application_command_autocomplete::ApplicationCommandAutocomplete,
+    interaction_type::InteractionType, message_component::MessageComponentInteraction, ping::Ping,
 };
 
 use crate::{
-    application::interaction::application_command::CommandData,
+    application::interaction::{
+        application_command::CommandData,
+        application_command_autocomplete::ApplicationCommandAutocompleteData,
+        message_component::MessageComponentInteractionData,
+    },
+    channel::Message,
     guild::PartialMember,
     id::{ApplicationId, ChannelId, GuildId, InteractionId},
     user::User,
@@ -18,7 +28,10 @@ use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
-use std::fmt::{Formatter, Result as FmtResult};
+use std::{
+    convert::TryFrom,
+    fmt::{Formatter, Result as FmtResult},
+};
 
 /// Payload received when a user executes an interaction.
 ///
@@ -34,6 +47,10 @@ pub enum Interaction {
     Ping(Box<Ping>),
     /// Application command variant.
     ApplicationCommand(Box<ApplicationCommand>),
+    /// Message component variant.
+    MessageComponent(Box<MessageComponentInteraction>),
+    /// Application command autocomplete variant.
+    ApplicationCommandAutocomplete(Box<ApplicationCommandAutocomplete>),
 }
 
 impl Interaction {
@@ -41,6 +58,40 @@ impl Interaction {
         match self {
             Self::Ping(_) => None,
             Self::ApplicationCommand(inner) => inner.guild_id,
+            Self::MessageComponent(inner) => inner.guild_id,
+            Self::ApplicationCommandAutocomplete(inner) => inner.guild_id,
+        }
+    }
+}
+
+/// Data of an interaction, whose type depends on the type of the interaction.
+///
+/// The data may be received before the type of the interaction, so the type of
+/// data is inferred from its fields.
+///
+/// Autocomplete data is tried first because it has the same fields as
+/// application command data, but always has a focused option.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum InteractionData {
+    ApplicationCommandAutocomplete(FocusedAutocompleteData),
+    ApplicationCommand(CommandData),
+    MessageComponent(MessageComponentInteractionData),
+}
+
+/// Autocomplete data that has a focused option.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "ApplicationCommandAutocompleteData")]
+struct FocusedAutocompleteData(ApplicationCommandAutocompleteData);
+
+impl TryFrom<ApplicationCommandAutocompleteData> for FocusedAutocompleteData {
+    type Error = &'static str;
+
+    fn try_from(data: ApplicationCommandAutocompleteData) -> Result<Self, Self::Error> {
+        if data.focused().is_some() {
+            Ok(Self(data))
+        } else {
+            Err("autocomplete data has no focused option")
         }
     }
 }
@@ -60,6 +111,7 @@ enum InteractionField {
     GuildId,
     Id,
     Member,
+    Message,
     Token,
     Type,
     User,
@@ -78,10 +130,11 @@ impl<'de> Visitor<'de> for InteractionVisitor {
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
         let mut application_id: Option<ApplicationId> = None;
         let mut channel_id: Option<ChannelId> = None;
-        let mut data: Option<CommandData> = None;
+        let mut data: Option<InteractionData> = None;
         let mut guild_id: Option<Option<GuildId>> = None;
         let mut id: Option<InteractionId> = None;
         let mut member: Option<Option<PartialMember>> = None;
+        let mut message: Option<Message> = None;
         let mut token: Option<String> = None;
         let mut kind: Option<InteractionType> = None;
         let mut user: Option<Option<User>> = None;
@@ -153,6 +206,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     member = Some(map.next_value()?);
                 }
+                InteractionField::Message => {
+                    if message.is_some() {
+                        return Err(DeError::duplicate_field("message"));
+                    }
+
+                    message = Some(map.next_value()?);
+                }
                 InteractionField::Token => {
                     if token.is_some() {
                         return Err(DeError::duplicate_field("token"));
@@ -204,7 +264,15 @@ impl<'de> Visitor<'de> for InteractionVisitor {
             }
             InteractionType::ApplicationCommand => {
                 let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
+                let data = match data {
+                    Some(InteractionData::ApplicationCommand(data)) => data,
+                    Some(_) => {
+                        return Err(DeError::custom(
+                            "expected application command data for application command",
+                        ))
+                    }
+                    None => return Err(DeError::missing_field("data")),
+                };
 
                 let guild_id = guild_id.unwrap_or_default();
                 let member = member.unwrap_or_default();
@@ -224,6 +292,70 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     user,
                 }))
             }
+            InteractionType::MessageComponent => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = match data {
+                    Some(InteractionData::MessageComponent(data)) => data,
+                    Some(_) => {
+                        return Err(DeError::custom(
+                            "expected message component data for message component",
+                        ))
+                    }
+                    None => return Err(DeError::missing_field("data")),
+                };
+                let message = message.ok_or_else(|| DeError::missing_field("message"))?;
+
+                let guild_id = guild_id.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling message component");
+
+                Self::Value::MessageComponent(Box::new(MessageComponentInteraction {
+                    application_id,
+                    channel_id,
+                    data,
+                    guild_id,
+                    id,
+                    kind,
+                    member,
+                    message,
+                    token,
+                    user,
+                }))
+            }
+            InteractionType::ApplicationCommandAutocomplete => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = match data {
+                    Some(InteractionData::ApplicationCommandAutocomplete(data)) => data.0,
+                    Some(_) => {
+                        return Err(DeError::custom(
+                            "expected autocomplete data with a focused option for autocomplete",
+                        ))
+                    }
+                    None => return Err(DeError::missing_field("data")),
+                };
+
+                let guild_id = guild_id.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling application command autocomplete");
+
+                Self::Value::ApplicationCommandAutocomplete(Box::new(
+                    ApplicationCommandAutocomplete {
+                        application_id,
+                        channel_id,
+                        data,
+                        guild_id,
+                        id,
+                        kind,
+                        member,
+                        token,
+                        user,
+                    },
+                ))
+            }
         })
     }
 }
@@ -231,10 +363,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 #[cfg(test)]
 mod test {
     use crate::{
+        application::command::CommandType,
+        application::component::ComponentType,
         application::interaction::{
             application_command::{
                 ApplicationCommand, CommandData, CommandDataOption, CommandInteractionDataResolved,
             },
+            message_component::MessageComponentInteractionData,
             Interaction, InteractionType,
         },
         guild::{PartialMember, Permissions},
@@ -251,14 +386,17 @@ mod test {
             channel_id: ChannelId(200),
             data: CommandData {
                 id: CommandId(300),
+                kind: CommandType::ChatInput,
                 name: "command name".into(),
                 options: vec![CommandDataOption::String {
                     name: "member".into(),
                     value: "600".into(),
                 }],
                 resolved: Some(CommandInteractionDataResolved {
+                    attachments: Vec::new(),
                     channels: Vec::new(),
                     members: Vec::new(),
+                    messages: Vec::new(),
                     roles: Vec::new(),
                     users: vec![User {
                         avatar: Some("avatar string".into()),
@@ -276,6 +414,7 @@ mod test {
                         verified: None,
                     }],
                 }),
+                target_id: None,
             },
             guild_id: Some(GuildId(400)),
             id: InteractionId(500),
@@ -326,21 +465,25 @@ mod test {
                 Token::Str("data"),
                 Token::Struct {
                     name: "CommandData",
-                    len: 4,
+                    len: 5,
                 },
                 Token::Str("id"),
                 Token::NewtypeStruct { name: "CommandId" },
                 Token::Str("300"),
+                Token::Str("type"),
+                Token::U8(1),
                 Token::Str("name"),
                 Token::Str("command name"),
                 Token::Str("options"),
                 Token::Seq { len: Some(1) },
                 Token::Struct {
                     name: "CommandDataOption",
-                    len: 2,
+                    len: 3,
                 },
                 Token::Str("name"),
                 Token::Str("member"),
+                Token::Str("type"),
+                Token::U8(3),
                 Token::Str("value"),
                 Token::Str("600"),
                 Token::StructEnd,
@@ -434,4 +577,154 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn test_message_component() {
+        const JSON: &str = r#"{
+            "application_id": "100",
+            "channel_id": "200",
+            "data": {
+                "component_type": 3,
+                "custom_id": "select",
+                "values": ["a", "b"]
+            },
+            "guild_id": "400",
+            "id": "500",
+            "message": {
+                "attachments": [],
+                "author": {
+                    "avatar": null,
+                    "discriminator": "1111",
+                    "id": "100",
+                    "username": "bot"
+                },
+                "channel_id": "200",
+                "components": [{
+                    "type": 1,
+                    "components": [{
+                        "type": 3,
+                        "custom_id": "select",
+                        "options": [{"label": "A", "value": "a"}]
+                    }]
+                }],
+                "content": "pick one",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "700",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2021-01-01T00:00:00.000000+00:00",
+                "tts": false,
+                "type": 0
+            },
+            "token": "interaction token",
+            "type": 3,
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(JSON).unwrap();
+        let component = match interaction {
+            Interaction::MessageComponent(component) => component,
+            other => panic!("expected message component, got {:?}", other),
+        };
+
+        assert_eq!(
+            MessageComponentInteractionData {
+                component_type: ComponentType::SelectMenu,
+                custom_id: "select".to_owned(),
+                values: vec!["a".to_owned(), "b".to_owned()],
+            },
+            component.data
+        );
+        assert_eq!(Some(GuildId(400)), component.guild_id);
+        assert_eq!(1, component.message.components.len());
+        assert!(component.member.is_none());
+    }
+
+    #[test]
+    fn test_application_command_autocomplete() {
+        const JSON: &str = r#"{
+            "application_id": "100",
+            "channel_id": "200",
+            "data": {
+                "id": "300",
+                "name": "search",
+                "options": [
+                    {"name": "limit", "type": 4, "value": 5},
+                    {"focused": true, "name": "query", "type": 3, "value": "twi"}
+                ]
+            },
+            "id": "500",
+            "token": "interaction token",
+            "type": 4,
+            "user": {
+                "avatar": null,
+                "discriminator": "1111",
+                "id": "600",
+                "username": "username"
+            },
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(JSON).unwrap();
+        let autocomplete = match interaction {
+            Interaction::ApplicationCommandAutocomplete(autocomplete) => autocomplete,
+            other => panic!("expected autocomplete, got {:?}", other),
+        };
+
+        let focused = autocomplete.data.focused().unwrap();
+        assert_eq!("query", focused.name);
+        assert_eq!(Some("twi"), focused.value.as_deref());
+        assert_eq!(Some("5"), autocomplete.data.options[0].value.as_deref());
+        assert!(autocomplete.guild_id.is_none());
+    }
+
+    #[test]
+    fn test_user_command() {
+        const JSON: &str = r#"{
+            "application_id": "100",
+            "channel_id": "200",
+            "data": {
+                "id": "300",
+                "name": "High Five",
+                "resolved": {
+                    "users": {
+                        "700": {
+                            "avatar": null,
+                            "discriminator": "2222",
+                            "id": "700",
+                            "username": "target"
+                        }
+                    }
+                },
+                "target_id": "700",
+                "type": 2
+            },
+            "id": "500",
+            "token": "interaction token",
+            "type": 2,
+            "user": {
+                "avatar": null,
+                "discriminator": "1111",
+                "id": "600",
+                "username": "username"
+            },
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(JSON).unwrap();
+        let command = match interaction {
+            Interaction::ApplicationCommand(command) => command,
+            other => panic!("expected application command, got {:?}", other),
+        };
+
+        assert_eq!(CommandType::User, command.data.kind);
+        assert_eq!(
+            Some(UserId(700)),
+            command.data.target_user().map(|user| user.id)
+        );
+        assert!(command.data.target_message().is_none());
+    }
 }
@@ -2,6 +2,7 @@ pub mod embed;
 pub mod message;
 pub mod permission_overwrite;
 pub mod stage_instance;
+pub mod thread;
 pub mod webhook;
 
 mod attachment;
@@ -14,6 +15,7 @@ mod private_channel;
 mod reaction;
 mod reaction_type;
 mod text_channel;
+mod thread_channel;
 mod video_quality_mode;
 mod voice_channel;
 
@@ -30,12 +32,14 @@ pub use self::{
     reaction_type::ReactionType,
     stage_instance::StageInstance,
     text_channel::TextChannel,
+    thread_channel::ThreadChannel,
     video_quality_mode::VideoQualityMode,
     voice_channel::VoiceChannel,
     webhook::{Webhook, WebhookType},
 };
 
-use crate::id::{ChannelId, GuildId, MessageId};
+use self::thread::ThreadMember;
+use crate::id::{ChannelId, GuildId, MessageId, UserId};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
@@ -108,6 +112,7 @@ pub enum GuildChannel {
     Text(TextChannel),
     Voice(VoiceChannel),
     Stage(VoiceChannel),
+    Thread(ThreadChannel),
 }
 
 impl GuildChannel {
@@ -118,6 +123,7 @@ impl GuildChannel {
             Self::Text(text) => text.guild_id,
             Self::Voice(voice) => voice.guild_id,
             Self::Stage(stage) => stage.guild_id,
+            Self::Thread(thread) => thread.guild_id,
         }
     }
 
@@ -128,6 +134,7 @@ impl GuildChannel {
             Self::Text(text) => text.id,
             Self::Voice(voice) => voice.id,
             Self::Stage(stage) => stage.id,
+            Self::Thread(thread) => thread.id,
         }
     }
 
@@ -137,6 +144,7 @@ impl GuildChannel {
             Self::Category(c) => c.kind,
             Self::Text(c) => c.kind,
             Self::Stage(c) | Self::Voice(c) => c.kind,
+            Self::Thread(c) => c.kind,
         }
     }
 
@@ -147,6 +155,7 @@ impl GuildChannel {
             Self::Text(text) => text.name.as_ref(),
             Self::Voice(voice) => voice.name.as_ref(),
             Self::Stage(stage) => stage.name.as_ref(),
+            Self::Thread(thread) => thread.name.as_ref(),
         }
     }
 }
@@ -159,12 +168,17 @@ enum GuildChannelField {
     Id,
     LastMessageId,
     LastPinTimestamp,
+    Member,
+    MemberCount,
+    MessageCount,
     Name,
     Nsfw,
+    OwnerId,
     ParentId,
     PermissionOverwrites,
     Position,
     RateLimitPerUser,
+    ThreadMetadata,
     Topic,
     Type,
     UserLimit,
@@ -185,6 +199,9 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         const VARIANTS: &[&str] = &[
             "GuildCategory",
             "GuildNews",
+            "GuildNewsThread",
+            "GuildPrivateThread",
+            "GuildPublicThread",
             "GuildStore",
             "GuildText",
             "GuildVoice",
@@ -193,15 +210,20 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let mut bitrate = None;
         let mut guild_id = None;
         let mut id = None;
-        let mut kind = None;
+        let mut kind: Option<ChannelType> = None;
         let mut last_message_id: Option<Option<MessageId>> = None;
         let mut last_pin_timestamp: Option<Option<String>> = None;
+        let mut member: Option<Option<ThreadMember>> = None;
+        let mut member_count = None;
+        let mut message_count = None;
         let mut name = None;
         let mut nsfw = None;
+        let mut owner_id: Option<Option<UserId>> = None;
         let mut parent_id: Option<Option<ChannelId>> = None;
         let mut permission_overwrites = None;
         let mut position = None;
         let mut rate_limit_per_user = None;
+        let mut thread_metadata = None;
         let mut topic: Option<Option<String>> = None;
         let mut user_limit = None;
         let mut video_quality_mode = None;
@@ -273,6 +295,27 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     last_pin_timestamp = Some(map.next_value()?);
                 }
+                GuildChannelField::Member => {
+                    if member.is_some() {
+                        return Err(DeError::duplicate_field("member"));
+                    }
+
+                    member = Some(map.next_value()?);
+                }
+                GuildChannelField::MemberCount => {
+                    if member_count.is_some() {
+                        return Err(DeError::duplicate_field("member_count"));
+                    }
+
+                    member_count = Some(map.next_value()?);
+                }
+                GuildChannelField::MessageCount => {
+                    if message_count.is_some() {
+                        return Err(DeError::duplicate_field("message_count"));
+                    }
+
+                    message_count = Some(map.next_value()?);
+                }
                 GuildChannelField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -287,6 +330,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     nsfw = Some(map.next_value()?);
                 }
+                GuildChannelField::OwnerId => {
+                    if owner_id.is_some() {
+                        return Err(DeError::duplicate_field("owner_id"));
+                    }
+
+                    owner_id = Some(map.next_value()?);
+                }
                 GuildChannelField::ParentId => {
                     if parent_id.is_some() {
                         return Err(DeError::duplicate_field("parent_id"));
@@ -315,6 +365,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     rate_limit_per_user = map.next_value::<Option<u64>>()?;
                 }
+                GuildChannelField::ThreadMetadata => {
+                    if thread_metadata.is_some() {
+                        return Err(DeError::duplicate_field("thread_metadata"));
+                    }
+
+                    thread_metadata = Some(map.next_value()?);
+                }
                 GuildChannelField::Topic => {
                     if topic.is_some() {
                         return Err(DeError::duplicate_field("topic"));
@@ -344,12 +401,57 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let id = id.ok_or_else(|| DeError::missing_field("id"))?;
         let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
         let name = name.ok_or_else(|| DeError::missing_field("name"))?;
+        let parent_id = parent_id.unwrap_or_default();
+
+        // Threads don't have the permission overwrites or position of other
+        // guild channels.
+        if kind.is_thread() {
+            let member_count =
+                member_count.ok_or_else(|| DeError::missing_field("member_count"))?;
+            let message_count =
+                message_count.ok_or_else(|| DeError::missing_field("message_count"))?;
+            let thread_metadata =
+                thread_metadata.ok_or_else(|| DeError::missing_field("thread_metadata"))?;
+
+            let last_message_id = last_message_id.unwrap_or_default();
+            let member = member.unwrap_or_default();
+            let owner_id = owner_id.unwrap_or_default();
+
+            tracing::trace!(
+                %id,
+                ?kind,
+                ?last_message_id,
+                ?member,
+                %member_count,
+                %message_count,
+                %name,
+                ?owner_id,
+                ?parent_id,
+                ?thread_metadata,
+                "handling thread channel"
+            );
+
+            return Ok(GuildChannel::Thread(ThreadChannel {
+                guild_id,
+                id,
+                kind,
+                last_message_id,
+                member,
+                member_count,
+                message_count,
+                name,
+                owner_id,
+                parent_id,
+                rate_limit_per_user,
+                thread_metadata,
+            }));
+        }
+
         let permission_overwrites =
             permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
         let position = position.ok_or_else(|| DeError::missing_field("position"))?;
 
         let nsfw = nsfw.unwrap_or_default();
-        let parent_id = parent_id.unwrap_or_default();
 
         tracing::trace!(
             %id,
@@ -442,10 +544,13 @@ impl<'de> Deserialize<'de> for GuildChannel {
 mod tests {
     use super::{
         CategoryChannel, Channel, ChannelType, Group, GuildChannel, PrivateChannel, TextChannel,
-        VoiceChannel,
+        ThreadChannel, VoiceChannel,
     };
     use crate::{
-        channel::permission_overwrite::PermissionOverwrite,
+        channel::{
+            permission_overwrite::PermissionOverwrite,
+            thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+        },
         id::{ChannelId, GuildId, MessageId, UserId},
     };
 
@@ -807,4 +912,61 @@ mod tests {
             .unwrap()
         );
     }
+
+    #[test]
+    fn test_guild_thread_channel_deserialization() {
+        let value = GuildChannel::Thread(ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: Some(MessageId(3)),
+            member: Some(ThreadMember {
+                flags: 1,
+                id: Some(ChannelId(2)),
+                join_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                user_id: Some(UserId(4)),
+            }),
+            member_count: 5,
+            message_count: 6,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(4)),
+            parent_id: Some(ChannelId(7)),
+            rate_limit_per_user: Some(0),
+            thread_metadata: ThreadMetadata {
+                archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                invitable: None,
+                locked: false,
+            },
+        });
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "1",
+                "id": "2",
+                "last_message_id": "3",
+                "member": {
+                    "flags": 1,
+                    "id": "2",
+                    "join_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                    "user_id": "4",
+                },
+                "member_count": 5,
+                "message_count": 6,
+                "name": "thread",
+                "owner_id": "4",
+                "parent_id": "7",
+                "rate_limit_per_user": 0,
+                "thread_metadata": {
+                    "archive_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                    "archived": false,
+                    "auto_archive_duration": 1440,
+                },
+                "type": 11,
+            }))
+            .unwrap()
+        );
+    }
 }
@@ -0,0 +1,76 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::time::Duration;
+
+/// Duration of inactivity after which a thread is automatically archived.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u16)]
+pub enum AutoArchiveDuration {
+    /// One hour.
+    Hour = 60,
+    /// One day.
+    Day = 1440,
+    /// Three days.
+    ///
+    /// Requires the guild to be boosted to tier 1.
+    ThreeDays = 4320,
+    /// One week.
+    ///
+    /// Requires the guild to be boosted to tier 2.
+    Week = 10080,
+}
+
+impl AutoArchiveDuration {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Hour => "Hour",
+            Self::Day => "Day",
+            Self::ThreeDays => "ThreeDays",
+            Self::Week => "Week",
+        }
+    }
+
+    /// Number of minutes of the duration.
+    pub const fn minutes(self) -> u16 {
+        self as u16
+    }
+}
+
+impl From<AutoArchiveDuration> for Duration {
+    fn from(value: AutoArchiveDuration) -> Self {
+        Self::from_secs(u64::from(value.minutes()) * 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoArchiveDuration;
+    use serde_test::Token;
+    use std::time::Duration;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoArchiveDuration::Hour, &[Token::U16(60)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Day, &[Token::U16(1440)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::ThreeDays, &[Token::U16(4320)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Week, &[Token::U16(10080)]);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!("Hour", AutoArchiveDuration::Hour.name());
+        assert_eq!("Day", AutoArchiveDuration::Day.name());
+        assert_eq!("ThreeDays", AutoArchiveDuration::ThreeDays.name());
+        assert_eq!("Week", AutoArchiveDuration::Week.name());
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(
+            Duration::from_secs(3600),
+            Duration::from(AutoArchiveDuration::Hour)
+        );
+        assert_eq!(10080, AutoArchiveDuration::Week.minutes());
+    }
+}
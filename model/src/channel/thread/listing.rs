@@ -0,0 +1,17 @@
+use super::ThreadMember;
+use crate::channel::Channel;
+use serde::{Deserialize, Serialize};
+
+/// List of threads and the current user's membership in them.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadsListing {
+    /// Whether there are more threads that can be retrieved.
+    ///
+    /// Not present when listing active threads.
+    #[serde(default)]
+    pub has_more: bool,
+    /// Thread members of the current user for the threads it has joined.
+    pub members: Vec<ThreadMember>,
+    /// Threads in the listing.
+    pub threads: Vec<Channel>,
+}
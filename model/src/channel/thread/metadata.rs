@@ -0,0 +1,62 @@
+use super::AutoArchiveDuration;
+use serde::{Deserialize, Serialize};
+
+/// Metadata of a thread channel, which is not present on other channels.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMetadata {
+    /// When the thread's archive status was last changed.
+    pub archive_timestamp: String,
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// Duration of inactivity after which the thread is automatically
+    /// archived.
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// Whether members that aren't moderators can add other members to the
+    /// thread.
+    ///
+    /// Only present on private threads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    /// Whether the thread is locked, in which case only members with the
+    /// [`MANAGE_THREADS`] permission can unarchive it.
+    ///
+    /// [`MANAGE_THREADS`]: crate::guild::Permissions::MANAGE_THREADS
+    #[serde(default)]
+    pub locked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_metadata() {
+        let value = ThreadMetadata {
+            archive_timestamp: "2021-07-01T00:00:00.000000+00:00".to_owned(),
+            archived: false,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            invitable: None,
+            locked: true,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-07-01T00:00:00.000000+00:00"),
+                Token::Str("archived"),
+                Token::Bool(false),
+                Token::Str("auto_archive_duration"),
+                Token::U16(1440),
+                Token::Str("locked"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Types for working with thread channels and their members.
+
+mod auto_archive_duration;
+mod listing;
+mod member;
+mod metadata;
+
+pub use self::{
+    auto_archive_duration::AutoArchiveDuration, listing::ThreadsListing, member::ThreadMember,
+    metadata::ThreadMetadata,
+};
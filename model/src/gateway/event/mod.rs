@@ -135,6 +135,18 @@ pub enum Event {
     StageInstanceDelete(StageInstanceDelete),
     /// A stage instance was updated in a stage channel.
     StageInstanceUpdate(StageInstanceUpdate),
+    /// A thread was created or the current user was added to a private thread.
+    ThreadCreate(ThreadCreate),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDelete),
+    /// The active threads of a guild's channels were synced.
+    ThreadListSync(ThreadListSync),
+    /// The thread member of the current user was updated.
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    /// Members were added to or removed from a thread.
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    /// A thread was updated.
+    ThreadUpdate(ThreadUpdate),
     /// A user started typing in a channel.
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
@@ -204,6 +216,12 @@ impl Event {
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
@@ -257,6 +275,12 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             DispatchEvent::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             DispatchEvent::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
+            DispatchEvent::ThreadCreate(v) => Self::ThreadCreate(v),
+            DispatchEvent::ThreadDelete(v) => Self::ThreadDelete(v),
+            DispatchEvent::ThreadListSync(v) => Self::ThreadListSync(v),
+            DispatchEvent::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            DispatchEvent::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
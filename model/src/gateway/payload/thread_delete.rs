@@ -0,0 +1,56 @@
+use crate::{
+    channel::ChannelType,
+    id::{ChannelId, GuildId},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadDelete {
+    pub guild_id: GuildId,
+    pub id: ChannelId,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    pub parent_id: ChannelId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadDelete;
+    use crate::{
+        channel::ChannelType,
+        id::{ChannelId, GuildId},
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_delete() {
+        let value = ThreadDelete {
+            guild_id: GuildId(1),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            parent_id: ChannelId(3),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadDelete",
+                    len: 4,
+                },
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(11),
+                Token::Str("parent_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("3"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    channel::{thread::ThreadMember, GuildChannel},
+    id::{ChannelId, GuildId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Active threads of a guild were synced, such as when the current user
+/// gained access to a channel.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadListSync {
+    /// IDs of the parent channels whose threads are being synced.
+    ///
+    /// If empty then the threads of the whole guild are being synced.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    pub guild_id: GuildId,
+    /// Thread members of the current user for the threads it has joined.
+    pub members: Vec<ThreadMember>,
+    /// Active threads of the channels being synced.
+    pub threads: Vec<GuildChannel>,
+}
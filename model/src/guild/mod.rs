@@ -107,6 +107,8 @@ pub struct Guild {
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
     #[serde(default)]
+    pub threads: Vec<GuildChannel>,
+    #[serde(default)]
     pub unavailable: bool,
     pub vanity_url_code: Option<String>,
     pub verification_level: VerificationLevel,
@@ -162,6 +164,7 @@ impl<'de> Deserialize<'de> for Guild {
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
+            Threads,
             Unavailable,
             VerificationLevel,
             VoiceStates,
@@ -219,6 +222,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
+                let mut threads = None::<Vec<GuildChannel>>;
                 let mut unavailable = None;
                 let mut verification_level = None;
                 let mut voice_states = None::<Vec<VoiceState>>;
@@ -523,6 +527,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             rules_channel_id = Some(map.next_value()?);
                         }
+                        Field::Threads => {
+                            if threads.is_some() {
+                                return Err(DeError::duplicate_field("threads"));
+                            }
+
+                            threads = Some(map.next_value()?);
+                        }
                         Field::Unavailable => {
                             if unavailable.is_some() {
                                 return Err(DeError::duplicate_field("unavailable"));
@@ -614,6 +625,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let splash = splash.unwrap_or_default();
                 let stage_instances = stage_instances.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
+                let mut threads = threads.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
                 let mut voice_states = voice_states.unwrap_or_default();
@@ -662,6 +674,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?stage_instances,
                     ?system_channel_flags,
                     ?system_channel_id,
+                    ?threads,
                     ?unavailable,
                     ?vanity_url_code,
                     ?voice_states,
@@ -681,6 +694,15 @@ impl<'de> Deserialize<'de> for Guild {
                         GuildChannel::Voice(c) | GuildChannel::Stage(c) => {
                             c.guild_id.replace(id);
                         }
+                        GuildChannel::Thread(c) => {
+                            c.guild_id.replace(id);
+                        }
+                    }
+                }
+
+                for thread in &mut threads {
+                    if let GuildChannel::Thread(thread) = thread {
+                        thread.guild_id.replace(id);
                     }
                 }
 
@@ -735,6 +757,7 @@ impl<'de> Deserialize<'de> for Guild {
                     stage_instances,
                     system_channel_flags,
                     system_channel_id,
+                    threads,
                     unavailable,
                     vanity_url_code,
                     verification_level,
@@ -783,6 +806,7 @@ impl<'de> Deserialize<'de> for Guild {
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
+            "threads",
             "unavailable",
             "verification_level",
             "voice_states",
@@ -846,6 +870,7 @@ mod tests {
             stage_instances: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
             system_channel_id: Some(ChannelId(7)),
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
@@ -859,7 +884,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Guild",
-                    len: 43,
+                    len: 44,
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
@@ -969,6 +994,9 @@ mod tests {
                 Token::Some,
                 Token::NewtypeStruct { name: "ChannelId" },
                 Token::Str("7"),
+                Token::Str("threads"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("unavailable"),
                 Token::Bool(false),
                 Token::Str("vanity_url_code"),
@@ -40,6 +40,9 @@ bitflags! {
         const MANAGE_EMOJIS = 0x4000_0000;
         const USE_SLASH_COMMANDS = 0x8000_0000;
         const REQUEST_TO_SPEAK = 0x10000_0000;
+        const MANAGE_THREADS = 0x40000_0000;
+        const USE_PUBLIC_THREADS = 0x80000_0000;
+        const USE_PRIVATE_THREADS = 0x100000_0000;
     }
 }
 
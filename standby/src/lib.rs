@@ -850,6 +850,12 @@ const fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::StageInstanceCreate(e) => Some(e.0.guild_id),
         Event::StageInstanceDelete(e) => Some(e.0.guild_id),
         Event::StageInstanceUpdate(e) => Some(e.0.guild_id),
+        Event::ThreadCreate(e) => e.0.guild_id(),
+        Event::ThreadDelete(e) => Some(e.guild_id),
+        Event::ThreadListSync(e) => Some(e.guild_id),
+        Event::ThreadMemberUpdate(_) => None,
+        Event::ThreadMembersUpdate(e) => Some(e.guild_id),
+        Event::ThreadUpdate(e) => e.0.guild_id(),
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
         Event::UserUpdate(_) => None,
@@ -911,6 +917,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(1),
+            components: Vec::new(),
             content: "test".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
@@ -0,0 +1,614 @@
+//! Utilities for building URLs to images on Discord's CDN.
+//!
+//! Models only contain the hashes of images such as avatars and icons. A
+//! [`CdnUrl`] builds the URL of an image from the ID of its owner and its
+//! hash, in a [format] and [size] of choice.
+//!
+//! Animated images, whose hashes start with `a_`, are in the GIF format by
+//! default, while other images are in the PNG format by default.
+//!
+//! # Examples
+//!
+//! Build the URL of a user's avatar with a size of 256 pixels:
+//!
+//! ```
+//! use twilight_model::id::UserId;
+//! use twilight_util::cdn::CdnUrl;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let url = CdnUrl::user_avatar(UserId(123), "a_hash").size(256)?;
+//!
+//! assert_eq!(
+//!     "https://cdn.discordapp.com/avatars/123/a_hash.gif?size=256",
+//!     url.to_string(),
+//! );
+//! # Ok(()) }
+//! ```
+//!
+//! [format]: CdnUrl::format
+//! [size]: CdnUrl::size
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{ApplicationId, EmojiId, GuildId, UserId},
+    oauth::id::TeamId,
+};
+
+/// Base URL of the CDN.
+const BASE: &str = "https://cdn.discordapp.com/";
+
+/// Maximum size of an image.
+pub const SIZE_MAX: u16 = 4096;
+
+/// Minimum size of an image.
+pub const SIZE_MIN: u16 = 16;
+
+/// Error when setting the [format] or [size] of a [`CdnUrl`].
+///
+/// [format]: CdnUrl::format
+/// [size]: CdnUrl::size
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct CdnUrlError {
+    kind: CdnUrlErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CdnUrlError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CdnUrlErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CdnUrlErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CdnUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CdnUrlErrorType::FormatUnsupported { format } => {
+                f.write_str("image isn't available in the ")?;
+                f.write_str(format.name())?;
+
+                f.write_str(" format")
+            }
+            CdnUrlErrorType::SizeInvalid { size } => {
+                f.write_str("size ")?;
+                Display::fmt(size, f)?;
+                f.write_str(" isn't a power of two between ")?;
+                Display::fmt(&SIZE_MIN, f)?;
+                f.write_str(" and ")?;
+
+                Display::fmt(&SIZE_MAX, f)
+            }
+        }
+    }
+}
+
+impl Error for CdnUrlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CdnUrlError`] that occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CdnUrlErrorType {
+    /// Image isn't available in the format.
+    ///
+    /// Only animated images are available in the GIF format, and default
+    /// avatars are only available in the PNG format.
+    FormatUnsupported {
+        /// Provided format.
+        format: ImageFormat,
+    },
+    /// Size isn't a power of two between [`SIZE_MIN`] and [`SIZE_MAX`].
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+/// Format of an image.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ImageFormat {
+    /// GIF format, only available for animated images.
+    Gif,
+    /// JPEG format.
+    Jpeg,
+    /// PNG format.
+    Png,
+    /// WebP format.
+    WebP,
+}
+
+impl ImageFormat {
+    /// File extension of the format.
+    #[must_use = "retrieving the extension has no effect if left unused"]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+
+    /// Name of the format.
+    #[must_use = "retrieving the name has no effect if left unused"]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Gif => "GIF",
+            Self::Jpeg => "JPEG",
+            Self::Png => "PNG",
+            Self::WebP => "WebP",
+        }
+    }
+}
+
+/// Image on the CDN and the path to it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Route<'a> {
+    ApplicationIcon {
+        application_id: ApplicationId,
+        hash: &'a str,
+    },
+    DefaultUserAvatar {
+        discriminator: u16,
+    },
+    Emoji {
+        emoji_id: EmojiId,
+    },
+    GuildBanner {
+        guild_id: GuildId,
+        hash: &'a str,
+    },
+    GuildDiscoverySplash {
+        guild_id: GuildId,
+        hash: &'a str,
+    },
+    GuildIcon {
+        guild_id: GuildId,
+        hash: &'a str,
+    },
+    GuildSplash {
+        guild_id: GuildId,
+        hash: &'a str,
+    },
+    MemberAvatar {
+        guild_id: GuildId,
+        hash: &'a str,
+        user_id: UserId,
+    },
+    TeamIcon {
+        hash: &'a str,
+        team_id: TeamId,
+    },
+    UserAvatar {
+        hash: &'a str,
+        user_id: UserId,
+    },
+    UserBanner {
+        hash: &'a str,
+        user_id: UserId,
+    },
+}
+
+impl Display for Route<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ApplicationIcon {
+                application_id,
+                hash,
+            } => {
+                f.write_str("app-icons/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::DefaultUserAvatar { discriminator } => {
+                f.write_str("embed/avatars/")?;
+
+                Display::fmt(&(discriminator % 5), f)
+            }
+            Self::Emoji { emoji_id } => {
+                f.write_str("emojis/")?;
+
+                Display::fmt(emoji_id, f)
+            }
+            Self::GuildBanner { guild_id, hash } => {
+                f.write_str("banners/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::GuildDiscoverySplash { guild_id, hash } => {
+                f.write_str("discovery-splashes/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::GuildIcon { guild_id, hash } => {
+                f.write_str("icons/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::GuildSplash { guild_id, hash } => {
+                f.write_str("splashes/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::MemberAvatar {
+                guild_id,
+                hash,
+                user_id,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/users/")?;
+                Display::fmt(user_id, f)?;
+                f.write_str("/avatars/")?;
+
+                f.write_str(hash)
+            }
+            Self::TeamIcon { hash, team_id } => {
+                f.write_str("team-icons/")?;
+                Display::fmt(team_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::UserAvatar { hash, user_id } => {
+                f.write_str("avatars/")?;
+                Display::fmt(user_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+            Self::UserBanner { hash, user_id } => {
+                f.write_str("banners/")?;
+                Display::fmt(user_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(hash)
+            }
+        }
+    }
+}
+
+/// URL of an image on Discord's CDN.
+///
+/// The URL is built by displaying it. Refer to the [module] documentation
+/// for more information.
+///
+/// [module]: self
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CdnUrl<'a> {
+    animated: bool,
+    format: Option<ImageFormat>,
+    route: Route<'a>,
+    size: Option<u16>,
+}
+
+impl<'a> CdnUrl<'a> {
+    const fn new(route: Route<'a>, animated: bool) -> Self {
+        Self {
+            animated,
+            format: None,
+            route,
+            size: None,
+        }
+    }
+
+    fn hashed(route: Route<'a>, hash: &str) -> Self {
+        Self::new(route, hash.starts_with("a_"))
+    }
+
+    /// URL of an application's icon.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn application_icon(application_id: ApplicationId, hash: &'a str) -> Self {
+        Self::hashed(
+            Route::ApplicationIcon {
+                application_id,
+                hash,
+            },
+            hash,
+        )
+    }
+
+    /// URL of the default avatar of a user without an avatar, which depends
+    /// on the user's discriminator.
+    ///
+    /// Default avatars are only available in the PNG format.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub const fn default_user_avatar(discriminator: u16) -> Self {
+        Self::new(Route::DefaultUserAvatar { discriminator }, false)
+    }
+
+    /// URL of a custom emoji, which may be animated.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub const fn emoji(emoji_id: EmojiId, animated: bool) -> Self {
+        Self::new(Route::Emoji { emoji_id }, animated)
+    }
+
+    /// URL of a guild's banner.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn guild_banner(guild_id: GuildId, hash: &'a str) -> Self {
+        Self::hashed(Route::GuildBanner { guild_id, hash }, hash)
+    }
+
+    /// URL of a guild's discovery splash.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn guild_discovery_splash(guild_id: GuildId, hash: &'a str) -> Self {
+        Self::hashed(Route::GuildDiscoverySplash { guild_id, hash }, hash)
+    }
+
+    /// URL of a guild's icon.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn guild_icon(guild_id: GuildId, hash: &'a str) -> Self {
+        Self::hashed(Route::GuildIcon { guild_id, hash }, hash)
+    }
+
+    /// URL of a guild's invite splash.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn guild_splash(guild_id: GuildId, hash: &'a str) -> Self {
+        Self::hashed(Route::GuildSplash { guild_id, hash }, hash)
+    }
+
+    /// URL of a member's guild specific avatar.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn member_avatar(guild_id: GuildId, user_id: UserId, hash: &'a str) -> Self {
+        Self::hashed(
+            Route::MemberAvatar {
+                guild_id,
+                hash,
+                user_id,
+            },
+            hash,
+        )
+    }
+
+    /// URL of a team's icon.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn team_icon(team_id: TeamId, hash: &'a str) -> Self {
+        Self::hashed(Route::TeamIcon { hash, team_id }, hash)
+    }
+
+    /// URL of a user's avatar.
+    ///
+    /// Use [`default_user_avatar`] for users without an avatar.
+    ///
+    /// [`default_user_avatar`]: Self::default_user_avatar
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn user_avatar(user_id: UserId, hash: &'a str) -> Self {
+        Self::hashed(Route::UserAvatar { hash, user_id }, hash)
+    }
+
+    /// URL of a user's banner.
+    #[must_use = "building a URL has no effect if left unused"]
+    pub fn user_banner(user_id: UserId, hash: &'a str) -> Self {
+        Self::hashed(Route::UserBanner { hash, user_id }, hash)
+    }
+
+    /// Whether the image is animated.
+    #[must_use = "retrieving whether the image is animated has no effect if left unused"]
+    pub const fn animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Format of the image.
+    ///
+    /// Defaults to [`ImageFormat::Gif`] for animated images and
+    /// [`ImageFormat::Png`] for other images.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CdnUrlErrorType::FormatUnsupported`] error type if the
+    /// format is GIF and the image isn't animated, or if the image is a
+    /// default avatar and the format isn't PNG.
+    pub fn format(mut self, format: ImageFormat) -> Result<Self, CdnUrlError> {
+        let supported = match self.route {
+            Route::DefaultUserAvatar { .. } => format == ImageFormat::Png,
+            _ => format != ImageFormat::Gif || self.animated,
+        };
+
+        if !supported {
+            return Err(CdnUrlError {
+                kind: CdnUrlErrorType::FormatUnsupported { format },
+                source: None,
+            });
+        }
+
+        self.format.replace(format);
+
+        Ok(self)
+    }
+
+    /// Size of the image in pixels.
+    ///
+    /// The size must be a power of two between [`SIZE_MIN`] and
+    /// [`SIZE_MAX`]. Defaults to the size of the uploaded image.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CdnUrlErrorType::SizeInvalid`] error type if the size is
+    /// not a power of two or is out of range.
+    pub fn size(mut self, size: u16) -> Result<Self, CdnUrlError> {
+        if !size.is_power_of_two() || !(SIZE_MIN..=SIZE_MAX).contains(&size) {
+            return Err(CdnUrlError {
+                kind: CdnUrlErrorType::SizeInvalid { size },
+                source: None,
+            });
+        }
+
+        self.size.replace(size);
+
+        Ok(self)
+    }
+}
+
+impl Display for CdnUrl<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let format = self.format.unwrap_or(if self.animated {
+            ImageFormat::Gif
+        } else {
+            ImageFormat::Png
+        });
+
+        f.write_str(BASE)?;
+        Display::fmt(&self.route, f)?;
+        f.write_str(".")?;
+        f.write_str(format.extension())?;
+
+        if let Some(size) = self.size {
+            f.write_str("?size=")?;
+            Display::fmt(&size, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrl, CdnUrlError, CdnUrlErrorType, ImageFormat};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::{Debug, Display},
+    };
+    use twilight_model::{
+        id::{ApplicationId, EmojiId, GuildId, UserId},
+        oauth::id::TeamId,
+    };
+
+    assert_impl_all!(CdnUrl<'_>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CdnUrlErrorType: Debug, Send, Sync);
+    assert_impl_all!(CdnUrlError: Debug, Error, Send, Sync);
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_routes() {
+        let urls = [
+            (
+                CdnUrl::application_icon(ApplicationId(1), "hash"),
+                "app-icons/1/hash.png",
+            ),
+            (CdnUrl::default_user_avatar(1234), "embed/avatars/4.png"),
+            (CdnUrl::emoji(EmojiId(1), false), "emojis/1.png"),
+            (CdnUrl::emoji(EmojiId(1), true), "emojis/1.gif"),
+            (
+                CdnUrl::guild_banner(GuildId(1), "hash"),
+                "banners/1/hash.png",
+            ),
+            (
+                CdnUrl::guild_discovery_splash(GuildId(1), "hash"),
+                "discovery-splashes/1/hash.png",
+            ),
+            (
+                CdnUrl::guild_icon(GuildId(1), "a_hash"),
+                "icons/1/a_hash.gif",
+            ),
+            (
+                CdnUrl::guild_splash(GuildId(1), "hash"),
+                "splashes/1/hash.png",
+            ),
+            (
+                CdnUrl::member_avatar(GuildId(1), UserId(2), "a_hash"),
+                "guilds/1/users/2/avatars/a_hash.gif",
+            ),
+            (
+                CdnUrl::team_icon(TeamId(1), "hash"),
+                "team-icons/1/hash.png",
+            ),
+            (CdnUrl::user_avatar(UserId(1), "hash"), "avatars/1/hash.png"),
+            (
+                CdnUrl::user_banner(UserId(1), "a_hash"),
+                "banners/1/a_hash.gif",
+            ),
+        ];
+
+        for (url, path) in &urls {
+            assert_eq!(
+                format!("https://cdn.discordapp.com/{}", path),
+                url.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let url = CdnUrl::user_avatar(UserId(1), "a_hash");
+        assert!(url.animated());
+        assert_eq!(
+            "https://cdn.discordapp.com/avatars/1/a_hash.webp",
+            url.format(ImageFormat::WebP).unwrap().to_string()
+        );
+
+        let url = CdnUrl::user_avatar(UserId(1), "hash");
+        assert!(!url.animated());
+        assert_eq!(
+            "https://cdn.discordapp.com/avatars/1/hash.jpg",
+            url.clone().format(ImageFormat::Jpeg).unwrap().to_string()
+        );
+        assert!(matches!(
+            url.format(ImageFormat::Gif).unwrap_err().kind(),
+            CdnUrlErrorType::FormatUnsupported {
+                format: ImageFormat::Gif
+            }
+        ));
+
+        assert!(CdnUrl::default_user_avatar(1)
+            .format(ImageFormat::WebP)
+            .is_err());
+    }
+
+    #[test]
+    fn test_size() {
+        let url = CdnUrl::guild_icon(GuildId(1), "hash");
+
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/hash.webp?size=16",
+            url.clone()
+                .format(ImageFormat::WebP)
+                .unwrap()
+                .size(16)
+                .unwrap()
+                .to_string()
+        );
+        assert!(url.clone().size(4096).is_ok());
+
+        for size in &[0, 8, 100, 8192] {
+            assert!(matches!(
+                url.clone().size(*size).unwrap_err().kind(),
+                CdnUrlErrorType::SizeInvalid { size: s } if s == size
+            ));
+        }
+    }
+}
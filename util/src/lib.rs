@@ -7,6 +7,11 @@
 //!
 //! ## Features
 //!
+//! ### `cdn`
+//!
+//! Provides a builder for URLs to images on Discord's CDN, such as avatars,
+//! icons and emojis.
+//!
 //! ### `link`
 //!
 //! Provides implementations for parsing and formatting entities' URLs, such as
@@ -46,6 +51,10 @@
 )]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "cdn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cdn")))]
+pub mod cdn;
+
 #[cfg(feature = "link")]
 #[cfg_attr(docsrs, doc(cfg(feature = "link")))]
 pub mod link;